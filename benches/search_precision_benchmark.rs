@@ -73,6 +73,9 @@ fn bench_query_latency(c: &mut Criterion) {
                         as_of_frame: None,
                         as_of_ts: None,
                         no_sketch: false,
+                        reranker: None,
+                        filter: None,
+                        fusion: None,
                     })
                     .unwrap();
                 total += start.elapsed();
@@ -106,6 +109,9 @@ fn bench_precision(c: &mut Criterion) {
                         as_of_frame: None,
                         as_of_ts: None,
                         no_sketch: false,
+                        reranker: None,
+                        filter: None,
+                        fusion: None,
                     })
                     .unwrap();
 
@@ -149,6 +155,9 @@ fn bench_result_count(c: &mut Criterion) {
                         as_of_frame: None,
                         as_of_ts: None,
                         no_sketch: false,
+                        reranker: None,
+                        filter: None,
+                        fusion: None,
                     })
                     .unwrap();
                 let _count = results.hits.len();
@@ -97,6 +97,9 @@ fn main() -> Result<()> {
         as_of_frame: None,
         as_of_ts: None,
         no_sketch: false,
+        reranker: None,
+        filter: None,
+        fusion: None,
     };
     let response = mem.search(request)?;
     println!("   Query: 'memvid'");
@@ -126,6 +129,9 @@ fn main() -> Result<()> {
         as_of_frame: None,
         as_of_ts: None,
         no_sketch: false,
+        reranker: None,
+        filter: None,
+        fusion: None,
     };
     let response = mem.search(request)?;
     println!("   Query: 'documentation' (scope: mv2://docs/)");
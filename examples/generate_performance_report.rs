@@ -71,6 +71,9 @@ fn main() -> memvid_core::Result<()> {
                 as_of_frame: None,
                 as_of_ts: None,
                 no_sketch: false,
+                reranker: None,
+                filter: None,
+                fusion: None,
             })?;
         }
 
@@ -94,6 +97,9 @@ fn main() -> memvid_core::Result<()> {
                 as_of_frame: None,
                 as_of_ts: None,
                 no_sketch: false,
+                reranker: None,
+                filter: None,
+                fusion: None,
             })?;
 
             let terms: Vec<&str> = query.split_whitespace().collect();
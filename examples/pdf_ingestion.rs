@@ -112,6 +112,9 @@ fn main() -> Result<()> {
             as_of_frame: None,
             as_of_ts: None,
             no_sketch: false,
+            reranker: None,
+            filter: None,
+            fusion: None,
         };
 
         let response = mem.search(request)?;
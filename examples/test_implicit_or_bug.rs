@@ -53,6 +53,9 @@ fn main() -> memvid_core::Result<()> {
         as_of_frame: None,
         as_of_ts: None,
         no_sketch: false,
+        reranker: None,
+        filter: None,
+        fusion: None,
     })?;
 
     println!("ACTUAL RESULTS: {} documents found", results.hits.len());
@@ -0,0 +1,515 @@
+//! OpenAI-compatible chat completion client for answer synthesis.
+//!
+//! Works with the OpenAI API and any server exposing the same
+//! `/chat/completions` endpoint (vLLM, llama.cpp, Ollama, LM Studio, ...).
+//! Requires the `api_llm` feature.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use memvid_core::api_llm::{OpenAIChatConfig, OpenAIChatSynthesizer};
+//!
+//! // Requires OPENAI_API_KEY environment variable
+//! let synthesizer = OpenAIChatSynthesizer::new(OpenAIChatConfig::default())?;
+//! let response = mem.ask_with_synthesizer(request, None::<&MyEmbedder>, &synthesizer)?;
+//! ```
+
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+use reqwest::blocking::Client;
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE, HeaderMap, HeaderValue};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{MemvidError, Result};
+use crate::types::AskContextFragment;
+use crate::types::synthesis::{
+    AnswerSynthesizer, SynthesisConfig, SynthesizedAnswer, build_synthesis_prompt,
+    parse_cited_answer,
+};
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// Configuration for the OpenAI-compatible chat synthesizer
+#[derive(Debug, Clone)]
+pub struct OpenAIChatConfig {
+    /// Model name (e.g., "gpt-4o-mini")
+    pub model: String,
+    /// Environment variable name for API key (default: "OPENAI_API_KEY")
+    pub api_key_env: String,
+    /// Explicit API key; takes precedence over `api_key_env`
+    pub api_key: Option<String>,
+    /// Whether a missing API key is an error (disable for local servers)
+    pub require_api_key: bool,
+    /// API base URL. Default: "https://api.openai.com/v1"
+    pub base_url: String,
+    /// Request timeout in seconds
+    pub timeout_secs: u64,
+    /// Maximum retries on rate limit (429) errors
+    pub max_retries: u32,
+    /// Initial backoff in milliseconds for exponential retry
+    pub initial_backoff_ms: u64,
+    /// Sampling temperature (0.0 keeps answers as deterministic as the server allows)
+    pub temperature: f32,
+    /// Prompt construction and token budgeting settings
+    pub synthesis: SynthesisConfig,
+}
+
+impl Default for OpenAIChatConfig {
+    fn default() -> Self {
+        Self {
+            model: "gpt-4o-mini".to_string(),
+            api_key_env: "OPENAI_API_KEY".to_string(),
+            api_key: None,
+            require_api_key: true,
+            base_url: "https://api.openai.com/v1".to_string(),
+            timeout_secs: 60,
+            max_retries: 3,
+            initial_backoff_ms: 1000,
+            temperature: 0.0,
+            synthesis: SynthesisConfig::default(),
+        }
+    }
+}
+
+impl OpenAIChatConfig {
+    /// Config for a local OpenAI-compatible server that does not need an API key
+    #[must_use]
+    pub fn local(base_url: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            model: model.into(),
+            base_url: base_url.into(),
+            require_api_key: false,
+            ..Default::default()
+        }
+    }
+
+    /// Set the model name
+    #[must_use]
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    /// Set custom base URL (for Azure OpenAI, proxies or local servers)
+    #[must_use]
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into();
+        self
+    }
+
+    /// Set custom API key environment variable name
+    #[must_use]
+    pub fn with_api_key_env(mut self, env_var: impl Into<String>) -> Self {
+        self.api_key_env = env_var.into();
+        self
+    }
+
+    /// Set an explicit API key
+    #[must_use]
+    pub fn with_api_key(mut self, key: impl Into<String>) -> Self {
+        self.api_key = Some(key.into());
+        self
+    }
+
+    /// Set request timeout
+    #[must_use]
+    pub fn with_timeout(mut self, secs: u64) -> Self {
+        self.timeout_secs = secs;
+        self
+    }
+
+    /// Set prompt and budgeting settings
+    #[must_use]
+    pub fn with_synthesis(mut self, synthesis: SynthesisConfig) -> Self {
+        self.synthesis = synthesis;
+        self
+    }
+}
+
+// ============================================================================
+// API Request/Response Types
+// ============================================================================
+
+#[derive(Serialize)]
+struct ChatRequest<'a> {
+    model: &'a str,
+    messages: Vec<ChatMessage<'a>>,
+    temperature: f32,
+    max_tokens: usize,
+}
+
+#[derive(Serialize)]
+struct ChatMessage<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+#[derive(Deserialize)]
+struct ChatResponse {
+    choices: Vec<ChatChoice>,
+}
+
+#[derive(Deserialize)]
+struct ChatChoice {
+    message: ChatChoiceMessage,
+}
+
+#[derive(Deserialize)]
+struct ChatChoiceMessage {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ApiError {
+    error: ApiErrorDetail,
+}
+
+#[derive(Deserialize)]
+struct ApiErrorDetail {
+    message: String,
+    #[serde(rename = "type")]
+    error_type: Option<String>,
+}
+
+// ============================================================================
+// Chat Synthesizer
+// ============================================================================
+
+/// Answer synthesizer backed by an OpenAI-compatible chat completion endpoint
+pub struct OpenAIChatSynthesizer {
+    config: OpenAIChatConfig,
+    client: Client,
+    api_key: Option<String>,
+}
+
+impl OpenAIChatSynthesizer {
+    /// Create a new chat synthesizer
+    ///
+    /// Uses `config.api_key` when set, otherwise reads the environment variable
+    /// named by `config.api_key_env`. Returns an error if no key is available and
+    /// `config.require_api_key` is set.
+    pub fn new(config: OpenAIChatConfig) -> Result<Self> {
+        let api_key = config
+            .api_key
+            .clone()
+            .or_else(|| std::env::var(&config.api_key_env).ok())
+            .filter(|key| !key.is_empty());
+
+        if api_key.is_none() && config.require_api_key {
+            return Err(MemvidError::SynthesisFailed {
+                reason: format!(
+                    "API key not found. Set the {} environment variable.",
+                    config.api_key_env
+                )
+                .into(),
+            });
+        }
+
+        let client = Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build()
+            .map_err(|e| MemvidError::SynthesisFailed {
+                reason: format!("Failed to create HTTP client: {e}").into(),
+            })?;
+
+        tracing::info!(model = %config.model, "OpenAI chat synthesizer initialized");
+
+        Ok(Self {
+            config,
+            client,
+            api_key,
+        })
+    }
+
+    /// Access the configuration
+    #[must_use]
+    pub fn config(&self) -> &OpenAIChatConfig {
+        &self.config
+    }
+
+    /// Make a chat completion request with retry logic
+    fn request_completion(&self, system: &str, user: &str) -> Result<String> {
+        let url = format!("{}/chat/completions", self.config.base_url);
+
+        let request_body = ChatRequest {
+            model: &self.config.model,
+            messages: vec![
+                ChatMessage {
+                    role: "system",
+                    content: system,
+                },
+                ChatMessage {
+                    role: "user",
+                    content: user,
+                },
+            ],
+            temperature: self.config.temperature,
+            max_tokens: self.config.synthesis.max_answer_tokens,
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        if let Some(api_key) = self.api_key.as_deref() {
+            headers.insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {api_key}")).map_err(|_| {
+                    MemvidError::SynthesisFailed {
+                        reason: "Invalid API key format".into(),
+                    }
+                })?,
+            );
+        }
+
+        let mut backoff_ms = self.config.initial_backoff_ms;
+        let mut last_error = None;
+
+        for attempt in 0..=self.config.max_retries {
+            if attempt > 0 {
+                tracing::warn!(
+                    attempt = attempt,
+                    backoff_ms = backoff_ms,
+                    "Retrying chat completion request"
+                );
+                std::thread::sleep(Duration::from_millis(backoff_ms));
+                backoff_ms *= 2; // Exponential backoff
+            }
+
+            let response = self
+                .client
+                .post(&url)
+                .headers(headers.clone())
+                .json(&request_body)
+                .send();
+
+            match response {
+                Ok(resp) => {
+                    let status = resp.status();
+
+                    if status.is_success() {
+                        let chat_response: ChatResponse =
+                            resp.json().map_err(|e| MemvidError::SynthesisFailed {
+                                reason: format!("Failed to parse response: {e}").into(),
+                            })?;
+                        return chat_response
+                            .choices
+                            .into_iter()
+                            .next()
+                            .and_then(|choice| choice.message.content)
+                            .ok_or_else(|| MemvidError::SynthesisFailed {
+                                reason: "No completion returned".into(),
+                            });
+                    }
+
+                    // Handle rate limiting
+                    if status.as_u16() == 429 {
+                        last_error = Some(MemvidError::SynthesisFailed {
+                            reason: "Rate limit exceeded".into(),
+                        });
+                        continue;
+                    }
+
+                    // Parse error response
+                    let error_text = resp.text().unwrap_or_default();
+                    let error_msg =
+                        if let Ok(api_error) = serde_json::from_str::<ApiError>(&error_text) {
+                            format!(
+                                "Chat API error ({}): {}",
+                                api_error.error.error_type.unwrap_or_default(),
+                                api_error.error.message
+                            )
+                        } else {
+                            format!("Chat API error ({status}): {error_text}")
+                        };
+
+                    return Err(MemvidError::SynthesisFailed {
+                        reason: error_msg.into(),
+                    });
+                }
+                Err(e) => {
+                    let retryable = e.is_timeout() || e.is_connect();
+                    let error = MemvidError::SynthesisFailed {
+                        reason: format!("Request failed: {e}").into(),
+                    };
+                    if !retryable {
+                        return Err(error);
+                    }
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        // All retries exhausted
+        Err(last_error.unwrap_or_else(|| MemvidError::SynthesisFailed {
+            reason: "Max retries exceeded".into(),
+        }))
+    }
+}
+
+impl std::fmt::Debug for OpenAIChatSynthesizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OpenAIChatSynthesizer")
+            .field("model", &self.config.model)
+            .field("base_url", &self.config.base_url)
+            .field("api_key", &self.api_key.as_ref().map(|_| "[REDACTED]"))
+            .finish_non_exhaustive()
+    }
+}
+
+// ============================================================================
+// AnswerSynthesizer Implementation
+// ============================================================================
+
+impl AnswerSynthesizer for OpenAIChatSynthesizer {
+    fn kind(&self) -> &'static str {
+        "openai-chat"
+    }
+
+    fn synthesize(
+        &self,
+        question: &str,
+        fragments: &[AskContextFragment],
+    ) -> Result<Option<SynthesizedAnswer>> {
+        let prompt = build_synthesis_prompt(question, fragments, &self.config.synthesis);
+        if prompt.fragments.is_empty() {
+            return Ok(None);
+        }
+
+        let completion = self.request_completion(&prompt.system, &prompt.user)?;
+        let valid: BTreeSet<usize> = prompt.fragments.iter().map(|f| f.index).collect();
+        let answer = parse_cited_answer(&completion, &valid);
+        if answer.text.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(answer))
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AskContextFragmentKind;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    /// Serve one canned HTTP response and report the request body.
+    fn serve_once(status: &str, body: &str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind stub");
+        let addr = listener.local_addr().expect("stub addr");
+        let status = status.to_string();
+        let body = body.to_string();
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().expect("accept");
+            let mut reader = BufReader::new(stream.try_clone().expect("clone"));
+            let mut content_length = 0usize;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).expect("read header");
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().expect("length");
+                    }
+                }
+            }
+            let mut request_body = vec![0u8; content_length];
+            reader.read_exact(&mut request_body).expect("read body");
+            tx.send(String::from_utf8(request_body).expect("utf8"))
+                .expect("send body");
+            let mut stream = stream;
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .expect("write response");
+        });
+        (format!("http://{addr}/v1"), rx)
+    }
+
+    fn fragment(rank: usize, text: &str) -> AskContextFragment {
+        AskContextFragment {
+            rank,
+            frame_id: rank as u64,
+            uri: format!("mv2://doc/{rank}"),
+            title: None,
+            score: None,
+            matches: 0,
+            range: None,
+            chunk_range: None,
+            text: text.to_string(),
+            kind: Some(AskContextFragmentKind::Full),
+            #[cfg(feature = "temporal_track")]
+            temporal: None,
+        }
+    }
+
+    #[test]
+    fn test_config_defaults() {
+        let config = OpenAIChatConfig::default();
+        assert_eq!(config.model, "gpt-4o-mini");
+        assert_eq!(config.api_key_env, "OPENAI_API_KEY");
+        assert!(config.require_api_key);
+
+        let local = OpenAIChatConfig::local("http://localhost:8080/v1", "llama");
+        assert!(!local.require_api_key);
+        assert_eq!(local.model, "llama");
+    }
+
+    #[test]
+    fn test_synthesizer_requires_api_key() {
+        let config = OpenAIChatConfig::default().with_api_key_env("NONEXISTENT_API_KEY_12345");
+        let err = OpenAIChatSynthesizer::new(config).unwrap_err();
+        assert!(format!("{err:?}").contains("NONEXISTENT_API_KEY_12345"));
+    }
+
+    #[test]
+    fn test_synthesize_against_stub() {
+        let (base_url, requests) = serve_once(
+            "200 OK",
+            r#"{"choices":[{"message":{"role":"assistant","content":"Invoices are due Friday [1][5]."}}]}"#,
+        );
+        let config = OpenAIChatConfig::local(base_url, "stub-model").with_api_key("secret");
+        let synthesizer = OpenAIChatSynthesizer::new(config).unwrap();
+
+        let fragments = vec![fragment(1, "Invoices are due on Friday.")];
+        let answer = synthesizer
+            .synthesize("When are invoices due?", &fragments)
+            .unwrap()
+            .expect("answer");
+        assert_eq!(answer.text, "Invoices are due Friday [1].");
+        assert_eq!(answer.cited, vec![1]);
+
+        let body: serde_json::Value = serde_json::from_str(&requests.recv().unwrap()).unwrap();
+        assert_eq!(body["model"], "stub-model");
+        assert_eq!(body["messages"][0]["role"], "system");
+        let user = body["messages"][1]["content"].as_str().unwrap();
+        assert!(user.contains("[1] mv2://doc/1\nInvoices are due on Friday."));
+    }
+
+    #[test]
+    fn test_synthesize_surfaces_api_errors() {
+        let (base_url, _requests) = serve_once(
+            "400 Bad Request",
+            r#"{"error":{"message":"bad model","type":"invalid_request_error"}}"#,
+        );
+        let synthesizer =
+            OpenAIChatSynthesizer::new(OpenAIChatConfig::local(base_url, "missing")).unwrap();
+        let err = synthesizer
+            .synthesize("question", &[fragment(1, "context")])
+            .unwrap_err();
+        assert!(err.to_string().contains("bad model"));
+    }
+}
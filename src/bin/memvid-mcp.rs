@@ -0,0 +1,92 @@
+//! `memvid-mcp`: expose one `.mv2` memory to agents as a Model Context Protocol server.
+//!
+//! Speaks JSON-RPC over stdin/stdout (the MCP stdio transport); diagnostics go to
+//! stderr. See `memvid_core::mcp` for the tools.
+
+use memvid_core::Memvid;
+use memvid_core::mcp::McpServer;
+use std::env;
+use std::io;
+use std::path::PathBuf;
+
+fn print_usage() {
+    eprintln!("Usage: memvid-mcp <file.mv2> [--read-only] [--create] [--record [NAME]]");
+    eprintln!();
+    eprintln!("Options:");
+    eprintln!("  --read-only        Open the memory read-only; memory_put is rejected");
+    eprintln!("  --create           Create the memory (with lexical search) if it does not exist");
+    eprintln!("  --record [NAME]    Record tool calls as a replay session, saved on exit");
+}
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let mut path: Option<PathBuf> = None;
+    let mut read_only = false;
+    let mut create = false;
+    let mut record: Option<Option<String>> = None;
+
+    let mut args = env::args().skip(1).peekable();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--read-only" => read_only = true,
+            "--create" => create = true,
+            "--record" => {
+                let name = args.next_if(|next| !next.starts_with("--") && !next.ends_with(".mv2"));
+                record = Some(name);
+            }
+            "-h" | "--help" => {
+                print_usage();
+                return Ok(());
+            }
+            other if other.starts_with("--") => return Err(format!("unknown flag: {other}").into()),
+            other => path = Some(PathBuf::from(other)),
+        }
+    }
+    let Some(path) = path else {
+        print_usage();
+        std::process::exit(1);
+    };
+    if read_only && record.is_some() {
+        return Err("--record needs write access; drop --read-only".into());
+    }
+
+    let mut memvid = if create && !path.exists() {
+        let mut memvid = Memvid::create(&path)?;
+        memvid.enable_lex()?;
+        memvid.commit()?;
+        memvid
+    } else if read_only {
+        Memvid::open_read_only(&path)?
+    } else {
+        Memvid::open(&path)?
+    };
+    if let Some(name) = record.clone() {
+        memvid.load_replay_sessions()?;
+        memvid.start_session(Some(name.unwrap_or_else(|| "mcp".to_string())), None)?;
+    }
+    eprintln!("memvid-mcp: serving {} over stdio", path.display());
+
+    let mut server = McpServer::new(memvid);
+    server.run(io::stdin().lock(), io::stdout().lock())?;
+
+    if record.is_some() {
+        // Mirror the CLI: end the session, commit, save replay, then commit again.
+        let memvid = server.memvid_mut();
+        let session = memvid.end_session()?;
+        memvid.commit()?;
+        memvid.save_replay_sessions()?;
+        memvid.commit()?;
+        eprintln!(
+            "memvid-mcp: recorded {} tool calls in session {}",
+            session.actions.len(),
+            session.session_id
+        );
+    }
+    Ok(())
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("memvid-mcp: {err}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,94 @@
+//! `memvid-serve`: serve one `.mv2` memory over a local HTTP/JSON API.
+//!
+//! The memory is opened once and its lock held for the life of the process. With
+//! `--read-only` no lock is taken and the snapshot is refreshed before each request, so
+//! the server follows commits made by another writer. See `memvid_core::serve` for the
+//! routes.
+
+use memvid_core::Memvid;
+use memvid_core::serve::Server;
+use std::env;
+use std::path::PathBuf;
+
+const DEFAULT_ADDR: &str = "127.0.0.1:8700";
+
+fn print_usage() {
+    eprintln!("Usage: memvid-serve <file.mv2> [--addr HOST:PORT] [--read-only] [--create]");
+    eprintln!();
+    eprintln!("Options:");
+    eprintln!("  --addr HOST:PORT   Address to listen on (default: {DEFAULT_ADDR})");
+    eprintln!("  --read-only        Open the memory read-only; writes are rejected");
+    eprintln!("  --create           Create the memory (with lexical search) if it does not exist");
+}
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let mut path: Option<PathBuf> = None;
+    let mut addr = DEFAULT_ADDR.to_string();
+    let mut read_only = false;
+    let mut create = false;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--addr" => addr = args.next().ok_or("missing value for --addr")?,
+            "--read-only" => read_only = true,
+            "--create" => create = true,
+            "-h" | "--help" => {
+                print_usage();
+                return Ok(());
+            }
+            other if other.starts_with("--") => return Err(format!("unknown flag: {other}").into()),
+            other => path = Some(PathBuf::from(other)),
+        }
+    }
+    let Some(path) = path else {
+        print_usage();
+        std::process::exit(1);
+    };
+
+    let memvid = if create && !path.exists() {
+        let mut memvid = Memvid::create(&path)?;
+        memvid.enable_lex()?;
+        memvid.commit()?;
+        memvid
+    } else if read_only {
+        Memvid::open_read_only(&path)?
+    } else {
+        Memvid::open(&path)?
+    };
+    let mut server = Server::new(memvid);
+
+    let http =
+        tiny_http::Server::http(&addr).map_err(|err| format!("cannot listen on {addr}: {err}"))?;
+    eprintln!("memvid-serve: {} on http://{addr}", path.display());
+
+    let content_type = tiny_http::Header::from_bytes("Content-Type", "application/json")
+        .map_err(|()| "invalid content-type header")?;
+    for mut request in http.incoming_requests() {
+        let mut body = Vec::new();
+        let response = match request.as_reader().read_to_end(&mut body) {
+            Ok(_) => server.handle(request.method().as_str(), request.url(), &body),
+            Err(err) => memvid_core::serve::ApiResponse {
+                status: 400,
+                body: serde_json::json!({
+                    "error": { "kind": "BadRequest", "message": err.to_string() }
+                }),
+            },
+        };
+        let payload = serde_json::to_vec(&response.body)?;
+        let reply = tiny_http::Response::from_data(payload)
+            .with_status_code(response.status)
+            .with_header(content_type.clone());
+        if let Err(err) = request.respond(reply) {
+            eprintln!("memvid-serve: failed to send response: {err}");
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("memvid-serve: {err}");
+        std::process::exit(1);
+    }
+}
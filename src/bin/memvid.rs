@@ -1,41 +1,160 @@
-use memvid_core::{Memvid, PutOptions, SearchRequest};
+use memvid_core::table::{export_to_csv, export_to_json, get_table, list_tables};
+use memvid_core::{
+    AskMode, AskRequest, DoctorOptions, DoctorPlan, DocumentProcessor, ExportOptions,
+    ExtractedDocument, FilterExpr, FilterField, FusionMethod, ImportOptions, Memvid, PurgeSelector,
+    PutOptions, PutOptionsBuilder, RetentionPolicy, SearchRequest, SearchResponse, TimelineQuery,
+    UriConflict, VecEmbedder,
+};
 #[cfg(feature = "vec")]
-use memvid_core::{DoctorOptions, LocalTextEmbedder, TextEmbedConfig};
-use std::collections::HashMap;
+use memvid_core::{LocalTextEmbedder, TextEmbedConfig};
+use serde::Serialize;
 use std::env;
 use std::fs;
 use std::io::{self, Read};
-use std::path::PathBuf;
+use std::num::NonZeroU64;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+type CliResult = Result<(), Box<dyn std::error::Error>>;
+
+/// Global options shared by every command.
+struct Cli {
+    /// Memory file selected with `--file`, `--memory` or `$MEMVID_MEMORY`.
+    path: PathBuf,
+    /// Emit machine-readable JSON instead of human-readable text.
+    json: bool,
+}
 
-/// Global override set by --memory flag before command dispatch
-static MEMORY_PATH_OVERRIDE: std::sync::OnceLock<PathBuf> = std::sync::OnceLock::new();
+impl Cli {
+    fn open(&self) -> Result<Memvid, Box<dyn std::error::Error>> {
+        if !self.path.exists() {
+            return Err(format!(
+                "no memory file at {} (create one with `memvid create`)",
+                self.path.display()
+            )
+            .into());
+        }
+        Ok(Memvid::open(&self.path)?)
+    }
 
-fn get_memory_path() -> PathBuf {
-    // 1. --memory CLI flag (highest priority)
-    if let Some(p) = MEMORY_PATH_OVERRIDE.get() {
-        return p.clone();
+    fn open_read_only(&self) -> Result<Memvid, Box<dyn std::error::Error>> {
+        if !self.path.exists() {
+            return Err(format!("no memory file at {}", self.path.display()).into());
+        }
+        Ok(Memvid::open_read_only(&self.path)?)
     }
-    // 2. MEMVID_MEMORY env var
-    if let Ok(p) = env::var("MEMVID_MEMORY") {
-        let expanded = if p.starts_with('~') {
-            let home = env::var("HOME").unwrap_or_else(|_| ".".to_string());
-            p.replacen('~', &home, 1)
+
+    fn open_or_create(&self) -> Result<Memvid, Box<dyn std::error::Error>> {
+        if self.path.exists() {
+            return Ok(Memvid::open(&self.path)?);
+        }
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut mem = Memvid::create(&self.path)?;
+        mem.enable_lex()?;
+        Ok(mem)
+    }
+
+    /// Print `value` as JSON, or run `human` to print it as text.
+    fn emit<T: Serialize + ?Sized>(&self, value: &T, human: impl FnOnce(&T)) -> CliResult {
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(value)?);
         } else {
-            p
+            human(value);
+        }
+        Ok(())
+    }
+}
+
+/// Command arguments left after the command name, consumed flag by flag.
+struct Args {
+    items: Vec<String>,
+    /// Arguments after a `--` separator, always positional.
+    trailing: Vec<String>,
+}
+
+impl Args {
+    fn new(items: &[String]) -> Self {
+        let (items, trailing) = match items.iter().position(|item| item == "--") {
+            Some(split) => (&items[..split], &items[split + 1..]),
+            None => (items, &[][..]),
         };
-        return PathBuf::from(expanded);
+        Self {
+            items: items.to_vec(),
+            trailing: trailing.to_vec(),
+        }
+    }
+
+    /// Remove every occurrence of a boolean flag, returning whether it was present.
+    fn flag(&mut self, names: &[&str]) -> bool {
+        let before = self.items.len();
+        self.items.retain(|item| !names.contains(&item.as_str()));
+        self.items.len() != before
+    }
+
+    /// Remove every `NAME VALUE` pair for a repeatable option.
+    fn values(&mut self, names: &[&str]) -> Result<Vec<String>, String> {
+        let mut values = Vec::new();
+        while let Some(index) = self
+            .items
+            .iter()
+            .position(|item| names.contains(&item.as_str()))
+        {
+            if index + 1 >= self.items.len() {
+                return Err(format!("missing value for {}", self.items[index]));
+            }
+            values.push(self.items.remove(index + 1));
+            self.items.remove(index);
+        }
+        Ok(values)
+    }
+
+    /// Remove a single-valued option; the last occurrence wins.
+    fn value(&mut self, names: &[&str]) -> Result<Option<String>, String> {
+        Ok(self.values(names)?.pop())
+    }
+
+    fn parsed<T: FromStr>(&mut self, names: &[&str]) -> Result<Option<T>, String> {
+        match self.value(names)? {
+            Some(raw) => raw
+                .parse()
+                .map(Some)
+                .map_err(|_| format!("invalid value for {}: {raw}", names[0])),
+            None => Ok(None),
+        }
+    }
+
+    /// Remaining positional arguments; any leftover `--flag` is an error.
+    fn positionals(self) -> Result<Vec<String>, String> {
+        if let Some(unknown) = self
+            .items
+            .iter()
+            .find(|item| item.starts_with("--") && item.len() > 2)
+        {
+            return Err(format!("unknown option: {unknown}"));
+        }
+        let mut items = self.items;
+        items.extend(self.trailing);
+        Ok(items)
     }
-    // 3. Default fallback
-    let home = env::var("HOME").unwrap_or_else(|_| ".".to_string());
-    PathBuf::from(home).join(".memvid").join("claude.mv2")
 }
 
-fn ensure_memory_dir() -> io::Result<()> {
-    let path = get_memory_path();
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)?;
+fn expand_home(raw: &str) -> PathBuf {
+    if raw.starts_with('~') {
+        let home = env::var("HOME").unwrap_or_else(|_| ".".to_string());
+        PathBuf::from(raw.replacen('~', &home, 1))
+    } else {
+        PathBuf::from(raw)
     }
-    Ok(())
+}
+
+fn default_memory_path() -> PathBuf {
+    if let Ok(p) = env::var("MEMVID_MEMORY") {
+        return expand_home(&p);
+    }
+    let home = env::var("HOME").unwrap_or_else(|_| ".".to_string());
+    PathBuf::from(home).join(".memvid").join("claude.mv2")
 }
 
 #[cfg(feature = "vec")]
@@ -44,15 +163,174 @@ fn get_embedder() -> Result<LocalTextEmbedder, Box<dyn std::error::Error>> {
     Ok(LocalTextEmbedder::new(config)?)
 }
 
-fn cmd_save(title: Option<&str>, tags: Vec<(&str, &str)>, content: &str) -> Result<(), Box<dyn std::error::Error>> {
-    ensure_memory_dir()?;
-    let path = get_memory_path();
+/// Local text model exposed through the query-embedding trait `ask` expects.
+#[cfg(feature = "vec")]
+struct LocalQueryEmbedder(LocalTextEmbedder);
 
-    let mut mem = if path.exists() {
-        Memvid::open(&path)?
-    } else {
-        Memvid::create(&path)?
-    };
+#[cfg(feature = "vec")]
+impl VecEmbedder for LocalQueryEmbedder {
+    fn embed_query(&self, text: &str) -> memvid_core::Result<Vec<f32>> {
+        self.0.encode_text(text)
+    }
+
+    fn embedding_dimension(&self) -> usize {
+        self.0.model_info().dims as usize
+    }
+}
+
+/// Embed `query` with the local text model, or fail when vector search is unavailable.
+fn embed_query(query: &str) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
+    #[cfg(feature = "vec")]
+    {
+        Ok(get_embedder()?.encode_text(query)?)
+    }
+    #[cfg(not(feature = "vec"))]
+    {
+        let _ = query;
+        Err("semantic search requires a build with the `vec` feature".into())
+    }
+}
+
+/// Parse `key=value` pairs into filter clauses. Built-in field names (`uri`, `title`,
+/// `kind`, `track`, `role`, `tag`, `label`, `mime`) address frame fields; any other key
+/// addresses `extra_metadata`.
+fn where_clauses(pairs: &[String]) -> Result<Vec<FilterExpr>, String> {
+    pairs
+        .iter()
+        .map(|pair| {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("expected KEY=VALUE, got {pair}"))?;
+            let field = match key {
+                "uri" => FilterField::Uri,
+                "title" => FilterField::Title,
+                "kind" => FilterField::Kind,
+                "track" => FilterField::Track,
+                "role" => FilterField::Role,
+                "tag" => FilterField::Tag,
+                "label" => FilterField::Label,
+                "mime" => FilterField::Mime,
+                other => FilterField::metadata(other),
+            };
+            Ok(FilterExpr::eq(field, value))
+        })
+        .collect()
+}
+
+/// Combine `--filter JSON` and `--where KEY=VALUE` options into one filter.
+fn filter_from_args(args: &mut Args) -> Result<Option<FilterExpr>, Box<dyn std::error::Error>> {
+    let mut clauses = where_clauses(&args.values(&["--where", "-w"])?)?;
+    if let Some(json) = args.value(&["--filter"])? {
+        clauses.push(serde_json::from_str(&json)?);
+    }
+    Ok(match clauses.len() {
+        0 => None,
+        1 => clauses.pop(),
+        _ => Some(FilterExpr::and(clauses)),
+    })
+}
+
+fn search_request(query: &str, top_k: usize, snippet_chars: usize) -> SearchRequest {
+    SearchRequest {
+        query: query.to_string(),
+        top_k,
+        snippet_chars,
+        uri: None,
+        scope: None,
+        cursor: None,
+        #[cfg(feature = "temporal_track")]
+        temporal: None,
+        as_of_frame: None,
+        as_of_ts: None,
+        no_sketch: false,
+        reranker: None,
+        filter: None,
+        fusion: None,
+    }
+}
+
+fn cmd_create(cli: &Cli, args: Args) -> CliResult {
+    let mut args = args;
+    let force = args.flag(&["--force"]);
+    let vec = args.flag(&["--vec"]);
+    args.positionals()?;
+
+    if cli.path.exists() && !force {
+        return Err(format!(
+            "{} already exists (use --force to overwrite)",
+            cli.path.display()
+        )
+        .into());
+    }
+    if let Some(parent) = cli.path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut mem = Memvid::create(&cli.path)?;
+    mem.enable_lex()?;
+    if vec {
+        mem.enable_vec()?;
+    }
+    mem.commit()?;
+    let stats = mem.stats()?;
+    cli.emit(&stats, |_| println!("Created {}", cli.path.display()))
+}
+
+/// Parse `save [--title T] [--tag k=v]... (--stdin | <text>...)` and store the note.
+fn cmd_save_args(cli: &Cli, args: &[String]) -> CliResult {
+    let mut title: Option<&str> = None;
+    let mut tags: Vec<(&str, &str)> = Vec::new();
+    let mut content = String::new();
+    let mut use_stdin = false;
+    let mut i = 1;
+
+    while i < args.len() {
+        match args[i].as_str() {
+            "--title" | "-t" => {
+                if i + 1 < args.len() {
+                    title = Some(&args[i + 1]);
+                    i += 2;
+                } else {
+                    return Err("Missing title value".into());
+                }
+            }
+            "--tag" => {
+                if i + 1 < args.len() {
+                    if let Some((k, v)) = args[i + 1].split_once('=') {
+                        tags.push((k, v));
+                    }
+                    i += 2;
+                } else {
+                    return Err("Missing tag value".into());
+                }
+            }
+            "--stdin" => {
+                use_stdin = true;
+                i += 1;
+            }
+            "--" => {
+                content = args[i + 1..].join(" ");
+                break;
+            }
+            _ => {
+                content = args[i..].join(" ");
+                break;
+            }
+        }
+    }
+
+    if use_stdin {
+        io::stdin().read_to_string(&mut content)?;
+    }
+
+    if content.trim().is_empty() {
+        return Err("No content provided".into());
+    }
+
+    cmd_save(cli, title, tags, &content)
+}
+
+fn cmd_save(cli: &Cli, title: Option<&str>, tags: Vec<(&str, &str)>, content: &str) -> CliResult {
+    let mut mem = cli.open_or_create()?;
 
     let mut opts = PutOptions::builder();
 
@@ -64,9 +342,12 @@ fn cmd_save(title: Option<&str>, tags: Vec<(&str, &str)>, content: &str) -> Resu
         opts = opts.tag(key, value);
     }
 
+    // IDs are assigned in WAL order at commit, so the next one is this document's.
+    let frame_id = mem.next_frame_id();
+
     // Generate embedding if vec feature is enabled
     #[cfg(feature = "vec")]
-    let seq = {
+    {
         match get_embedder() {
             Ok(embedder) => {
                 match embedder.encode_text(content) {
@@ -83,217 +364,618 @@ fn cmd_save(title: Option<&str>, tags: Vec<(&str, &str)>, content: &str) -> Resu
                 eprintln!("Warning: Could not load embedder ({}), saving without", e);
                 mem.put_bytes_with_options(content.as_bytes(), opts.build())?
             }
-        }
-    };
+        };
+    }
 
     #[cfg(not(feature = "vec"))]
-    let seq = mem.put_bytes_with_options(content.as_bytes(), opts.build())?;
+    mem.put_bytes_with_options(content.as_bytes(), opts.build())?;
 
     mem.commit()?;
 
-    println!("Saved to memory (frame {})", seq);
-    Ok(())
+    cli.emit(&serde_json::json!({ "frame_id": frame_id }), |_| {
+        println!("Saved to memory (frame {frame_id})");
+    })
 }
 
-fn cmd_search(query: &str, top_k: usize) -> Result<(), Box<dyn std::error::Error>> {
-    let path = get_memory_path();
+/// A file to ingest and the URI it gets when `--uri` is not given.
+struct InputFile {
+    path: PathBuf,
+    uri: String,
+}
 
-    if !path.exists() {
-        println!("No memory file found. Save something first with 'memvid save'");
-        return Ok(());
+/// Expand `put` inputs into files: directories are walked recursively and arguments
+/// containing `*`, `?` or `[` are matched as globs.
+///
+/// Like `Memvid::ingest_dir`, files found under a directory or glob root are named
+/// `mv2://<root name>/<relative path>`; a file given directly is `mv2://<file name>`.
+fn collect_inputs(inputs: &[String]) -> Result<Vec<InputFile>, Box<dyn std::error::Error>> {
+    let mut files = Vec::new();
+    for input in inputs {
+        let (root, found) = if input.contains(['*', '?', '[']) {
+            let (root, matched) = glob_files(input)?;
+            if matched.is_empty() {
+                return Err(format!("no files match {input}").into());
+            }
+            (root, matched)
+        } else {
+            let path = PathBuf::from(input);
+            if path.is_dir() {
+                let mut found = Vec::new();
+                walk_dir(&path, &mut found)?;
+                (path, found)
+            } else if path.is_file() {
+                let name = path
+                    .file_name()
+                    .map_or_else(|| input.clone(), |name| name.to_string_lossy().into_owned());
+                files.push(InputFile {
+                    uri: format!("mv2://{name}"),
+                    path,
+                });
+                continue;
+            } else {
+                return Err(format!("no such file or directory: {input}").into());
+            }
+        };
+        let base = root
+            .canonicalize()
+            .ok()
+            .and_then(|path| {
+                path.file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+            })
+            .unwrap_or_else(|| "files".to_string());
+        for path in found {
+            let relative: Vec<String> = path
+                .strip_prefix(&root)
+                .unwrap_or(&path)
+                .components()
+                .map(|part| part.as_os_str().to_string_lossy().into_owned())
+                .collect();
+            files.push(InputFile {
+                uri: format!("mv2://{base}/{}", relative.join("/")),
+                path,
+            });
+        }
     }
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    files.dedup_by(|a, b| a.path == b.path);
+    Ok(files)
+}
 
-    let mut mem = Memvid::open(&path)?;
+fn walk_dir(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with('.'));
+        if hidden {
+            continue;
+        }
+        if path.is_dir() {
+            walk_dir(&path, files)?;
+        } else if path.is_file() {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
 
-    // Run lexical search (always available with lex feature)
-    let request = SearchRequest {
-        query: query.to_string(),
-        top_k: top_k * 2, // Get more results for hybrid merging
-        snippet_chars: 300,
-        uri: None,
-        scope: None,
-        cursor: None,
-        #[cfg(feature = "temporal_track")]
-        temporal: None,
-        as_of_frame: None,
-        as_of_ts: None,
-        no_sketch: false,
+/// Files matching `pattern`, searched from its longest wildcard-free directory prefix,
+/// which is returned alongside them.
+fn glob_files(pattern: &str) -> io::Result<(PathBuf, Vec<PathBuf>)> {
+    let pattern = pattern.replace('\\', "/");
+    let segments: Vec<&str> = pattern.split('/').collect();
+    let literal = segments
+        .iter()
+        .take_while(|segment| !segment.contains(['*', '?', '[']))
+        .count()
+        .min(segments.len() - 1);
+    let root = if literal == 0 {
+        if pattern.starts_with('/') {
+            PathBuf::from("/")
+        } else {
+            PathBuf::from(".")
+        }
+    } else {
+        PathBuf::from(segments[..literal].join("/"))
     };
+    let rest = &segments[literal..];
 
-    let lex_response = mem.search(request)?;
+    let mut candidates = Vec::new();
+    if root.is_dir() {
+        walk_dir(&root, &mut candidates)?;
+    }
+    let matched = candidates
+        .into_iter()
+        .filter(|path| {
+            let Ok(relative) = path.strip_prefix(&root) else {
+                return false;
+            };
+            let parts: Vec<String> = relative
+                .components()
+                .map(|part| part.as_os_str().to_string_lossy().into_owned())
+                .collect();
+            let parts: Vec<&str> = parts.iter().map(String::as_str).collect();
+            glob_segments(rest, &parts)
+        })
+        .collect();
+    Ok((root, matched))
+}
 
-    // Try hybrid search with vec if available
-    #[cfg(feature = "vec")]
-    let final_hits = {
-        let stats = mem.stats()?;
-        if stats.has_vec_index {
-            match get_embedder() {
-                Ok(embedder) => {
-                    match embedder.encode_text(query) {
-                        Ok(query_embedding) => {
-                            match mem.vec_search_with_embedding(query, &query_embedding, top_k * 2, 300, None) {
-                                Ok(vec_response) => {
-                                    // Hybrid merge using Reciprocal Rank Fusion
-                                    merge_results_rrf(&lex_response.hits, &vec_response.hits, top_k)
-                                }
-                                Err(_) => {
-                                    // Fall back to lex only
-                                    lex_response.hits.into_iter().take(top_k).collect()
-                                }
-                            }
-                        }
-                        Err(_) => lex_response.hits.into_iter().take(top_k).collect(),
-                    }
+/// Match path segments against pattern segments, where `**` spans any number of them.
+fn glob_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| glob_segments(rest, &path[skip..])),
+        Some((segment, rest)) => path
+            .split_first()
+            .is_some_and(|(head, tail)| wildcard_match(segment, head) && glob_segments(rest, tail)),
+    }
+}
+
+/// Match one name against `*`, `?` and `[abc]` wildcards.
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    fn go(p: &[char], n: &[char]) -> bool {
+        match p.first() {
+            None => n.is_empty(),
+            Some('*') => (0..=n.len()).any(|skip| go(&p[1..], &n[skip..])),
+            Some('?') => !n.is_empty() && go(&p[1..], &n[1..]),
+            Some('[') => match p.iter().position(|c| *c == ']') {
+                Some(close) if !n.is_empty() => {
+                    p[1..close].contains(&n[0]) && go(&p[close + 1..], &n[1..])
                 }
-                Err(_) => lex_response.hits.into_iter().take(top_k).collect(),
-            }
-        } else {
-            lex_response.hits.into_iter().take(top_k).collect()
+                _ => false,
+            },
+            Some(c) => n.first() == Some(c) && go(&p[1..], &n[1..]),
         }
-    };
+    }
+    go(&pattern, &name)
+}
 
-    #[cfg(not(feature = "vec"))]
-    let final_hits: Vec<_> = lex_response.hits.into_iter().take(top_k).collect();
+#[derive(Serialize)]
+struct PutReport {
+    added: Vec<PutEntry>,
+    failed: Vec<PutFailure>,
+}
 
-    if final_hits.is_empty() {
-        println!("No results found for: {}", query);
-        return Ok(());
+#[derive(Serialize)]
+struct PutEntry {
+    source: String,
+    uri: Option<String>,
+    frame_id: u64,
+}
+
+#[derive(Serialize)]
+struct PutFailure {
+    source: String,
+    error: String,
+}
+
+fn cmd_put(cli: &Cli, args: Args) -> CliResult {
+    let mut args = args;
+    let title = args.value(&["--title", "-t"])?;
+    let labels = args.values(&["--label", "-l"])?;
+    let tags = args.values(&["--tag"])?;
+    let uri = args.value(&["--uri"])?;
+    let stdin = args.flag(&["--stdin"]);
+    let mut inputs = args.positionals()?;
+    let stdin = stdin || inputs.iter().any(|input| input == "-");
+    inputs.retain(|input| input != "-");
+    if inputs.is_empty() && !stdin {
+        return Err("put needs files, directories, globs or - for stdin".into());
     }
 
-    println!("Found {} results ({} ms):\n", final_hits.len(), lex_response.elapsed_ms);
+    let files = collect_inputs(&inputs)?;
+    if uri.is_some() && files.len() + usize::from(stdin) > 1 {
+        return Err("--uri can only be used with a single input".into());
+    }
 
-    for hit in final_hits {
-        let title = hit.title.as_deref().unwrap_or("Untitled");
-        let score = hit.score.unwrap_or(0.0);
-        println!("--- [{}] {} (score: {:.3}) ---", hit.frame_id, title, score);
-        println!("{}\n", hit.text.trim());
+    let base_options = || {
+        let mut opts = PutOptions::builder();
+        for label in &labels {
+            opts = opts.label(label);
+        }
+        for tag in &tags {
+            opts = match tag.split_once('=') {
+                Some((key, value)) => opts.tag(key, value),
+                None => opts.push_tag(tag),
+            };
+        }
+        opts
+    };
+
+    let mut mem = cli.open_or_create()?;
+    let processor = DocumentProcessor::default();
+    let mut report = PutReport {
+        added: Vec::new(),
+        failed: Vec::new(),
+    };
+
+    if stdin {
+        let mut bytes = Vec::new();
+        io::stdin().read_to_end(&mut bytes)?;
+        let mut opts = base_options();
+        if let Some(title) = &title {
+            opts = opts.title(title);
+        }
+        if let Some(uri) = &uri {
+            opts = opts.uri(uri);
+        }
+        let options = extracted_options(opts, processor.extract_from_bytes(&bytes)?);
+        let frame_id = mem.next_frame_id();
+        mem.put_bytes_with_options(&bytes, options)?;
+        report.added.push(PutEntry {
+            source: "-".to_string(),
+            uri: uri.clone(),
+            frame_id,
+        });
     }
 
+    for input in &files {
+        let file = &input.path;
+        let source = file.display().to_string();
+        let extracted = match processor.extract_from_path(file) {
+            Ok(extracted) => extracted,
+            Err(err) => {
+                report.failed.push(PutFailure {
+                    source,
+                    error: err.to_string(),
+                });
+                continue;
+            }
+        };
+        let bytes = fs::read(file)?;
+        let file_uri = uri.clone().unwrap_or_else(|| input.uri.clone());
+        let file_title = title.clone().unwrap_or_else(|| {
+            file.file_stem().map_or_else(
+                || source.clone(),
+                |stem| stem.to_string_lossy().into_owned(),
+            )
+        });
+        let opts = base_options().uri(&file_uri).title(file_title);
+        let mut options = extracted_options(opts, extracted);
+        options.source_path = Some(source.clone());
+        let frame_id = mem.next_frame_id();
+        match mem.put_bytes_with_options(&bytes, options) {
+            Ok(_) => report.added.push(PutEntry {
+                source,
+                uri: Some(file_uri),
+                frame_id,
+            }),
+            Err(err) => report.failed.push(PutFailure {
+                source,
+                error: err.to_string(),
+            }),
+        }
+    }
+    mem.commit()?;
+
+    cli.emit(&report, |report| {
+        for entry in &report.added {
+            match &entry.uri {
+                Some(uri) => println!("+ {} -> {uri}", entry.source),
+                None => println!("+ {}", entry.source),
+            }
+        }
+        for failure in &report.failed {
+            eprintln!("! {}: {}", failure.source, failure.error);
+        }
+        println!(
+            "Added {} document(s), {} failed",
+            report.added.len(),
+            report.failed.len()
+        );
+    })?;
+    if report.added.is_empty() && !report.failed.is_empty() {
+        return Err("no documents were added".into());
+    }
     Ok(())
 }
 
-/// Merge search results using Reciprocal Rank Fusion (RRF)
-/// This gives good results even when scores from different systems aren't comparable
-#[cfg(feature = "vec")]
-fn merge_results_rrf(
-    lex_hits: &[memvid_core::SearchHit],
-    vec_hits: &[memvid_core::SearchHit],
-    top_k: usize,
-) -> Vec<memvid_core::SearchHit> {
-    use std::collections::HashSet;
-
-    const K: f32 = 60.0; // RRF constant - standard value
-
-    let mut scores: HashMap<u64, f32> = HashMap::new();
-    let mut hits_by_id: HashMap<u64, memvid_core::SearchHit> = HashMap::new();
+/// Finish `opts` with the text and MIME type extracted from a document's bytes.
+fn extracted_options(opts: PutOptionsBuilder, extracted: ExtractedDocument) -> PutOptions {
+    let mut opts = opts;
+    if let Some(mime) = &extracted.mime_type {
+        opts = opts.metadata_entry("mime", serde_json::Value::String(mime.clone()));
+    }
+    let mut options = opts.build();
+    options.search_text = extracted.text;
+    options
+}
 
-    // Add lexical results with RRF scores
-    for (rank, hit) in lex_hits.iter().enumerate() {
-        let rrf_score = 1.0 / (K + (rank + 1) as f32);
-        *scores.entry(hit.frame_id).or_insert(0.0) += rrf_score;
-        hits_by_id.entry(hit.frame_id).or_insert_with(|| hit.clone());
+fn parse_fusion(raw: &str) -> Result<FusionMethod, String> {
+    match raw.split_once(':') {
+        None if raw == "rrf" => Ok(FusionMethod::default()),
+        Some(("rrf", k)) => k
+            .parse()
+            .map(|k| FusionMethod::Rrf { k })
+            .map_err(|_| format!("invalid RRF k: {k}")),
+        None if raw == "weighted" => Ok(FusionMethod::Weighted {
+            lexical: 0.5,
+            vector: 0.5,
+        }),
+        Some(("weighted", weights)) => {
+            let (lexical, vector) = weights
+                .split_once(',')
+                .ok_or_else(|| format!("expected weighted:LEX,VEC, got {raw}"))?;
+            Ok(FusionMethod::Weighted {
+                lexical: lexical
+                    .parse()
+                    .map_err(|_| format!("invalid weight: {lexical}"))?,
+                vector: vector
+                    .parse()
+                    .map_err(|_| format!("invalid weight: {vector}"))?,
+            })
+        }
+        _ => Err(format!(
+            "unknown fusion method: {raw} (rrf[:K] or weighted[:LEX,VEC])"
+        )),
     }
+}
 
-    // Add vector results with RRF scores
-    for (rank, hit) in vec_hits.iter().enumerate() {
-        let rrf_score = 1.0 / (K + (rank + 1) as f32);
-        *scores.entry(hit.frame_id).or_insert(0.0) += rrf_score;
-        hits_by_id.entry(hit.frame_id).or_insert_with(|| hit.clone());
+fn cmd_find(cli: &Cli, args: Args) -> CliResult {
+    let mut args = args;
+    let mode = args
+        .value(&["--mode", "--engine"])?
+        .unwrap_or_else(|| "auto".to_string());
+    let top_k = args.parsed(&["--top", "-n"])?.unwrap_or(5);
+    let snippet_chars = args.parsed(&["--snippet"])?.unwrap_or(300);
+    let scope = args.value(&["--scope"])?;
+    let uri = args.value(&["--uri"])?;
+    let cursor = args.value(&["--cursor"])?;
+    let fusion = args
+        .value(&["--fusion"])?
+        .map(|raw| parse_fusion(&raw))
+        .transpose()?;
+    let as_of_frame = args.parsed(&["--as-of-frame"])?;
+    let as_of_ts = args.parsed(&["--as-of-ts"])?;
+    let filter = filter_from_args(&mut args)?;
+    let query = args.positionals()?.join(" ");
+    if query.trim().is_empty() {
+        return Err("No search query provided".into());
     }
 
-    // Sort by combined RRF score
-    let mut scored: Vec<_> = scores.into_iter().collect();
-    scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    let mut mem = cli.open()?;
+    let request = SearchRequest {
+        scope,
+        uri,
+        cursor,
+        filter,
+        fusion,
+        as_of_frame,
+        as_of_ts,
+        ..search_request(&query, top_k, snippet_chars)
+    };
 
-    // Return top_k hits with updated scores
-    let mut seen = HashSet::new();
-    scored
-        .into_iter()
-        .filter_map(|(frame_id, rrf_score)| {
-            if seen.insert(frame_id) {
-                hits_by_id.remove(&frame_id).map(|mut hit| {
-                    hit.score = Some(rrf_score);
-                    hit
-                })
+    let single_engine = matches!(
+        mode.as_str(),
+        "lex" | "lexical" | "sem" | "vec" | "semantic"
+    );
+    if single_engine && request.fusion.is_some() {
+        return Err(format!("--fusion only applies to hybrid and auto search, not {mode}").into());
+    }
+
+    let response = match mode.as_str() {
+        "lex" | "lexical" => mem.search(request)?,
+        "sem" | "vec" | "semantic" => {
+            let embedding = embed_query(&query)?;
+            mem.vec_search_request(&request, &embedding)?
+        }
+        "hybrid" => {
+            let embedding = embed_query(&query)?;
+            mem.search_with_embedding(request, Some(&embedding))?
+        }
+        "auto" => {
+            // Embed the query for hybrid search when a vector index is available
+            let embedding = if mem.stats()?.has_vec_index {
+                embed_query(&query).ok()
             } else {
                 None
-            }
-        })
-        .take(top_k)
-        .collect()
+            };
+            mem.search_with_embedding(request, embedding.as_deref())?
+        }
+        other => {
+            return Err(format!("unknown search mode: {other} (auto, lex, sem, hybrid)").into());
+        }
+    };
+    cli.emit(&response, print_search_response)
 }
 
-fn cmd_stats() -> Result<(), Box<dyn std::error::Error>> {
-    let path = get_memory_path();
+fn print_search_response(response: &SearchResponse) {
+    if response.hits.is_empty() {
+        println!("No results found for: {}", response.query);
+        return;
+    }
 
-    if !path.exists() {
-        println!("No memory file found at {:?}", path);
-        return Ok(());
+    println!(
+        "Found {} results ({} ms):\n",
+        response.hits.len(),
+        response.elapsed_ms
+    );
+
+    for hit in &response.hits {
+        let title = hit.title.as_deref().unwrap_or("Untitled");
+        let score = hit.score.unwrap_or(0.0);
+        println!("--- [{}] {} (score: {:.3}) ---", hit.frame_id, title, score);
+        println!("{}\n", hit.text.trim());
     }
+    if let Some(cursor) = &response.next_cursor {
+        println!("More results: --cursor {cursor}");
+    }
+}
 
-    let mem = Memvid::open(&path)?;
-    let stats = mem.stats()?;
+fn cmd_ask(cli: &Cli, args: Args) -> CliResult {
+    let mut args = args;
+    let mode = match args.value(&["--mode"])?.as_deref() {
+        None | Some("hybrid") => AskMode::Hybrid,
+        Some("lex" | "lexical") => AskMode::Lex,
+        Some("sem" | "semantic") => AskMode::Sem,
+        Some(other) => return Err(format!("unknown ask mode: {other} (lex, sem, hybrid)").into()),
+    };
+    let top_k = args.parsed(&["--top", "-n"])?.unwrap_or(8);
+    let snippet_chars = args.parsed(&["--snippet"])?.unwrap_or(320);
+    let context_only = args.flag(&["--context-only"]);
+    let scope = args.value(&["--scope"])?;
+    let uri = args.value(&["--uri"])?;
+    let start = args.parsed(&["--since"])?;
+    let end = args.parsed(&["--until"])?;
+    let filter = filter_from_args(&mut args)?;
+    let question = args.positionals()?.join(" ");
+    if question.trim().is_empty() {
+        return Err("No question provided".into());
+    }
+
+    let mut mem = cli.open()?;
+    let request = AskRequest {
+        question,
+        top_k,
+        snippet_chars,
+        uri,
+        scope,
+        cursor: None,
+        start,
+        end,
+        #[cfg(feature = "temporal_track")]
+        temporal: None,
+        context_only,
+        mode,
+        as_of_frame: None,
+        as_of_ts: None,
+        adaptive: None,
+        reranker: None,
+        filter,
+    };
 
-    println!("Memory Statistics:");
-    println!("  Location: {:?}", path);
-    println!("  Frames: {}", stats.frame_count);
-    println!("  Active frames: {}", stats.active_frame_count);
-    println!("  Size: {} bytes", stats.size_bytes);
-    println!("  Has lex index: {}", stats.has_lex_index);
     #[cfg(feature = "vec")]
-    println!("  Has vec index: {}", stats.has_vec_index);
-    println!("  Compression: {:.1}%", stats.compression_ratio_percent);
+    let embedder = if mode == AskMode::Lex || !mem.stats()?.has_vec_index {
+        None
+    } else {
+        Some(LocalQueryEmbedder(get_embedder()?))
+    };
+    #[cfg(feature = "vec")]
+    let response = mem.ask(request, embedder.as_ref())?;
+    #[cfg(not(feature = "vec"))]
+    let response = mem.ask(request, None::<&dyn VecEmbedder>)?;
 
-    Ok(())
+    cli.emit(&response, |response| {
+        match &response.answer {
+            Some(answer) => println!("{answer}\n"),
+            None => println!("(no answer synthesized)\n"),
+        }
+        for citation in &response.citations {
+            println!("[{}] frame {}", citation.index, citation.frame_id);
+        }
+    })
 }
 
-fn cmd_list_recent(count: usize) -> Result<(), Box<dyn std::error::Error>> {
-    let path = get_memory_path();
-
-    if !path.exists() {
-        println!("No memory file found at {:?}", path);
-        return Ok(());
+fn cmd_timeline(cli: &Cli, args: Args) -> CliResult {
+    let mut args = args;
+    let limit: Option<NonZeroU64> = args.parsed(&["--limit", "-n"])?;
+    let since = args.parsed(&["--since"])?;
+    let until = args.parsed(&["--until"])?;
+    let reverse = args.flag(&["--reverse"]);
+    args.positionals()?;
+
+    let mut mem = cli.open_read_only()?;
+    let mut query = TimelineQuery::builder().reverse(reverse);
+    if let Some(limit) = limit.or(NonZeroU64::new(50)) {
+        query = query.limit(limit);
+    }
+    if let Some(since) = since {
+        query = query.since(since);
+    }
+    if let Some(until) = until {
+        query = query.until(until);
     }
+    let entries = mem.timeline(query.build())?;
+    cli.emit(&entries, |entries| {
+        for entry in entries {
+            let preview: String = entry
+                .preview
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .chars()
+                .take(80)
+                .collect();
+            println!(
+                "[{}] {} {}",
+                entry.frame_id,
+                entry.timestamp,
+                preview.trim()
+            );
+        }
+    })
+}
 
-    let mem = Memvid::open(&path)?;
+fn cmd_stats(cli: &Cli) -> CliResult {
+    let mem = cli.open_read_only()?;
+    let stats = mem.stats()?;
+
+    cli.emit(&stats, |stats| {
+        println!("Memory Statistics:");
+        println!("  Location: {:?}", cli.path);
+        println!("  Frames: {}", stats.frame_count);
+        println!("  Active frames: {}", stats.active_frame_count);
+        println!("  Size: {} bytes", stats.size_bytes);
+        println!("  Has lex index: {}", stats.has_lex_index);
+        println!("  Has vec index: {}", stats.has_vec_index);
+        println!("  Compression: {:.1}%", stats.compression_ratio_percent);
+    })
+}
+
+fn cmd_list_recent(cli: &Cli, count: usize) -> CliResult {
+    let mem = cli.open_read_only()?;
     let stats = mem.stats()?;
     let total = stats.frame_count as usize;
 
-    println!("Total frames: {}", total);
-    println!("Listing last {} frames:", count);
-
-    let start = if total > count { total - count } else { 0 };
-    for i in start..total {
-        match mem.frame_by_id(i as u64) {
-            Ok(frame) => {
-                let has_search = if frame.search_text.is_some() { "✓" } else { "✗" };
-                let has_mime = if frame.metadata.as_ref().and_then(|m| m.mime.as_ref()).is_some() { "✓" } else { "✗" };
-                let title_preview = frame.title.as_deref().unwrap_or("(no title)");
-                let title_short = if title_preview.len() > 40 { &title_preview[..40] } else { title_preview };
-                println!("  [{}] search:{} mime:{} {:?}", i, has_search, has_mime, title_short);
-            }
-            Err(e) => {
-                println!("  [{}] ERROR: {}", i, e);
-            }
+    let start = total.saturating_sub(count);
+    let frames: Vec<_> = (start..total)
+        .filter_map(|i| mem.frame_by_id(i as u64).ok())
+        .collect();
+    cli.emit(&frames, |frames| {
+        println!("Total frames: {}", total);
+        println!("Listing last {} frames:", count);
+        for frame in frames {
+            let has_search = if frame.search_text.is_some() {
+                "✓"
+            } else {
+                "✗"
+            };
+            let has_mime = if frame
+                .metadata
+                .as_ref()
+                .and_then(|m| m.mime.as_ref())
+                .is_some()
+            {
+                "✓"
+            } else {
+                "✗"
+            };
+            let title_short: String = frame
+                .title
+                .as_deref()
+                .unwrap_or("(no title)")
+                .chars()
+                .take(40)
+                .collect();
+            println!(
+                "  [{}] search:{} mime:{} {:?}",
+                frame.id, has_search, has_mime, title_short
+            );
         }
-    }
-
-    Ok(())
+    })
 }
 
-fn cmd_test_save_search() -> Result<(), Box<dyn std::error::Error>> {
-    ensure_memory_dir()?;
-    let path = get_memory_path();
+fn cmd_test_save_search(cli: &Cli) -> CliResult {
+    let path = &cli.path;
 
     println!("Opening/creating memvid at {:?}", path);
 
-    let mut mem = if path.exists() {
-        println!("Opening existing file...");
-        Memvid::open(&path)?
-    } else {
-        println!("Creating new file...");
-        Memvid::create(&path)?
-    };
+    let mut mem = cli.open_or_create()?;
 
     let stats = mem.stats()?;
     println!("Stats after open: frames={}, has_lex={}", stats.frame_count, stats.has_lex_index);
@@ -319,21 +1001,7 @@ fn cmd_test_save_search() -> Result<(), Box<dyn std::error::Error>> {
     // Try searching for "testuniq" which is simpler
     println!("\nSearching for 'testuniq' in same session...");
 
-    let request = SearchRequest {
-        query: "testuniq".to_string(),
-        top_k: 5,
-        snippet_chars: 300,
-        uri: None,
-        scope: None,
-        cursor: None,
-        #[cfg(feature = "temporal_track")]
-        temporal: None,
-        as_of_frame: None,
-        as_of_ts: None,
-        no_sketch: false,
-    };
-
-    let response = mem.search(request)?;
+    let response = mem.search(search_request("testuniq", 5, 300))?;
 
     if response.hits.is_empty() {
         println!("❌ No results found in SAME session!");
@@ -348,23 +1016,9 @@ fn cmd_test_save_search() -> Result<(), Box<dyn std::error::Error>> {
 
     // Now reopen and search again
     println!("\nReopening file and searching again...");
-    let mut mem2 = Memvid::open(&path)?;
-
-    let request2 = SearchRequest {
-        query: "testuniq".to_string(),
-        top_k: 5,
-        snippet_chars: 300,
-        uri: None,
-        scope: None,
-        cursor: None,
-        #[cfg(feature = "temporal_track")]
-        temporal: None,
-        as_of_frame: None,
-        as_of_ts: None,
-        no_sketch: false,
-    };
+    let mut mem2 = Memvid::open(path)?;
 
-    let response2 = mem2.search(request2)?;
+    let response2 = mem2.search(search_request("testuniq", 5, 300))?;
 
     if response2.hits.is_empty() {
         println!("❌ No results found after REOPEN!");
@@ -378,93 +1032,555 @@ fn cmd_test_save_search() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-fn cmd_inspect(frame_id: u64) -> Result<(), Box<dyn std::error::Error>> {
-    let path = get_memory_path();
+fn cmd_inspect(cli: &Cli, frame_id: u64) -> CliResult {
+    let mem = cli.open_read_only()?;
+    let frame = mem.frame_by_id(frame_id)?;
+
+    cli.emit(&frame, |frame| {
+        println!("Frame {}:", frame_id);
+        println!("  Status: {:?}", frame.status);
+        println!("  Timestamp: {}", frame.timestamp);
+        println!("  Title: {:?}", frame.title);
+        println!("  URI: {:?}", frame.uri);
+        println!("  Search text present: {}", frame.search_text.is_some());
+        if let Some(ref text) = frame.search_text {
+            let preview: String = text.chars().take(100).collect();
+            println!("  Search text preview: {:?}", preview);
+        }
+        println!("  Tags: {:?}", frame.tags);
+        println!("  Labels: {:?}", frame.labels);
+        println!("  Role: {:?}", frame.role);
+        if let Some(ref meta) = frame.metadata {
+            println!("  MIME: {:?}", meta.mime);
+        } else {
+            println!("  Metadata: None");
+        }
+        println!("  Payload length: {}", frame.payload_length);
+        println!("  Canonical encoding: {:?}", frame.canonical_encoding);
+    })
+}
 
-    if !path.exists() {
-        println!("No memory file found at {:?}", path);
+fn cmd_verify(cli: &Cli, args: Args) -> CliResult {
+    let mut args = args;
+    let deep = args.flag(&["--deep"]);
+    args.positionals()?;
+
+    let report = Memvid::verify(&cli.path, deep)?;
+    cli.emit(&report, |report| {
+        for check in &report.checks {
+            match &check.details {
+                Some(details) => println!("  {:?} {} ({details})", check.status, check.name),
+                None => println!("  {:?} {}", check.status, check.name),
+            }
+        }
+        println!("Overall: {:?}", report.overall_status);
+    })
+}
+
+fn cmd_doctor(cli: &Cli, args: Args) -> CliResult {
+    let mut args = args;
+    let plan_only = args.flag(&["--plan"]);
+    let options = DoctorOptions {
+        rebuild_vec_index: args.flag(&["--rebuild-vec"]),
+        rebuild_lex_index: args.flag(&["--rebuild-lex"]),
+        rebuild_time_index: args.flag(&["--rebuild-time"]),
+        vacuum: args.flag(&["--vacuum"]),
+        dry_run: false,
+        quiet: cli.json || plan_only,
+    };
+    let apply = args.value(&["--apply"])?;
+    args.positionals()?;
+
+    if plan_only {
+        // Plans are always JSON so they can be saved and passed back to --apply.
+        let plan = Memvid::doctor_plan(&cli.path, options)?;
+        println!("{}", serde_json::to_string_pretty(&plan)?);
         return Ok(());
     }
 
-    let mem = Memvid::open(&path)?;
+    if !cli.json {
+        println!("Running doctor on {:?}...", cli.path);
+    }
+    let report = match apply {
+        Some(plan_path) => {
+            let mut plan: DoctorPlan = serde_json::from_slice(&fs::read(&plan_path)?)?;
+            plan.options.quiet = cli.json;
+            Memvid::doctor_apply(&cli.path, plan)?
+        }
+        None => Memvid::doctor(&cli.path, options)?,
+    };
 
-    match mem.frame_by_id(frame_id) {
-        Ok(frame) => {
-            println!("Frame {}:", frame_id);
-            println!("  Status: {:?}", frame.status);
-            println!("  Timestamp: {}", frame.timestamp);
-            println!("  Title: {:?}", frame.title);
-            println!("  URI: {:?}", frame.uri);
-            println!("  Search text present: {}", frame.search_text.is_some());
-            if let Some(ref text) = frame.search_text {
-                let preview = if text.len() > 100 { &text[..100] } else { text };
-                println!("  Search text preview: {:?}", preview);
+    cli.emit(&report, |report| {
+        println!("Doctor completed: {:?}", report.status);
+        if let Some(v) = &report.verification {
+            println!("  Verified: {:?}", v.overall_status);
+        }
+    })
+}
+
+fn cmd_vacuum(cli: &Cli, args: Args) -> CliResult {
+    let mut args = args;
+    let budget: Option<u64> = args.parsed(&["--budget"])?;
+    args.positionals()?;
+    let mut mem = cli.open()?;
+    let report = match budget {
+        Some(budget) => mem.vacuum_incremental(budget)?,
+        None => mem.vacuum()?,
+    };
+    cli.emit(&report, |report| {
+        println!(
+            "Vacuumed {}: {} -> {} bytes ({} reclaimed, {} frames moved)",
+            cli.path.display(),
+            report.bytes_before,
+            report.bytes_after,
+            report.bytes_reclaimed,
+            report.frames_moved
+        );
+        if !report.is_complete() {
+            println!("  {} frames left to compact; run again", report.frames_remaining);
+        }
+    })
+}
+
+fn cmd_tables(cli: &Cli, args: Args) -> CliResult {
+    let mut args = args;
+    let format = args
+        .value(&["--format"])?
+        .unwrap_or_else(|| "csv".to_string());
+    let out = args.value(&["--out", "-o"])?;
+    let positionals = args.positionals()?;
+    let mut mem = cli.open()?;
+
+    match positionals.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        [] | ["list"] => {
+            let tables = list_tables(&mut mem)?;
+            cli.emit(&tables, |tables| {
+                if tables.is_empty() {
+                    println!("No tables stored.");
+                }
+                for table in tables {
+                    println!(
+                        "{}  {} rows x {} cols  {} (pages {}-{})",
+                        table.table_id,
+                        table.n_rows,
+                        table.n_cols,
+                        table.source_file,
+                        table.page_start,
+                        table.page_end
+                    );
+                }
+            })
+        }
+        ["export", table_id] => {
+            let table = get_table(&mut mem, table_id)?
+                .ok_or_else(|| format!("no table with id {table_id}"))?;
+            let rendered = match format.as_str() {
+                "csv" => export_to_csv(&table),
+                "json" => export_to_json(&table, false)?,
+                "records" => export_to_json(&table, true)?,
+                other => return Err(format!("unknown table format: {other} (csv, json, records)").into()),
+            };
+            match out {
+                Some(out) => fs::write(&out, rendered)?,
+                None => print!("{rendered}"),
             }
-            println!("  Tags: {:?}", frame.tags);
-            println!("  Labels: {:?}", frame.labels);
-            println!("  Role: {:?}", frame.role);
-            if let Some(ref meta) = frame.metadata {
-                println!("  MIME: {:?}", meta.mime);
+            Ok(())
+        }
+        _ => Err("usage: memvid tables [list | export <table_id> [--format csv|json|records] [--out FILE]]".into()),
+    }
+}
+
+fn cmd_cards(cli: &Cli, args: Args) -> CliResult {
+    let mut args = args;
+    let slot = args.value(&["--slot"])?;
+    let positionals = args.positionals()?;
+    let mem = cli.open_read_only()?;
+
+    match (positionals.first(), slot) {
+        (None, _) => {
+            let entities = mem.memory_entities();
+            cli.emit(&entities, |entities| {
+                for entity in entities {
+                    println!("{entity}");
+                }
+            })
+        }
+        (Some(entity), Some(slot)) => {
+            let card = mem.get_current_memory(entity, &slot);
+            cli.emit(&card, |card| match card {
+                Some(card) => println!("{}.{} = {}", card.entity, card.slot, card.value),
+                None => println!("No memory for {entity}.{slot}"),
+            })
+        }
+        (Some(entity), None) => {
+            let cards = mem.get_entity_memories(entity);
+            cli.emit(&cards, |cards| {
+                for card in cards {
+                    println!("{}.{} = {}", card.entity, card.slot, card.value);
+                }
+            })
+        }
+    }
+}
+
+fn cmd_mesh(cli: &Cli, args: Args) -> CliResult {
+    let mut args = args;
+    let hops = args.parsed(&["--hops"])?.unwrap_or(1);
+    let positionals = args.positionals()?;
+    let mem = cli.open_read_only()?;
+
+    match positionals
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["follow", start, link] => {
+            let results = mem.follow(start, link, hops);
+            cli.emit(&results, |results| {
+                if results.is_empty() {
+                    println!("No {link} relationships from {start}");
+                }
+                for result in results {
+                    println!(
+                        "{} ({:?}, {} hop(s), confidence {:.2})",
+                        result.node, result.kind, result.path_length, result.confidence
+                    );
+                }
+            })
+        }
+        ["stats"] => {
+            let stats = mem.logic_mesh_stats();
+            cli.emit(&stats, |stats| println!("{stats:#?}"))
+        }
+        _ => Err("usage: memvid mesh follow <start> <link> [--hops N] | memvid mesh stats".into()),
+    }
+}
+
+fn cmd_retention(cli: &Cli, args: Args) -> CliResult {
+    let positionals = args.positionals()?;
+    match positionals.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        [] | ["show"] => {
+            let mem = cli.open_read_only()?;
+            cli.emit(mem.retention_policy(), |policy| {
+                if policy.is_empty() {
+                    println!("No retention rules.");
+                }
+                for rule in &policy.rules {
+                    println!("{}: {:?} {:?}", rule.name, rule.limit, rule.scope);
+                }
+            })
+        }
+        ["set", source] => {
+            let raw = if *source == "-" {
+                let mut raw = String::new();
+                io::stdin().read_to_string(&mut raw)?;
+                raw
             } else {
-                println!("  Metadata: None");
-            }
-            println!("  Payload length: {}", frame.payload_length);
-            println!("  Canonical encoding: {:?}", frame.canonical_encoding);
+                fs::read_to_string(source)?
+            };
+            let policy: RetentionPolicy =
+                serde_json::from_str(&raw).map_err(|err| format!("invalid policy: {err}"))?;
+            let mut mem = cli.open()?;
+            mem.set_retention_policy(policy)?;
+            mem.commit()?;
+            cli.emit(mem.retention_policy(), |policy| {
+                println!("Stored {} retention rule(s)", policy.rules.len());
+            })
         }
-        Err(e) => {
-            println!("Error getting frame {}: {}", frame_id, e);
+        ["apply"] => {
+            let mut mem = cli.open()?;
+            let report = mem.apply_retention()?;
+            cli.emit(&report, |report| {
+                println!("Expired {} frame(s)", report.tombstoned.len());
+                for rule in &report.rules {
+                    println!("  {}: {} version(s)", rule.name, rule.expired);
+                }
+                if let Some(vacuum) = &report.vacuum {
+                    println!("  Vacuum reclaimed {} bytes", vacuum.bytes_reclaimed);
+                }
+            })
         }
+        _ => Err("usage: memvid retention [show | set <policy.json|-> | apply]".into()),
     }
+}
 
-    Ok(())
+fn cmd_purge(cli: &Cli, args: Args) -> CliResult {
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+
+    let mut args = args;
+    let frames: Vec<u64> = args
+        .values(&["--frame"])?
+        .iter()
+        .map(|raw| raw.parse().map_err(|_| format!("invalid frame id: {raw}")))
+        .collect::<Result<_, _>>()?;
+    let uri = args.value(&["--uri"])?;
+    let key_path = args.value(&["--sign-key"])?;
+    let filter = filter_from_args(&mut args)?;
+    args.positionals()?;
+    let selector = match (frames.is_empty(), uri, filter) {
+        (false, None, None) => PurgeSelector::Frames(frames),
+        (true, Some(uri), None) => PurgeSelector::Uri(uri),
+        (true, None, Some(filter)) => PurgeSelector::Filter(filter),
+        _ => {
+            return Err("usage: memvid purge (--frame ID... | --uri U | --where k=v... | --filter JSON) [--sign-key FILE]".into());
+        }
+    };
+    let signing_key = match key_path {
+        Some(path) => {
+            let seed = STANDARD
+                .decode(fs::read_to_string(&path)?.trim())
+                .map_err(|err| format!("invalid signing key: {err}"))?;
+            let seed: [u8; 32] = seed
+                .try_into()
+                .map_err(|_| "signing key must be a base64 32-byte Ed25519 seed")?;
+            Some(ed25519_dalek::SigningKey::from_bytes(&seed))
+        }
+        None => None,
+    };
+    let mut mem = cli.open()?;
+    let receipt = mem.purge_frames(&selector, signing_key.as_ref())?;
+    cli.emit(&receipt, |receipt| {
+        println!(
+            "Purged {} frame(s); file checksum {}",
+            receipt.frames.len(),
+            receipt.file_checksum
+        );
+        let artifacts = &receipt.artifacts;
+        println!(
+            "  dropped {} card(s), {} mesh node(s), {} mesh edge(s), {} sketch(es), {} CLIP embedding(s)",
+            artifacts.memory_cards,
+            artifacts.mesh_nodes,
+            artifacts.mesh_edges,
+            artifacts.sketch_entries,
+            artifacts.clip_embeddings
+        );
+        if let Some(signer) = &receipt.signer {
+            println!("  signed by {signer}");
+        }
+    })
 }
 
-#[cfg(feature = "vec")]
-fn cmd_doctor(rebuild_vec: bool, rebuild_lex: bool) -> Result<(), Box<dyn std::error::Error>> {
-    let path = get_memory_path();
+fn cmd_import(cli: &Cli, args: Args) -> CliResult {
+    let mut args = args;
+    let on_conflict = match args.value(&["--on-conflict"])?.as_deref() {
+        None | Some("skip") => UriConflict::Skip,
+        Some("replace") => UriConflict::Replace,
+        Some("version") => UriConflict::Version,
+        Some(other) => return Err(format!("invalid value for --on-conflict: {other}").into()),
+    };
+    let history = args.flag(&["--history"]);
+    let jsonl = args.value(&["--jsonl"])?;
+    let (source_path, report) = if let Some(stream) = jsonl {
+        args.positionals()?;
+        let mut mem = cli.open()?;
+        let report = if stream == "-" {
+            mem.import(io::stdin().lock())?
+        } else {
+            mem.import(io::BufReader::new(fs::File::open(&stream)?))?
+        };
+        (stream, report)
+    } else {
+        let [source_path] = args.positionals()?.try_into().map_err(|_| {
+            "usage: memvid import (<source.mv2> [--on-conflict skip|replace|version] [--history] | --jsonl FILE|-)"
+        })?;
+        let mut source = Memvid::open_read_only(&source_path)?;
+        let mut mem = cli.open()?;
+        let report = mem.import_from(&mut source, ImportOptions::new(on_conflict).with_superseded(history))?;
+        (source_path, report)
+    };
+    cli.emit(&report, |report| {
+        println!(
+            "Imported {} frame(s) from {source_path}; skipped {}, replaced {}, versioned {}",
+            report.imported(),
+            report.skipped.len(),
+            report.replaced.len(),
+            report.versioned
+        );
+        println!(
+            "  {} card(s), {} mesh node(s), {} mesh edge(s); {} embedding(s) copied, {} dropped",
+            report.memory_cards,
+            report.mesh_nodes,
+            report.mesh_edges,
+            report.embeddings_copied,
+            report.embeddings_dropped
+        );
+    })
+}
 
-    if !path.exists() {
-        println!("No memory file found at {:?}", path);
+fn cmd_export(cli: &Cli, args: Args) -> CliResult {
+    let mut args = args;
+    let out = args.value(&["--out", "-o"])?;
+    let options = if args.flag(&["--active-only"]) {
+        ExportOptions::active_only()
+    } else {
+        ExportOptions::default()
+    };
+    args.positionals()?;
+    let mut mem = cli.open_read_only()?;
+    let Some(out) = out else {
+        // The stream owns stdout; nothing else is printed.
+        mem.export(io::BufWriter::new(io::stdout().lock()), options)?;
         return Ok(());
-    }
+    };
+    let counts = mem.export(io::BufWriter::new(fs::File::create(&out)?), options)?;
+    cli.emit(&counts, |counts| {
+        println!(
+            "Exported {} frame(s), {} embedding(s), {} card(s), {} mesh node(s) to {out}",
+            counts.frames, counts.embeddings, counts.memory_cards, counts.mesh_nodes
+        );
+    })
+}
 
-    println!("Running doctor on {:?}...", path);
+fn cmd_backup(cli: &Cli, args: Args) -> CliResult {
+    let mut args = args;
+    let since = args.parsed::<u64>(&["--since"])?.unwrap_or(0);
+    let out = args
+        .value(&["--out", "-o"])?
+        .ok_or("usage: memvid backup [--since GENERATION] --out FILE")?;
+    args.positionals()?;
+    let mut mem = cli.open_read_only()?;
+    let report = mem.backup_since(since, io::BufWriter::new(fs::File::create(&out)?))?;
+    cli.emit(&report, |report| {
+        let kind = if report.is_full() { "full" } else { "incremental" };
+        println!(
+            "Wrote {kind} backup of generation {} to {out}: {} of {} bytes",
+            report.generation, report.bytes, report.file_len
+        );
+    })
+}
 
-    let options = DoctorOptions {
-        rebuild_vec_index: rebuild_vec,
-        rebuild_lex_index: rebuild_lex,
-        rebuild_time_index: false,
-        dry_run: false,
-        quiet: false,
-        vacuum: false,
+fn cmd_restore(cli: &Cli, args: Args) -> CliResult {
+    let positionals = args.positionals()?;
+    let Some((base, increments)) = positionals.split_first() else {
+        return Err("usage: memvid restore <full.bak> [increment.bak...]".into());
     };
+    let open = |path: &String| fs::File::open(path).map(io::BufReader::new);
+    let increments = increments.iter().map(open).collect::<Result<Vec<_>, _>>()?;
+    let report = Memvid::restore(&cli.path, open(base)?, increments)?;
+    cli.emit(&report, |report| {
+        println!(
+            "Restored generation {} to {} ({} bytes, blake3 {})",
+            report.generation,
+            cli.path.display(),
+            report.file_len,
+            report.file_hash
+        );
+    })
+}
 
-    let report = Memvid::doctor(&path, options)?;
+#[cfg(feature = "replay")]
+fn cmd_replay(cli: &Cli, args: Args) -> CliResult {
+    use memvid_core::replay::ReplayEngine;
+    use uuid::Uuid;
+
+    let positionals = args.positionals()?;
+    let mut mem = cli.open_read_only()?;
+    mem.load_replay_sessions()?;
+    let session = |mem: &Memvid,
+                   raw: &str|
+     -> Result<memvid_core::ReplaySession, Box<dyn std::error::Error>> {
+        let id = Uuid::parse_str(raw).map_err(|_| format!("invalid session id: {raw}"))?;
+        Ok(mem
+            .get_session(id)
+            .cloned()
+            .ok_or_else(|| format!("no session {id}"))?)
+    };
 
-    println!("Doctor completed: {:?}", report.status);
-    if let Some(v) = &report.verification {
-        println!("  Verified: {:?}", v.overall_status);
+    match positionals
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        [] | ["list"] => {
+            let sessions = mem.list_sessions();
+            cli.emit(&sessions, |sessions| {
+                for session in sessions {
+                    println!(
+                        "{}  {}  {} action(s)",
+                        session.session_id,
+                        session.name.as_deref().unwrap_or("(unnamed)"),
+                        session.action_count
+                    );
+                }
+            })
+        }
+        ["show", id] => {
+            let session = session(&mem, id)?;
+            cli.emit(&session, |session| {
+                for action in &session.actions {
+                    println!(
+                        "#{} {} {}",
+                        action.sequence,
+                        action.action_type.name(),
+                        action.input_preview
+                    );
+                }
+            })
+        }
+        ["compare", a, b] => {
+            let comparison = ReplayEngine::compare_sessions(&session(&mem, a)?, &session(&mem, b)?);
+            cli.emit(&comparison, |comparison| {
+                println!("Matching actions: {}", comparison.matching_actions);
+                println!("Only in A: {:?}", comparison.actions_only_in_a);
+                println!("Only in B: {:?}", comparison.actions_only_in_b);
+                for diff in &comparison.differing_actions {
+                    println!("  #{}: {}", diff.sequence, diff.description);
+                }
+            })
+        }
+        _ => Err("usage: memvid replay [list | show <id> | compare <a> <b>]".into()),
     }
+}
 
-    Ok(())
+/// Capsule password from `$MEMVID_PASSWORD`, or the first line of stdin.
+#[cfg(feature = "encryption")]
+fn read_password() -> Result<String, Box<dyn std::error::Error>> {
+    if let Ok(password) = env::var("MEMVID_PASSWORD") {
+        return Ok(password);
+    }
+    let mut line = String::new();
+    io::stdin().read_line(&mut line)?;
+    let password = line.trim_end_matches(['\r', '\n']).to_string();
+    if password.is_empty() {
+        return Err("no password given (set MEMVID_PASSWORD or pipe it on stdin)".into());
+    }
+    Ok(password)
 }
 
-#[cfg(feature = "vec")]
-fn cmd_embed_all() -> Result<(), Box<dyn std::error::Error>> {
-    use std::time::Instant;
+#[cfg(feature = "encryption")]
+fn cmd_lock(cli: &Cli, args: Args, unlock: bool) -> CliResult {
+    use memvid_core::encryption::{lock_file, unlock_file};
 
-    let path = get_memory_path();
+    let mut args = args;
+    let out = args.value(&["--out", "-o"])?.map(PathBuf::from);
+    let positionals = args.positionals()?;
+    let input = positionals
+        .first()
+        .map_or_else(|| cli.path.clone(), PathBuf::from);
+    let password = read_password()?;
 
-    if !path.exists() {
-        println!("No memory file found at {:?}", path);
-        return Ok(());
-    }
+    let written = if unlock {
+        unlock_file(&input, out.as_deref(), password.as_bytes())?
+    } else {
+        lock_file(&input, out.as_deref(), password.as_bytes())?
+    };
+    cli.emit(&serde_json::json!({ "output": written }), |_| {
+        println!("Wrote {}", written.display());
+    })
+}
+
+#[cfg(feature = "vec")]
+fn cmd_embed_all(cli: &Cli) -> CliResult {
+    use std::time::Instant;
 
     println!("Loading embedding model...");
     let embedder = get_embedder()?;
 
     println!("Opening memory file...");
-    let mut mem = Memvid::open(&path)?;
+    let mut mem = cli.open()?;
     let stats = mem.stats()?;
     let total_frames = stats.frame_count;
 
@@ -542,23 +1658,50 @@ fn cmd_embed_all() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 fn print_usage() {
-    let path = get_memory_path();
-    eprintln!("Usage: memvid [--memory <path>] <command> [args]");
+    let path = default_memory_path();
+    eprintln!("Usage: memvid [--file <path>] [--json] <command> [args] [-- <literal args>]");
     eprintln!();
     eprintln!("Commands:");
+    eprintln!("  create [--vec] [--force]                 Create an empty memory");
+    eprintln!("  put <file|dir|glob|->... [--title T] [--label L] [--tag k=v] [--uri U]");
+    eprintln!("                                           Ingest documents (- reads stdin)");
     eprintln!("  save [--title <title>] [--tag key=value]... <content>");
     eprintln!("  save --stdin [--title <title>] [--tag key=value]...");
-    eprintln!("  search <query> [--top <n>]");
+    eprintln!("  find <query> [--mode auto|lex|sem|hybrid] [--top N] [--scope P] [--uri U]");
+    eprintln!("       [--where k=v]... [--filter JSON] [--fusion rrf[:K]|weighted[:L,V]]");
+    eprintln!("       [--cursor C] [--as-of-frame N] [--as-of-ts TS]   (alias: search)");
+    eprintln!("  ask <question> [--mode lex|sem|hybrid] [--top N] [--context-only]");
+    eprintln!("       [--scope P] [--where k=v]... [--filter JSON] [--since TS] [--until TS]");
+    eprintln!("  timeline [--limit N] [--since TS] [--until TS] [--reverse]");
     eprintln!("  stats");
     eprintln!("  list [count]                             List recent frames");
     eprintln!("  inspect <frame_id>                       Show frame details");
+    eprintln!("  verify [--deep]                          Check file integrity");
+    eprintln!("  doctor [--rebuild-lex] [--rebuild-vec] [--rebuild-time] [--vacuum]");
+    eprintln!("         [--plan | --apply PLAN.json]      Repair indexes (--plan prints the plan)");
+    eprintln!("  vacuum [--budget BYTES]                  Compact the memory file (--budget: one");
+    eprintln!("                                           bounded pass; repeat until complete)");
+    eprintln!("  tables [list | export <id> [--format csv|json|records] [--out FILE]]");
+    eprintln!("  cards [<entity> [--slot S]]              List memory cards");
+    eprintln!("  mesh follow <start> <link> [--hops N] | mesh stats");
+    eprintln!("  retention [show | set <policy.json|-> | apply]   Manage TTL/version rules");
+    eprintln!("  purge (--frame ID... | --uri U | --where k=v... | --filter JSON) [--sign-key FILE]");
+    eprintln!("                                           Erase frames and derived data; prints a receipt");
+    eprintln!("  import <source.mv2> [--on-conflict skip|replace|version] [--history]");
+    eprintln!("                                           Merge another memory's frames into this one");
+    eprintln!("  import --jsonl <FILE|->                  Restore an interchange stream");
+    eprintln!("  export [--out FILE] [--active-only]      Write a JSONL interchange stream");
+    eprintln!("  backup [--since GENERATION] --out FILE   Write a full or incremental backup");
+    eprintln!("  restore <full.bak> [increment.bak...]    Rebuild the memory file from backups");
+    #[cfg(feature = "replay")]
+    eprintln!("  replay [list | show <id> | compare <a> <b>]");
+    #[cfg(feature = "encryption")]
+    eprintln!("  lock [--out PATH] | unlock <capsule.mv2e> [--out PATH]   ($MEMVID_PASSWORD)");
     #[cfg(feature = "vec")]
     eprintln!("  embed-all                                Generate embeddings for all frames");
-    #[cfg(feature = "vec")]
-    eprintln!("  doctor [--rebuild-lex] [--rebuild-vec]   Rebuild indexes");
     eprintln!();
     eprintln!("Memory path (in priority order):");
-    eprintln!("  1. --memory <path>      CLI flag");
+    eprintln!("  1. --file / -f <path>   CLI flag (alias: --memory / -m)");
     eprintln!("  2. $MEMVID_MEMORY       Environment variable");
     eprintln!("  3. ~/.memvid/claude.mv2 Default");
     eprintln!();
@@ -567,158 +1710,102 @@ fn print_usage() {
     eprintln!("Hybrid search (lex + semantic) enabled.");
 }
 
-fn main() {
-    let raw_args: Vec<String> = env::args().collect();
-
-    // Parse global --memory flag before command dispatch
-    let args: Vec<String> = {
-        let mut filtered = vec![raw_args[0].clone()];
-        let mut i = 1;
-        while i < raw_args.len() {
-            if raw_args[i] == "--memory" || raw_args[i] == "-m" {
-                if i + 1 < raw_args.len() {
-                    let p = &raw_args[i + 1];
-                    let expanded = if p.starts_with('~') {
-                        let home = env::var("HOME").unwrap_or_else(|_| ".".to_string());
-                        p.replacen('~', &home, 1)
-                    } else {
-                        p.clone()
-                    };
-                    let _ = MEMORY_PATH_OVERRIDE.set(PathBuf::from(expanded));
-                    i += 2;
-                    continue;
-                } else {
-                    eprintln!("Missing path for --memory flag");
-                    std::process::exit(1);
-                }
+/// Split global options (`--file`, `--memory`, `--json`) out of the raw arguments.
+///
+/// Everything after a `--` separator is passed through untouched, separator included, so
+/// command arguments can spell these flags literally.
+fn parse_globals(raw_args: &[String]) -> Result<(Cli, Vec<String>), String> {
+    let mut path = None;
+    let mut json = false;
+    let mut rest = Vec::new();
+    let mut iter = raw_args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--" => {
+                rest.push(arg.clone());
+                rest.extend(iter.cloned());
+                break;
             }
-            filtered.push(raw_args[i].clone());
-            i += 1;
+            "--file" | "-f" | "--memory" | "-m" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| format!("Missing path for {arg} flag"))?;
+                path = Some(expand_home(value));
+            }
+            "--json" => json = true,
+            _ => rest.push(arg.clone()),
+        }
+    }
+    let path = path.unwrap_or_else(default_memory_path);
+    Ok((Cli { path, json }, rest))
+}
+
+fn main() {
+    let raw_args: Vec<String> = env::args().skip(1).collect();
+    let (cli, args) = match parse_globals(&raw_args) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
         }
-        filtered
     };
 
-    if args.len() < 2 {
+    let Some(command) = args.first() else {
         print_usage();
         std::process::exit(1);
-    }
-
-    let result = match args[1].as_str() {
-        "save" => {
-            let mut title: Option<&str> = None;
-            let mut tags: Vec<(&str, &str)> = Vec::new();
-            let mut content = String::new();
-            let mut use_stdin = false;
-            let mut i = 2;
-
-            while i < args.len() {
-                match args[i].as_str() {
-                    "--title" | "-t" => {
-                        if i + 1 < args.len() {
-                            title = Some(&args[i + 1]);
-                            i += 2;
-                        } else {
-                            eprintln!("Missing title value");
-                            std::process::exit(1);
-                        }
-                    }
-                    "--tag" => {
-                        if i + 1 < args.len() {
-                            if let Some((k, v)) = args[i + 1].split_once('=') {
-                                tags.push((k, v));
-                            }
-                            i += 2;
-                        } else {
-                            eprintln!("Missing tag value");
-                            std::process::exit(1);
-                        }
-                    }
-                    "--stdin" => {
-                        use_stdin = true;
-                        i += 1;
-                    }
-                    _ => {
-                        content = args[i..].join(" ");
-                        break;
-                    }
-                }
-            }
-
-            if use_stdin {
-                io::stdin().read_to_string(&mut content).expect("Failed to read stdin");
-            }
-
-            if content.trim().is_empty() {
-                eprintln!("No content provided");
-                std::process::exit(1);
-            }
-
-            // Convert owned strings to references for the function call
-            let tags_refs: Vec<(&str, &str)> = tags.iter().map(|(k, v)| (*k, *v)).collect();
-            cmd_save(title, tags_refs, &content)
-        }
-        "search" | "find" => {
-            let mut query = String::new();
-            let mut top_k = 5;
-            let mut i = 2;
-
-            while i < args.len() {
-                match args[i].as_str() {
-                    "--top" | "-n" => {
-                        if i + 1 < args.len() {
-                            top_k = args[i + 1].parse().unwrap_or(5);
-                            i += 2;
-                        } else {
-                            i += 1;
-                        }
-                    }
-                    _ => {
-                        if query.is_empty() {
-                            query = args[i..].join(" ");
-                        }
-                        break;
-                    }
-                }
-            }
-
-            if query.is_empty() {
-                eprintln!("No search query provided");
-                std::process::exit(1);
-            }
-
-            cmd_search(&query, top_k)
-        }
-        "stats" => cmd_stats(),
+    };
+    let rest = Args::new(&args[1..]);
+
+    let result = match command.as_str() {
+        "create" | "init" => cmd_create(&cli, rest),
+        "put" => cmd_put(&cli, rest),
+        "save" => cmd_save_args(&cli, &args),
+        "search" | "find" => cmd_find(&cli, rest),
+        "ask" => cmd_ask(&cli, rest),
+        "timeline" => cmd_timeline(&cli, rest),
+        "stats" => cmd_stats(&cli),
         "inspect" => {
-            if args.len() < 3 {
+            if args.len() < 2 {
                 eprintln!("Usage: memvid inspect <frame_id>");
                 std::process::exit(1);
             }
-            let frame_id: u64 = args[2].parse().unwrap_or_else(|_| {
-                eprintln!("Invalid frame ID: {}", args[2]);
+            let frame_id: u64 = args[1].parse().unwrap_or_else(|_| {
+                eprintln!("Invalid frame ID: {}", args[1]);
                 std::process::exit(1);
             });
-            cmd_inspect(frame_id)
+            cmd_inspect(&cli, frame_id)
         }
         "list" | "ls" => {
-            let count: usize = args.get(2).and_then(|s| s.parse().ok()).unwrap_or(20);
-            cmd_list_recent(count)
-        }
-        "test-save-search" => cmd_test_save_search(),
-        #[cfg(feature = "vec")]
-        "doctor" => {
-            let rebuild_vec = args.iter().any(|a| a == "--rebuild-vec");
-            let rebuild_lex = args.iter().any(|a| a == "--rebuild-lex");
-            cmd_doctor(rebuild_vec, rebuild_lex)
+            let count: usize = args.get(1).and_then(|s| s.parse().ok()).unwrap_or(20);
+            cmd_list_recent(&cli, count)
         }
+        "test-save-search" => cmd_test_save_search(&cli),
+        "verify" => cmd_verify(&cli, rest),
+        "doctor" => cmd_doctor(&cli, rest),
+        "vacuum" => cmd_vacuum(&cli, rest),
+        "tables" => cmd_tables(&cli, rest),
+        "cards" => cmd_cards(&cli, rest),
+        "mesh" => cmd_mesh(&cli, rest),
+        "retention" => cmd_retention(&cli, rest),
+        "purge" => cmd_purge(&cli, rest),
+        "import" => cmd_import(&cli, rest),
+        "export" => cmd_export(&cli, rest),
+        "backup" => cmd_backup(&cli, rest),
+        "restore" => cmd_restore(&cli, rest),
+        #[cfg(feature = "replay")]
+        "replay" => cmd_replay(&cli, rest),
+        #[cfg(feature = "encryption")]
+        "lock" => cmd_lock(&cli, rest, false),
+        #[cfg(feature = "encryption")]
+        "unlock" => cmd_lock(&cli, rest, true),
         #[cfg(feature = "vec")]
-        "embed-all" => cmd_embed_all(),
+        "embed-all" => cmd_embed_all(&cli),
         "help" | "--help" | "-h" => {
             print_usage();
             Ok(())
         }
         _ => {
-            eprintln!("Unknown command: {}", args[1]);
+            eprintln!("Unknown command: {}", command);
             print_usage();
             std::process::exit(1);
         }
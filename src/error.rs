@@ -166,6 +166,24 @@ pub enum MemvidError {
     #[error("Doctor operation failed: {reason}")]
     Doctor { reason: String },
 
+    #[error("Vacuum aborted; compacted image failed verification: {reason}")]
+    VacuumVerification { reason: String },
+
+    #[error("Invalid retention rule: {reason}")]
+    InvalidRetention { reason: String },
+
+    #[error("Invalid interchange stream at line {line}: {reason}")]
+    InvalidInterchange { line: usize, reason: String },
+
+    #[error("Cannot back up since generation {generation}: {reason}")]
+    BackupUnavailable { generation: u64, reason: String },
+
+    #[error("Invalid backup stream: {reason}")]
+    InvalidBackup { reason: String },
+
+    #[error("Invalid archive {path}: {reason}")]
+    InvalidArchive { path: PathBuf, reason: String },
+
     #[error("Feature '{feature}' is not available in this build")]
     FeatureUnavailable { feature: &'static str },
 
@@ -190,6 +208,9 @@ pub enum MemvidError {
     #[error("Model signature verification failed: {reason}")]
     ModelSignatureInvalid { reason: Box<str> },
 
+    #[error("Purge receipt signature verification failed: {reason}")]
+    PurgeReceiptInvalid { reason: Box<str> },
+
     #[error("Model manifest invalid: {reason}")]
     ModelManifestInvalid { reason: Box<str> },
 
@@ -205,12 +226,28 @@ pub enum MemvidError {
     #[error("Reranking failed: {reason}")]
     RerankFailed { reason: Box<str> },
 
+    #[error("Answer synthesis failed: {reason}")]
+    SynthesisFailed { reason: Box<str> },
+
     #[error("Model mismatch: Index is bound to '{expected}', but requested model was '{actual}'")]
     ModelMismatch { expected: String, actual: String },
 
     #[error("Invalid query: {reason}")]
     InvalidQuery { reason: String },
 
+    #[error("Invalid graph query at {start}..{end}: {reason}")]
+    InvalidGraphQuery {
+        reason: String,
+        start: usize,
+        end: usize,
+    },
+
+    #[error("Invalid N-Triples at line {line}: {reason}")]
+    InvalidNTriples { line: usize, reason: String },
+
+    #[error("Invalid entity alias: {reason}")]
+    InvalidEntityAlias { reason: String },
+
     #[error("Tantivy error: {reason}")]
     Tantivy { reason: String },
 
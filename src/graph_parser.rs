@@ -0,0 +1,564 @@
+//! Parser for the textual graph query language.
+//!
+//! A small Cypher-like syntax over the same triples `GraphMatcher` evaluates:
+//!
+//! ```text
+//! MATCH (?p)-[:employer]->("acme"), (?p)<-[:manager]-(?boss)
+//! OPTIONAL MATCH (?p)-[:email]->(?mail)
+//! WHERE ?p.location = "berlin" AND ?boss != "dave"
+//! RETURN ?p, ?mail
+//! LIMIT 10
+//! ```
+//!
+//! Nodes are `(?var)` or a literal (`("quoted")`, a bare word or a number); edges are
+//! `-[:predicate]->`, `<-[:predicate]-` or `-[?var]->` for a variable predicate. Paths can
+//! chain several edges. `WHERE` takes `AND`-ed comparisons of a variable, or of a
+//! variable's property (`?p.location`, which adds the triple `?p location ?p.location`), to
+//! a literal with `=`, `!=`, `<>`, `<`, `<=`, `>`, `>=` or `CONTAINS`. Keywords are
+//! case-insensitive. Errors carry the byte range of the offending input.
+
+use crate::error::{MemvidError, Result};
+use crate::types::{CompareOp, GraphQuery, PatternTerm, TriplePattern, ValueFilter};
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Colon,
+    Comma,
+    Dot,
+    Star,
+    Dash,
+    /// `->`
+    Arrow,
+    /// `<-`
+    LeftArrow,
+    Op(CompareOp),
+    Variable(String),
+    Str(String),
+    Number(String),
+    Word(String),
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    start: usize,
+    end: usize,
+}
+
+fn error(reason: impl Into<String>, start: usize, end: usize) -> MemvidError {
+    MemvidError::InvalidGraphQuery {
+        reason: reason.into(),
+        start,
+        end,
+    }
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>> {
+    let bytes = text.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let start = pos;
+        let c = bytes[pos];
+        let (kind, len) = match c {
+            b if b.is_ascii_whitespace() => {
+                pos += 1;
+                continue;
+            }
+            b'(' => (TokenKind::LParen, 1),
+            b')' => (TokenKind::RParen, 1),
+            b'[' => (TokenKind::LBracket, 1),
+            b']' => (TokenKind::RBracket, 1),
+            b':' => (TokenKind::Colon, 1),
+            b',' => (TokenKind::Comma, 1),
+            b'.' => (TokenKind::Dot, 1),
+            b'*' => (TokenKind::Star, 1),
+            b'-' if bytes.get(pos + 1) == Some(&b'>') => (TokenKind::Arrow, 2),
+            b'-' => (TokenKind::Dash, 1),
+            b'<' => match bytes.get(pos + 1) {
+                Some(b'-') => (TokenKind::LeftArrow, 2),
+                Some(b'=') => (TokenKind::Op(CompareOp::Le), 2),
+                Some(b'>') => (TokenKind::Op(CompareOp::Ne), 2),
+                _ => (TokenKind::Op(CompareOp::Lt), 1),
+            },
+            b'>' if bytes.get(pos + 1) == Some(&b'=') => (TokenKind::Op(CompareOp::Ge), 2),
+            b'>' => (TokenKind::Op(CompareOp::Gt), 1),
+            b'=' => (TokenKind::Op(CompareOp::Eq), 1),
+            b'!' if bytes.get(pos + 1) == Some(&b'=') => (TokenKind::Op(CompareOp::Ne), 2),
+            b'"' | b'\'' => {
+                let (value, len) = read_string(text, pos)?;
+                (TokenKind::Str(value), len)
+            }
+            b'?' => {
+                let len = 1 + word_len(&text[pos + 1..]);
+                if len == 1 {
+                    return Err(error("expected a variable name after `?`", pos, pos + 1));
+                }
+                (
+                    TokenKind::Variable(text[pos + 1..pos + len].to_string()),
+                    len,
+                )
+            }
+            b if b.is_ascii_digit() => {
+                let len = text[pos..]
+                    .find(|ch: char| !(ch.is_ascii_digit() || ch == '.'))
+                    .unwrap_or(text.len() - pos);
+                (TokenKind::Number(text[pos..pos + len].to_string()), len)
+            }
+            _ => {
+                let len = word_len(&text[pos..]);
+                if len == 0 {
+                    let ch_len = text[pos..].chars().next().map_or(1, char::len_utf8);
+                    return Err(error(
+                        format!("unexpected character `{}`", &text[pos..pos + ch_len]),
+                        pos,
+                        pos + ch_len,
+                    ));
+                }
+                (TokenKind::Word(text[pos..pos + len].to_string()), len)
+            }
+        };
+        pos += len;
+        tokens.push(Token {
+            kind,
+            start,
+            end: pos,
+        });
+    }
+    Ok(tokens)
+}
+
+/// Length in bytes of the identifier at the start of `text`.
+fn word_len(text: &str) -> usize {
+    text.find(|ch: char| !(ch.is_alphanumeric() || ch == '_'))
+        .unwrap_or(text.len())
+}
+
+/// Read a quoted string starting at `start`; returns its value and its length with quotes.
+fn read_string(text: &str, start: usize) -> Result<(String, usize)> {
+    let quote = text[start..].chars().next().unwrap_or('"');
+    let mut value = String::new();
+    let mut chars = text[start + 1..].char_indices();
+    while let Some((offset, ch)) = chars.next() {
+        match ch {
+            '\\' => match chars.next() {
+                Some((_, escaped)) => value.push(escaped),
+                None => break,
+            },
+            ch if ch == quote => return Ok((value, offset + 2)),
+            ch => value.push(ch),
+        }
+    }
+    Err(error("unterminated string", start, text.len()))
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    tokens: Vec<Token>,
+    position: usize,
+    /// The query built so far.
+    query: GraphQuery,
+}
+
+/// Parse a graph query into its pattern, returned variables and limit.
+pub(crate) fn parse_graph_query(text: &str) -> Result<GraphQuery> {
+    let mut parser = Parser {
+        text,
+        tokens: tokenize(text)?,
+        position: 0,
+        query: GraphQuery::default(),
+    };
+    parser.parse()?;
+    Ok(parser.query)
+}
+
+impl Parser<'_> {
+    fn parse(&mut self) -> Result<()> {
+        self.expect_keyword("MATCH")?;
+        self.parse_paths(false)?;
+        while self.eat_keyword("OPTIONAL") {
+            self.expect_keyword("MATCH")?;
+            self.parse_paths(true)?;
+        }
+        if self.eat_keyword("WHERE") {
+            self.parse_condition()?;
+            while self.eat_keyword("AND") {
+                self.parse_condition()?;
+            }
+        }
+        if self.eat_keyword("RETURN") {
+            self.parse_returns()?;
+        }
+        if self.eat_keyword("LIMIT") {
+            let token = self.next("a number after LIMIT")?;
+            let TokenKind::Number(number) = &token.kind else {
+                return Err(error(
+                    "expected a number after LIMIT",
+                    token.start,
+                    token.end,
+                ));
+            };
+            let limit = number
+                .parse()
+                .map_err(|_| error("LIMIT must be a whole number", token.start, token.end))?;
+            self.query.limit = Some(limit);
+        }
+        if let Some(token) = self.tokens.get(self.position) {
+            return Err(error(
+                format!("unexpected `{}`", self.slice(token)),
+                token.start,
+                token.end,
+            ));
+        }
+        Ok(())
+    }
+
+    /// `path (',' path)*`
+    fn parse_paths(&mut self, optional: bool) -> Result<()> {
+        loop {
+            let mut subject = self.parse_node()?;
+            loop {
+                let forward = match self.peek_kind() {
+                    Some(TokenKind::Dash) => true,
+                    Some(TokenKind::LeftArrow) => false,
+                    _ => break,
+                };
+                self.position += 1;
+                self.expect(&TokenKind::LBracket, "`[`")?;
+                let predicate = self.parse_predicate()?;
+                self.expect(&TokenKind::RBracket, "`]`")?;
+                if forward {
+                    self.expect(&TokenKind::Arrow, "`->`")?;
+                } else {
+                    self.expect(&TokenKind::Dash, "`-`")?;
+                }
+                let object = self.parse_node()?;
+                let triple = if forward {
+                    TriplePattern::new(subject, predicate, object.clone())
+                } else {
+                    TriplePattern::new(object.clone(), predicate, subject)
+                };
+                if optional {
+                    self.query.pattern.add_optional(triple);
+                } else {
+                    self.query.pattern.add(triple);
+                }
+                subject = object;
+            }
+            if !self.eat(&TokenKind::Comma) {
+                return Ok(());
+            }
+        }
+    }
+
+    /// `'(' term ')'`
+    fn parse_node(&mut self) -> Result<PatternTerm> {
+        self.expect(&TokenKind::LParen, "`(` to start a node")?;
+        let token = self.next("a variable or value")?;
+        let term = match &token.kind {
+            TokenKind::Variable(name) => PatternTerm::Variable(name.clone()),
+            TokenKind::Str(value) | TokenKind::Number(value) | TokenKind::Word(value) => {
+                PatternTerm::Literal(value.clone())
+            }
+            _ => {
+                return Err(error(
+                    "expected a variable or value inside `( )`",
+                    token.start,
+                    token.end,
+                ));
+            }
+        };
+        self.expect(&TokenKind::RParen, "`)` to close the node")?;
+        Ok(term)
+    }
+
+    /// `':' name` or `?var`
+    fn parse_predicate(&mut self) -> Result<PatternTerm> {
+        let token = self.next("`:predicate` or `?var`")?;
+        match &token.kind {
+            TokenKind::Variable(name) => Ok(PatternTerm::Variable(name.clone())),
+            TokenKind::Colon => {
+                let name = self.next("a predicate name after `:`")?;
+                match &name.kind {
+                    TokenKind::Word(value) | TokenKind::Str(value) => {
+                        Ok(PatternTerm::Literal(value.to_lowercase()))
+                    }
+                    _ => Err(error(
+                        "expected a predicate name after `:`",
+                        name.start,
+                        name.end,
+                    )),
+                }
+            }
+            _ => Err(error(
+                "expected `:predicate` or `?var` inside `[ ]`",
+                token.start,
+                token.end,
+            )),
+        }
+    }
+
+    /// `?var op value` or `?var.property op value`
+    fn parse_condition(&mut self) -> Result<()> {
+        let token = self.next("a variable")?;
+        let TokenKind::Variable(name) = &token.kind else {
+            return Err(error(
+                "expected a variable to compare",
+                token.start,
+                token.end,
+            ));
+        };
+        self.check_bound(name, &token)?;
+        let mut variable = name.clone();
+        if self.eat(&TokenKind::Dot) {
+            let property = self.next("a property name after `.`")?;
+            let (TokenKind::Word(slot) | TokenKind::Str(slot)) = &property.kind else {
+                return Err(error(
+                    "expected a property name after `.`",
+                    property.start,
+                    property.end,
+                ));
+            };
+            let slot = slot.to_lowercase();
+            let value_var = format!("{name}.{slot}");
+            if !self.query.pattern.variables().contains(&value_var.as_str()) {
+                self.query.pattern.add(TriplePattern::new(
+                    PatternTerm::Variable(name.clone()),
+                    PatternTerm::Literal(slot),
+                    PatternTerm::Variable(value_var.clone()),
+                ));
+            }
+            variable = value_var;
+        }
+
+        let op_token = self.next("a comparison operator")?;
+        let op = match &op_token.kind {
+            TokenKind::Op(op) => *op,
+            TokenKind::Word(word) if word.eq_ignore_ascii_case("CONTAINS") => CompareOp::Contains,
+            _ => {
+                return Err(error(
+                    "expected a comparison operator (=, !=, <, <=, >, >=, CONTAINS)",
+                    op_token.start,
+                    op_token.end,
+                ));
+            }
+        };
+
+        let negative = self.eat(&TokenKind::Dash);
+        let value_token = self.next("a value to compare with")?;
+        let value = match &value_token.kind {
+            TokenKind::Number(number) if negative => format!("-{number}"),
+            TokenKind::Str(value) | TokenKind::Number(value) | TokenKind::Word(value)
+                if !negative =>
+            {
+                value.clone()
+            }
+            _ => {
+                return Err(error(
+                    "expected a string or number to compare with",
+                    value_token.start,
+                    value_token.end,
+                ));
+            }
+        };
+        self.query
+            .pattern
+            .add_filter(ValueFilter::new(&variable, op, value));
+        Ok(())
+    }
+
+    /// `'*'` or `?var (',' ?var)*`
+    fn parse_returns(&mut self) -> Result<()> {
+        if self.eat(&TokenKind::Star) {
+            return Ok(());
+        }
+        loop {
+            let token = self.next("a variable to return")?;
+            let TokenKind::Variable(name) = &token.kind else {
+                return Err(error(
+                    "expected a variable to return",
+                    token.start,
+                    token.end,
+                ));
+            };
+            self.check_bound(name, &token)?;
+            self.query.returns.push(name.clone());
+            if !self.eat(&TokenKind::Comma) {
+                return Ok(());
+            }
+        }
+    }
+
+    fn check_bound(&self, name: &str, token: &Token) -> Result<()> {
+        if self.query.pattern.variables().contains(&name) {
+            Ok(())
+        } else {
+            Err(error(
+                format!("variable `?{name}` does not appear in MATCH"),
+                token.start,
+                token.end,
+            ))
+        }
+    }
+
+    fn slice(&self, token: &Token) -> &str {
+        &self.text[token.start..token.end]
+    }
+
+    fn peek_kind(&self) -> Option<&TokenKind> {
+        self.tokens.get(self.position).map(|token| &token.kind)
+    }
+
+    fn next(&mut self, expected: &str) -> Result<Token> {
+        let Some(token) = self.tokens.get(self.position).cloned() else {
+            return Err(error(
+                format!("expected {expected}, found end of query"),
+                self.text.len(),
+                self.text.len(),
+            ));
+        };
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn eat(&mut self, kind: &TokenKind) -> bool {
+        if self.peek_kind() == Some(kind) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, kind: &TokenKind, expected: &str) -> Result<()> {
+        let token = self.next(expected)?;
+        if token.kind == *kind {
+            Ok(())
+        } else {
+            Err(error(
+                format!("expected {expected}, found `{}`", self.slice(&token)),
+                token.start,
+                token.end,
+            ))
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        match self.peek_kind() {
+            Some(TokenKind::Word(word)) if word.eq_ignore_ascii_case(keyword) => {
+                self.position += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<()> {
+        if self.eat_keyword(keyword) {
+            return Ok(());
+        }
+        let (start, end) = self
+            .tokens
+            .get(self.position)
+            .map_or((self.text.len(), self.text.len()), |token| {
+                (token.start, token.end)
+            });
+        Err(error(format!("expected {keyword}"), start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn var(name: &str) -> PatternTerm {
+        PatternTerm::Variable(name.to_string())
+    }
+
+    fn lit(value: &str) -> PatternTerm {
+        PatternTerm::Literal(value.to_string())
+    }
+
+    fn span(text: &str) -> (usize, usize, String) {
+        match parse_graph_query(text) {
+            Err(MemvidError::InvalidGraphQuery { reason, start, end }) => (start, end, reason),
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_match_where_return_limit() {
+        let query = parse_graph_query(
+            r#"MATCH (?p)-[:employer]->("acme") WHERE ?p.location = "berlin" RETURN ?p LIMIT 10"#,
+        )
+        .expect("parse");
+        assert_eq!(
+            query.pattern.triples,
+            vec![
+                TriplePattern::new(var("p"), lit("employer"), lit("acme")),
+                TriplePattern::new(var("p"), lit("location"), var("p.location")),
+            ]
+        );
+        assert_eq!(
+            query.pattern.filters,
+            vec![ValueFilter::new("p.location", CompareOp::Eq, "berlin")]
+        );
+        assert_eq!(query.returns, vec!["p"]);
+        assert_eq!(query.limit, Some(10));
+    }
+
+    #[test]
+    fn parses_chains_reverse_edges_and_optional() {
+        let query = parse_graph_query(
+            "match (?a)-[?rel]->(?b)<-[:Manager]-(bob), (?a)-[:age]->(?age) \
+             optional match (?b)-[:email]->(?mail) \
+             where ?age >= -3 and ?mail contains 'example' return *",
+        )
+        .expect("parse");
+        assert_eq!(
+            query.pattern.triples,
+            vec![
+                TriplePattern::new(var("a"), var("rel"), var("b")),
+                TriplePattern::new(lit("bob"), lit("manager"), var("b")),
+                TriplePattern::new(var("a"), lit("age"), var("age")),
+            ]
+        );
+        assert_eq!(
+            query.pattern.optional,
+            vec![TriplePattern::new(var("b"), lit("email"), var("mail"))]
+        );
+        assert_eq!(
+            query.pattern.filters,
+            vec![
+                ValueFilter::new("age", CompareOp::Ge, "-3"),
+                ValueFilter::new("mail", CompareOp::Contains, "example"),
+            ]
+        );
+        assert!(query.returns.is_empty());
+        assert_eq!(query.limit, None);
+    }
+
+    #[test]
+    fn reports_error_spans() {
+        let (start, end, reason) = span("MATCH (?p)-[:employer]->(\"acme\") RETURN ?q");
+        assert_eq!((start, end), (40, 42));
+        assert!(reason.contains("?q"), "{reason}");
+
+        let (start, end, _) = span("MATCH (?p)-[employer]->(?o)");
+        assert_eq!((start, end), (12, 20));
+
+        let (start, end, _) = span("MATCH (?p)-[:x]->(\"open");
+        assert_eq!((start, end), (18, 23));
+
+        let (start, end, reason) = span("MATCH (?p)-[:x]->(?o) LIMIT");
+        assert_eq!((start, end), (27, 27));
+        assert!(reason.contains("end of query"), "{reason}");
+
+        let (start, end, _) = span("FIND (?p)");
+        assert_eq!((start, end), (0, 4));
+    }
+}
@@ -5,11 +5,11 @@
 //! 2. Match patterns against entity state (`MemoryCards`) or graph (Logic-Mesh)
 //! 3. Combine graph-filtered candidates with vector ranking
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use crate::types::{
-    GraphMatchResult, GraphPattern, HybridSearchHit, PatternTerm, QueryPlan, SearchRequest,
-    TriplePattern,
+    EntityAliasTable, GraphMatchResult, GraphPattern, GraphQuery, HybridSearchHit, LinkType,
+    MemoryCard, PatternTerm, QueryPlan, SearchRequest, TriplePattern, VersionRelation,
 };
 use crate::{FrameId, Memvid, Result};
 
@@ -186,11 +186,109 @@ fn extract_possessive_query(query: &str) -> Option<(String, String)> {
     None
 }
 
-/// Graph matcher that executes patterns against `MemoryCards`.
+/// Graph matcher that executes patterns against `MemoryCards` and Logic-Mesh edges.
+///
+/// Each memory card contributes the statement `entity slot value` for its entity's current
+/// value of the slot (the latest `Sets`/`Updates` card plus the `Extends` cards after it,
+/// nothing once retracted). Each Logic-Mesh edge contributes `from link to`, using the
+/// nodes' display names. Entity names are replaced by their canonical entity from the
+/// alias table, so cards recorded under "Bob" and "Bob Smith" describe one subject once the
+/// names are linked. Literal subjects and predicates match case-insensitively (a literal
+/// subject through its alias too), and a literal predicate also matches mesh edges through
+/// its `LinkType` aliases; a literal object matches any value containing it or its
+/// canonical entity.
 pub struct GraphMatcher<'a> {
     memvid: &'a Memvid,
 }
 
+/// A subject-predicate-object statement patterns bind against.
+struct Fact {
+    subject: String,
+    predicate: String,
+    object: String,
+    frame_id: FrameId,
+    confidence: f32,
+    /// Came from a Logic-Mesh edge, so `LinkType` aliases apply to the predicate.
+    mesh: bool,
+}
+
+/// Facts with lookups by lowercase subject and predicate, so triples with a literal or
+/// bound subject or predicate only visit the facts that can match it.
+struct FactIndex {
+    facts: Vec<Fact>,
+    by_subject: HashMap<String, Vec<usize>>,
+    by_predicate: HashMap<String, Vec<usize>>,
+}
+
+impl FactIndex {
+    fn new(facts: Vec<Fact>) -> Self {
+        let mut by_subject: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut by_predicate: HashMap<String, Vec<usize>> = HashMap::new();
+        for (index, fact) in facts.iter().enumerate() {
+            by_subject
+                .entry(fact.subject.to_lowercase())
+                .or_default()
+                .push(index);
+            by_predicate
+                .entry(fact.predicate.to_lowercase())
+                .or_default()
+                .push(index);
+        }
+        Self {
+            facts,
+            by_subject,
+            by_predicate,
+        }
+    }
+
+    /// Facts that may match `triple` under `bindings`, in their original order. Matches
+    /// still have to be confirmed term by term.
+    fn candidates(
+        &self,
+        triple: &TriplePattern,
+        bindings: &HashMap<String, String>,
+        aliases: &EntityAliasTable,
+    ) -> Vec<&Fact> {
+        let subject = match &triple.subject {
+            PatternTerm::Literal(literal) => Some(aliases.resolve(literal).to_lowercase()),
+            PatternTerm::Variable(var) => bindings.get(var).map(|value| value.to_lowercase()),
+        };
+        let mut indices: Vec<usize> = if let Some(subject) = subject {
+            self.by_subject.get(&subject).cloned().unwrap_or_default()
+        } else {
+            let keys = match &triple.predicate {
+                PatternTerm::Literal(literal) => vec![
+                    literal.to_lowercase(),
+                    LinkType::from_str(literal).as_str().to_lowercase(),
+                ],
+                PatternTerm::Variable(var) => match bindings.get(var) {
+                    Some(value) => vec![value.to_lowercase()],
+                    None => return self.facts.iter().collect(),
+                },
+            };
+            keys.iter()
+                .filter_map(|key| self.by_predicate.get(key))
+                .flatten()
+                .copied()
+                .collect()
+        };
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+            .into_iter()
+            .map(|index| &self.facts[index])
+            .collect()
+    }
+}
+
+/// Variable bindings found so far, with the frames that support them.
+#[derive(Debug, Clone)]
+struct Solution {
+    bindings: HashMap<String, String>,
+    frame_ids: Vec<FrameId>,
+    confidence: f32,
+}
+
 impl<'a> GraphMatcher<'a> {
     /// Create a new graph matcher.
     #[must_use]
@@ -198,88 +296,181 @@ impl<'a> GraphMatcher<'a> {
         Self { memvid }
     }
 
-    /// Execute a graph pattern and return matching results.
+    /// Execute a graph pattern and return one result per distinct set of bindings.
+    ///
+    /// Required triples are joined on their shared variables, then optional triples extend
+    /// each solution where they can, then filters are applied. A result's `entity` is the
+    /// value of the first triple's subject and its confidence the lowest confidence among
+    /// the statements it used.
     #[must_use]
     pub fn execute(&self, pattern: &GraphPattern) -> Vec<GraphMatchResult> {
-        let mut results = Vec::new();
-
-        for triple in &pattern.triples {
-            let matches = self.match_triple(triple);
-            results.extend(matches);
+        if pattern.triples.is_empty() {
+            return Vec::new();
+        }
+        let facts = FactIndex::new(self.facts());
+        let aliases = self.memvid.entity_aliases();
+
+        let mut solutions = vec![Solution {
+            bindings: HashMap::new(),
+            frame_ids: Vec::new(),
+            confidence: 1.0,
+        }];
+        for triple in join_order(&pattern.triples) {
+            solutions = solutions
+                .iter()
+                .flat_map(|solution| extend_solution(solution, triple, &facts, aliases))
+                .collect();
+            if solutions.is_empty() {
+                return Vec::new();
+            }
+        }
+        for triple in &pattern.optional {
+            solutions = solutions
+                .into_iter()
+                .flat_map(|solution| {
+                    let extended = extend_solution(&solution, triple, &facts, aliases);
+                    if extended.is_empty() {
+                        vec![solution]
+                    } else {
+                        extended
+                    }
+                })
+                .collect();
         }
+        solutions.retain(|solution| {
+            pattern.filters.iter().all(|filter| {
+                solution
+                    .bindings
+                    .get(&filter.variable)
+                    .is_some_and(|value| filter.matches(value))
+            })
+        });
 
-        // Deduplicate by entity
         let mut seen = HashSet::new();
-        results.retain(|r| seen.insert(r.entity.clone()));
-
+        let mut results = Vec::new();
+        for solution in solutions {
+            let mut key: Vec<(String, String)> = solution
+                .bindings
+                .iter()
+                .map(|(var, value)| (var.clone(), value.to_lowercase()))
+                .collect();
+            key.sort();
+            if !seen.insert(key) {
+                continue;
+            }
+            let entity = match &pattern.triples[0].subject {
+                PatternTerm::Literal(entity) => entity.clone(),
+                PatternTerm::Variable(var) => {
+                    solution.bindings.get(var).cloned().unwrap_or_default()
+                }
+            };
+            let mut result = GraphMatchResult::new(entity, solution.frame_ids, solution.confidence);
+            result.bindings = solution.bindings;
+            results.push(result);
+        }
         results
     }
 
-    fn match_triple(&self, triple: &TriplePattern) -> Vec<GraphMatchResult> {
-        let mut results = Vec::new();
+    /// Execute a parsed [`GraphQuery`]: match its pattern, keep only the `RETURN` variables
+    /// (merging results that become identical) and apply its `LIMIT`. With `RETURN`, a
+    /// result's `entity` is the value of the first returned variable.
+    #[must_use]
+    pub fn execute_query(&self, query: &GraphQuery) -> Vec<GraphMatchResult> {
+        let results = self.execute(&query.pattern);
+        let limit = query.limit.unwrap_or(usize::MAX);
+        if query.returns.is_empty() {
+            return results.into_iter().take(limit).collect();
+        }
 
-        match (&triple.subject, &triple.predicate, &triple.object) {
-            // Pattern: ?entity :slot "value" - find entities with this slot value
-            (
-                PatternTerm::Variable(var),
-                PatternTerm::Literal(slot),
-                PatternTerm::Literal(value),
-            ) => {
-                // Iterate all entities and check for matching slot value
-                for entity in self.memvid.memory_entities() {
-                    let cards = self.memvid.get_entity_memories(&entity);
-                    for card in cards {
-                        if card.slot.to_lowercase() == *slot
-                            && card.value.to_lowercase().contains(&value.to_lowercase())
-                        {
-                            let mut result = GraphMatchResult::new(
-                                entity.clone(),
-                                vec![card.source_frame_id],
-                                1.0,
-                            );
-                            result.bind(var, entity.clone());
-                            results.push(result);
-                            break; // One match per entity
-                        }
+        let mut projected: Vec<GraphMatchResult> = Vec::new();
+        let mut index_by_key: HashMap<Vec<(String, String)>, usize> = HashMap::new();
+        for mut result in results {
+            result.bindings.retain(|var, _| query.returns.contains(var));
+            let mut key: Vec<(String, String)> = result
+                .bindings
+                .iter()
+                .map(|(var, value)| (var.clone(), value.to_lowercase()))
+                .collect();
+            key.sort();
+            if let Some(&index) = index_by_key.get(&key) {
+                let kept = &mut projected[index];
+                for frame_id in result.frame_ids {
+                    if !kept.frame_ids.contains(&frame_id) {
+                        kept.frame_ids.push(frame_id);
                     }
                 }
+                kept.confidence = kept.confidence.max(result.confidence);
+                continue;
             }
-
-            // Pattern: "entity" :slot ?value - get entity's slot value
-            (
-                PatternTerm::Literal(entity),
-                PatternTerm::Literal(slot),
-                PatternTerm::Variable(var),
-            ) => {
-                if let Some(card) = self.memvid.get_current_memory(entity, slot) {
-                    let mut result =
-                        GraphMatchResult::new(entity.clone(), vec![card.source_frame_id], 1.0);
-                    result.bind(var, card.value.clone());
-                    results.push(result);
-                }
+            if projected.len() == limit {
+                continue;
+            }
+            if let Some(value) = result.bindings.get(&query.returns[0]) {
+                result.entity = value.clone();
             }
+            index_by_key.insert(key, projected.len());
+            projected.push(result);
+        }
+        projected
+    }
 
-            // Pattern: "entity" :slot "value" - check if entity has this exact value
-            (
-                PatternTerm::Literal(entity),
-                PatternTerm::Literal(slot),
-                PatternTerm::Literal(value),
-            ) => {
-                if let Some(card) = self.memvid.get_current_memory(entity, slot) {
-                    if card.value.to_lowercase().contains(&value.to_lowercase()) {
-                        let result =
-                            GraphMatchResult::new(entity.clone(), vec![card.source_frame_id], 1.0);
-                        results.push(result);
-                    }
-                }
+    /// The statements patterns are matched against: current memory card values first, then
+    /// Logic-Mesh edges.
+    fn facts(&self) -> Vec<Fact> {
+        let aliases = self.memvid.entity_aliases();
+        let mut by_slot: BTreeMap<(String, String), Vec<&MemoryCard>> = BTreeMap::new();
+        for entity in self.memvid.memory_entities() {
+            for card in self.memvid.get_entity_memories(&entity) {
+                let subject = EntityAliasTable::normalize(aliases.resolve(&card.entity));
+                by_slot
+                    .entry((subject, card.slot.to_lowercase()))
+                    .or_default()
+                    .push(card);
             }
+        }
 
-            _ => {
-                // Other patterns not yet implemented
+        let mut facts = Vec::new();
+        for ((_, slot), mut cards) in by_slot {
+            cards.sort_by_key(|card| std::cmp::Reverse(card.effective_timestamp()));
+            for card in cards {
+                if card.is_retracted() {
+                    break;
+                }
+                facts.push(Fact {
+                    subject: aliases.resolve(&card.entity).to_string(),
+                    predicate: slot.clone(),
+                    object: card.value.clone(),
+                    frame_id: card.source_frame_id,
+                    confidence: card.confidence.unwrap_or(1.0),
+                    mesh: false,
+                });
+                if card.version_relation != VersionRelation::Extends {
+                    break;
+                }
             }
         }
 
-        results
+        let mesh = self.memvid.logic_mesh();
+        let names: HashMap<u64, &str> = mesh
+            .nodes
+            .iter()
+            .map(|node| (node.id, aliases.resolve(&node.display_name)))
+            .collect();
+        for edge in &mesh.edges {
+            let (Some(from), Some(to)) = (names.get(&edge.from_node), names.get(&edge.to_node))
+            else {
+                continue;
+            };
+            facts.push(Fact {
+                subject: (*from).to_string(),
+                predicate: edge.link.as_str().to_string(),
+                object: (*to).to_string(),
+                frame_id: edge.frame_id,
+                confidence: edge.confidence_f32(),
+                mesh: true,
+            });
+        }
+        facts
     }
 
     /// Get frame IDs from graph matches for use in vector search filtering.
@@ -307,6 +498,122 @@ impl<'a> GraphMatcher<'a> {
     }
 }
 
+/// Order required triples so each step binds against as many known terms as possible:
+/// repeatedly take the triple with the most literals and already-bound variables.
+fn join_order(triples: &[TriplePattern]) -> Vec<&TriplePattern> {
+    let mut remaining: Vec<&TriplePattern> = triples.iter().collect();
+    let mut bound: HashSet<&str> = HashSet::new();
+    let mut ordered = Vec::with_capacity(remaining.len());
+    while !remaining.is_empty() {
+        let known = |term: &PatternTerm| term.variable_name().is_none_or(|var| bound.contains(var));
+        let index = remaining
+            .iter()
+            .enumerate()
+            .max_by_key(|(index, triple)| {
+                let score = [&triple.subject, &triple.predicate, &triple.object]
+                    .into_iter()
+                    .filter(|term| known(term))
+                    .count();
+                (score, std::cmp::Reverse(*index))
+            })
+            .map_or(0, |(index, _)| index);
+        let triple = remaining.remove(index);
+        for term in [&triple.subject, &triple.predicate, &triple.object] {
+            if let Some(var) = term.variable_name() {
+                bound.insert(var);
+            }
+        }
+        ordered.push(triple);
+    }
+    ordered
+}
+
+/// Every way `triple` matches a fact consistently with `solution`'s bindings.
+fn extend_solution(
+    solution: &Solution,
+    triple: &TriplePattern,
+    facts: &FactIndex,
+    aliases: &EntityAliasTable,
+) -> Vec<Solution> {
+    let mut extended = Vec::new();
+    let mut scratch = Vec::new();
+    for fact in facts.candidates(triple, &solution.bindings, aliases) {
+        scratch.clear();
+        let predicate_matches = |literal: &str| {
+            fact.predicate.eq_ignore_ascii_case(literal)
+                || (fact.mesh && LinkType::from_str(literal).as_str() == fact.predicate)
+        };
+        let matched = bind_term(
+            &triple.subject,
+            &fact.subject,
+            &solution.bindings,
+            &mut scratch,
+            |literal| fact.subject.to_lowercase() == aliases.resolve(literal).to_lowercase(),
+        ) && bind_term(
+            &triple.predicate,
+            &fact.predicate,
+            &solution.bindings,
+            &mut scratch,
+            predicate_matches,
+        ) && bind_term(
+            &triple.object,
+            &fact.object,
+            &solution.bindings,
+            &mut scratch,
+            |literal| {
+                let object = fact.object.to_lowercase();
+                object.contains(&literal.to_lowercase())
+                    || object.contains(&aliases.resolve(literal).to_lowercase())
+            },
+        );
+        if !matched {
+            continue;
+        }
+        let mut bindings = solution.bindings.clone();
+        for (var, value) in &scratch {
+            bindings.insert((*var).to_string(), (*value).to_string());
+        }
+        let mut frame_ids = solution.frame_ids.clone();
+        if !frame_ids.contains(&fact.frame_id) {
+            frame_ids.push(fact.frame_id);
+        }
+        extended.push(Solution {
+            bindings,
+            frame_ids,
+            confidence: solution.confidence.min(fact.confidence),
+        });
+    }
+    extended
+}
+
+/// Match one pattern term against a fact value: literals through `literal_matches`, bound
+/// variables by case-insensitive equality, free variables by recording them in `scratch`
+/// on top of `bindings`.
+fn bind_term<'a>(
+    term: &'a PatternTerm,
+    value: &'a str,
+    bindings: &HashMap<String, String>,
+    scratch: &mut Vec<(&'a str, &'a str)>,
+    literal_matches: impl Fn(&str) -> bool,
+) -> bool {
+    match term {
+        PatternTerm::Literal(literal) => literal_matches(literal),
+        PatternTerm::Variable(var) => {
+            let bound = bindings.get(var).map(String::as_str).or_else(|| {
+                scratch
+                    .iter()
+                    .find(|(name, _)| name == var)
+                    .map(|(_, bound)| *bound)
+            });
+            if let Some(bound) = bound {
+                return bound.to_lowercase() == value.to_lowercase();
+            }
+            scratch.push((var, value));
+            true
+        }
+    }
+}
+
 /// Execute a hybrid search: graph filter + vector ranking.
 pub fn hybrid_search(memvid: &mut Memvid, plan: &QueryPlan) -> Result<Vec<HybridSearchHit>> {
     match plan {
@@ -327,6 +634,9 @@ pub fn hybrid_search(memvid: &mut Memvid, plan: &QueryPlan) -> Result<Vec<Hybrid
                 as_of_frame: None,
                 as_of_ts: None,
                 no_sketch: false,
+                reranker: None,
+                filter: None,
+                fusion: None,
             };
             let response = memvid.search(request)?;
             Ok(response
@@ -391,6 +701,9 @@ pub fn hybrid_search(memvid: &mut Memvid, plan: &QueryPlan) -> Result<Vec<Hybrid
                     as_of_frame: None,
                     as_of_ts: None,
                     no_sketch: false,
+                    reranker: None,
+                    filter: None,
+                    fusion: None,
                 };
                 let response = memvid.search(request)?;
                 return Ok(response
@@ -438,6 +751,197 @@ pub fn hybrid_search(memvid: &mut Memvid, plan: &QueryPlan) -> Result<Vec<Hybrid
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::run_serial_test;
+    use crate::types::{CompareOp, EntityKind, MemoryCardBuilder, MeshEdge, MeshNode, ValueFilter};
+    use tempfile::tempdir;
+
+    fn card(entity: &str, slot: &str, value: &str, date: i64) -> MemoryCardBuilder {
+        MemoryCardBuilder::new()
+            .fact()
+            .entity(entity)
+            .slot(slot)
+            .value(value)
+            .document_date(date)
+            .source(0, None)
+            .engine("test", "1.0.0")
+    }
+
+    /// Alice and Carol live in Berlin, Bob moved from Paris to Munich; Alice, Bob and Carol
+    /// have workplaces in cards, and the mesh says Dave and Alice work for Acme.
+    fn with_people<T>(f: impl FnOnce(&Memvid) -> T) -> T {
+        run_serial_test(|| {
+            let dir = tempdir().expect("tmp");
+            let mut mem = Memvid::create(dir.path().join("graph.mv2")).expect("create");
+            let cards = [
+                card("alice", "workplace", "Acme", 1),
+                card("alice", "location", "Berlin", 1),
+                card("bob", "workplace", "Acme", 1),
+                card("bob", "location", "Paris", 1),
+                card("bob", "location", "Munich", 2).updates(),
+                card("carol", "workplace", "Globex", 1),
+                card("carol", "location", "Berlin", 1),
+            ];
+            for builder in cards {
+                mem.put_memory_card(builder.build(0).expect("card"))
+                    .expect("put card");
+            }
+
+            let acme = MeshNode::new(
+                "acme".into(),
+                "Acme".into(),
+                EntityKind::Organization,
+                0.9,
+                1,
+                0,
+                4,
+            );
+            let dave = MeshNode::new(
+                "dave".into(),
+                "Dave".into(),
+                EntityKind::Person,
+                0.9,
+                1,
+                10,
+                4,
+            );
+            let alice = MeshNode::new(
+                "alice".into(),
+                "Alice".into(),
+                EntityKind::Person,
+                0.9,
+                2,
+                0,
+                5,
+            );
+            let edges = [
+                MeshEdge::new(dave.id, acme.id, LinkType::Employer, 0.7, 1),
+                MeshEdge::new(alice.id, acme.id, LinkType::Employer, 0.8, 2),
+            ];
+            let mesh = mem.logic_mesh_mut();
+            mesh.merge_node(acme);
+            mesh.merge_node(dave);
+            mesh.merge_node(alice);
+            for edge in edges {
+                mesh.merge_edge(edge);
+            }
+            mesh.finalize();
+            f(&mem)
+        })
+    }
+
+    fn bound(results: &[GraphMatchResult], var: &str) -> Vec<String> {
+        let mut values: Vec<String> = results
+            .iter()
+            .filter_map(|result| result.bindings.get(var).cloned())
+            .collect();
+        values.sort();
+        values
+    }
+
+    #[test]
+    fn test_matcher_joins_shared_variables() {
+        with_people(|mem| {
+            let mut pattern = GraphPattern::new();
+            pattern.add(TriplePattern::any_slot_value("p", "workplace", "acme"));
+            pattern.add(TriplePattern::any_slot_value("p", "location", "berlin"));
+            let results = GraphMatcher::new(mem).execute(&pattern);
+            assert_eq!(bound(&results, "p"), vec!["alice"]);
+            assert_eq!(results[0].entity, "alice");
+
+            // Bob's current location is Munich, so his old Paris card no longer matches.
+            let pattern =
+                GraphPattern::single(TriplePattern::any_slot_value("p", "location", "paris"));
+            assert!(GraphMatcher::new(mem).execute(&pattern).is_empty());
+        });
+    }
+
+    #[test]
+    fn test_matcher_variable_predicates_and_mesh_edges() {
+        with_people(|mem| {
+            let pattern = GraphPattern::single(TriplePattern::new(
+                PatternTerm::Literal("alice".into()),
+                PatternTerm::Variable("rel".into()),
+                PatternTerm::Variable("value".into()),
+            ));
+            let results = GraphMatcher::new(mem).execute(&pattern);
+            assert_eq!(
+                bound(&results, "rel"),
+                vec!["employer", "location", "workplace"]
+            );
+
+            // `works_at` is an alias of the mesh's `employer` link.
+            let pattern =
+                GraphPattern::single(TriplePattern::any_slot_value("who", "works_at", "acme"));
+            let results = GraphMatcher::new(mem).execute(&pattern);
+            assert_eq!(bound(&results, "who"), vec!["Alice", "Dave"]);
+            let dave = results
+                .iter()
+                .find(|result| result.entity == "Dave")
+                .expect("dave");
+            assert_eq!(dave.frame_ids, vec![1]);
+            assert!((dave.confidence - 0.7).abs() < f32::EPSILON);
+        });
+    }
+
+    #[test]
+    fn test_graph_query_text() {
+        with_people(|mem| {
+            let results = mem
+                .graph_query(
+                    r#"MATCH (?p)-[:workplace]->("acme") WHERE ?p.location = "berlin" RETURN ?p"#,
+                )
+                .expect("query");
+            assert_eq!(bound(&results, "p"), vec!["alice"]);
+            assert_eq!(results[0].entity, "alice");
+            assert_eq!(results[0].bindings.len(), 1);
+
+            // Projecting onto ?org merges the people working there; LIMIT caps the rest.
+            let results = mem
+                .graph_query("match (?p)-[:workplace]->(?org) return ?org limit 5")
+                .expect("query");
+            assert_eq!(bound(&results, "org"), vec!["Acme", "Globex"]);
+            let acme = results
+                .iter()
+                .find(|result| result.entity == "Acme")
+                .expect("acme");
+            assert_eq!(acme.frame_ids, vec![0]);
+            let limited = mem
+                .graph_query("MATCH (?p)-[:location]->(?city) RETURN ?p LIMIT 2")
+                .expect("query");
+            assert_eq!(limited.len(), 2);
+
+            match mem.graph_query("MATCH (?p)-[:workplace]->(?org) RETURN ?city") {
+                Err(crate::MemvidError::InvalidGraphQuery { start, end, .. }) => {
+                    assert_eq!((start, end), (39, 44));
+                }
+                other => panic!("expected an invalid graph query, got {other:?}"),
+            }
+        });
+    }
+
+    #[test]
+    fn test_matcher_optional_patterns_and_filters() {
+        with_people(|mem| {
+            let mut pattern = GraphPattern::new();
+            pattern.add(TriplePattern::new(
+                PatternTerm::Variable("p".into()),
+                PatternTerm::Variable("link".into()),
+                PatternTerm::Literal("acme".into()),
+            ));
+            pattern.add_optional(TriplePattern::new(
+                PatternTerm::Variable("p".into()),
+                PatternTerm::Literal("location".into()),
+                PatternTerm::Variable("city".into()),
+            ));
+            let results = GraphMatcher::new(mem).execute(&pattern);
+            assert_eq!(bound(&results, "p"), vec!["Alice", "Dave", "alice", "bob"]);
+            assert_eq!(bound(&results, "city"), vec!["Berlin", "Berlin", "Munich"]);
+
+            pattern.add_filter(ValueFilter::new("?city", CompareOp::Ne, "berlin"));
+            let results = GraphMatcher::new(mem).execute(&pattern);
+            assert_eq!(bound(&results, "p"), vec!["bob"]);
+        });
+    }
 
     #[test]
     fn test_query_planner_detects_location() {
@@ -36,6 +36,8 @@ pub struct EmbeddedWal {
     checkpoint_sequence: u64,
     appends_since_checkpoint: u64,
     read_only: bool,
+    /// When false, appends skip the per-record fsync until `sync` is called.
+    sync_on_append: bool,
 }
 
 impl EmbeddedWal {
@@ -80,6 +82,7 @@ impl EmbeddedWal {
             checkpoint_sequence,
             appends_since_checkpoint: 0,
             read_only,
+            sync_on_append: true,
         };
 
         if !wal.read_only {
@@ -173,6 +176,34 @@ impl EmbeddedWal {
         self.maybe_write_sentinel()
     }
 
+    /// Zero the whole region once every record has been checkpointed.
+    ///
+    /// Applied records keep the bytes they logged until the ring wraps over them, so
+    /// callers that must not leave deleted content behind scrub the region explicitly.
+    /// Sequence numbers carry on from where they were.
+    pub fn scrub_committed(&mut self, header: &mut Header) -> Result<()> {
+        self.assert_writable()?;
+        if self.pending_bytes > 0 {
+            return Err(MemvidError::CheckpointFailed {
+                reason: "cannot scrub WAL with pending records".into(),
+            });
+        }
+        let zeros = vec![0u8; 64 * 1024];
+        let mut written = 0u64;
+        self.file.seek(SeekFrom::Start(self.region_offset))?;
+        while written < self.region_size {
+            let len = usize::try_from(self.region_size - written)
+                .map_or(zeros.len(), |remaining| remaining.min(zeros.len()));
+            self.file.write_all(&zeros[..len])?;
+            written += len as u64;
+        }
+        self.write_head = 0;
+        self.checkpoint_head = 0;
+        header.wal_checkpoint_pos = 0;
+        header.wal_sequence = self.checkpoint_sequence;
+        Ok(())
+    }
+
     pub fn pending_records(&mut self) -> Result<Vec<WalRecord>> {
         self.records_after(self.checkpoint_sequence)
     }
@@ -202,6 +233,19 @@ impl EmbeddedWal {
             .collect())
     }
 
+    /// Toggle the per-record fsync. Batch writers disable it and call `sync` once.
+    pub fn set_sync_on_append(&mut self, enabled: bool) {
+        self.sync_on_append = enabled;
+    }
+
+    /// Flush all appended records to disk.
+    pub fn sync(&self) -> Result<()> {
+        if !self.read_only {
+            self.file.sync_all()?;
+        }
+        Ok(())
+    }
+
     #[must_use]
     pub fn stats(&self) -> WalStats {
         WalStats {
@@ -250,7 +294,9 @@ impl EmbeddedWal {
 
         // Force fsync to ensure data is durable before returning
         // Critical for preventing corruption during rapid file operations
-        self.file.sync_all()?;
+        if self.sync_on_append {
+            self.file.sync_all()?;
+        }
 
         Ok(())
     }
@@ -269,6 +315,11 @@ impl EmbeddedWal {
                 let zero_tail = vec![0u8; remaining as usize];
                 self.seek_and_write(pos, &zero_tail)?;
             }
+            // Records are scanned from the start of the region, so a sentinel there would
+            // hide every pending record; the next append grows the WAL instead.
+            if self.pending_bytes > 0 {
+                return Ok(pos);
+            }
             pos = 0;
         }
         let zero = [0u8; ENTRY_HEADER_SIZE];
@@ -472,6 +523,44 @@ mod tests {
         assert_eq!(records[0].payload, vec![0xCC; 32]);
     }
 
+    #[test]
+    fn pending_records_survive_tail_sentinel() {
+        let size = (ENTRY_HEADER_SIZE as u64 * 2) + 64;
+        let (file, mut header) = prepare_wal(size);
+        let mut wal = EmbeddedWal::open(&file, &header).expect("open wal");
+
+        wal.append_entry(&[0xAA; 32]).expect("append a");
+        wal.record_checkpoint(&mut header).expect("checkpoint");
+        // Leaves less than a record header before the end of the region.
+        wal.append_entry(&[0xBB; 8]).expect("append b");
+
+        let mut reopened = EmbeddedWal::open(&file, &header).expect("reopen");
+        let records = reopened.pending_records().expect("pending");
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].payload, vec![0xBB; 8]);
+        assert!(reopened.append_entry(&[0xCC; 8]).is_err());
+    }
+
+    #[test]
+    fn scrub_committed_zeroes_region() {
+        let (mut file, mut header) = prepare_wal(1024);
+        let mut wal = EmbeddedWal::open(&file, &header).expect("open wal");
+        wal.append_entry(b"secret payload").expect("append");
+        assert!(wal.scrub_committed(&mut header).is_err());
+
+        wal.record_checkpoint(&mut header).expect("checkpoint");
+        wal.scrub_committed(&mut header).expect("scrub");
+
+        let mut region = vec![0u8; 1024];
+        file.seek(SeekFrom::Start(WAL_OFFSET)).expect("seek");
+        file.read_exact(&mut region).expect("read region");
+        assert!(region.iter().all(|&byte| byte == 0));
+
+        let mut reopened = EmbeddedWal::open(&file, &header).expect("reopen");
+        assert!(reopened.pending_records().expect("pending").is_empty());
+        assert_eq!(reopened.append_entry(b"next").expect("append"), 2);
+    }
+
     #[test]
     fn corrupted_record_reports_offset() {
         let (mut file, header) = prepare_wal(64);
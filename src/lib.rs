@@ -91,6 +91,7 @@ pub mod models;
 pub mod pii;
 pub mod reader;
 mod registry;
+pub mod rerank;
 mod search;
 pub mod signature;
 pub mod structure;
@@ -113,6 +114,9 @@ pub mod triplet;
 // Graph-aware search for hybrid retrieval
 pub mod graph_search;
 
+// Textual graph query language (MATCH ... WHERE ... RETURN)
+mod graph_parser;
+
 // CLIP module is always compiled (for ClipIndexManifest serde compatibility)
 // but ClipModel/inference requires the "clip" feature
 pub mod clip;
@@ -139,6 +143,18 @@ pub mod symspell_cleanup;
 #[cfg(feature = "api_embed")]
 pub mod api_embed;
 
+// OpenAI-compatible chat completion client for answer synthesis - requires network
+#[cfg(feature = "api_llm")]
+pub mod api_llm;
+
+// JSON API routing for the memvid-serve HTTP binary
+#[cfg(feature = "serve")]
+pub mod serve;
+
+// MCP (JSON-RPC over stdio) tools for the memvid-mcp binary
+#[cfg(feature = "mcp")]
+pub mod mcp;
+
 #[cfg(test)]
 mod tests_lex_flag;
 
@@ -171,9 +187,10 @@ pub use io::time_index::{
 pub use io::wal::{EmbeddedWal, WalRecord, WalStats};
 pub use lex::{LexIndex, LexIndexArtifact, LexIndexBuilder, LexSearchHit};
 pub use lock::FileLock;
+use lock::LockMode;
 pub use memvid::{
     BlobReader, EnrichmentHandle, EnrichmentStats, LockSettings, Memvid, OpenReadOptions,
-    SketchCandidate, SketchSearchOptions, SketchSearchStats,
+    SNAPSHOT_LEASE_TTL, SketchCandidate, SketchSearchOptions, SketchSearchStats,
     mutation::{CommitMode, CommitOptions},
     start_enrichment_worker, start_enrichment_worker_with_embeddings,
 };
@@ -183,12 +200,15 @@ pub use models::{
     ModelManifest, ModelManifestEntry, ModelVerification, ModelVerificationStatus,
     ModelVerifyOptions, verify_model_dir, verify_models,
 };
+#[cfg(feature = "whisper")]
+pub use reader::AudioReader;
 pub use reader::{
-    DocumentFormat, DocumentReader, PassthroughReader, PdfReader, ReaderDiagnostics, ReaderHint,
-    ReaderOutput, ReaderRegistry,
+    DocumentFormat, DocumentReader, ImageFormat, ImageProbe, PassthroughReader, PdfReader,
+    ReaderDiagnostics, ReaderHint, ReaderOutput, ReaderRegistry, probe_image,
 };
 pub use signature::{
-    parse_ed25519_public_key_base64, verify_model_manifest, verify_ticket_signature,
+    parse_ed25519_public_key_base64, sign_purge_receipt, verify_model_manifest,
+    verify_purge_receipt, verify_ticket_signature,
 };
 pub use text::{NormalizedText, normalize_text, truncate_at_grapheme_boundary};
 #[cfg(feature = "temporal_track")]
@@ -200,21 +220,26 @@ pub use types::{
 };
 pub use types::{
     AskCitation, AskMode, AskRequest, AskResponse, AskRetriever, AskStats, AudioSegmentMetadata,
-    AuditOptions, AuditReport, CanonicalEncoding, DOCTOR_PLAN_VERSION, DocAudioMetadata,
-    DocExifMetadata, DocGpsMetadata, DocMetadata, DoctorActionDetail, DoctorActionKind,
-    DoctorActionPlan, DoctorActionReport, DoctorActionStatus, DoctorFinding, DoctorFindingCode,
-    DoctorMetrics, DoctorOptions, DoctorPhaseDuration, DoctorPhaseKind, DoctorPhasePlan,
-    DoctorPhaseReport, DoctorPhaseStatus, DoctorPlan, DoctorReport, DoctorSeverity, DoctorStatus,
-    EmbeddingIdentity, EmbeddingIdentityCount, EmbeddingIdentitySummary, Frame, FrameId, FrameRole,
-    FrameStatus, Header, IndexManifests, LexIndexManifest, LexSegmentDescriptor,
-    MEMVID_EMBEDDING_DIMENSION_KEY, MEMVID_EMBEDDING_MODEL_KEY, MEMVID_EMBEDDING_NORMALIZED_KEY,
-    MEMVID_EMBEDDING_PROVIDER_KEY, MediaManifest, MemvidHandle, Open, PutOptions,
-    PutOptionsBuilder, Sealed, SearchEngineKind, SearchHit, SearchHitMetadata, SearchParams,
+    AuditOptions, AuditReport, BACKUP_MAGIC, BackupReport, CanonicalEncoding, DOCTOR_PLAN_VERSION,
+    DocAudioMetadata, DocExifMetadata, DocGpsMetadata, DocMetadata, DoctorActionDetail,
+    DoctorActionKind, DoctorActionPlan, DoctorActionReport, DoctorActionStatus, DoctorFinding,
+    DoctorFindingCode, DoctorMetrics, DoctorOptions, DoctorPhaseDuration, DoctorPhaseKind,
+    DoctorPhasePlan, DoctorPhaseReport, DoctorPhaseStatus, DoctorPlan, DoctorReport,
+    DoctorSeverity, DoctorStatus, EmbeddingIdentity, EmbeddingIdentityCount,
+    EmbeddingIdentitySummary, ExportOptions, FilterExpr, FilterField, Frame, FrameId, FrameRecord,
+    FrameRole, FrameStatus, FusionMethod, GenerationMark, Header, INTERCHANGE_FORMAT,
+    INTERCHANGE_VERSION, ImportOptions, ImportReport, IndexManifests, IngestOptions, IngestReport,
+    InterchangeCounts, InterchangeHeader, InterchangeRecord, LexIndexManifest,
+    LexSegmentDescriptor, MEMVID_EMBEDDING_DIMENSION_KEY, MEMVID_EMBEDDING_MODEL_KEY,
+    MEMVID_EMBEDDING_NORMALIZED_KEY, MEMVID_EMBEDDING_PROVIDER_KEY, MediaManifest, MemvidHandle,
+    Open, PurgeArtifacts, PurgeReceipt, PurgeSelector, PurgedFrame, PutManyOpts, PutOptions,
+    PutOptionsBuilder, PutRequest, RetentionLimit, RetentionPolicy, RetentionReport, RetentionRule,
+    RetentionRuleOutcome, Sealed, SearchEngineKind, SearchHit, SearchHitMetadata, SearchParams,
     SearchRequest, SearchResponse, SegmentCatalog, SegmentCommon, SegmentCompression, SegmentMeta,
     SegmentSpan, SourceSpan, Stats, TextChunkManifest, TextChunkRange, Ticket, TicketRef, Tier,
     TimeIndexManifest, TimeSegmentDescriptor, TimelineEntry, TimelineQuery, TimelineQueryBuilder,
-    Toc, VecEmbedder, VecIndexManifest, VecSegmentDescriptor, VectorCompression, VerificationCheck,
-    VerificationReport, VerificationStatus,
+    Toc, UriConflict, VacuumReport, VecEmbedder, VecIndexManifest, VecSegmentDescriptor,
+    VectorCompression, VerificationCheck, VerificationReport, VerificationStatus,
 };
 // Memory card types for structured memory extraction and storage
 pub use types::{
@@ -224,8 +249,10 @@ pub use types::{
 };
 // Logic-Mesh types for entity-relationship graph traversal
 pub use types::{
-    EdgeDirection, EntityKind, FollowResult, LOGIC_MESH_MAGIC, LOGIC_MESH_VERSION, LinkType,
-    LogicMesh, LogicMeshManifest, MeshEdge, MeshNode,
+    AliasSource, CentralityMeasure, CentralityScore, CommunityMethod, EdgeDirection, EntityAlias,
+    EntityAliasTable, EntityKind, FollowResult, GraphFormat, LOGIC_MESH_MAGIC, LOGIC_MESH_VERSION,
+    LinkType, LogicMesh, LogicMeshManifest, MeshCommunity, MeshEdge, MeshNode, MeshPath,
+    MeshPathStep, NTriplesImport,
 };
 // Sketch track types for fast candidate generation
 pub use types::{
@@ -261,9 +288,14 @@ pub use types::{
     EmbeddingResult,
 };
 // Reranker types for second-stage ranking in RAG pipelines
+pub use rerank::Bm25Reranker;
 pub use types::reranker::{
     Reranker, RerankerConfig, RerankerDocument, RerankerKind, RerankerResult,
 };
+// Answer synthesis types for turning retrieved context into answers
+pub use types::synthesis::{
+    AnswerSynthesizer, ExtractiveSynthesizer, SynthesisConfig, SynthesisPrompt, SynthesizedAnswer,
+};
 #[cfg(feature = "parallel_segments")]
 pub use types::{IndexSegmentRef, SegmentKind, SegmentStats};
 pub use vec::{VecIndex, VecIndexArtifact, VecSearchHit};
@@ -277,12 +309,21 @@ pub use text_embed::{
     LocalTextEmbedder, TEXT_EMBED_MODELS, TextEmbedConfig, TextEmbedModelInfo,
     default_text_model_info, get_text_model_info,
 };
+// Local cross-encoder reranker - feature-gated
+#[cfg(feature = "vec")]
+pub use rerank::{
+    CROSS_ENCODER_MODELS, CrossEncoderConfig, CrossEncoderModelInfo, CrossEncoderReranker,
+    default_cross_encoder_model_info, get_cross_encoder_model_info,
+};
 // API-based embedding providers - feature-gated
 #[cfg(feature = "api_embed")]
 pub use api_embed::{
     OPENAI_MODELS, OpenAIConfig, OpenAIEmbedder, OpenAIModelInfo, default_openai_model_info,
     get_openai_model_info,
 };
+// OpenAI-compatible answer synthesis - feature-gated
+#[cfg(feature = "api_llm")]
+pub use api_llm::{OpenAIChatConfig, OpenAIChatSynthesizer};
 // CLIP visual embeddings - types always available for serde compatibility
 pub use clip::{
     CLIP_MODELS, ClipConfig, ClipDocument, ClipEmbeddingProvider, ClipError, ClipIndex,
@@ -300,7 +341,7 @@ pub use whisper::{
 };
 // Audio decoding and transcription require the "whisper" feature
 #[cfg(feature = "whisper")]
-pub use whisper::{WHISPER_SAMPLE_RATE, WhisperTranscriber, decode_audio_file};
+pub use whisper::{WHISPER_SAMPLE_RATE, WhisperTranscriber, decode_audio_bytes, decode_audio_file};
 // Structure-aware chunking for preserving tables and code blocks
 pub use structure::{
     ChunkType, ChunkingOptions, ChunkingResult, StructuralChunker, StructuredChunk,
@@ -346,9 +387,10 @@ static SERIAL_TEST_MUTEX: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
 
 #[cfg(test)]
 pub(crate) fn run_serial_test<T>(f: impl FnOnce() -> T) -> T {
+    // A panicking test poisons the mutex; recover so one failure does not cascade.
     let _guard = SERIAL_TEST_MUTEX
         .lock()
-        .expect("memvid-core serial test mutex poisoned");
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
     f()
 }
 
@@ -415,8 +457,19 @@ impl Memvid {
         self.read_only
     }
 
+    /// Upgrade a shared read-only handle to exclusive access before mutating.
+    ///
+    /// Snapshot readers from [`Memvid::open_read_only`] hold no lock to upgrade and a TOC
+    /// that may be behind the file, so unlike shared handles they are refused rather than
+    /// upgraded in place.
     pub(crate) fn ensure_writable(&mut self) -> Result<()> {
         if self.read_only {
+            if self.lock.mode() == LockMode::None {
+                return Err(MemvidError::Lock(
+                    "read-only snapshot cannot be upgraded; reopen with Memvid::open to write"
+                        .to_string(),
+                ));
+            }
             self.lock.upgrade_to_exclusive()?;
             self.read_only = false;
         }
@@ -693,6 +746,9 @@ mod tests {
                 as_of_frame: None,
                 as_of_ts: None,
                 no_sketch: false,
+                reranker: None,
+                filter: None,
+                fusion: None,
             };
             let response = mem.search(request).expect("search");
             assert_eq!(response.hits.len(), 1);
@@ -712,6 +768,9 @@ mod tests {
                 as_of_frame: None,
                 as_of_ts: None,
                 no_sketch: false,
+                reranker: None,
+                filter: None,
+                fusion: None,
             };
             let response = reopened.search(request).expect("search reopened");
             assert_eq!(response.hits.len(), 1);
@@ -783,6 +842,9 @@ mod tests {
                     as_of_frame: None,
                     as_of_ts: None,
                     no_sketch: false,
+                    reranker: None,
+                    filter: None,
+                    fusion: None,
                 })
                 .expect("search");
 
@@ -844,6 +906,9 @@ mod tests {
                     as_of_frame: None,
                     as_of_ts: None,
                     no_sketch: false,
+                    reranker: None,
+                    filter: None,
+                    fusion: None,
                 })
                 .expect("search");
 
@@ -928,6 +993,9 @@ mod tests {
                     as_of_frame: None,
                     as_of_ts: None,
                     no_sketch: false,
+                    reranker: None,
+                    filter: None,
+                    fusion: None,
                 })
                 .expect("uri search");
             assert_eq!(uri_response.engine, SearchEngineKind::Tantivy);
@@ -951,6 +1019,9 @@ mod tests {
                     as_of_frame: None,
                     as_of_ts: None,
                     no_sketch: false,
+                    reranker: None,
+                    filter: None,
+                    fusion: None,
                 })
                 .expect("scope search");
             assert_eq!(scope_response.engine, SearchEngineKind::Tantivy);
@@ -1004,6 +1075,9 @@ mod tests {
                     as_of_frame: None,
                     as_of_ts: None,
                     no_sketch: false,
+                    reranker: None,
+                    filter: None,
+                    fusion: None,
                 })
                 .expect("page one");
             assert_eq!(first_page.engine, SearchEngineKind::Tantivy);
@@ -1027,6 +1101,9 @@ mod tests {
                     as_of_frame: None,
                     as_of_ts: None,
                     no_sketch: false,
+                    reranker: None,
+                    filter: None,
+                    fusion: None,
                 })
                 .expect("page two");
             assert_eq!(second_page.engine, SearchEngineKind::Tantivy);
@@ -1068,6 +1145,9 @@ mod tests {
                     as_of_frame: None,
                     as_of_ts: None,
                     no_sketch: false,
+                    reranker: None,
+                    filter: None,
+                    fusion: None,
                 })
                 .expect("search with tantivy");
 
@@ -0,0 +1,449 @@
+//! Model Context Protocol (MCP) server behind the `memvid-mcp` binary.
+//!
+//! [`McpServer`] speaks JSON-RPC 2.0 over newline-delimited stdio, the MCP stdio
+//! transport, and exposes one open [`Memvid`] to an agent as tools:
+//!
+//! | Tool | Arguments | Result |
+//! |------|-----------|--------|
+//! | `memory_put` | `PutRequest` fields | `{"frame_id"}` |
+//! | `memory_search` | `SearchRequest` fields, optional `query_embedding` | `SearchResponse` |
+//! | `memory_ask` | `AskRequest` fields | `AskResponse` |
+//! | `memory_get_current` | `entity`, `slot` | `MemoryCard` or `null` |
+//! | `memory_timeline` | `TimelineQuery` fields | `[TimelineEntry]` |
+//! | `mesh_follow` | `start`, `link`, optional `hops` | `[FollowResult]` |
+//!
+//! Numeric request fields the library requires (`top_k`, `snippet_chars`) default when
+//! omitted. Each `tools/call` is recorded as an [`ActionType::ToolCall`] when a replay
+//! session is active. Requires the `mcp` feature.
+//!
+//! [`ActionType::ToolCall`]: crate::replay::ActionType::ToolCall
+
+use std::io::{self, BufRead, Write};
+use std::time::Instant;
+
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value, json};
+
+use crate::memvid::lifecycle::Memvid;
+use crate::types::{AskRequest, FrameId, PutRequest, SearchRequest, TimelineQuery, VecEmbedder};
+
+/// MCP protocol revision this server implements.
+pub const PROTOCOL_VERSION: &str = "2024-11-05";
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+const DEFAULT_SEARCH_TOP_K: u64 = 10;
+const DEFAULT_ASK_TOP_K: u64 = 8;
+const DEFAULT_SNIPPET_CHARS: u64 = 240;
+const DEFAULT_TIMELINE_LIMIT: u64 = 50;
+
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ToolCall {
+    name: String,
+    #[serde(default)]
+    arguments: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchArgs {
+    #[serde(flatten)]
+    request: SearchRequest,
+    #[serde(default)]
+    query_embedding: Option<Vec<f32>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CurrentMemoryArgs {
+    entity: String,
+    slot: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct FollowArgs {
+    start: String,
+    link: String,
+    #[serde(default = "default_hops")]
+    hops: usize,
+}
+
+fn default_hops() -> usize {
+    1
+}
+
+/// Tool output plus the frames it wrote or returned, for replay recording.
+type ToolOutput = (Value, Vec<FrameId>);
+
+/// Serves MCP tool calls against an open memory.
+pub struct McpServer {
+    memvid: Memvid,
+}
+
+impl McpServer {
+    #[must_use]
+    pub fn new(memvid: Memvid) -> Self {
+        Self { memvid }
+    }
+
+    #[must_use]
+    pub fn memvid(&self) -> &Memvid {
+        &self.memvid
+    }
+
+    pub fn memvid_mut(&mut self) -> &mut Memvid {
+        &mut self.memvid
+    }
+
+    /// Release the memory, dropping the server.
+    #[must_use]
+    pub fn into_inner(self) -> Memvid {
+        self.memvid
+    }
+
+    /// Answer newline-delimited JSON-RPC messages from `input` until it is exhausted.
+    ///
+    /// # Errors
+    /// Returns an error if reading from `input` or writing to `output` fails.
+    pub fn run<R: BufRead, W: Write>(&mut self, input: R, mut output: W) -> io::Result<()> {
+        for line in input.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Some(reply) = self.handle_message(&line) {
+                writeln!(output, "{reply}")?;
+                output.flush()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Answer one JSON-RPC message; notifications produce no reply.
+    pub fn handle_message(&mut self, message: &str) -> Option<Value> {
+        let message: Value = match serde_json::from_str(message) {
+            Ok(message) => message,
+            Err(err) => {
+                return Some(error_reply(
+                    Value::Null,
+                    &RpcError::new(PARSE_ERROR, format!("parse error: {err}")),
+                ));
+            }
+        };
+        let id = message.get("id").cloned();
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            return Some(error_reply(
+                id.unwrap_or(Value::Null),
+                &RpcError::new(INVALID_REQUEST, "missing method"),
+            ));
+        };
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+        let result = self.dispatch(method, params);
+        // Notifications carry no id and never get a reply, even on error.
+        let id = id?;
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(err) => error_reply(id, &err),
+        })
+    }
+
+    fn dispatch(&mut self, method: &str, params: Value) -> Result<Value, RpcError> {
+        match method {
+            "initialize" => Ok(json!({
+                "protocolVersion": PROTOCOL_VERSION,
+                "capabilities": { "tools": { "listChanged": false } },
+                "serverInfo": { "name": "memvid", "version": env!("CARGO_PKG_VERSION") },
+            })),
+            "ping" | "notifications/initialized" | "notifications/cancelled" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": tool_definitions() })),
+            "tools/call" => {
+                let call: ToolCall = serde_json::from_value(params).map_err(|err| {
+                    RpcError::new(INVALID_PARAMS, format!("invalid params: {err}"))
+                })?;
+                Ok(self.call_tool(&call))
+            }
+            _ => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("method not found: {method}"),
+            )),
+        }
+    }
+
+    /// Run a tool and wrap its output as an MCP tool result. Tool failures are reported
+    /// in the result with `isError` so the agent can see and react to them.
+    fn call_tool(&mut self, call: &ToolCall) -> Value {
+        let started = Instant::now();
+        let arguments = call
+            .arguments
+            .clone()
+            .unwrap_or_else(|| Value::Object(Map::new()));
+        let outcome = self.run_tool(&call.name, arguments.clone());
+
+        let (text, frames, is_error) = match outcome {
+            Ok((value, frames)) => (
+                serde_json::to_string_pretty(&value).unwrap_or_default(),
+                frames,
+                false,
+            ),
+            Err(message) => (message, Vec::new(), true),
+        };
+        self.memvid.record_tool_call_action(
+            &call.name,
+            arguments.to_string().as_bytes(),
+            text.as_bytes(),
+            u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+            frames,
+        );
+
+        json!({
+            "content": [{ "type": "text", "text": text }],
+            "isError": is_error,
+        })
+    }
+
+    fn run_tool(&mut self, name: &str, arguments: Value) -> Result<ToolOutput, String> {
+        match name {
+            "memory_put" => {
+                let request: PutRequest = parse(arguments)?;
+                let frame_ids = self
+                    .memvid
+                    .put_many_committed(vec![request])
+                    .map_err(|err| err.to_string())?;
+                let frame_id = frame_ids.first().copied();
+                Ok((json!({ "frame_id": frame_id }), frame_ids))
+            }
+            "memory_search" => {
+                let arguments = with_defaults(
+                    arguments,
+                    &[
+                        ("top_k", DEFAULT_SEARCH_TOP_K),
+                        ("snippet_chars", DEFAULT_SNIPPET_CHARS),
+                    ],
+                );
+                let SearchArgs {
+                    request,
+                    query_embedding,
+                } = parse(arguments)?;
+                let response = self
+                    .memvid
+                    .search_with_embedding(request, query_embedding.as_deref())
+                    .map_err(|err| err.to_string())?;
+                let frames = response.hits.iter().map(|hit| hit.frame_id).collect();
+                Ok((to_value(&response)?, frames))
+            }
+            "memory_ask" => {
+                let arguments = with_defaults(
+                    arguments,
+                    &[
+                        ("top_k", DEFAULT_ASK_TOP_K),
+                        ("snippet_chars", DEFAULT_SNIPPET_CHARS),
+                    ],
+                );
+                let request: AskRequest = parse(arguments)?;
+                let response = self
+                    .memvid
+                    .ask(request, None::<&dyn VecEmbedder>)
+                    .map_err(|err| err.to_string())?;
+                let frames = response
+                    .retrieval
+                    .hits
+                    .iter()
+                    .map(|hit| hit.frame_id)
+                    .collect();
+                Ok((to_value(&response)?, frames))
+            }
+            "memory_get_current" => {
+                let CurrentMemoryArgs { entity, slot } = parse(arguments)?;
+                let card = self.memvid.get_current_memory(&entity, &slot);
+                let frames = card.map(|card| card.source_frame_id).into_iter().collect();
+                Ok((to_value(&card)?, frames))
+            }
+            "memory_timeline" => {
+                let arguments = with_defaults(arguments, &[("limit", DEFAULT_TIMELINE_LIMIT)]);
+                let query: TimelineQuery = parse(arguments)?;
+                let entries = self.memvid.timeline(query).map_err(|err| err.to_string())?;
+                let frames = entries.iter().map(|entry| entry.frame_id).collect();
+                Ok((to_value(&entries)?, frames))
+            }
+            "mesh_follow" => {
+                let FollowArgs { start, link, hops } = parse(arguments)?;
+                let results = self.memvid.follow(&start, &link, hops);
+                let frames = results
+                    .iter()
+                    .flat_map(|result| result.frame_ids.iter().copied())
+                    .collect();
+                Ok((to_value(&results)?, frames))
+            }
+            _ => Err(format!("unknown tool: {name}")),
+        }
+    }
+}
+
+/// Fill in numeric fields the library types require but agents usually omit.
+fn with_defaults(mut arguments: Value, defaults: &[(&str, u64)]) -> Value {
+    if let Value::Object(map) = &mut arguments {
+        for (key, value) in defaults {
+            map.entry(*key).or_insert_with(|| json!(value));
+        }
+    }
+    arguments
+}
+
+fn parse<T: DeserializeOwned>(arguments: Value) -> Result<T, String> {
+    serde_json::from_value(arguments).map_err(|err| format!("invalid arguments: {err}"))
+}
+
+fn to_value<T: serde::Serialize + ?Sized>(value: &T) -> Result<Value, String> {
+    serde_json::to_value(value).map_err(|err| format!("result serialization failed: {err}"))
+}
+
+fn error_reply(id: Value, err: &RpcError) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": err.code, "message": err.message },
+    })
+}
+
+/// Tool descriptors returned by `tools/list`.
+fn tool_definitions() -> Value {
+    json!([
+        {
+            "name": "memory_put",
+            "description": "Store a document in memory and return its frame id.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "title": { "type": "string" },
+                    "label": { "type": "string", "description": "Primary category" },
+                    "text": { "type": "string" },
+                    "uri": { "type": "string" },
+                    "tags": { "type": "array", "items": { "type": "string" } },
+                    "labels": { "type": "array", "items": { "type": "string" } },
+                    "metadata": { "type": "object" }
+                },
+                "required": ["title", "label", "text"]
+            }
+        },
+        {
+            "name": "memory_search",
+            "description": "Search memory and return ranked snippets with frame ids.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "query": { "type": "string" },
+                    "top_k": { "type": "integer", "minimum": 1, "default": DEFAULT_SEARCH_TOP_K },
+                    "snippet_chars": { "type": "integer", "default": DEFAULT_SNIPPET_CHARS },
+                    "scope": { "type": "string", "description": "URI prefix to search under" },
+                    "filter": { "type": "object", "description": "Metadata filter expression" },
+                    "cursor": { "type": "string", "description": "next_cursor of a previous page" },
+                    "query_embedding": { "type": "array", "items": { "type": "number" } }
+                },
+                "required": ["query"]
+            }
+        },
+        {
+            "name": "memory_ask",
+            "description": "Answer a question from memory, returning the answer and its supporting context.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "question": { "type": "string" },
+                    "top_k": { "type": "integer", "minimum": 1, "default": DEFAULT_ASK_TOP_K },
+                    "snippet_chars": { "type": "integer", "default": DEFAULT_SNIPPET_CHARS },
+                    "scope": { "type": "string" },
+                    "context_only": { "type": "boolean" }
+                },
+                "required": ["question"]
+            }
+        },
+        {
+            "name": "memory_get_current",
+            "description": "Return the current memory card for an entity slot (e.g. user / employer), or null.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "entity": { "type": "string" },
+                    "slot": { "type": "string" }
+                },
+                "required": ["entity", "slot"]
+            }
+        },
+        {
+            "name": "memory_timeline",
+            "description": "List stored frames chronologically.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "limit": { "type": "integer", "minimum": 1, "default": DEFAULT_TIMELINE_LIMIT },
+                    "since": { "type": "integer", "description": "Unix timestamp" },
+                    "until": { "type": "integer", "description": "Unix timestamp" },
+                    "reverse": { "type": "boolean" }
+                }
+            }
+        },
+        {
+            "name": "mesh_follow",
+            "description": "Follow a relationship from an entity through the logic mesh.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "start": { "type": "string" },
+                    "link": { "type": "string", "description": "Relationship type, e.g. works_at" },
+                    "hops": { "type": "integer", "minimum": 1, "default": 1 }
+                },
+                "required": ["start", "link"]
+            }
+        }
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn protocol_errors_and_notifications() {
+        let dir = TempDir::new().expect("tempdir");
+        let memvid = Memvid::create(dir.path().join("mcp.mv2")).expect("create");
+        let mut server = McpServer::new(memvid);
+
+        let reply = server.handle_message("{not json").expect("reply");
+        assert_eq!(reply["error"]["code"], PARSE_ERROR);
+
+        let reply = server
+            .handle_message(r#"{"jsonrpc":"2.0","id":1,"method":"resources/list"}"#)
+            .expect("reply");
+        assert_eq!(reply["error"]["code"], METHOD_NOT_FOUND);
+
+        assert!(
+            server
+                .handle_message(r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#)
+                .is_none()
+        );
+
+        let reply = server
+            .handle_message(
+                r#"{"jsonrpc":"2.0","id":2,"method":"tools/call","params":{"name":"memory_put","arguments":{"title":"x"}}}"#,
+            )
+            .expect("reply");
+        assert_eq!(reply["result"]["isError"], true);
+    }
+}
@@ -0,0 +1,84 @@
+//! Entity alias management for `Memvid`.
+//!
+//! Aliases live in the TOC (see [`EntityAliasTable`]) and are persisted on the next
+//! `commit`; memory, mesh and graph lookups expand names through them.
+
+use crate::memvid::lifecycle::Memvid;
+use crate::types::{AliasSource, EntityAlias, EntityAliasTable};
+use crate::{MemvidError, Result};
+
+impl Memvid {
+    /// Aliases linking entity names to their canonical entity.
+    #[must_use]
+    pub fn entity_aliases(&self) -> &EntityAliasTable {
+        &self.toc.entity_aliases
+    }
+
+    /// The canonical entity for `name`, or `name` itself when it is not an alias.
+    #[must_use]
+    pub fn resolve_entity<'a>(&'a self, name: &'a str) -> &'a str {
+        self.toc.entity_aliases.resolve(name)
+    }
+
+    /// Make `alias` (and every name already grouped with it) an alias of `keep`'s entity.
+    ///
+    /// When both names are already grouped, `keep` becomes the group's canonical entity. A
+    /// previous [`Memvid::split_entity`] of the two names is forgotten. Returns whether
+    /// anything changed.
+    ///
+    /// # Errors
+    /// Returns [`MemvidError::InvalidEntityAlias`] when either name is empty or both are the
+    /// same entity name.
+    pub fn merge_entities(&mut self, keep: &str, alias: &str) -> Result<bool> {
+        self.ensure_writable()?;
+        let keep_key = EntityAliasTable::normalize(keep);
+        let alias_key = EntityAliasTable::normalize(alias);
+        if keep_key.is_empty() || alias_key.is_empty() {
+            return Err(MemvidError::InvalidEntityAlias {
+                reason: "entity names must not be empty".into(),
+            });
+        }
+        if keep_key == alias_key {
+            return Err(MemvidError::InvalidEntityAlias {
+                reason: format!("cannot merge '{keep}' with itself"),
+            });
+        }
+        let changed = self
+            .toc
+            .entity_aliases
+            .link(alias, keep, AliasSource::Manual);
+        if changed {
+            self.dirty = true;
+        }
+        Ok(changed)
+    }
+
+    /// Detach `name` from its alias group and return the links removed.
+    ///
+    /// An alias is unlinked from its canonical entity; a canonical entity loses all of its
+    /// aliases. [`Memvid::resolve_entities`] will not link the separated names again.
+    pub fn split_entity(&mut self, name: &str) -> Result<Vec<EntityAlias>> {
+        self.ensure_writable()?;
+        let removed = self.toc.entity_aliases.split(name);
+        if !removed.is_empty() {
+            self.dirty = true;
+        }
+        Ok(removed)
+    }
+
+    /// Link aliases found by the deterministic heuristics in [`EntityAliasTable::infer`]:
+    /// email edges, email local parts spelling a person's name, name containment and
+    /// co-occurrence with a single person, across several frames or in a frame where the
+    /// local part carries part of the name. Returns the links added.
+    pub fn resolve_entities(&mut self) -> Result<Vec<EntityAlias>> {
+        self.ensure_writable()?;
+        let added = self
+            .toc
+            .entity_aliases
+            .infer(&self.logic_mesh, &self.memories_track);
+        if !added.is_empty() {
+            self.dirty = true;
+        }
+        Ok(added)
+    }
+}
@@ -9,6 +9,8 @@ use crate::memvid::lifecycle::Memvid;
 use crate::memvid::search::helpers::{build_context, reorder_hits_by_token_matches};
 #[cfg(feature = "temporal_track")]
 use crate::types::TemporalFilter;
+use crate::types::reranker::RerankerKind;
+use crate::types::synthesis::{AnswerSynthesizer, ExtractiveSynthesizer};
 use crate::types::{
     AskCitation, AskContextFragment, AskContextFragmentKind, AskMode, AskRequest, AskResponse,
     AskRetriever, AskStats, SearchEngineKind, SearchHit, SearchParams, SearchRequest,
@@ -20,9 +22,26 @@ const RRF_K: f32 = 60.0;
 
 #[cfg(feature = "lex")]
 impl Memvid {
+    /// Retrieve context for `request` and answer it with the extractive synthesizer.
     pub fn ask<E>(&mut self, request: AskRequest, embedder: Option<&E>) -> Result<AskResponse>
     where
         E: VecEmbedder + ?Sized,
+    {
+        self.ask_with_synthesizer(request, embedder, &ExtractiveSynthesizer::default())
+    }
+
+    /// Retrieve context for `request` and answer it with `synthesizer`.
+    ///
+    /// If the synthesizer fails, the answer falls back to the extractive synthesizer.
+    pub fn ask_with_synthesizer<E, S>(
+        &mut self,
+        request: AskRequest,
+        embedder: Option<&E>,
+        synthesizer: &S,
+    ) -> Result<AskResponse>
+    where
+        E: VecEmbedder + ?Sized,
+        S: AnswerSynthesizer + ?Sized,
     {
         if !self.lex_enabled {
             return Err(MemvidError::LexNotEnabled);
@@ -105,6 +124,9 @@ impl Memvid {
             // Disable sketch pre-filter for ask queries - accuracy is more important than speed
             // SimHash can filter out semantically relevant documents that use different wording
             no_sketch: true,
+            reranker: None,
+            filter: request.filter.clone(),
+            fusion: None,
         };
 
         // Pre-compute the query embedding once so we can reuse it for vector recall and semantic re-rank
@@ -209,6 +231,8 @@ impl Memvid {
         // Build multiple candidate lists (lexical variants + vector) and fuse with RRF.
         let mut candidate_lists: Vec<Vec<SearchHit>> = Vec::new();
         let mut vector_used = false;
+        // Set when adaptive vector retrieval already reranked before its cutoff.
+        let mut reranked_before_cutoff = false;
 
         // For analytical questions, use timeline directly (skip RRF mixing with search)
         // This ensures full-text hits are preserved - search hits are truncated and would
@@ -260,12 +284,13 @@ impl Memvid {
 
             // Vector-only candidate list.
             if self.vec_enabled && query_embedding.is_some() {
-                let vec_hits = vector_hits(
+                let (vec_hits, reranked) = vector_hits(
                     self,
                     query_embedding.as_deref().unwrap_or(&[]),
                     &request,
                     effective_top_k.max(24).min(64),
                 )?;
+                reranked_before_cutoff = reranked;
                 if !vec_hits.is_empty() {
                     candidate_lists.push(vec_hits);
                     vector_used = true;
@@ -362,34 +387,25 @@ impl Memvid {
             )?;
         }
 
+        // Second-stage reranking runs after every heuristic reordering so the requested
+        // reranker decides the final order; only corrections are promoted past it. Adaptive
+        // retrieval has already applied it ahead of its cutoff.
+        if let Some(kind) = request
+            .reranker
+            .filter(|kind| *kind != RerankerKind::None && !reranked_before_cutoff)
+        {
+            let hits = std::mem::take(&mut retrieval.hits);
+            let keep = hits.len();
+            retrieval.hits = self.rerank_hits(&request.question, hits, kind, keep)?;
+            retrieval.total_hits = retrieval.hits.len();
+        }
+
         // Apply correction boost AFTER all other reranking - corrections should have final priority
         // This ensures user corrections override all other ranking signals
         promote_corrections(self, &mut retrieval.hits)?;
 
         retrieval.context = build_context(&retrieval.hits);
 
-        let (answer, citations, synthesis_ms) = if request.context_only {
-            (None, Vec::new(), 0)
-        } else {
-            let synth_start = Instant::now();
-            let citations = build_citations(&retrieval.hits, &semantic_scores);
-            let answer = synthesize_answer(&request.question, &retrieval.hits, &citations);
-            let synth_ms = synth_start.elapsed().as_millis();
-            (answer, citations, synth_ms)
-        };
-
-        let retriever = determine_retriever(
-            request.mode,
-            semantics_applied,
-            lex_fallback_used,
-            timeline_fallback_used,
-        );
-        let stats = AskStats {
-            retrieval_ms,
-            synthesis_ms,
-            latency_ms: total_start.elapsed().as_millis(),
-        };
-
         let context_fragments: Vec<AskContextFragment> = retrieval
             .hits
             .iter()
@@ -412,6 +428,28 @@ impl Memvid {
             })
             .collect();
 
+        let (answer, citations, synthesis_ms) = if request.context_only {
+            (None, Vec::new(), 0)
+        } else {
+            let synth_start = Instant::now();
+            let citations = build_citations(&retrieval.hits, &semantic_scores);
+            let answer = synthesize_answer(&request.question, &context_fragments, synthesizer);
+            let synth_ms = synth_start.elapsed().as_millis();
+            (answer, citations, synth_ms)
+        };
+
+        let retriever = determine_retriever(
+            request.mode,
+            semantics_applied,
+            lex_fallback_used,
+            timeline_fallback_used,
+        );
+        let stats = AskStats {
+            retrieval_ms,
+            synthesis_ms,
+            latency_ms: total_start.elapsed().as_millis(),
+        };
+
         Ok(AskResponse {
             question: request.question,
             mode: request.mode,
@@ -593,6 +631,14 @@ impl Memvid {
         for (rank, (frame_id, parent_uri)) in all_frame_ids.iter().enumerate() {
             // Get full frame content for the context
             let (frame_text, frame_uri) = match self.frame_by_id(*frame_id) {
+                Ok(frame)
+                    if request
+                        .filter
+                        .as_ref()
+                        .is_some_and(|filter| !filter.matches(&frame)) =>
+                {
+                    continue;
+                }
                 Ok(frame) => {
                     let content = self.frame_content(&frame).unwrap_or_else(|_| String::new());
                     let uri = frame
@@ -629,6 +675,8 @@ impl Memvid {
                 text: frame_text.clone(),
                 chunk_text: Some(frame_text.clone()),
                 metadata: None,
+                lexical_score: None,
+                vector_score: None,
             });
         }
 
@@ -660,6 +708,19 @@ impl Memvid {
     {
         Err(MemvidError::LexNotEnabled)
     }
+
+    pub fn ask_with_synthesizer<E, S>(
+        &mut self,
+        _request: AskRequest,
+        _embedder: Option<&E>,
+        _synthesizer: &S,
+    ) -> Result<AskResponse>
+    where
+        E: VecEmbedder + ?Sized,
+        S: AnswerSynthesizer + ?Sized,
+    {
+        Err(MemvidError::LexNotEnabled)
+    }
 }
 
 fn determine_retriever(
@@ -763,40 +824,41 @@ fn build_citations(hits: &[SearchHit], semantic_scores: &HashMap<u64, f32>) -> V
         .collect()
 }
 
-fn synthesize_answer(
+fn synthesize_answer<S>(
     question: &str,
-    hits: &[SearchHit],
-    citations: &[AskCitation],
-) -> Option<String> {
-    if hits.is_empty() {
+    fragments: &[AskContextFragment],
+    synthesizer: &S,
+) -> Option<String>
+where
+    S: AnswerSynthesizer + ?Sized,
+{
+    if fragments.is_empty() {
         return None;
     }
 
-    let mut segments = Vec::new();
-    for citation in citations.iter().take(3) {
-        if let Some(hit) = hits
-            .iter()
-            .find(|candidate| candidate.frame_id == citation.frame_id)
-        {
-            let snippet = hit.text.trim();
-            if snippet.is_empty() {
-                continue;
-            }
-            let sanitized = snippet.split_whitespace().collect::<Vec<_>>().join(" ");
-            if sanitized.is_empty() {
-                continue;
-            }
-            segments.push(format!("{} [{}]", sanitized, citation.index));
+    let synthesized = match synthesizer.synthesize(question, fragments) {
+        Ok(Some(answer)) => Some(answer),
+        Ok(None) => None,
+        Err(err) => {
+            tracing::warn!(
+                synthesizer = synthesizer.kind(),
+                "answer synthesis failed, using extractive fallback: {err}"
+            );
+            ExtractiveSynthesizer::default()
+                .synthesize(question, fragments)
+                .ok()
+                .flatten()
         }
-    }
-
-    if segments.is_empty() {
-        return Some(format!(
-            "No direct synthesis available for '{question}'. Review the top contexts manually.",
-        ));
-    }
+    };
 
-    Some(segments.join(" "))
+    Some(synthesized.map_or_else(
+        || {
+            format!(
+                "No direct synthesis available for '{question}'. Review the top contexts manually."
+            )
+        },
+        |answer| answer.text,
+    ))
 }
 
 fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
@@ -1318,26 +1380,30 @@ fn diversify_hits_for_aggregation(hits: &mut Vec<SearchHit>, target_unique: usiz
     *hits = diversified;
 }
 
-/// Retrieve pure vector hits for fusion.
+/// Retrieve pure vector hits for fusion, and whether the request's reranker already ran
+/// on them (adaptive retrieval reranks before its cutoff).
 fn vector_hits(
     memvid: &mut Memvid,
     query_embedding: &[f32],
     request: &AskRequest,
     limit: usize,
-) -> Result<Vec<SearchHit>> {
+) -> Result<(Vec<SearchHit>, bool)> {
     if !memvid.vec_enabled || query_embedding.is_empty() {
-        return Ok(Vec::new());
+        return Ok((Vec::new(), false));
     }
 
     // Use adaptive retrieval if configured
     if let Some(ref adaptive_config) = request.adaptive {
         if adaptive_config.enabled {
-            let result = memvid.search_adaptive(
+            let reranker = request.reranker.filter(|kind| *kind != RerankerKind::None);
+            let result = memvid.search_adaptive_with_reranker(
                 &request.question,
                 query_embedding,
                 adaptive_config.clone(),
                 request.snippet_chars,
                 request.scope.as_deref(),
+                request.filter.as_ref(),
+                reranker,
             )?;
             tracing::debug!(
                 "adaptive retrieval: {} -> {} results ({})",
@@ -1345,19 +1411,20 @@ fn vector_hits(
                 result.stats.returned,
                 result.stats.triggered_by
             );
-            return Ok(result.results);
+            return Ok((result.results, reranker.is_some()));
         }
     }
 
-    let vec_response = memvid.vec_search_with_embedding(
+    let vec_response = memvid.vec_search_with_embedding_filtered(
         &request.question,
         query_embedding,
         limit,
         request.snippet_chars,
         request.scope.as_deref(),
+        request.filter.as_ref(),
     )?;
 
-    Ok(vec_response.hits)
+    Ok((vec_response.hits, false))
 }
 
 /// Fuse multiple hit lists using Reciprocal Rank Fusion.
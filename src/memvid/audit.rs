@@ -71,6 +71,8 @@ impl Memvid {
             as_of_frame: None,
             as_of_ts: None,
             adaptive: None,
+            reranker: None,
+            filter: None,
         };
 
         let response = self.ask(request, embedder)?;
@@ -0,0 +1,376 @@
+//! Incremental backups and point-in-time restore.
+
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::Result;
+use crate::error::MemvidError;
+use crate::memvid::lifecycle::Memvid;
+use crate::types::{BACKUP_MAGIC, BackupReport, GenerationMark, MAX_GENERATION_MARKS};
+
+const BACKUP_COPY_BUFFER_SIZE: usize = 1024 * 1024;
+
+/// Fixed-size head of a backup stream.
+struct BackupHeader {
+    base_generation: u64,
+    generation: u64,
+    file_len: u64,
+    merkle_root: [u8; 32],
+    extent_count: u32,
+}
+
+impl Memvid {
+    /// Write the bytes that changed after `generation` to `writer`.
+    ///
+    /// The stream carries the header and WAL region plus every byte from the lowest offset
+    /// rewritten since `generation` to the end of the file: the new frames, segments and
+    /// TOC. Every increment re-sends the full header and WAL region, even when they did not
+    /// change. Offsets are taken from the generation marks the TOC keeps. `generation` 0
+    /// writes a full backup. The whole file is read once to record its BLAKE3 hash.
+    ///
+    /// Fails with [`MemvidError::BackupUnavailable`] when `generation` is newer than this
+    /// memory or older than its oldest mark.
+    pub fn backup_since<W: Write>(
+        &mut self,
+        generation: u64,
+        mut writer: W,
+    ) -> Result<BackupReport> {
+        if generation > self.generation {
+            return Err(MemvidError::BackupUnavailable {
+                generation,
+                reason: format!("the memory is at generation {}", self.generation),
+            });
+        }
+        let file_len = self.file.metadata()?.len();
+        let data_start = self.header.wal_offset + self.header.wal_size;
+        let floor = if generation == 0 {
+            0
+        } else {
+            self.rewrite_floor_since(generation)?.unwrap_or(file_len)
+        };
+        let extents: Vec<(u64, u64)> = if floor <= data_start {
+            vec![(0, file_len)]
+        } else {
+            vec![
+                (0, data_start.min(file_len)),
+                (floor.min(file_len), file_len),
+            ]
+        }
+        .into_iter()
+        .filter(|(start, end)| start < end)
+        .collect();
+
+        let header = BackupHeader {
+            base_generation: generation,
+            generation: self.generation,
+            file_len,
+            merkle_root: self.toc.merkle_root,
+            extent_count: u32::try_from(extents.len()).unwrap_or(u32::MAX),
+        };
+        header.write_to(&mut writer)?;
+
+        let mut source = self.file.try_clone()?;
+        source.seek(SeekFrom::Start(0))?;
+        let mut hasher = blake3::Hasher::new();
+        let mut buffer = vec![0u8; BACKUP_COPY_BUFFER_SIZE];
+        let mut position = 0;
+        let mut bytes = 0;
+        for &(start, end) in &extents {
+            copy_span(
+                &mut source,
+                start - position,
+                &mut hasher,
+                None,
+                &mut buffer,
+            )?;
+            writer.write_all(&start.to_le_bytes())?;
+            writer.write_all(&(end - start).to_le_bytes())?;
+            copy_span(
+                &mut source,
+                end - start,
+                &mut hasher,
+                Some(&mut writer),
+                &mut buffer,
+            )?;
+            position = end;
+            bytes += end - start;
+        }
+        copy_span(
+            &mut source,
+            file_len - position,
+            &mut hasher,
+            None,
+            &mut buffer,
+        )?;
+        let file_hash = hasher.finalize();
+        writer.write_all(file_hash.as_bytes())?;
+        writer.flush()?;
+
+        tracing::info!(
+            base_generation = generation,
+            generation = self.generation,
+            bytes,
+            file_len,
+            "backup written"
+        );
+        Ok(header.report(file_hash.to_hex().to_string(), extents.len(), bytes))
+    }
+
+    /// Rebuild a memory file at `path` from a full backup and the increments taken after it,
+    /// applied in order.
+    ///
+    /// Stop the increments at the generation you want back. Each increment must start at or
+    /// before the generation restored so far. The finished file is checked against the
+    /// BLAKE3 hash of the last backup applied, so the result is byte-identical to the
+    /// captured memory or an error. `path` must not exist. Returns the last backup's report.
+    pub fn restore<P, R, I>(path: P, base: R, increments: I) -> Result<BackupReport>
+    where
+        P: AsRef<Path>,
+        R: Read,
+        I: IntoIterator,
+        I::Item: Read,
+    {
+        let path = path.as_ref();
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .map_err(|source| MemvidError::Io {
+                source,
+                path: Some(path.to_path_buf()),
+            })?;
+        let mut buffer = vec![0u8; BACKUP_COPY_BUFFER_SIZE];
+
+        let mut report = apply_backup(&mut file, base, None, &mut buffer)?;
+        for increment in increments {
+            report = apply_backup(&mut file, increment, Some(report.generation), &mut buffer)?;
+        }
+
+        file.sync_all()?;
+        file.seek(SeekFrom::Start(0))?;
+        let mut hasher = blake3::Hasher::new();
+        std::io::copy(&mut file, &mut hasher)?;
+        let restored_hash = hasher.finalize().to_hex().to_string();
+        if restored_hash != report.file_hash {
+            return Err(MemvidError::InvalidBackup {
+                reason: format!(
+                    "restored file hash {restored_hash} does not match the backup's {}",
+                    report.file_hash
+                ),
+            });
+        }
+        tracing::info!(
+            generation = report.generation,
+            file_len = report.file_len,
+            "restore completed"
+        );
+        Ok(report)
+    }
+
+    /// Lowest offset rewritten after `generation`, `None` when nothing was.
+    fn rewrite_floor_since(&self, generation: u64) -> Result<Option<u64>> {
+        let marks = &self.toc.generation_marks;
+        match marks.first() {
+            Some(oldest) if oldest.generation <= generation.saturating_add(1) => {}
+            oldest => {
+                return Err(MemvidError::BackupUnavailable {
+                    generation,
+                    reason: match oldest {
+                        Some(oldest) => format!(
+                            "the oldest recorded generation is {}; take a full backup",
+                            oldest.generation
+                        ),
+                        None => "no generations are recorded yet; take a full backup".into(),
+                    },
+                });
+            }
+        }
+        Ok(marks
+            .iter()
+            .filter(|mark| mark.generation > generation)
+            .map(|mark| mark.rewrite_offset)
+            .chain(self.backup_floor)
+            .min())
+    }
+
+    /// Note that bytes from `offset` on may have been rewritten since the last TOC write.
+    pub(crate) fn lower_backup_floor(&mut self, offset: u64) {
+        self.backup_floor = Some(self.backup_floor.map_or(offset, |floor| floor.min(offset)));
+    }
+
+    /// Fold the rewrites since the last TOC write into the mark for the TOC about to be
+    /// written.
+    pub(crate) fn record_generation_mark(&mut self) {
+        self.lower_backup_floor(self.header.footer_offset);
+        let mut rewrite_offset = self
+            .backup_floor
+            .take()
+            .unwrap_or(self.header.footer_offset);
+        let generation = self.generation;
+        let marks = &mut self.toc.generation_marks;
+        // A commit writes the TOC more than once, and a rolled-back commit can leave marks
+        // ahead of the generation; merge them so marks stay ordered.
+        if let Some(position) = marks.iter().position(|mark| mark.generation >= generation) {
+            rewrite_offset = marks[position..]
+                .iter()
+                .map(|mark| mark.rewrite_offset)
+                .fold(rewrite_offset, u64::min);
+            marks.truncate(position);
+        }
+        marks.push(GenerationMark {
+            generation,
+            rewrite_offset,
+        });
+        if marks.len() > MAX_GENERATION_MARKS {
+            let excess = marks.len() - MAX_GENERATION_MARKS;
+            marks.drain(..excess);
+        }
+    }
+}
+
+impl BackupHeader {
+    fn write_to<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(BACKUP_MAGIC)?;
+        writer.write_all(&self.base_generation.to_le_bytes())?;
+        writer.write_all(&self.generation.to_le_bytes())?;
+        writer.write_all(&self.file_len.to_le_bytes())?;
+        writer.write_all(&self.merkle_root)?;
+        writer.write_all(&self.extent_count.to_le_bytes())?;
+        Ok(())
+    }
+
+    fn read_from<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut magic = [0u8; 8];
+        read_exact(reader, &mut magic)?;
+        if &magic != BACKUP_MAGIC {
+            return Err(MemvidError::InvalidBackup {
+                reason: "missing backup magic".into(),
+            });
+        }
+        let base_generation = read_u64(reader)?;
+        let generation = read_u64(reader)?;
+        let file_len = read_u64(reader)?;
+        let mut merkle_root = [0u8; 32];
+        read_exact(reader, &mut merkle_root)?;
+        let mut extent_count = [0u8; 4];
+        read_exact(reader, &mut extent_count)?;
+        Ok(Self {
+            base_generation,
+            generation,
+            file_len,
+            merkle_root,
+            extent_count: u32::from_le_bytes(extent_count),
+        })
+    }
+
+    fn report(&self, file_hash: String, extents: usize, bytes: u64) -> BackupReport {
+        BackupReport {
+            base_generation: self.base_generation,
+            generation: self.generation,
+            file_len: self.file_len,
+            file_hash,
+            merkle_root: hex::encode(self.merkle_root),
+            extents,
+            bytes,
+        }
+    }
+}
+
+/// Apply one backup stream to `file`. `restored` is the generation the file holds so far,
+/// `None` while it is still empty.
+fn apply_backup<R: Read>(
+    file: &mut File,
+    mut reader: R,
+    restored: Option<u64>,
+    buffer: &mut [u8],
+) -> Result<BackupReport> {
+    let header = BackupHeader::read_from(&mut reader)?;
+    match restored {
+        None if header.base_generation != 0 => {
+            return Err(MemvidError::InvalidBackup {
+                reason: format!(
+                    "the base must be a full backup, not an increment since generation {}",
+                    header.base_generation
+                ),
+            });
+        }
+        Some(restored) if header.base_generation > restored || header.generation < restored => {
+            return Err(MemvidError::InvalidBackup {
+                reason: format!(
+                    "increment {}..{} does not apply to generation {restored}",
+                    header.base_generation, header.generation
+                ),
+            });
+        }
+        _ => {}
+    }
+
+    file.set_len(header.file_len)?;
+    let mut bytes = 0;
+    for _ in 0..header.extent_count {
+        let offset = read_u64(&mut reader)?;
+        let len = read_u64(&mut reader)?;
+        if offset
+            .checked_add(len)
+            .is_none_or(|end| end > header.file_len)
+        {
+            return Err(MemvidError::InvalidBackup {
+                reason: format!("extent {offset}+{len} lies past the end of the file"),
+            });
+        }
+        file.seek(SeekFrom::Start(offset))?;
+        let mut remaining = len;
+        while remaining > 0 {
+            let chunk = usize::try_from(remaining.min(buffer.len() as u64)).unwrap_or(buffer.len());
+            read_exact(&mut reader, &mut buffer[..chunk])?;
+            file.write_all(&buffer[..chunk])?;
+            remaining -= chunk as u64;
+        }
+        bytes += len;
+    }
+    let mut file_hash = [0u8; 32];
+    read_exact(&mut reader, &mut file_hash)?;
+    Ok(header.report(
+        hex::encode(file_hash),
+        usize::try_from(header.extent_count).unwrap_or(usize::MAX),
+        bytes,
+    ))
+}
+
+/// Feed `len` bytes of `source` to `hasher`, and to `sink` when given.
+fn copy_span<R: Read>(
+    source: &mut R,
+    len: u64,
+    hasher: &mut blake3::Hasher,
+    mut sink: Option<&mut dyn Write>,
+    buffer: &mut [u8],
+) -> Result<()> {
+    let mut remaining = len;
+    while remaining > 0 {
+        let chunk = usize::try_from(remaining.min(buffer.len() as u64)).unwrap_or(buffer.len());
+        source.read_exact(&mut buffer[..chunk])?;
+        hasher.update(&buffer[..chunk]);
+        if let Some(sink) = sink.as_mut() {
+            sink.write_all(&buffer[..chunk])?;
+        }
+        remaining -= chunk as u64;
+    }
+    Ok(())
+}
+
+fn read_exact<R: Read>(reader: &mut R, buf: &mut [u8]) -> Result<()> {
+    reader.read_exact(buf).map_err(|err| match err.kind() {
+        std::io::ErrorKind::UnexpectedEof => MemvidError::InvalidBackup {
+            reason: "stream ends early".into(),
+        },
+        _ => err.into(),
+    })
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> Result<u64> {
+    let mut bytes = [0u8; 8];
+    read_exact(reader, &mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
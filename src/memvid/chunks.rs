@@ -11,9 +11,9 @@
 //! maintains context about the table structure.
 
 use crate::{
-    normalize_text,
+    TranscriptionSegment, normalize_text,
     structure::{ChunkingOptions, StructuralChunker, detect_structure},
-    types::{TextChunkManifest, TextChunkRange},
+    types::{AudioSegmentMetadata, TextChunkManifest, TextChunkRange},
 };
 
 pub(crate) const DEFAULT_CHUNK_CHARS: usize = 1_200;
@@ -25,6 +25,14 @@ pub(crate) struct DocumentChunkPlan {
     pub chunks: Vec<String>,
 }
 
+/// Chunk plan for a timestamped transcript: `segments[i]` holds the audio segments spoken
+/// in `plan.chunks[i]`.
+#[derive(Debug, Clone)]
+pub(crate) struct TranscriptChunkPlan {
+    pub plan: DocumentChunkPlan,
+    pub segments: Vec<Vec<AudioSegmentMetadata>>,
+}
+
 pub(crate) fn plan_document_chunks(raw: &[u8]) -> Option<DocumentChunkPlan> {
     let Ok(text) = String::from_utf8(raw.to_vec()) else {
         return None;
@@ -59,6 +67,72 @@ pub(crate) fn plan_text_chunks(text: &str) -> Option<DocumentChunkPlan> {
     }
 }
 
+/// Plan chunks along transcript segment boundaries.
+///
+/// Consecutive segments are packed up to `DEFAULT_CHUNK_CHARS`, never splitting one, so
+/// every chunk covers a single stretch of audio. Like [`plan_text_chunks`], returns `None`
+/// when the whole transcript fits in one chunk.
+pub(crate) fn plan_transcript_chunks(
+    segments: &[TranscriptionSegment],
+) -> Option<TranscriptChunkPlan> {
+    let mut chunks: Vec<String> = Vec::new();
+    let mut chunk_segments: Vec<Vec<AudioSegmentMetadata>> = Vec::new();
+    let mut ranges: Vec<TextChunkRange> = Vec::new();
+    let mut offset = 0usize;
+
+    for segment in segments {
+        let text = segment.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let chars = text.chars().count();
+        let timing = AudioSegmentMetadata {
+            start_seconds: segment.start,
+            end_seconds: segment.end,
+            label: None,
+        };
+        match (
+            chunks.last_mut(),
+            ranges.last_mut(),
+            chunk_segments.last_mut(),
+        ) {
+            (Some(chunk), Some(range), Some(timings))
+                if range.end - range.start + 1 + chars <= DEFAULT_CHUNK_CHARS =>
+            {
+                chunk.push(' ');
+                chunk.push_str(text);
+                range.end += 1 + chars;
+                timings.push(timing);
+                offset = range.end;
+            }
+            _ => {
+                let start = if chunks.is_empty() { 0 } else { offset + 1 };
+                chunks.push(text.to_string());
+                ranges.push(TextChunkRange {
+                    start,
+                    end: start + chars,
+                });
+                chunk_segments.push(vec![timing]);
+                offset = start + chars;
+            }
+        }
+    }
+
+    if chunks.len() <= 1 {
+        return None;
+    }
+    Some(TranscriptChunkPlan {
+        plan: DocumentChunkPlan {
+            manifest: TextChunkManifest {
+                chunk_chars: DEFAULT_CHUNK_CHARS,
+                chunks: ranges,
+            },
+            chunks,
+        },
+        segments: chunk_segments,
+    })
+}
+
 /// Structure-aware chunking that preserves tables and code blocks.
 fn plan_structural_chunks(
     text: &str,
@@ -244,6 +318,38 @@ mod tests {
         assert_eq!(plan.chunks.len(), plan.manifest.chunks.len());
     }
 
+    #[test]
+    fn transcript_chunks_follow_segment_boundaries() {
+        let segments: Vec<TranscriptionSegment> = (0..60u8)
+            .map(|i| TranscriptionSegment {
+                start: f32::from(i) * 5.0,
+                end: f32::from(i) * 5.0 + 4.5,
+                text: format!(" Speaker notes for minute {i} of the weekly planning meeting. "),
+            })
+            .collect();
+        let transcript = plan_transcript_chunks(&segments).expect("transcript plan");
+        let plan = &transcript.plan;
+        assert!(plan.chunks.len() > 1);
+        assert_eq!(transcript.segments.len(), plan.chunks.len());
+
+        let joined = plan.chunks.join(" ");
+        let mut next_start = 0.0;
+        for ((chunk, range), timings) in plan
+            .chunks
+            .iter()
+            .zip(&plan.manifest.chunks)
+            .zip(&transcript.segments)
+        {
+            assert!(chunk.chars().count() <= DEFAULT_CHUNK_CHARS);
+            assert_eq!(&slice_text_range(&joined, range), chunk);
+            assert!(chunk.ends_with("meeting."), "segment split: {chunk}");
+            assert!((timings[0].start_seconds - next_start).abs() < f32::EPSILON);
+            next_start = timings.last().unwrap().start_seconds + 5.0;
+        }
+
+        assert!(plan_transcript_chunks(&segments[..3]).is_none());
+    }
+
     #[test]
     fn structural_chunking_preserves_table_headers() {
         // Create a document with a large table
@@ -74,6 +74,7 @@ struct PlanProbe {
 }
 
 pub(crate) fn doctor_plan(path: &Path, options: DoctorOptions) -> Result<DoctorPlan> {
+    set_doctor_quiet(options.quiet);
     doctor_log!(
         "doctor: planning for {:?} (options: rebuild_time={}, rebuild_lex={}, rebuild_vec={})",
         path,
@@ -138,6 +139,7 @@ fn try_recover_from_wal_corruption(path: &Path) -> Result<Memvid> {
 }
 
 pub(crate) fn doctor_apply(path: &Path, plan: DoctorPlan) -> Result<DoctorReport> {
+    set_doctor_quiet(plan.options.quiet);
     if plan.options.dry_run {
         let findings = plan.findings.clone();
         let status = if plan.is_noop() {
@@ -1375,11 +1377,14 @@ impl DoctorExecutor {
                 })
             }
             DoctorActionKind::VacuumCompaction => {
-                mem.vacuum()?;
+                let vacuum = mem.vacuum()?;
                 Ok(DoctorActionReport {
                     action: action.action,
                     status: DoctorActionStatus::Executed,
-                    detail: Some("vacuum completed".into()),
+                    detail: Some(format!(
+                        "vacuum completed; reclaimed {} bytes",
+                        vacuum.bytes_reclaimed
+                    )),
                 })
             }
             DoctorActionKind::RecomputeToc => {
@@ -1458,6 +1463,7 @@ impl DoctorExecutor {
         }
 
         doctor_log!("doctor: rebuild_indexes start");
+        mem.generation = mem.generation.wrapping_add(1);
         mem.rebuild_indexes(&[])?;
         doctor_log!("doctor: rebuild_indexes done");
 
@@ -201,16 +201,16 @@ impl Memvid {
     /// Find an active frame by its content BLAKE3 hash.
     ///
     /// This is used for deduplication - if a frame with the same content hash already exists,
-    /// we can skip re-ingestion. The hash is computed from the original file bytes.
+    /// we can skip re-ingestion. The hash is computed from the original file bytes, and
+    /// matches either the stored payload or the recorded `source_sha256` of the source file.
     ///
     /// Returns `None` if no matching frame is found.
     #[must_use]
     pub fn find_frame_by_hash(&self, hash: &[u8; 32]) -> Option<&Frame> {
-        self.toc
-            .frames
-            .iter()
-            .rev()
-            .find(|frame| frame.status == FrameStatus::Active && frame.checksum == *hash)
+        self.toc.frames.iter().rev().find(|frame| {
+            frame.status == FrameStatus::Active
+                && (frame.checksum == *hash || frame.source_sha256.as_ref() == Some(hash))
+        })
     }
 
     pub fn blob_reader(&mut self, frame_id: FrameId) -> Result<BlobReader> {
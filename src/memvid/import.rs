@@ -0,0 +1,361 @@
+//! Merging the frames of one memory into another.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use crate::Result;
+use crate::memvid::lifecycle::Memvid;
+use crate::memvid::mutation::{FrameWalOp, WalEntryData};
+use crate::types::{
+    Frame, FrameId, FrameStatus, ImportOptions, ImportReport, MemoryCard, MeshEdge, MeshNode,
+    UriConflict,
+};
+
+impl Memvid {
+    /// Copy the frames of `source` into this memory, then commit.
+    ///
+    /// Active documents and their chunks are copied with their stored payloads and
+    /// metadata (older versions too when `include_superseded` is set). Frames receive
+    /// fresh ids here; `parent_id` and `supersedes` links are rewritten to match, and links
+    /// to frames that were not copied are dropped. Embeddings are carried over as stored
+    /// when both memories use the same vector model and dimension, so nothing is
+    /// re-embedded. Memory cards and Logic-Mesh entries derived from the copied frames
+    /// follow them, the mesh through `merge_node`/`merge_edge`.
+    ///
+    /// A source document whose URI names an active document here is resolved by
+    /// `options.on_conflict`; chunks always follow their document.
+    ///
+    /// `source` is only read; it is borrowed mutably because its vector index loads lazily.
+    pub fn import_from(
+        &mut self,
+        source: &mut Memvid,
+        options: ImportOptions,
+    ) -> Result<ImportReport> {
+        self.ensure_writable()?;
+        self.commit()?;
+        let mut report = ImportReport::default();
+        let mut heads = self.active_documents_by_uri();
+
+        let mut selected: Vec<Frame> = Vec::new();
+        let mut included: BTreeSet<FrameId> = BTreeSet::new();
+        let mut replaced: BTreeSet<FrameId> = BTreeSet::new();
+        for frame in &source.toc.frames {
+            let keep = match frame.parent_id {
+                Some(parent) => included.contains(&parent) && frame.status != FrameStatus::Deleted,
+                None => match frame.status {
+                    FrameStatus::Active => true,
+                    FrameStatus::Superseded => options.include_superseded,
+                    FrameStatus::Deleted => false,
+                },
+            };
+            if !keep {
+                continue;
+            }
+            if frame.parent_id.is_none() {
+                if let Some(&taken) = imported_uri(frame).and_then(|uri| heads.get(uri)) {
+                    match options.on_conflict {
+                        UriConflict::Skip => {
+                            report.skipped.push(frame.id);
+                            continue;
+                        }
+                        UriConflict::Replace => {
+                            replaced.insert(taken);
+                        }
+                        UriConflict::Version => {}
+                    }
+                }
+            }
+            included.insert(frame.id);
+            selected.push(frame.clone());
+        }
+
+        let children: Vec<FrameId> = self
+            .toc
+            .frames
+            .iter()
+            .filter(|frame| frame.status != FrameStatus::Deleted)
+            .filter(|frame| {
+                frame
+                    .parent_id
+                    .is_some_and(|parent| replaced.contains(&parent))
+            })
+            .map(|frame| frame.id)
+            .collect();
+        replaced.extend(children);
+        for &frame_id in &replaced {
+            let frame = self.frame_by_id(frame_id)?;
+            self.append_tombstone(&frame)?;
+        }
+
+        let carry_embeddings = self.can_carry_embeddings(source)?;
+        if carry_embeddings && self.vec_model.is_none() {
+            if let Some(model) = source.bound_vec_model() {
+                self.set_vec_model(&model)?;
+            }
+        }
+
+        let mut sequences: HashMap<FrameId, u64> = HashMap::new();
+        for frame in &selected {
+            let payload = if frame.payload_length > 0 {
+                source.read_frame_payload_bytes(frame)?
+            } else {
+                Vec::new()
+            };
+            let mut embedding = source.frame_embedding(frame.id)?;
+            if embedding.is_some() {
+                if carry_embeddings {
+                    if !self.vec_enabled {
+                        self.enable_vec()?;
+                    }
+                    report.embeddings_copied += 1;
+                } else {
+                    embedding = None;
+                    report.embeddings_dropped += 1;
+                }
+            }
+
+            let uri = imported_uri(frame).map(str::to_string);
+            let mut supersedes = frame
+                .supersedes
+                .and_then(|previous| report.frame_map.get(&previous).copied());
+            if supersedes.is_none()
+                && frame.parent_id.is_none()
+                && options.on_conflict == UriConflict::Version
+            {
+                if let Some(&head) = uri.as_deref().and_then(|uri| heads.get(uri)) {
+                    supersedes = Some(head);
+                    report.versioned += 1;
+                }
+            }
+
+            let frame_id = self.next_frame_id();
+            let seq = self.append_frame_entry(WalEntryData {
+                timestamp: frame.timestamp,
+                kind: frame.kind.clone(),
+                track: frame.track.clone(),
+                payload,
+                embedding,
+                uri: uri.clone(),
+                title: frame.title.clone(),
+                canonical_encoding: frame.canonical_encoding,
+                canonical_length: frame.canonical_length,
+                metadata: frame.metadata.clone(),
+                search_text: frame.search_text.clone(),
+                tags: frame.tags.clone(),
+                labels: frame.labels.clone(),
+                extra_metadata: frame.extra_metadata.clone(),
+                content_dates: frame.content_dates.clone(),
+                chunk_manifest: frame.chunk_manifest.clone(),
+                role: frame.role,
+                parent_sequence: frame
+                    .parent_id
+                    .and_then(|parent| sequences.get(&parent).copied()),
+                chunk_index: frame.chunk_index,
+                chunk_count: frame.chunk_count,
+                op: FrameWalOp::Insert,
+                target_frame_id: None,
+                supersedes_frame_id: supersedes,
+                reuse_payload_from: None,
+                source_sha256: frame.source_sha256,
+                source_path: frame.source_path.clone(),
+                enrichment_state: frame.enrichment_state,
+            })?;
+            sequences.insert(frame.id, seq);
+            report.frame_map.insert(frame.id, frame_id);
+            if frame.parent_id.is_none() && options.on_conflict == UriConflict::Version {
+                if let Some(uri) = uri {
+                    heads.insert(uri, frame_id);
+                }
+            }
+            if self.wal.should_checkpoint() {
+                self.commit()?;
+            }
+        }
+        self.commit()?;
+        self.relink_imported_children(&selected, &report);
+
+        if options.memories {
+            report.memory_cards =
+                self.merge_imported_cards(source.memories_track.cards(), &report.frame_map);
+        }
+        if options.logic_mesh {
+            (report.mesh_nodes, report.mesh_edges) = self.merge_imported_mesh(
+                &source.logic_mesh.nodes,
+                &source.logic_mesh.edges,
+                &report.frame_map,
+            );
+        }
+        if report.memory_cards > 0 || report.mesh_nodes > 0 {
+            self.dirty = true;
+        }
+        if self.dirty {
+            self.commit()?;
+        }
+
+        report.replaced = replaced.into_iter().collect();
+        tracing::info!(
+            imported = report.imported(),
+            skipped = report.skipped.len(),
+            replaced = report.replaced.len(),
+            "import completed"
+        );
+        Ok(report)
+    }
+
+    /// Active top-level frames keyed by the URI a caller gave them.
+    fn active_documents_by_uri(&self) -> HashMap<String, FrameId> {
+        let mut heads = HashMap::new();
+        for frame in &self.toc.frames {
+            if frame.status != FrameStatus::Active || frame.parent_id.is_some() {
+                continue;
+            }
+            if let Some(uri) = imported_uri(frame) {
+                heads.insert(uri.to_string(), frame.id);
+            }
+        }
+        heads
+    }
+
+    fn can_carry_embeddings(&self, source: &Memvid) -> Result<bool> {
+        if !source.vec_enabled {
+            return Ok(false);
+        }
+        self.accepts_embeddings_from(
+            source.bound_vec_model().as_deref(),
+            source.effective_vec_index_dimension()?,
+        )
+    }
+
+    /// Embeddings can be copied verbatim when the vector spaces agree: same model (or the
+    /// destination has none bound yet) and same dimension.
+    pub(crate) fn accepts_embeddings_from(
+        &self,
+        model: Option<&str>,
+        dimension: Option<u32>,
+    ) -> Result<bool> {
+        let models_match = match (model, self.bound_vec_model()) {
+            (Some(theirs), Some(ours)) => theirs == ours,
+            (None, Some(_)) => false,
+            (_, None) => true,
+        };
+        let dimensions_match = match (dimension, self.effective_vec_index_dimension()?) {
+            (Some(theirs), Some(ours)) => theirs == ours,
+            _ => true,
+        };
+        Ok(models_match && dimensions_match)
+    }
+
+    /// The embedding model recorded for this memory, whether or not the index is loaded.
+    pub(crate) fn bound_vec_model(&self) -> Option<String> {
+        self.vec_model.clone().or_else(|| {
+            self.toc
+                .indexes
+                .vec
+                .as_ref()
+                .and_then(|manifest| manifest.model.clone())
+        })
+    }
+
+    /// Add copies of `cards` whose source frame was imported, pointing at the new frame ids.
+    /// Returns the number of cards added.
+    pub(crate) fn merge_imported_cards(
+        &mut self,
+        cards: &[MemoryCard],
+        frame_map: &BTreeMap<FrameId, FrameId>,
+    ) -> usize {
+        let cards: Vec<MemoryCard> = cards
+            .iter()
+            .filter_map(|card| {
+                let &frame_id = frame_map.get(&card.source_frame_id)?;
+                let mut card = card.clone();
+                card.source_frame_id = frame_id;
+                Some(card)
+            })
+            .collect();
+        let added = cards.len();
+        self.memories_track.add_cards(cards);
+        added
+    }
+
+    /// Merge the Logic-Mesh nodes mentioned by imported frames, and the edges between them,
+    /// with frame ids rewritten through `frame_map`. Returns the merged node and edge counts.
+    pub(crate) fn merge_imported_mesh(
+        &mut self,
+        nodes: &[MeshNode],
+        edges: &[MeshEdge],
+        frame_map: &BTreeMap<FrameId, FrameId>,
+    ) -> (usize, usize) {
+        let mut merged: HashSet<u64> = HashSet::new();
+        for node in nodes {
+            let mut node = node.clone();
+            node.frame_ids = node
+                .frame_ids
+                .iter()
+                .filter_map(|frame_id| frame_map.get(frame_id).copied())
+                .collect();
+            node.mentions = node
+                .mentions
+                .iter()
+                .filter_map(|&(frame_id, start, len)| {
+                    frame_map.get(&frame_id).map(|&mapped| (mapped, start, len))
+                })
+                .collect();
+            if node.frame_ids.is_empty() {
+                continue;
+            }
+            merged.insert(node.id);
+            self.logic_mesh.merge_node(node);
+        }
+        let mut merged_edges = 0;
+        for edge in edges {
+            let Some(&frame_id) = frame_map.get(&edge.frame_id) else {
+                continue;
+            };
+            if !merged.contains(&edge.from_node) || !merged.contains(&edge.to_node) {
+                continue;
+            }
+            let mut edge = edge.clone();
+            edge.frame_id = frame_id;
+            self.logic_mesh.merge_edge(edge);
+            merged_edges += 1;
+        }
+        if !merged.is_empty() {
+            self.logic_mesh.finalize();
+        }
+        (merged.len(), merged_edges)
+    }
+
+    /// Restore `parent_id` for chunks whose parent was applied by an earlier checkpoint,
+    /// which the WAL's sequence-based linking cannot see.
+    pub(crate) fn relink_imported_children(&mut self, selected: &[Frame], report: &ImportReport) {
+        for frame in selected {
+            let Some(parent) = frame.parent_id else {
+                continue;
+            };
+            let (Some(&child), Some(&parent)) = (
+                report.frame_map.get(&frame.id),
+                report.frame_map.get(&parent),
+            ) else {
+                continue;
+            };
+            let Some(entry) = usize::try_from(child)
+                .ok()
+                .and_then(|index| self.toc.frames.get_mut(index))
+            else {
+                continue;
+            };
+            if entry.parent_id != Some(parent) {
+                entry.parent_id = Some(parent);
+                self.dirty = true;
+            }
+        }
+    }
+}
+
+/// The URI to carry over, or `None` when the frame only has the placeholder URI derived
+/// from its id (the destination assigns its own).
+fn imported_uri(frame: &Frame) -> Option<&str> {
+    frame
+        .uri
+        .as_deref()
+        .filter(|uri| *uri != crate::default_uri(frame.id))
+}
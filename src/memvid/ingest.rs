@@ -0,0 +1,280 @@
+//! Mirroring a directory tree, and the `.zip` archives in it, into a memory.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use blake3::hash;
+use zip::ZipArchive;
+
+use crate::memvid::lifecycle::Memvid;
+use crate::types::{Frame, FrameStatus, IngestOptions, IngestReport, PutOptions};
+use crate::{MemvidError, Result};
+
+/// One file found by the walk, named by its path relative to the ingested root.
+struct SourceFile {
+    relative: Vec<String>,
+    path: PathBuf,
+}
+
+/// Bookkeeping for one `ingest_dir` run.
+struct SyncState {
+    /// `mv2://<prefix>/`, the URI every file of the tree starts with.
+    base: String,
+    /// Documents under `base` before the run.
+    heads: BTreeMap<String, Frame>,
+    /// URIs of the files found by this run.
+    seen: HashSet<String>,
+    tags: Vec<String>,
+    report: IngestReport,
+}
+
+impl Memvid {
+    /// Mirror the files under `root` into this memory, then commit.
+    ///
+    /// Each file becomes a document at `mv2://<prefix>/<relative path>` and goes through the
+    /// usual reader routing of `put`; `.zip` archives contribute their entries under the
+    /// archive's own path unless `expand_archives` is off. Running it again brings the memory
+    /// back in line with the tree: files whose hash matches the stored `source_sha256` are
+    /// skipped, changed files become a new version superseding the old document (extracted
+    /// afresh rather than inheriting its search text as `update_frame` would), and documents
+    /// under the prefix whose file disappeared are deleted along with their chunks.
+    ///
+    /// Symbolic links are not followed.
+    pub fn ingest_dir(
+        &mut self,
+        root: impl AsRef<Path>,
+        options: IngestOptions,
+    ) -> Result<IngestReport> {
+        let root = root.as_ref();
+        self.ensure_writable()?;
+        let prefix = options.uri_prefix.clone().unwrap_or_else(|| {
+            root.canonicalize()
+                .ok()
+                .and_then(|path| {
+                    path.file_name()
+                        .map(|name| name.to_string_lossy().into_owned())
+                })
+                .unwrap_or_else(|| "files".to_string())
+        });
+        let base = format!("mv2://{}/", prefix.trim_matches('/'));
+
+        let mut files = Vec::new();
+        walk_dir(root, &mut Vec::new(), &options, &mut files)?;
+        self.commit()?;
+        let mut state = SyncState {
+            heads: self.active_documents_under(&base),
+            base,
+            seen: HashSet::new(),
+            tags: options.tags.clone(),
+            report: IngestReport::default(),
+        };
+
+        for file in files {
+            let is_archive = file
+                .path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("zip"));
+            if is_archive && options.expand_archives {
+                let handle = File::open(&file.path).map_err(|source| MemvidError::Io {
+                    source,
+                    path: Some(file.path.clone()),
+                })?;
+                let mut archive =
+                    ZipArchive::new(handle).map_err(|err| archive_error(&file.path, &err))?;
+                for index in 0..archive.len() {
+                    let mut entry = archive
+                        .by_index(index)
+                        .map_err(|err| archive_error(&file.path, &err))?;
+                    let Some(name) = entry.enclosed_name() else {
+                        continue;
+                    };
+                    if entry.is_dir() {
+                        continue;
+                    }
+                    let mut relative = file.relative.clone();
+                    relative.extend(
+                        name.components()
+                            .map(|part| part.as_os_str().to_string_lossy().into_owned()),
+                    );
+                    if !wanted(&relative, &options) {
+                        continue;
+                    }
+                    let mut bytes = Vec::new();
+                    entry
+                        .read_to_end(&mut bytes)
+                        .map_err(|source| MemvidError::Io {
+                            source,
+                            path: Some(file.path.clone()),
+                        })?;
+                    let origin = format!("{}!/{}", file.path.display(), name.display());
+                    self.sync_source(&mut state, &relative, origin, &bytes)?;
+                }
+            } else if wanted(&file.relative, &options) {
+                let bytes = fs::read(&file.path).map_err(|source| MemvidError::Io {
+                    source,
+                    path: Some(file.path.clone()),
+                })?;
+                let origin = file.path.display().to_string();
+                self.sync_source(&mut state, &file.relative, origin, &bytes)?;
+            }
+        }
+
+        let mut report = state.report;
+        if options.delete_missing {
+            for (uri, frame) in &state.heads {
+                if state.seen.contains(uri) {
+                    continue;
+                }
+                self.tombstone_with_children(frame)?;
+                report.deleted.push(uri.clone());
+            }
+        }
+        self.commit()?;
+
+        tracing::info!(
+            added = report.added.len(),
+            updated = report.updated.len(),
+            unchanged = report.unchanged,
+            deleted = report.deleted.len(),
+            "ingest_dir completed"
+        );
+        Ok(report)
+    }
+
+    /// Store one file unless its stored document already has the same content hash.
+    fn sync_source(
+        &mut self,
+        state: &mut SyncState,
+        relative: &[String],
+        origin: String,
+        bytes: &[u8],
+    ) -> Result<()> {
+        let uri = format!("{}{}", state.base, relative.join("/"));
+        let digest = *hash(bytes).as_bytes();
+        let existing = state
+            .heads
+            .get(&uri)
+            .map(|frame| (frame.id, frame.source_sha256));
+        state.seen.insert(uri.clone());
+        if let Some((_, Some(stored))) = existing {
+            if stored == digest {
+                state.report.unchanged += 1;
+                return Ok(());
+            }
+        }
+
+        let put_options = PutOptions {
+            uri: Some(uri.clone()),
+            title: relative.last().cloned(),
+            tags: state.tags.clone(),
+            source_path: Some(origin),
+            instant_index: false,
+            ..PutOptions::default()
+        };
+        if let Some((frame_id, _)) = existing {
+            self.put_internal(Some(bytes), None, None, None, put_options, Some(frame_id))?;
+            state.report.updated.push(uri);
+        } else {
+            self.put_bytes_with_options(bytes, put_options)?;
+            state.report.added.push(uri);
+        }
+        Ok(())
+    }
+
+    /// Active top-level frames whose URI starts with `base`, keyed by URI.
+    fn active_documents_under(&self, base: &str) -> BTreeMap<String, Frame> {
+        self.toc
+            .frames
+            .iter()
+            .filter(|frame| frame.status == FrameStatus::Active && frame.parent_id.is_none())
+            .filter_map(|frame| {
+                let uri = frame.uri.as_deref()?;
+                uri.starts_with(base)
+                    .then(|| (uri.to_string(), frame.clone()))
+            })
+            .collect()
+    }
+
+    fn tombstone_with_children(&mut self, frame: &Frame) -> Result<()> {
+        let children: Vec<Frame> = self
+            .toc
+            .frames
+            .iter()
+            .filter(|child| {
+                child.parent_id == Some(frame.id) && child.status == FrameStatus::Active
+            })
+            .cloned()
+            .collect();
+        self.append_tombstone(frame)?;
+        for child in &children {
+            self.append_tombstone(child)?;
+        }
+        Ok(())
+    }
+}
+
+/// Collect the regular files under `dir` in name order.
+fn walk_dir(
+    dir: &Path,
+    relative: &mut Vec<String>,
+    options: &IngestOptions,
+    files: &mut Vec<SourceFile>,
+) -> Result<()> {
+    let io_error = |source| MemvidError::Io {
+        source,
+        path: Some(dir.to_path_buf()),
+    };
+    let mut entries = fs::read_dir(dir)
+        .map_err(io_error)?
+        .collect::<std::io::Result<Vec<_>>>()
+        .map_err(io_error)?;
+    entries.sort_by_key(fs::DirEntry::file_name);
+
+    for entry in entries {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if !options.include_hidden && name.starts_with('.') {
+            continue;
+        }
+        let file_type = entry.file_type().map_err(io_error)?;
+        relative.push(name);
+        if file_type.is_dir() {
+            walk_dir(&entry.path(), relative, options, files)?;
+        } else if file_type.is_file() {
+            files.push(SourceFile {
+                relative: relative.clone(),
+                path: entry.path(),
+            });
+        }
+        relative.pop();
+    }
+    Ok(())
+}
+
+/// Whether a file passes the hidden-file and extension filters.
+fn wanted(relative: &[String], options: &IngestOptions) -> bool {
+    if !options.include_hidden && relative.iter().any(|part| part.starts_with('.')) {
+        return false;
+    }
+    if options.extensions.is_empty() {
+        return true;
+    }
+    let extension = relative
+        .last()
+        .and_then(|name| Path::new(name).extension())
+        .map(|ext| ext.to_string_lossy().to_ascii_lowercase());
+    extension.is_some_and(|ext| {
+        options
+            .extensions
+            .iter()
+            .any(|wanted| wanted.trim_start_matches('.').eq_ignore_ascii_case(&ext))
+    })
+}
+
+fn archive_error(path: &Path, err: &zip::result::ZipError) -> MemvidError {
+    MemvidError::InvalidArchive {
+        path: path.to_path_buf(),
+        reason: err.to_string(),
+    }
+}
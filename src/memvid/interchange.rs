@@ -0,0 +1,449 @@
+//! Export to and import from the portable JSONL interchange format.
+
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+
+use crate::error::MemvidError;
+use crate::memvid::lifecycle::Memvid;
+use crate::memvid::mutation::{
+    DEFAULT_PAYLOAD_COMPRESSION_LEVEL, FrameWalOp, WalEntryData, prepare_canonical_payload,
+};
+use crate::types::{
+    CanonicalEncoding, ExportOptions, Frame, FrameId, FrameRecord, FrameStatus, INTERCHANGE_FORMAT,
+    INTERCHANGE_VERSION, ImportReport, InterchangeCounts, InterchangeHeader, InterchangeRecord,
+    MemoryCard, MeshEdge, MeshNode,
+};
+use crate::{Result, decode_canonical_bytes};
+
+/// A frame read from a stream, payload already decoded from base64.
+struct StreamFrame {
+    frame: Frame,
+    payload: Option<Vec<u8>>,
+    embedding: Option<Vec<f32>>,
+}
+
+/// A fully read and validated interchange stream.
+struct InterchangeStream {
+    header: InterchangeHeader,
+    frames: Vec<StreamFrame>,
+    cards: Vec<MemoryCard>,
+    nodes: Vec<MeshNode>,
+    edges: Vec<MeshEdge>,
+    /// Serialized replay sessions with the line they were read from.
+    replay_sessions: Vec<(usize, serde_json::Value)>,
+}
+
+impl Memvid {
+    /// Write this memory to `writer` as a JSONL interchange stream and return the record
+    /// counts written to its trailer.
+    ///
+    /// Frames are written in id order with their metadata, decoded canonical payload and
+    /// embedding, followed by memory cards, Logic-Mesh nodes and edges, and replay sessions.
+    /// Frames still waiting in the WAL are not included; commit first. The layout is
+    /// documented in [`crate::types::interchange`].
+    pub fn export<W: Write>(
+        &mut self,
+        mut writer: W,
+        options: ExportOptions,
+    ) -> Result<InterchangeCounts> {
+        let mut counts = InterchangeCounts::default();
+        let (vec_model, vec_dimension) = if options.embeddings && self.vec_enabled {
+            (
+                self.bound_vec_model(),
+                self.effective_vec_index_dimension()?,
+            )
+        } else {
+            (None, None)
+        };
+        let header = InterchangeHeader {
+            format: INTERCHANGE_FORMAT.to_string(),
+            version: INTERCHANGE_VERSION,
+            writer: crate::MEMVID_CORE_VERSION.to_string(),
+            exported_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| i64::try_from(d.as_secs()).unwrap_or(i64::MAX))
+                .unwrap_or(0),
+            vec_model,
+            vec_dimension,
+        };
+        write_record(&mut writer, &InterchangeRecord::Header(header))?;
+
+        let frames = self.toc.frames.clone();
+        for frame in frames {
+            if options.active_only && frame.status != FrameStatus::Active {
+                continue;
+            }
+            let live = frame.status != FrameStatus::Deleted;
+            let payload = if live && frame.payload_length > 0 {
+                let stored = self.read_frame_payload_bytes(&frame)?;
+                let canonical =
+                    decode_canonical_bytes(&stored, frame.canonical_encoding, frame.id)?;
+                Some(BASE64_STANDARD.encode(canonical))
+            } else {
+                None
+            };
+            let embedding = if live && options.embeddings {
+                self.frame_embedding(frame.id)?
+            } else {
+                None
+            };
+            counts.frames += 1;
+            if embedding.is_some() {
+                counts.embeddings += 1;
+            }
+            let record = FrameRecord {
+                frame,
+                payload,
+                embedding,
+            };
+            write_record(&mut writer, &InterchangeRecord::Frame(Box::new(record)))?;
+        }
+
+        if options.memories {
+            for card in self.memories_track.cards() {
+                write_record(
+                    &mut writer,
+                    &InterchangeRecord::MemoryCard(Box::new(card.clone())),
+                )?;
+                counts.memory_cards += 1;
+            }
+        }
+        if options.logic_mesh {
+            for node in &self.logic_mesh.nodes {
+                write_record(&mut writer, &InterchangeRecord::MeshNode(node.clone()))?;
+                counts.mesh_nodes += 1;
+            }
+            for edge in &self.logic_mesh.edges {
+                write_record(&mut writer, &InterchangeRecord::MeshEdge(edge.clone()))?;
+                counts.mesh_edges += 1;
+            }
+        }
+        #[cfg(feature = "replay")]
+        if options.replay {
+            if self.toc.replay_manifest.is_some() && self.completed_sessions.is_empty() {
+                self.load_replay_sessions()?;
+            }
+            for session in &self.completed_sessions {
+                let value = serde_json::to_value(session).map_err(std::io::Error::from)?;
+                write_record(&mut writer, &InterchangeRecord::ReplaySession(value))?;
+                counts.replay_sessions += 1;
+            }
+        }
+
+        write_record(&mut writer, &InterchangeRecord::Trailer(counts))?;
+        writer.flush()?;
+        tracing::info!(
+            frames = counts.frames,
+            embeddings = counts.embeddings,
+            "export completed"
+        );
+        Ok(counts)
+    }
+
+    /// Read a JSONL interchange stream written by [`Memvid::export`] into this memory, then
+    /// commit.
+    ///
+    /// The whole stream is read and checked against its header and trailer before anything
+    /// is written, so a truncated or newer-version stream leaves the memory untouched.
+    /// Frames are appended with fresh ids (restoring into an empty memory reproduces the
+    /// exported ids); `parent_id` and `supersedes` links, memory cards, Logic-Mesh entries
+    /// and replay sessions are rewritten to match. Frames exported as deleted are restored
+    /// as tombstones. Embeddings are kept when the stream's vector model and dimension match
+    /// this memory's, and dropped otherwise.
+    pub fn import<R: BufRead>(&mut self, reader: R) -> Result<ImportReport> {
+        self.ensure_writable()?;
+        let stream = read_stream(reader)?;
+        self.commit()?;
+        let mut report = ImportReport::default();
+
+        let carry_embeddings = self.accepts_embeddings_from(
+            stream.header.vec_model.as_deref(),
+            stream.header.vec_dimension,
+        )?;
+        if carry_embeddings && self.vec_model.is_none() {
+            if let Some(model) = stream.header.vec_model.as_deref() {
+                self.set_vec_model(model)?;
+            }
+        }
+
+        let mut sequences: HashMap<FrameId, u64> = HashMap::new();
+        for StreamFrame {
+            frame,
+            payload,
+            embedding,
+        } in &stream.frames
+        {
+            let (payload, canonical_encoding, canonical_length) = match payload {
+                Some(bytes) => {
+                    let level = if frame.canonical_encoding == CanonicalEncoding::Zstd {
+                        DEFAULT_PAYLOAD_COMPRESSION_LEVEL
+                    } else {
+                        0
+                    };
+                    prepare_canonical_payload(bytes, level)?
+                }
+                None => (Vec::new(), frame.canonical_encoding, frame.canonical_length),
+            };
+            let embedding = match embedding {
+                Some(_) if !carry_embeddings => {
+                    report.embeddings_dropped += 1;
+                    None
+                }
+                Some(embedding) => {
+                    if !self.vec_enabled {
+                        self.enable_vec()?;
+                    }
+                    report.embeddings_copied += 1;
+                    Some(embedding.clone())
+                }
+                None => None,
+            };
+
+            let frame_id = self.next_frame_id();
+            let seq = self.append_frame_entry(WalEntryData {
+                timestamp: frame.timestamp,
+                kind: frame.kind.clone(),
+                track: frame.track.clone(),
+                payload,
+                embedding,
+                uri: frame.uri.clone(),
+                title: frame.title.clone(),
+                canonical_encoding,
+                canonical_length,
+                metadata: frame.metadata.clone(),
+                search_text: frame.search_text.clone(),
+                tags: frame.tags.clone(),
+                labels: frame.labels.clone(),
+                extra_metadata: frame.extra_metadata.clone(),
+                content_dates: frame.content_dates.clone(),
+                chunk_manifest: frame.chunk_manifest.clone(),
+                role: frame.role,
+                parent_sequence: frame
+                    .parent_id
+                    .and_then(|parent| sequences.get(&parent).copied()),
+                chunk_index: frame.chunk_index,
+                chunk_count: frame.chunk_count,
+                op: FrameWalOp::Insert,
+                target_frame_id: None,
+                supersedes_frame_id: frame
+                    .supersedes
+                    .and_then(|previous| report.frame_map.get(&previous).copied()),
+                reuse_payload_from: None,
+                source_sha256: frame.source_sha256,
+                source_path: frame.source_path.clone(),
+                enrichment_state: frame.enrichment_state,
+            })?;
+            sequences.insert(frame.id, seq);
+            report.frame_map.insert(frame.id, frame_id);
+            if self.wal.should_checkpoint() {
+                self.commit()?;
+            }
+        }
+        self.commit()?;
+        let frames: Vec<Frame> = stream.frames.iter().map(|f| f.frame.clone()).collect();
+        self.relink_imported_children(&frames, &report);
+
+        for frame in frames.iter().filter(|f| f.status == FrameStatus::Deleted) {
+            let restored = self.frame_by_id(report.frame_map[&frame.id])?;
+            self.append_tombstone(&restored)?;
+        }
+
+        report.memory_cards = self.merge_imported_cards(&stream.cards, &report.frame_map);
+        (report.mesh_nodes, report.mesh_edges) =
+            self.merge_imported_mesh(&stream.nodes, &stream.edges, &report.frame_map);
+        report.replay_sessions = self.restore_replay_sessions(&stream.replay_sessions, &report)?;
+        if report.memory_cards > 0 || report.mesh_nodes > 0 {
+            self.dirty = true;
+        }
+        if self.dirty {
+            self.commit()?;
+        }
+
+        tracing::info!(
+            imported = report.imported(),
+            embeddings_dropped = report.embeddings_dropped,
+            "interchange import completed"
+        );
+        Ok(report)
+    }
+
+    #[cfg(feature = "replay")]
+    fn restore_replay_sessions(
+        &mut self,
+        sessions: &[(usize, serde_json::Value)],
+        report: &ImportReport,
+    ) -> Result<usize> {
+        if sessions.is_empty() {
+            return Ok(0);
+        }
+        if self.toc.replay_manifest.is_some() && self.completed_sessions.is_empty() {
+            self.load_replay_sessions()?;
+        }
+        let mut restored = 0;
+        for (line, value) in sessions {
+            let mut session: crate::replay::ReplaySession =
+                serde_json::from_value(value.clone())
+                    .map_err(|err| invalid(*line, format!("replay session: {err}")))?;
+            if self
+                .completed_sessions
+                .iter()
+                .any(|existing| existing.session_id == session.session_id)
+            {
+                continue;
+            }
+            session.remap_frames(&report.frame_map);
+            self.completed_sessions.push(session);
+            restored += 1;
+        }
+        if restored > 0 {
+            self.save_replay_sessions()?;
+        }
+        Ok(restored)
+    }
+
+    #[cfg(not(feature = "replay"))]
+    #[allow(clippy::unused_self, clippy::unnecessary_wraps)]
+    fn restore_replay_sessions(
+        &mut self,
+        sessions: &[(usize, serde_json::Value)],
+        _report: &ImportReport,
+    ) -> Result<usize> {
+        if !sessions.is_empty() {
+            tracing::warn!(
+                count = sessions.len(),
+                "skipping replay sessions; the replay feature is disabled"
+            );
+        }
+        Ok(0)
+    }
+}
+
+fn write_record<W: Write>(writer: &mut W, record: &InterchangeRecord) -> Result<()> {
+    serde_json::to_writer(&mut *writer, record).map_err(std::io::Error::from)?;
+    writer.write_all(b"\n")?;
+    Ok(())
+}
+
+fn invalid(line: usize, reason: impl Into<String>) -> MemvidError {
+    MemvidError::InvalidInterchange {
+        line,
+        reason: reason.into(),
+    }
+}
+
+/// Parse and validate a whole stream: header first, trailer last, counts matching.
+fn read_stream<R: BufRead>(reader: R) -> Result<InterchangeStream> {
+    let mut header: Option<InterchangeHeader> = None;
+    let mut trailer: Option<InterchangeCounts> = None;
+    let mut seen = InterchangeCounts::default();
+    let mut frames = Vec::new();
+    let mut cards = Vec::new();
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    let mut replay_sessions = Vec::new();
+    let mut last_line = 0;
+
+    for (index, line) in reader.lines().enumerate() {
+        let number = index + 1;
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        last_line = number;
+        if trailer.is_some() {
+            return Err(invalid(number, "record after trailer"));
+        }
+        let record: InterchangeRecord =
+            serde_json::from_str(&line).map_err(|err| invalid(number, err.to_string()))?;
+        if header.is_none() && !matches!(record, InterchangeRecord::Header(_)) {
+            return Err(invalid(number, "stream does not start with a header"));
+        }
+        match record {
+            InterchangeRecord::Header(found) => {
+                if header.is_some() {
+                    return Err(invalid(number, "duplicate header"));
+                }
+                if found.format != INTERCHANGE_FORMAT {
+                    return Err(invalid(
+                        number,
+                        format!("unknown format '{}'", found.format),
+                    ));
+                }
+                if found.version > INTERCHANGE_VERSION {
+                    return Err(invalid(
+                        number,
+                        format!(
+                            "version {} is newer than supported version {INTERCHANGE_VERSION}",
+                            found.version
+                        ),
+                    ));
+                }
+                header = Some(found);
+            }
+            InterchangeRecord::Frame(record) => {
+                let FrameRecord {
+                    frame,
+                    payload,
+                    embedding,
+                } = *record;
+                let payload = payload
+                    .map(|encoded| BASE64_STANDARD.decode(encoded))
+                    .transpose()
+                    .map_err(|err| invalid(number, format!("frame {} payload: {err}", frame.id)))?;
+                seen.frames += 1;
+                if embedding.is_some() {
+                    seen.embeddings += 1;
+                }
+                frames.push(StreamFrame {
+                    frame,
+                    payload,
+                    embedding,
+                });
+            }
+            InterchangeRecord::MemoryCard(card) => {
+                seen.memory_cards += 1;
+                cards.push(*card);
+            }
+            InterchangeRecord::MeshNode(node) => {
+                seen.mesh_nodes += 1;
+                nodes.push(node);
+            }
+            InterchangeRecord::MeshEdge(edge) => {
+                seen.mesh_edges += 1;
+                edges.push(edge);
+            }
+            InterchangeRecord::ReplaySession(session) => {
+                seen.replay_sessions += 1;
+                replay_sessions.push((number, session));
+            }
+            InterchangeRecord::Trailer(counts) => {
+                if counts != seen {
+                    return Err(invalid(
+                        number,
+                        format!("trailer counts {counts:?} do not match records {seen:?}"),
+                    ));
+                }
+                trailer = Some(counts);
+            }
+        }
+    }
+
+    let Some(header) = header else {
+        return Err(invalid(last_line, "empty stream"));
+    };
+    if trailer.is_none() {
+        return Err(invalid(last_line, "stream ends without a trailer"));
+    }
+    Ok(InterchangeStream {
+        header,
+        frames,
+        cards,
+        nodes,
+        edges,
+        replay_sessions,
+    })
+}
@@ -6,12 +6,14 @@
 //! - Validate TOC/footer layout, recover the latest valid footer when needed.
 //! - Wire up index state (lex/vector/time) without mutating payload bytes.
 
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::fs::{File, OpenOptions};
 use std::io::{Read, Seek, SeekFrom};
 use std::panic;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
+use std::time::Duration;
 
 use crate::constants::{MAGIC, SPEC_VERSION, WAL_OFFSET, WAL_SIZE_TINY};
 use crate::error::{MemvidError, Result};
@@ -21,25 +23,30 @@ use crate::io::header::HeaderCodec;
 use crate::io::manifest_wal::ManifestWal;
 use crate::io::wal::EmbeddedWal;
 use crate::lock::{FileLock, LockMode};
+use crate::registry::{self, SnapshotLease, SnapshotRecord};
 #[cfg(feature = "lex")]
 use crate::search::{EmbeddedLexStorage, TantivyEngine};
 #[cfg(feature = "temporal_track")]
 use crate::types::FrameId;
 #[cfg(feature = "parallel_segments")]
 use crate::types::IndexSegmentRef;
+use crate::types::reranker::{Reranker, RerankerKind};
 use crate::types::{
-    FrameStatus, Header, IndexManifests, LogicMesh, MemoriesTrack, SchemaRegistry, SegmentCatalog,
-    SketchTrack, TicketRef, Tier, Toc, VectorCompression,
+    FrameStatus, Header, IndexManifests, LogicMesh, MemoriesTrack, PutManyOpts, SchemaRegistry,
+    SegmentCatalog, SketchTrack, TicketRef, Tier, Toc, VectorCompression,
 };
 #[cfg(feature = "temporal_track")]
 use crate::{TemporalTrack, temporal_track_read};
 use crate::{lex::LexIndex, vec::VecIndex};
 use blake3::Hasher;
 use memmap2::Mmap;
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
 
 const DEFAULT_LOCK_TIMEOUT_MS: u64 = 250;
 const DEFAULT_HEARTBEAT_MS: u64 = 2_000;
 const DEFAULT_STALE_GRACE_MS: u64 = 10_000;
+/// How long a snapshot lease pins its file version without being renewed by `refresh`.
+pub const SNAPSHOT_LEASE_TTL: Duration = Duration::from_secs(15 * 60);
 
 /// Primary handle for interacting with a `.mv2` memory file.
 ///
@@ -59,6 +66,9 @@ pub struct Memvid {
     pub(crate) pending_frame_inserts: u64,
     pub(crate) data_end: u64,
     pub(crate) generation: u64,
+    /// Lowest data offset rewritten since the last TOC write, recorded in the next
+    /// [`crate::types::GenerationMark`].
+    pub(crate) backup_floor: Option<u64>,
     pub(crate) lock_settings: LockSettings,
     pub(crate) lex_enabled: bool,
     pub(crate) lex_index: Option<LexIndex>,
@@ -72,6 +82,8 @@ pub struct Memvid {
     /// CLIP visual embeddings index (separate from vec due to different dimensions)
     pub(crate) clip_enabled: bool,
     pub(crate) clip_index: Option<crate::clip::ClipIndex>,
+    /// Embeds images ingested through `put` into the CLIP index, when attached.
+    pub(crate) clip_provider: Option<Arc<dyn crate::clip::ClipEmbeddingProvider>>,
     pub(crate) dirty: bool,
     #[cfg(feature = "lex")]
     pub(crate) tantivy: Option<TantivyEngine>,
@@ -97,6 +109,13 @@ pub struct Memvid {
     /// Completed sessions stored in memory (until persisted to file).
     #[cfg(feature = "replay")]
     pub(crate) completed_sessions: Vec<crate::replay::ReplaySession>,
+    /// Rerankers resolved for `SearchRequest.reranker` / `AskRequest.reranker`.
+    pub(crate) rerankers: HashMap<RerankerKind, Arc<dyn Reranker>>,
+    /// Options of the `put_many` batch in progress, if any.
+    pub(crate) put_batch: Option<PutManyOpts>,
+    /// Lease advertising this read-only snapshot to writers of the same file.
+    #[allow(dead_code)] // Held for its Drop, which releases the lease
+    pub(crate) snapshot_lease: Option<SnapshotLease>,
 }
 
 /// Controls read-only open behaviour for `.mv2` memories.
@@ -181,6 +200,7 @@ impl Memvid {
             pending_frame_inserts: 0,
             data_end,
             generation: 0,
+            backup_floor: None,
             lock_settings: LockSettings::default(),
             lex_enabled: cfg!(feature = "lex"), // Enable by default if feature is enabled
             lex_index: None,
@@ -192,6 +212,7 @@ impl Memvid {
             vec_index: None,
             clip_enabled: cfg!(feature = "clip"), // Enable by default if feature is enabled
             clip_index: None,
+            clip_provider: None,
             dirty: false,
             #[cfg(feature = "lex")]
             tantivy: None,
@@ -210,6 +231,9 @@ impl Memvid {
             active_session: None,
             #[cfg(feature = "replay")]
             completed_sessions: Vec::new(),
+            rerankers: HashMap::new(),
+            put_batch: None,
+            snapshot_lease: None,
         };
 
         #[cfg(feature = "lex")]
@@ -295,7 +319,7 @@ impl Memvid {
         self.toc.frames.len()
     }
 
-    fn open_locked(mut file: File, lock: FileLock, path_ref: &Path) -> Result<Self> {
+    pub(crate) fn open_locked(mut file: File, lock: FileLock, path_ref: &Path) -> Result<Self> {
         // Fast-path detection for encrypted capsules (.mv2e).
         // This avoids confusing "invalid header" errors and provides an actionable hint.
         let mut magic = [0u8; 4];
@@ -361,6 +385,7 @@ impl Memvid {
             pending_frame_inserts: 0,
             data_end: 0,
             generation,
+            backup_floor: None,
             lock_settings: LockSettings::default(),
             lex_enabled: false,
             lex_index: None,
@@ -372,6 +397,7 @@ impl Memvid {
             vec_index: None,
             clip_enabled: false,
             clip_index: None,
+            clip_provider: None,
             dirty: false,
             #[cfg(feature = "lex")]
             tantivy: None,
@@ -390,6 +416,9 @@ impl Memvid {
             active_session: None,
             #[cfg(feature = "replay")]
             completed_sessions: Vec::new(),
+            rerankers: HashMap::new(),
+            put_batch: None,
+            snapshot_lease: None,
         };
         memvid.data_end = compute_data_end(&memvid.toc, &memvid.header);
         // Use consolidated helper for lex_enabled check
@@ -439,6 +468,13 @@ impl Memvid {
         Self::open_locked(file, lock, path_ref)
     }
 
+    /// Open a snapshot-isolated reader on the latest committed footer.
+    ///
+    /// The reader takes no OS lock, so writers are never blocked by it, and it cannot be
+    /// upgraded in place: mutations return [`MemvidError::Lock`]. Open with
+    /// [`Memvid::open`] (or `allow_repair`) for a writable handle. If the snapshot registry
+    /// is unavailable the reader falls back to a shared lock, and fails with
+    /// [`MemvidError::Lock`] when a writer holds the file.
     pub fn open_read_only<P: AsRef<Path>>(path: P) -> Result<Self> {
         Self::open_read_only_with_options(path, OpenReadOptions::default())
     }
@@ -459,18 +495,25 @@ impl Memvid {
 
     fn open_read_only_snapshot(path_ref: &Path) -> Result<Self> {
         let mut file = OpenOptions::new().read(true).write(true).open(path_ref)?;
+        // Snapshot readers take no OS lock so a writer can open the file concurrently; the
+        // lease tells that writer not to modify this file version in place. It pins
+        // everything until the footer is known, so nothing is reclaimed while the tail is
+        // being read.
+        let (lock, mut snapshot_lease) = protect_snapshot(&file, path_ref)?;
         let TailSnapshot {
             toc,
             footer_offset,
             data_end,
             generation,
         } = load_tail_snapshot(&file)?;
+        if let Some(lease) = snapshot_lease.as_mut() {
+            lease.pin(generation, footer_offset)?;
+        }
 
         let mut header = HeaderCodec::read(&mut file)?;
         header.footer_offset = footer_offset;
         header.toc_checksum = toc.toc_checksum;
 
-        let lock = FileLock::acquire_with_mode(&file, LockMode::Shared)?;
         let wal = EmbeddedWal::open_read_only(&file, &header)?;
 
         #[cfg(feature = "lex")]
@@ -490,6 +533,7 @@ impl Memvid {
             pending_frame_inserts: 0,
             data_end,
             generation,
+            backup_floor: None,
             lock_settings: LockSettings::default(),
             lex_enabled: false,
             lex_index: None,
@@ -501,6 +545,7 @@ impl Memvid {
             vec_index: None,
             clip_enabled: false,
             clip_index: None,
+            clip_provider: None,
             dirty: false,
             #[cfg(feature = "lex")]
             tantivy: None,
@@ -519,6 +564,9 @@ impl Memvid {
             active_session: None,
             #[cfg(feature = "replay")]
             completed_sessions: Vec::new(),
+            rerankers: HashMap::new(),
+            put_batch: None,
+            snapshot_lease,
         };
 
         // Use consolidated helper for lex_enabled check
@@ -550,6 +598,62 @@ impl Memvid {
         Ok(memvid)
     }
 
+    /// TOC generation of the committed footer this handle reads.
+    #[must_use]
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Re-pin a read-only snapshot to the latest committed footer.
+    ///
+    /// A snapshot keeps reading the TOC it opened, and its lease stops writers from
+    /// reclaiming the bytes that TOC references, until refreshed. Returns `true` when a
+    /// newer version was loaded; otherwise the lease is renewed, so readers that stay open
+    /// longer than [`SNAPSHOT_LEASE_TTL`] should refresh periodically. Writable handles
+    /// already see their own commits and always return `false`.
+    pub fn refresh(&mut self) -> Result<bool> {
+        if !self.read_only {
+            return Ok(false);
+        }
+
+        let latest = OpenOptions::new().read(true).open(&self.path)?;
+        let same_version = registry::snapshot_key(&latest, &self.path)?
+            == registry::snapshot_key(&self.file, &self.path)?;
+        if same_version && detect_generation(&latest)? == Some(self.generation) {
+            if let Some(lease) = self.snapshot_lease.as_mut() {
+                if let Err(err) = lease.renew() {
+                    tracing::warn!("failed to renew snapshot lease ({err})");
+                }
+            }
+            return Ok(false);
+        }
+        drop(latest);
+
+        let mut fresh = Self::open_read_only_snapshot(&self.path)?;
+        fresh.lock_settings = self.lock_settings.clone();
+        fresh.rerankers = std::mem::take(&mut self.rerankers);
+        fresh.clip_provider = self.clip_provider.take();
+        *self = fresh;
+        Ok(true)
+    }
+
+    /// Oldest generation a live snapshot reader pins in the file version this handle
+    /// writes, or `None` when no reader holds an unexpired lease on it.
+    ///
+    /// Registry failures are treated as pinning generation 0 so callers fall back to
+    /// copy-on-write and keep every byte in place.
+    pub(crate) fn oldest_pinned_generation(&self) -> Option<u64> {
+        match registry::snapshot_key(&self.file, &self.path)
+            .and_then(|key| registry::live_snapshots(&key, SNAPSHOT_LEASE_TTL))
+        {
+            Ok(live) => live.iter().map(|record| record.generation).min(),
+            Err(err) => {
+                tracing::warn!("snapshot registry unavailable ({err}); assuming live readers");
+                Some(0)
+            }
+        }
+    }
+
     pub(crate) fn try_open<P: AsRef<Path>>(path: P) -> Result<Self> {
         let path_ref = path.as_ref();
         ensure_single_file(path_ref)?;
@@ -1173,6 +1277,9 @@ pub(crate) fn empty_toc() -> Toc {
         memory_binding: None,
         replay_manifest: None,
         enrichment_queue: crate::types::EnrichmentQueueManifest::default(),
+        retention: crate::types::RetentionPolicy::default(),
+        generation_marks: Vec::new(),
+        entity_aliases: crate::types::EntityAliasTable::default(),
         merkle_root: [0u8; 32],
         toc_checksum: [0u8; 32],
     }
@@ -1340,6 +1447,34 @@ fn load_tail_snapshot(file: &File) -> Result<TailSnapshot> {
     })
 }
 
+/// Guard a read-only handle against in-place writes before it reads anything.
+///
+/// Registers a lease pinning generation 0, to be moved to the loaded footer with
+/// [`SnapshotLease::pin`]. When the registry is unavailable the reader holds a shared OS
+/// lock instead, which keeps writers out for as long as it stays open.
+fn protect_snapshot(file: &File, path: &Path) -> Result<(FileLock, Option<SnapshotLease>)> {
+    let opened_at = OffsetDateTime::now_utc()
+        .format(&Rfc3339)
+        .unwrap_or_default();
+    let record = SnapshotRecord {
+        pid: std::process::id(),
+        generation: 0,
+        footer_offset: 0,
+        file_path: path.display().to_string(),
+        last_heartbeat: opened_at.clone(),
+        opened_at,
+    };
+    match registry::snapshot_key(file, path)
+        .and_then(|key| registry::register_snapshot(&key, &record))
+    {
+        Ok(lease) => Ok((FileLock::unlocked(file)?, Some(lease))),
+        Err(err) => {
+            tracing::warn!("failed to register snapshot lease ({err}); taking a shared lock");
+            Ok((FileLock::acquire_with_mode(file, LockMode::Shared)?, None))
+        }
+    }
+}
+
 fn detect_generation(file: &File) -> Result<Option<u64>> {
     // Safety: read-only mapping for footer inspection.
     let mmap = unsafe { Mmap::map(file)? };
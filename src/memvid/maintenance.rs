@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use crate::Result;
 use crate::io::time_index::read_track as time_index_read;
@@ -12,7 +12,16 @@ impl Memvid {
     pub fn verify<P: AsRef<Path>>(path: P, deep: bool) -> Result<VerificationReport> {
         let path_buf = path.as_ref().to_path_buf();
         let mut mem = Self::open_read_only(&path_buf)?;
+        mem.verification_report(path_buf, deep)
+    }
 
+    /// Run the `verify` checks against an already-open handle.
+    pub(crate) fn verification_report(
+        &mut self,
+        file_path: PathBuf,
+        deep: bool,
+    ) -> Result<VerificationReport> {
+        let mem = self;
         let mut checks = Vec::new();
         let mut overall = VerificationStatus::Passed;
         let mut push_check = |name: &str, status: VerificationStatus, details: Option<String>| {
@@ -156,7 +165,7 @@ impl Memvid {
         }
 
         Ok(VerificationReport {
-            file_path: path_buf,
+            file_path,
             checks,
             overall_status: overall,
         })
@@ -212,15 +212,27 @@ impl Memvid {
 
     /// Get the current (most recent, non-retracted) memory for an entity:slot.
     ///
+    /// The entity is expanded through the entity alias table, so asking for "Bob" also
+    /// considers cards recorded under "Bob Smith" or "bsmith@corp.com" once they are linked.
+    ///
     /// # Arguments
     /// * `entity` - The entity (e.g., "user")
     /// * `slot` - The slot/attribute (e.g., "employer")
     ///
     /// # Returns
-    /// The most recent non-retracted card, if any.
+    /// The most recent non-retracted card across the entity's aliases, if any.
     #[must_use]
     pub fn get_current_memory(&self, entity: &str, slot: &str) -> Option<&MemoryCard> {
-        self.memories_track.get_current(entity, slot)
+        let names = self.toc.entity_aliases.group(entity);
+        if names.len() == 1 {
+            return self.memories_track.get_current(entity, slot);
+        }
+        let mut cards: Vec<&MemoryCard> = names
+            .iter()
+            .flat_map(|name| self.memories_track.get_cards(name, slot))
+            .collect();
+        cards.sort_by_key(|card| std::cmp::Reverse(card.effective_timestamp()));
+        cards.into_iter().find(|card| !card.is_retracted())
     }
 
     /// Get the memory value at a specific point in time.
@@ -4,10 +4,16 @@
 //! graph within an MV2 file, including adding nodes/edges, traversing relationships,
 //! and querying entities.
 
+use std::io::{BufRead, Write};
+
+use crate::Result;
+use crate::graph_search::GraphMatcher;
 use crate::memvid::lifecycle::Memvid;
+use crate::types::graph_formats::write_graph;
 use crate::types::{
-    EntityKind, FollowResult, FrameId, LogicMesh, LogicMeshStats, MeshEdge, MeshNode,
-    SearchHitEntity,
+    CentralityMeasure, CentralityScore, CommunityMethod, EntityAliasTable, EntityKind,
+    FollowResult, FrameId, GraphFormat, GraphMatchResult, GraphQuery, LinkType, LogicMesh,
+    LogicMeshStats, MeshCommunity, MeshEdge, MeshNode, MeshPath, NTriplesImport, SearchHitEntity,
 };
 
 impl Memvid {
@@ -88,6 +94,10 @@ impl Memvid {
     /// Traverses the Logic-Mesh starting from the named entity,
     /// following edges of the specified type up to the given number of hops.
     ///
+    /// The start is expanded through the entity alias table: traversal begins at every name
+    /// in its group, reached entities are reported under their canonical name, and results
+    /// naming the same entity are merged.
+    ///
     /// # Arguments
     /// * `start` - The entity name to start from (case-insensitive)
     /// * `link` - The relationship type to follow (e.g., "manager", "employer")
@@ -97,7 +107,128 @@ impl Memvid {
     /// A list of entities found by traversing the relationships.
     #[must_use]
     pub fn follow(&self, start: &str, link: &str, hops: usize) -> Vec<FollowResult> {
-        self.logic_mesh.follow(start, link, hops)
+        let aliases = &self.toc.entity_aliases;
+        if aliases.is_empty() {
+            return self.logic_mesh.follow(start, link, hops);
+        }
+        let group = aliases.group(start);
+        let start_key = EntityAliasTable::normalize(&group[0]);
+        let mut merged: Vec<FollowResult> = Vec::new();
+        for name in &group {
+            for mut result in self.logic_mesh.follow(name, link, hops) {
+                result.node = aliases.resolve(&result.node).to_string();
+                let key = EntityAliasTable::normalize(&result.node);
+                if key == start_key {
+                    continue;
+                }
+                let Some(existing) = merged
+                    .iter_mut()
+                    .find(|kept| EntityAliasTable::normalize(&kept.node) == key)
+                else {
+                    merged.push(result);
+                    continue;
+                };
+                existing.confidence = existing.confidence.max(result.confidence);
+                existing.path_length = existing.path_length.min(result.path_length);
+                for frame_id in result.frame_ids {
+                    if !existing.frame_ids.contains(&frame_id) {
+                        existing.frame_ids.push(frame_id);
+                    }
+                }
+            }
+        }
+        merged.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        merged
+    }
+
+    /// Find how two entities are connected.
+    ///
+    /// Edges are walked in either direction, so "how is Alice connected to Project X"
+    /// finds a path whichever way the relationships were extracted.
+    ///
+    /// # Arguments
+    /// * `from` - The entity to start from (case-insensitive)
+    /// * `to` - The entity to reach (case-insensitive)
+    /// * `links` - Only cross edges of these relationship types, or any edge when `None`
+    ///
+    /// # Returns
+    /// The shortest path with the frames supporting each step, or `None` if either entity is
+    /// unknown or they are not connected.
+    #[must_use]
+    pub fn mesh_path(&self, from: &str, to: &str, links: Option<&[LinkType]>) -> Option<MeshPath> {
+        self.logic_mesh.shortest_path(from, to, links)
+    }
+
+    /// Extract the part of the Logic-Mesh within `hops` relationships of an entity.
+    ///
+    /// # Returns
+    /// A finalized subgraph containing the reached entities and the edges between them, or
+    /// `None` if the entity is unknown.
+    #[must_use]
+    pub fn mesh_neighborhood(&self, start: &str, hops: usize) -> Option<LogicMesh> {
+        self.logic_mesh.neighborhood(start, hops)
+    }
+
+    /// Rank entities by degree or PageRank centrality, most central first.
+    #[must_use]
+    pub fn mesh_centrality(&self, measure: CentralityMeasure) -> Vec<CentralityScore> {
+        self.logic_mesh.centrality(measure)
+    }
+
+    /// Group entities into communities, largest first.
+    #[must_use]
+    pub fn mesh_communities(&self, method: CommunityMethod) -> Vec<MeshCommunity> {
+        self.logic_mesh.communities(method)
+    }
+
+    /// Run a textual graph query over memory cards and the Logic-Mesh.
+    ///
+    /// # Arguments
+    /// * `query` - e.g. `MATCH (?p)-[:employer]->("acme") WHERE ?p.location = "berlin" RETURN ?p`
+    ///
+    /// # Returns
+    /// One result per distinct set of returned bindings, up to the query's `LIMIT`, or
+    /// `MemvidError::InvalidGraphQuery` pointing at the part of the query that failed to parse.
+    pub fn graph_query(&self, query: &str) -> Result<Vec<GraphMatchResult>> {
+        let query = GraphQuery::parse(query)?;
+        Ok(GraphMatcher::new(self).execute_query(&query))
+    }
+
+    /// Export the knowledge graph for visualization or a triple store.
+    ///
+    /// Writes the Logic-Mesh and the memory cards as one graph in `format`; see
+    /// [`crate::types::graph_formats`] for the vocabulary.
+    ///
+    /// # Arguments
+    /// * `writer` - Destination for the rendered graph
+    /// * `format` - GraphML (Gephi, yEd), Graphviz DOT or N-Triples
+    pub fn export_graph<W: Write>(&self, writer: W, format: GraphFormat) -> Result<()> {
+        write_graph(
+            writer,
+            format,
+            Some(&self.logic_mesh),
+            Some(&self.memories_track),
+        )
+    }
+
+    /// Merge the entities and relationships described by an N-Triples stream into the
+    /// Logic-Mesh.
+    ///
+    /// # Arguments
+    /// * `reader` - N-Triples text, e.g. from [`Memvid::export_graph`] or a triple store
+    /// * `frame_id` - Frame credited for triples that carry no `urn:memvid:frame` provenance
+    ///
+    /// # Returns
+    /// Counts of merged nodes and edges, or `MemvidError::InvalidNTriples` naming the first
+    /// malformed line, in which case the mesh is left unchanged.
+    pub fn import_ntriples<R: BufRead>(
+        &mut self,
+        reader: R,
+        frame_id: FrameId,
+    ) -> Result<NTriplesImport> {
+        let report = self.logic_mesh.import_ntriples(reader, frame_id)?;
+        self.dirty = true;
+        Ok(report)
     }
 
     /// Find an entity node by name.
@@ -1,7 +1,9 @@
 //! Core `Memvid` type orchestrating `.mv2` lifecycle and mutations.
 
+pub mod aliases;
 pub mod ask;
 pub mod audit;
+pub mod backup;
 #[cfg(feature = "parallel_segments")]
 pub mod builder;
 pub mod chunks;
@@ -9,6 +11,9 @@ pub mod doctor;
 pub mod enrichment;
 pub mod frame;
 mod helpers;
+pub mod import;
+pub mod ingest;
+pub mod interchange;
 pub mod lifecycle;
 pub mod maintenance;
 pub mod memory;
@@ -16,8 +21,10 @@ pub mod mesh;
 pub mod mutation;
 #[cfg(feature = "parallel_segments")]
 pub mod planner;
+pub mod purge;
 #[cfg(feature = "replay")]
 pub mod replay_ops;
+pub mod retention;
 pub mod search;
 mod segments;
 pub mod sketch;
@@ -33,5 +40,5 @@ pub use enrichment::{
     start_enrichment_worker_with_embeddings,
 };
 pub use frame::BlobReader;
-pub use lifecycle::{LockSettings, Memvid, OpenReadOptions};
+pub use lifecycle::{LockSettings, Memvid, OpenReadOptions, SNAPSHOT_LEASE_TTL};
 pub use sketch::{SketchCandidate, SketchSearchOptions, SketchSearchStats};
@@ -39,14 +39,16 @@ use super::{
 #[cfg(feature = "temporal_track")]
 use crate::TemporalTrackManifest;
 use crate::analysis::auto_tag::AutoTagger;
+use crate::clip::ClipEmbeddingProvider;
 use crate::constants::{WAL_SIZE_LARGE, WAL_SIZE_MEDIUM};
 use crate::footer::CommitFooter;
 use crate::io::wal::{EmbeddedWal, WalRecord};
-use crate::memvid::chunks::{plan_document_chunks, plan_text_chunks};
+use crate::lock::{FileLock, LockMode};
+use crate::memvid::chunks::{plan_document_chunks, plan_text_chunks, plan_transcript_chunks};
 use crate::memvid::lifecycle::{Memvid, prepare_toc_bytes};
 use crate::reader::{
-    DocumentFormat, DocumentReader, PassthroughReader, ReaderDiagnostics, ReaderHint, ReaderOutput,
-    ReaderRegistry,
+    DocumentFormat, DocumentReader, ImageProbe, PassthroughReader, ReaderDiagnostics, ReaderHint,
+    ReaderOutput, ReaderRegistry, TRANSCRIPT_METADATA_KEY, probe_image,
 };
 #[cfg(feature = "lex")]
 use crate::search::{EmbeddedLexSegment, LexWalBatch, TantivySnapshot};
@@ -54,8 +56,9 @@ use crate::triplet::TripletExtractor;
 #[cfg(feature = "lex")]
 use crate::types::TantivySegmentDescriptor;
 use crate::types::{
-    CanonicalEncoding, DocMetadata, Frame, FrameId, FrameRole, FrameStatus, PutOptions,
-    SegmentCommon, TextChunkManifest, Tier,
+    CanonicalEncoding, DocAudioMetadata, DocMetadata, Frame, FrameId, FrameRole, FrameStatus,
+    MediaManifest, PutManyOpts, PutOptions, PutRequest, SegmentCommon, TextChunkManifest, Tier,
+    VacuumReport, VerificationStatus,
 };
 #[cfg(feature = "parallel_segments")]
 use crate::types::{IndexSegmentRef, SegmentKind, SegmentSpan, SegmentStats};
@@ -67,7 +70,8 @@ use crate::{
 };
 use crate::{
     DEFAULT_SEARCH_TEXT_LIMIT, ExtractedDocument, MemvidError, Result, TimeIndexEntry,
-    TimeIndexManifest, VecIndexManifest, normalize_text, time_index_append, wal_config,
+    TimeIndexManifest, TranscriptionResult, VecIndexManifest, normalize_text, time_index_append,
+    wal_config,
 };
 #[cfg(feature = "temporal_track")]
 use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
@@ -75,6 +79,13 @@ use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
 const MAGIC_SNIFF_BYTES: usize = 16;
 const WAL_ENTRY_HEADER_SIZE: u64 = 48;
 const WAL_SHIFT_BUFFER_SIZE: usize = 8 * 1024 * 1024;
+pub(crate) const DEFAULT_PAYLOAD_COMPRESSION_LEVEL: i32 = 3;
+/// Pages past this many are left out of visual PDF indexing.
+#[cfg(feature = "clip")]
+const CLIP_PDF_MAX_PAGES: usize = 64;
+/// Longest edge, in pixels, of a PDF page rendered for the CLIP index.
+#[cfg(feature = "clip")]
+const CLIP_PDF_PAGE_PX: u32 = 768;
 
 #[cfg(feature = "temporal_track")]
 const DEFAULT_TEMPORAL_TZ: &str = "America/Chicago";
@@ -235,6 +246,9 @@ fn infer_document_format(
     if detect_pdf_magic(magic) {
         return Some(DocumentFormat::Pdf);
     }
+    if detect_audio_magic(magic) {
+        return Some(DocumentFormat::Audio);
+    }
 
     // For ZIP-based OOXML formats (DOCX, XLSX, PPTX), magic bytes are just ZIP header
     // so we need to check file extension to distinguish them
@@ -266,6 +280,7 @@ fn infer_document_format(
                 Some(DocumentFormat::Pptx)
             }
             other if other.starts_with("text/") => Some(DocumentFormat::PlainText),
+            other if other.starts_with("audio/") => Some(DocumentFormat::Audio),
             _ => None,
         };
         if format.is_some() {
@@ -294,10 +309,25 @@ fn infer_format_from_extension(uri: Option<&str>) -> Option<DocumentFormat> {
         | "sql" => Some(DocumentFormat::PlainText),
         "md" | "markdown" => Some(DocumentFormat::Markdown),
         "html" | "htm" => Some(DocumentFormat::Html),
+        "mp3" | "wav" | "flac" | "ogg" | "oga" | "opus" | "m4a" | "aac" => {
+            Some(DocumentFormat::Audio)
+        }
         _ => None,
     }
 }
 
+/// Container signatures of the audio formats the Whisper decoder reads.
+fn detect_audio_magic(magic: Option<&[u8]>) -> bool {
+    let Some(bytes) = magic else {
+        return false;
+    };
+    bytes.starts_with(b"ID3")
+        || bytes.starts_with(b"fLaC")
+        || bytes.starts_with(b"OggS")
+        || (bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WAVE".as_slice()))
+        || bytes.get(4..11) == Some(b"ftypM4A".as_slice())
+}
+
 fn detect_pdf_magic(magic: Option<&[u8]>) -> bool {
     let mut slice = match magic {
         Some(slice) if !slice.is_empty() => slice,
@@ -378,6 +408,98 @@ fn finalize_reader_output(output: ReaderOutput, start: Instant) -> ExtractedDocu
     document
 }
 
+/// Remove the transcript a reader attached to `doc`, keeping it out of stored metadata.
+fn take_transcript(doc: &mut ExtractedDocument) -> Option<TranscriptionResult> {
+    let fields = doc.metadata.as_object_mut()?;
+    let value = fields.remove(TRANSCRIPT_METADATA_KEY)?;
+    if fields.is_empty() {
+        doc.metadata = serde_json::Value::Null;
+    }
+    serde_json::from_value(value)
+        .map_err(|err| {
+            tracing::warn!(target = "memvid::extract", error = %err, "ignoring malformed transcript");
+        })
+        .ok()
+}
+
+/// Record what `probe` found about an image in the fields of `meta` the caller left unset.
+fn apply_image_probe(
+    meta: &mut DocMetadata,
+    probe: ImageProbe,
+    bytes: u64,
+    filename: Option<String>,
+) {
+    let mime = probe.format.mime();
+    meta.mime.get_or_insert_with(|| mime.to_string());
+    meta.bytes.get_or_insert(bytes);
+    meta.width = meta.width.or(probe.width);
+    meta.height = meta.height.or(probe.height);
+    if meta.exif.is_none() {
+        meta.exif = probe.exif;
+    }
+    meta.media.get_or_insert_with(|| MediaManifest {
+        kind: "image".to_string(),
+        mime: mime.to_string(),
+        bytes,
+        filename,
+        duration_ms: None,
+        width: probe.width,
+        height: probe.height,
+        codec: Some(probe.format.label().to_string()),
+    });
+}
+
+/// A PDF page rendered for the CLIP index.
+#[cfg_attr(not(feature = "clip"), allow(dead_code))]
+struct PdfPageImage {
+    /// 1-based page number.
+    page: u32,
+    png: Vec<u8>,
+    embedding: Vec<f32>,
+}
+
+/// Render the pages of a PDF payload and embed them with `provider`, skipping blank and
+/// degenerate pages.
+#[cfg(feature = "clip")]
+fn render_pdf_page_images(
+    bytes: &[u8],
+    provider: &dyn ClipEmbeddingProvider,
+) -> Result<Vec<PdfPageImage>> {
+    let mut source = tempfile::NamedTempFile::new()?;
+    source.write_all(bytes)?;
+    source.flush()?;
+    let pages = crate::clip::render_pdf_pages_for_clip(
+        source.path(),
+        CLIP_PDF_MAX_PAGES,
+        CLIP_PDF_PAGE_PX,
+    )?;
+    crate::clip::filter_junk_images(pages, |(_, image)| crate::clip::get_image_info(image))
+        .into_iter()
+        .map(|(page, image)| {
+            let mut png = Vec::new();
+            image
+                .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+                .map_err(|err| MemvidError::ExtractionFailed {
+                    reason: format!("failed to encode PDF page {page}: {err}").into(),
+                })?;
+            let embedding = provider.embed_image_bytes(&png)?;
+            Ok(PdfPageImage {
+                page,
+                png,
+                embedding,
+            })
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "clip"))]
+fn render_pdf_page_images(
+    _bytes: &[u8],
+    _provider: &dyn ClipEmbeddingProvider,
+) -> Result<Vec<PdfPageImage>> {
+    Err(MemvidError::FeatureUnavailable { feature: "clip" })
+}
+
 fn log_reader_result(reader: &str, diagnostics: &ReaderDiagnostics, elapsed: Duration) {
     let duration_ms = diagnostics
         .duration_ms
@@ -428,6 +550,7 @@ impl Memvid {
         let original_toc = self.toc.clone();
         let original_data_end = self.data_end;
         let original_generation = self.generation;
+        let original_backup_floor = self.backup_floor;
         let original_dirty = self.dirty;
         #[cfg(feature = "lex")]
         let original_tantivy_dirty = self.tantivy_dirty;
@@ -439,10 +562,23 @@ impl Memvid {
         match op(self) {
             Ok(()) => {
                 self.file.sync_all()?;
+                // Lock the staged file before it replaces the original so the writer keeps
+                // exclusive access to the version that ends up at `path`.
+                let staged_lock = if self.lock.mode() == LockMode::Exclusive {
+                    Some(FileLock::acquire_with_mode(
+                        &self.file,
+                        LockMode::Exclusive,
+                    )?)
+                } else {
+                    None
+                };
                 match staging.commit() {
                     Ok(()) => {
                         drop(original_file.take());
                         drop(original_wal.take());
+                        if let Some(lock) = staged_lock {
+                            self.lock = lock;
+                        }
                         self.file = OpenOptions::new()
                             .read(true)
                             .write(true)
@@ -461,6 +597,7 @@ impl Memvid {
                         self.toc = original_toc;
                         self.data_end = original_data_end;
                         self.generation = original_generation;
+                        self.backup_floor = original_backup_floor;
                         self.dirty = original_dirty;
                         #[cfg(feature = "lex")]
                         {
@@ -482,6 +619,7 @@ impl Memvid {
                 self.toc = original_toc;
                 self.data_end = original_data_end;
                 self.generation = original_generation;
+                self.backup_floor = original_backup_floor;
                 self.dirty = original_dirty;
                 #[cfg(feature = "lex")]
                 {
@@ -600,8 +738,31 @@ impl Memvid {
         result
     }
 
+    /// Queue a fully formed frame record, bypassing extraction and chunking. Used by
+    /// import, which copies frames that were already processed by another memory.
+    pub(crate) fn append_frame_entry(&mut self, entry: WalEntryData) -> Result<u64> {
+        self.ensure_mutation_allowed()?;
+        let payload_tail = self.payload_region_end();
+        let required = entry.payload.len() as u64;
+        let limit = self.capacity_limit();
+        if payload_tail.saturating_add(required) > limit {
+            return Err(MemvidError::CapacityExceeded {
+                current: payload_tail,
+                limit,
+                required,
+            });
+        }
+        let bytes = encode_to_vec(WalEntry::Frame(entry), wal_config())?;
+        let seq = self.append_wal_entry(&bytes)?;
+        self.pending_frame_inserts = self.pending_frame_inserts.saturating_add(1);
+        self.dirty = true;
+        Ok(seq)
+    }
+
     fn append_wal_entry(&mut self, payload: &[u8]) -> Result<u64> {
         loop {
+            // The WAL is reopened on growth/commit, so reapply the batch sync policy each try.
+            self.wal.set_sync_on_append(self.put_batch.is_none());
             match self.wal.append_entry(payload) {
                 Ok(seq) => return Ok(seq),
                 Err(MemvidError::CheckpointFailed { reason })
@@ -613,7 +774,12 @@ impl Memvid {
                     let required = WAL_ENTRY_HEADER_SIZE
                         .saturating_add(payload.len() as u64)
                         .max(self.header.wal_size + 1);
-                    self.grow_wal_region(required)?;
+                    // Growth shifts the data region; never do that under a live snapshot.
+                    if self.oldest_pinned_generation().is_some() {
+                        self.with_staging_lock(move |mem| mem.grow_wal_region(required))?;
+                    } else {
+                        self.grow_wal_region(required)?;
+                    }
                 }
                 Err(err) => return Err(err),
             }
@@ -638,6 +804,8 @@ impl Memvid {
             return Ok(());
         }
 
+        // Moving the data region is a new generation for readers and backups alike.
+        self.generation = self.generation.wrapping_add(1);
         self.shift_data_for_wal_growth(delta)?;
         self.header.wal_size = new_size;
         self.header.footer_offset = self.header.footer_offset.saturating_add(delta);
@@ -663,6 +831,7 @@ impl Memvid {
         }
         let original_len = self.file.metadata()?.len();
         let data_start = self.header.wal_offset + self.header.wal_size;
+        self.lower_backup_floor(data_start);
         self.file.set_len(original_len + delta)?;
 
         let mut remaining = original_len.saturating_sub(data_start);
@@ -789,6 +958,8 @@ impl Memvid {
 
     fn commit_from_records(&mut self, records: Vec<WalRecord>, _mode: CommitMode) -> Result<()> {
         self.generation = self.generation.wrapping_add(1);
+        // Commits only write at or past the current data end.
+        self.lower_backup_floor(self.data_end.min(self.header.footer_offset));
 
         let delta = self.apply_records(records)?;
         let mut indexes_rebuilt = false;
@@ -871,6 +1042,7 @@ impl Memvid {
             return Ok(());
         }
         let records = self.wal.pending_records()?;
+        self.lower_backup_floor(self.data_end.min(self.header.footer_offset));
         let delta = self.apply_records(records)?;
         self.generation = self.generation.wrapping_add(1);
         let mut indexes_rebuilt = false;
@@ -1932,8 +2104,18 @@ impl Memvid {
             return Ok(());
         }
 
-        let payload_end = self.payload_region_end();
+        let mut payload_end = self.payload_region_end();
+        // Past the payload end sit the old index segments, and deleted or superseded
+        // payloads a snapshot's TOC may still reference. While a snapshot at or before this
+        // generation pins the file, append behind everything instead of reclaiming them.
+        if self
+            .oldest_pinned_generation()
+            .is_some_and(|oldest| oldest <= self.generation)
+        {
+            payload_end = payload_end.max(self.file.metadata()?.len());
+        }
         self.data_end = payload_end;
+        self.lower_backup_floor(payload_end);
         // Don't truncate if footer_offset is higher - there may be replay segments
         // or other data written after payload_end that must be preserved.
         let safe_truncate_len = self.header.footer_offset.max(payload_end);
@@ -2670,6 +2852,7 @@ impl Memvid {
             data_end = self.data_end,
             "rewrite_toc_footer: about to serialize TOC"
         );
+        self.record_generation_mark();
         let toc_bytes = prepare_toc_bytes(&mut self.toc)?;
         let footer_offset = self.header.footer_offset;
         self.file.seek(SeekFrom::Start(footer_offset))?;
@@ -2795,42 +2978,351 @@ impl Memvid {
 }
 
 impl Memvid {
-    pub fn vacuum(&mut self) -> Result<()> {
+    /// Compact the memory so it holds only active payloads and freshly built indexes.
+    ///
+    /// The compacted image is written to a temporary sibling file, checked with the same
+    /// routines as [`Memvid::verify`], and only then renamed over the original, so a crash
+    /// leaves either the old file or the new one in place, never a mix of both. Records
+    /// in the embedded WAL are zeroed once applied, so deleted payloads do not linger there.
+    pub fn vacuum(&mut self) -> Result<VacuumReport> {
+        self.vacuum_pass(None)
+    }
+
+    /// Budgeted variant of [`Memvid::vacuum`] for memories that cannot pause for long.
+    ///
+    /// Each call moves active payloads down into free space below them, in place, until
+    /// about `budget_bytes` have moved (always at least one frame). A move never writes
+    /// over bytes the committed TOC still references, and the updated TOC is appended
+    /// after the current one, so a crash leaves one of the two readable. Indexes are
+    /// untouched until nothing is left to move; that call rebuilds them and truncates the
+    /// file with the verified copy-on-write step of [`Memvid::vacuum`]. Call it until
+    /// [`VacuumReport::is_complete`]; gaps too small for any payload above them are left
+    /// for a full [`Memvid::vacuum`]. While a snapshot reader pins the file, the call
+    /// falls back to a full [`Memvid::vacuum`].
+    pub fn vacuum_incremental(&mut self, budget_bytes: u64) -> Result<VacuumReport> {
+        self.vacuum_pass(Some(budget_bytes))
+    }
+
+    fn vacuum_pass(&mut self, budget: Option<u64>) -> Result<VacuumReport> {
         self.commit()?;
+        let bytes_before = self.file.metadata()?.len();
+        let (frames_moved, bytes_moved, frames_remaining) = match budget {
+            Some(budget) if self.oldest_pinned_generation().is_none() => {
+                let (progress, changed) = self.pack_payloads_in_place(budget)?;
+                if progress.2 == 0 && changed {
+                    self.with_staging_lock(|mem| {
+                        mem.generation = mem.generation.wrapping_add(1);
+                        let replay_segment = mem.read_replay_segment()?;
+                        mem.rebuild_compacted(replay_segment)?;
+                        mem.verify_staged_image()
+                    })?;
+                }
+                progress
+            }
+            _ => {
+                let mut progress = (0, 0);
+                self.with_staging_lock(|mem| {
+                    mem.generation = mem.generation.wrapping_add(1);
+                    progress = mem.compact_payloads()?;
+                    mem.verify_staged_image()
+                })?;
+                (progress.0, progress.1, 0)
+            }
+        };
+        let bytes_after = self.file.metadata()?.len();
+        Ok(VacuumReport {
+            bytes_before,
+            bytes_after,
+            bytes_reclaimed: bytes_before.saturating_sub(bytes_after),
+            frames_moved,
+            bytes_moved,
+            frames_remaining,
+        })
+    }
 
-        let mut active_payloads: HashMap<FrameId, Vec<u8>> = HashMap::new();
-        let frames: Vec<Frame> = self
-            .toc
-            .frames
-            .iter()
-            .filter(|frame| frame.status == FrameStatus::Active)
-            .cloned()
-            .collect();
-        for frame in frames {
-            let bytes = self.read_frame_payload_bytes(&frame)?;
-            active_payloads.insert(frame.id, bytes);
+    /// Move up to about `budget` payload bytes into free space, publishing the TOC after
+    /// each batch. Returns `(frames_moved, bytes_moved, frames_remaining)` and whether the
+    /// file changed.
+    fn pack_payloads_in_place(&mut self, budget: u64) -> Result<((usize, u64, usize), bool)> {
+        // Deleted and superseded payloads only become free space once a published TOC
+        // stops referencing them.
+        let mut changed = false;
+        for frame in &mut self.toc.frames {
+            if frame.status != FrameStatus::Active && frame.payload_length != 0 {
+                frame.payload_offset = 0;
+                frame.payload_length = 0;
+                changed = true;
+            }
+        }
+        if changed {
+            self.append_toc_in_place()?;
+        }
+
+        let mut frames_moved = 0;
+        let mut bytes_moved = 0u64;
+        loop {
+            let plan = self.plan_payload_moves();
+            if plan.is_empty() {
+                return Ok(((frames_moved, bytes_moved, 0), changed));
+            }
+            // A reader that arrived since the last batch pins the published TOC; stop
+            // here and let the next call take the copy-on-write path.
+            if changed && self.oldest_pinned_generation().is_some() {
+                return Ok(((frames_moved, bytes_moved, plan.len()), changed));
+            }
+            let mut executed = 0;
+            for &(index, destination) in &plan {
+                let frame = self.toc.frames[index].clone();
+                if frames_moved > 0 && bytes_moved + frame.payload_length > budget {
+                    break;
+                }
+                let bytes = self.read_frame_payload_bytes(&frame)?;
+                self.file.seek(SeekFrom::Start(destination))?;
+                self.file.write_all(&bytes)?;
+                self.lower_backup_floor(destination);
+                self.toc.frames[index].payload_offset = destination;
+                frames_moved += 1;
+                bytes_moved += frame.payload_length;
+                executed += 1;
+            }
+            if executed > 0 {
+                changed = true;
+                self.file.sync_all()?;
+                self.append_toc_in_place()?;
+            }
+            if executed < plan.len() {
+                return Ok(((frames_moved, bytes_moved, plan.len() - executed), changed));
+            }
+        }
+    }
+
+    /// Moves that put active payloads, highest first, into the lowest free gap below them
+    /// that fits. Gaps exclude every range the TOC references, including the sources of
+    /// the planned moves, so the plan can run before the TOC is published.
+    fn plan_payload_moves(&self) -> Vec<(usize, u64)> {
+        let mut shared: HashMap<(u64, u64), usize> = HashMap::new();
+        let mut occupied = self.reserved_ranges();
+        for frame in &self.toc.frames {
+            if frame.status == FrameStatus::Active && frame.payload_length != 0 {
+                *shared
+                    .entry((frame.payload_offset, frame.payload_length))
+                    .or_default() += 1;
+                occupied.push((frame.payload_offset, frame.payload_length));
+            }
         }
+        occupied.sort_unstable();
 
+        let mut gaps: Vec<(u64, u64)> = Vec::new();
         let mut cursor = self.header.wal_offset + self.header.wal_size;
-        self.file.seek(SeekFrom::Start(cursor))?;
+        for &(offset, length) in &occupied {
+            if offset > cursor {
+                gaps.push((cursor, offset));
+            }
+            cursor = cursor.max(offset + length);
+        }
+
+        // Frames sharing a payload range (reused payloads) stay where they are.
+        let mut movers: Vec<usize> = (0..self.toc.frames.len())
+            .filter(|&index| {
+                let frame = &self.toc.frames[index];
+                frame.status == FrameStatus::Active
+                    && frame.payload_length != 0
+                    && shared[&(frame.payload_offset, frame.payload_length)] == 1
+            })
+            .collect();
+        movers.sort_by_key(|&index| std::cmp::Reverse(self.toc.frames[index].payload_offset));
+
+        let mut plan = Vec::new();
+        for index in movers {
+            let frame = &self.toc.frames[index];
+            let gap = gaps.iter_mut().find(|(start, end)| {
+                *start < frame.payload_offset && end - start >= frame.payload_length
+            });
+            if let Some(gap) = gap {
+                plan.push((index, gap.0));
+                gap.0 += frame.payload_length;
+            }
+        }
+        plan
+    }
+
+    /// Byte ranges of the non-payload structures the TOC references.
+    fn reserved_ranges(&self) -> Vec<(u64, u64)> {
+        let toc = &self.toc;
+        let mut ranges: Vec<(u64, u64)> = Vec::new();
+        ranges.extend(
+            toc.segments
+                .iter()
+                .map(|s| (s.bytes_offset, s.bytes_length)),
+        );
+        ranges.extend(
+            toc.indexes
+                .lex
+                .iter()
+                .map(|m| (m.bytes_offset, m.bytes_length)),
+        );
+        ranges.extend(
+            toc.indexes
+                .lex_segments
+                .iter()
+                .map(|m| (m.bytes_offset, m.bytes_length)),
+        );
+        ranges.extend(
+            toc.indexes
+                .vec
+                .iter()
+                .map(|m| (m.bytes_offset, m.bytes_length)),
+        );
+        ranges.extend(
+            toc.indexes
+                .clip
+                .iter()
+                .map(|m| (m.bytes_offset, m.bytes_length)),
+        );
+        ranges.extend(
+            toc.time_index
+                .iter()
+                .map(|m| (m.bytes_offset, m.bytes_length)),
+        );
+        #[cfg(feature = "temporal_track")]
+        ranges.extend(
+            toc.temporal_track
+                .iter()
+                .map(|m| (m.bytes_offset, m.bytes_length)),
+        );
+        ranges.extend(
+            toc.memories_track
+                .iter()
+                .map(|m| (m.bytes_offset, m.bytes_length)),
+        );
+        ranges.extend(
+            toc.logic_mesh
+                .iter()
+                .map(|m| (m.bytes_offset, m.bytes_length)),
+        );
+        ranges.extend(
+            toc.sketch_track
+                .iter()
+                .map(|m| (m.bytes_offset, m.bytes_length)),
+        );
+        ranges.extend(
+            toc.replay_manifest
+                .iter()
+                .map(|m| (m.segment_offset, m.segment_size)),
+        );
+        let catalog = &toc.segment_catalog;
+        let common = catalog
+            .lex_segments
+            .iter()
+            .map(|d| &d.common)
+            .chain(catalog.vec_segments.iter().map(|d| &d.common))
+            .chain(catalog.time_segments.iter().map(|d| &d.common))
+            .chain(catalog.tantivy_segments.iter().map(|d| &d.common));
+        ranges.extend(common.map(|c| (c.bytes_offset, c.bytes_length)));
+        #[cfg(feature = "temporal_track")]
+        ranges.extend(
+            catalog
+                .temporal_segments
+                .iter()
+                .map(|d| (d.common.bytes_offset, d.common.bytes_length)),
+        );
+        #[cfg(feature = "parallel_segments")]
+        ranges.extend(
+            catalog
+                .index_segments
+                .iter()
+                .map(|d| (d.common.bytes_offset, d.common.bytes_length)),
+        );
+        ranges.retain(|&(_, length)| length != 0);
+        ranges
+    }
+
+    /// Publish the in-memory TOC as a new generation written after the current footer, so
+    /// the previous TOC stays intact until the new one is complete.
+    fn append_toc_in_place(&mut self) -> Result<()> {
+        self.generation = self.generation.wrapping_add(1);
+        self.header.footer_offset = self.file.metadata()?.len();
+        self.rewrite_toc_footer()?;
+        self.header.toc_checksum = self.toc.toc_checksum;
+        crate::persist_header(&mut self.file, &self.header)?;
+        self.file.sync_all()?;
+        Ok(())
+    }
+
+    /// The replay segment only lives on disk; read it before its range is overwritten.
+    fn read_replay_segment(&mut self) -> Result<Option<Vec<u8>>> {
+        match self.toc.replay_manifest.as_ref() {
+            Some(manifest) if manifest.segment_size > 0 => {
+                let len = usize::try_from(manifest.segment_size).map_err(|_| {
+                    MemvidError::InvalidToc {
+                        reason: "replay segment too large to relocate".into(),
+                    }
+                })?;
+                let mut bytes = vec![0u8; len];
+                self.file.seek(SeekFrom::Start(manifest.segment_offset))?;
+                self.file.read_exact(&mut bytes)?;
+                Ok(Some(bytes))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Slide active payloads down so they sit back to back after the WAL region, then
+    /// rebuild the indexes behind them and truncate. Returns `(frames_moved, bytes_moved)`.
+    fn compact_payloads(&mut self) -> Result<(usize, u64)> {
+        // Indexes are rebuilt from memory, but the replay segment may sit inside the range
+        // we are about to overwrite.
+        let replay_segment = self.read_replay_segment()?;
+
         for frame in &mut self.toc.frames {
-            if frame.status == FrameStatus::Active {
-                if let Some(bytes) = active_payloads.get(&frame.id) {
-                    self.file.write_all(bytes)?;
-                    frame.payload_offset = cursor;
-                    frame.payload_length = bytes.len() as u64;
-                    cursor += bytes.len() as u64;
-                } else {
-                    frame.payload_offset = 0;
-                    frame.payload_length = 0;
-                }
-            } else {
+            if frame.status != FrameStatus::Active {
                 frame.payload_offset = 0;
                 frame.payload_length = 0;
             }
         }
+        let mut order: Vec<usize> = (0..self.toc.frames.len())
+            .filter(|&index| self.toc.frames[index].payload_length > 0)
+            .collect();
+        order.sort_by_key(|&index| self.toc.frames[index].payload_offset);
+
+        let mut cursor = self.header.wal_offset + self.header.wal_size;
+        self.lower_backup_floor(cursor);
+        let mut frames_moved = 0;
+        let mut bytes_moved = 0u64;
+        for &index in &order {
+            let frame = self.toc.frames[index].clone();
+            if frame.payload_offset == cursor {
+                cursor += frame.payload_length;
+                continue;
+            }
+            if frame.payload_offset < cursor {
+                return Err(MemvidError::InvalidFrame {
+                    frame_id: frame.id,
+                    reason: "payload overlaps a previous frame; run doctor before vacuum",
+                });
+            }
+            // Destination never reaches past the source's end, and later payloads start
+            // beyond it, so buffering one frame at a time is enough.
+            let bytes = self.read_frame_payload_bytes(&frame)?;
+            self.file.seek(SeekFrom::Start(cursor))?;
+            self.file.write_all(&bytes)?;
+            self.toc.frames[index].payload_offset = cursor;
+            cursor += frame.payload_length;
+            frames_moved += 1;
+            bytes_moved += frame.payload_length;
+        }
+
+        self.rebuild_compacted(replay_segment)?;
+        Ok((frames_moved, bytes_moved))
+    }
 
-        self.data_end = cursor;
+    /// Rebuild every index right after the last payload, re-append the replay segment and
+    /// sketch track, and write a fresh TOC, truncating whatever lay beyond.
+    fn rebuild_compacted(&mut self, replay_segment: Option<Vec<u8>>) -> Result<()> {
+        // Everything past the last payload is rebuilt, so let the footer fall back to it
+        // instead of preserving the old high-water mark.
+        self.header.footer_offset = self.payload_region_end();
+        self.data_end = self.header.footer_offset;
 
         self.toc.segments.clear();
         self.toc.indexes.lex_segments.clear();
@@ -2859,10 +3351,55 @@ impl Memvid {
         }
 
         self.rebuild_indexes(&[])?;
+
+        // The rebuild does not own the sketch track or replay segment; append them after
+        // the fresh indexes and rewrite the TOC so it points at the new copies.
+        self.persist_sketch_track()?;
+        if let Some(bytes) = replay_segment {
+            let offset = self.header.footer_offset;
+            self.file.seek(SeekFrom::Start(offset))?;
+            self.file.write_all(&bytes)?;
+            if let Some(manifest) = self.toc.replay_manifest.as_mut() {
+                manifest.segment_offset = offset;
+            }
+            self.header.footer_offset = offset + bytes.len() as u64;
+        }
+        // The Tantivy flush logs its batch like a commit would; checkpoint it, then zero
+        // the region, since applied records still hold the payloads they logged.
+        self.wal.record_checkpoint(&mut self.header)?;
+        self.wal.scrub_committed(&mut self.header)?;
+        self.rewrite_toc_footer()?;
+        self.header.toc_checksum = self.toc.toc_checksum;
+        crate::persist_header(&mut self.file, &self.header)?;
         self.file.sync_all()?;
         Ok(())
     }
 
+    /// Reopen the staged image from disk and run the `verify` checks against it.
+    fn verify_staged_image(&mut self) -> Result<()> {
+        self.file.sync_all()?;
+        let handle = self.file.try_clone()?;
+        // A shared lock keeps the checker read-only, so dropping it never writes back.
+        let lock = FileLock::acquire_with_mode(&handle, LockMode::Shared)?;
+        let path = self.path().to_path_buf();
+        let report = Self::open_locked(handle, lock, &path)?.verification_report(path, false)?;
+        if report.overall_status != VerificationStatus::Failed {
+            return Ok(());
+        }
+        let failed: Vec<String> = report
+            .checks
+            .iter()
+            .filter(|check| check.status == VerificationStatus::Failed)
+            .map(|check| match &check.details {
+                Some(details) => format!("{}: {details}", check.name),
+                None => check.name.clone(),
+            })
+            .collect();
+        Err(MemvidError::VacuumVerification {
+            reason: failed.join("; "),
+        })
+    }
+
     /// Preview how a document would be chunked without actually ingesting it.
     ///
     /// This is useful when you need to compute embeddings for each chunk externally
@@ -2945,6 +3482,72 @@ impl Memvid {
         )
     }
 
+    /// Ingest a batch of documents through a single WAL batch.
+    ///
+    /// Records are appended without per-entry fsyncs and, when
+    /// `opts.disable_auto_checkpoint` is set, without mid-batch checkpoints, so the next
+    /// commit publishes the lex/vec/time indexes once for the whole batch. The WAL is
+    /// synced once at the end unless `opts.skip_sync` is set. Returns the frame IDs the
+    /// documents receive, in request order; they are assigned when the batch commits. If a
+    /// document fails, earlier documents stay pending in the WAL.
+    pub fn put_many(
+        &mut self,
+        requests: Vec<PutRequest>,
+        opts: PutManyOpts,
+    ) -> Result<Vec<FrameId>> {
+        self.ensure_mutation_allowed()?;
+        if requests.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let skip_sync = opts.skip_sync;
+        let options_template = PutOptions {
+            enable_embedding: opts.enable_embedding,
+            auto_tag: opts.auto_tag,
+            extract_dates: opts.extract_dates,
+            extract_triplets: opts.enable_enrichment,
+            no_raw: opts.no_raw,
+            ..PutOptions::default()
+        };
+        let count = requests.len();
+        self.put_batch = Some(opts);
+
+        let mut frame_ids = Vec::with_capacity(count);
+        let mut outcome = Ok(());
+        for request in requests {
+            let (payload, embedding, options) =
+                put_request_into_parts(request, options_template.clone());
+            match self.put_frame_internal(Some(&payload), None, embedding, None, options, None) {
+                Ok((_, frame_id)) => frame_ids.push(frame_id),
+                Err(err) => {
+                    outcome = Err(err);
+                    break;
+                }
+            }
+        }
+
+        self.put_batch = None;
+        self.wal.set_sync_on_append(true);
+        let synced = if skip_sync { Ok(()) } else { self.wal.sync() };
+        outcome?;
+        synced?;
+
+        #[cfg(feature = "replay")]
+        self.record_put_many_action(frame_ids.clone());
+
+        tracing::debug!(count, "put_many appended batch");
+        Ok(frame_ids)
+    }
+
+    /// Ingest a batch with default [`PutManyOpts`] and commit it, returning the frame IDs
+    /// of its documents in request order.
+    #[cfg(any(feature = "serve", feature = "mcp"))]
+    pub(crate) fn put_many_committed(&mut self, requests: Vec<PutRequest>) -> Result<Vec<FrameId>> {
+        let frame_ids = self.put_many(requests, PutManyOpts::default())?;
+        self.commit()?;
+        Ok(frame_ids)
+    }
+
     /// Replace an existing frame's payload/metadata, keeping its identity and URI.
     pub fn update_frame(
         &mut self,
@@ -3035,7 +3638,13 @@ impl Memvid {
                 reason: "frame is not active",
             });
         }
+        self.append_tombstone(&frame)
+    }
 
+    /// Queue a tombstone for `frame` without the active-only guard of `delete_frame`, so
+    /// retention can also drop superseded versions.
+    pub(crate) fn append_tombstone(&mut self, frame: &Frame) -> Result<u64> {
+        let frame_id = frame.id;
         let mut tombstone = WalEntryData {
             timestamp: SystemTime::now()
                 .duration_since(UNIX_EPOCH)
@@ -3083,16 +3692,44 @@ impl Memvid {
 }
 
 impl Memvid {
-    fn put_internal(
+    pub(crate) fn put_internal(
         &mut self,
         payload: Option<&[u8]>,
         reuse_frame: Option<Frame>,
         embedding: Option<Vec<f32>>,
         chunk_embeddings: Option<Vec<Vec<f32>>>,
-        mut options: PutOptions,
+        options: PutOptions,
         supersedes: Option<FrameId>,
     ) -> Result<u64> {
+        self.put_frame_internal(
+            payload,
+            reuse_frame,
+            embedding,
+            chunk_embeddings,
+            options,
+            supersedes,
+        )
+        .map(|(seq, _)| seq)
+    }
+
+    /// [`Memvid::put_internal`], also returning the frame ID the document receives: the
+    /// ID it will be assigned at the next commit, or the existing frame on a dedup hit.
+    pub(crate) fn put_frame_internal(
+        &mut self,
+        payload: Option<&[u8]>,
+        reuse_frame: Option<Frame>,
+        embedding: Option<Vec<f32>>,
+        chunk_embeddings: Option<Vec<Vec<f32>>>,
+        mut options: PutOptions,
+        supersedes: Option<FrameId>,
+    ) -> Result<(u64, FrameId)> {
         self.ensure_mutation_allowed()?;
+        let compression_level = self
+            .put_batch
+            .as_ref()
+            .map_or(DEFAULT_PAYLOAD_COMPRESSION_LEVEL, |batch| {
+                batch.compression_level
+            });
 
         // Deduplication: if enabled and we have payload, check if identical content exists
         if options.dedup {
@@ -3105,7 +3742,7 @@ impl Memvid {
                         "dedup: skipping ingestion, identical content already exists"
                     );
                     // Return existing frame's sequence number (which equals frame_id for committed frames)
-                    return Ok(existing_frame.id);
+                    return Ok((existing_frame.id, existing_frame.id));
                 }
             }
         }
@@ -3182,7 +3819,7 @@ impl Memvid {
         let mut prepared_payload: Option<(Vec<u8>, CanonicalEncoding, Option<u64>)> = None;
         let payload_tail = self.payload_region_end();
         let projected = if let Some(bytes) = payload {
-            let (prepared, encoding, length) = prepare_canonical_payload(bytes)?;
+            let (prepared, encoding, length) = prepare_canonical_payload(bytes, compression_level)?;
             let len = prepared.len();
             prepared_payload = Some((prepared, encoding, length));
             payload_tail.saturating_add(len as u64)
@@ -3256,7 +3893,8 @@ impl Memvid {
             } else if let Some((prepared, encoding, length)) = prepared_payload.take() {
                 (prepared, encoding, length, None)
             } else if let Some(bytes) = payload {
-                let (prepared, encoding, length) = prepare_canonical_payload(bytes)?;
+                let (prepared, encoding, length) =
+                    prepare_canonical_payload(bytes, compression_level)?;
                 (prepared, encoding, length, None)
             } else if let Some(frame) = reuse_frame.as_ref() {
                 (
@@ -3272,6 +3910,14 @@ impl Memvid {
                 });
             };
 
+        // Frames that name their source file also remember its hash, so a re-sync can tell
+        // whether the file changed without comparing stored payloads.
+        if source_sha256.is_none() && source_path_value.is_some() {
+            if let Some(bytes) = payload {
+                source_sha256 = Some(*hash(bytes).as_bytes());
+            }
+        }
+
         // Track whether we'll create an extracted text chunk plan later
         let mut chunk_plan = raw_chunk_plan;
 
@@ -3294,7 +3940,7 @@ impl Memvid {
         let mut extraction_error = None;
         let mut is_skim_extraction = false; // Track if extraction was time-limited
 
-        let extracted = if run_extractor {
+        let mut extracted = if run_extractor {
             if let Some(bytes) = payload_for_processing {
                 let mime_hint = metadata.as_ref().and_then(|m| m.mime.as_deref());
                 let uri_hint = options.uri.as_deref();
@@ -3373,6 +4019,59 @@ impl Memvid {
             return Err(err);
         }
 
+        // Transcribed audio is chunked along its timestamped segments so every chunk frame
+        // knows which stretch of the recording it covers.
+        let mut chunk_segments = None;
+        if let Some(transcript) = extracted.as_mut().and_then(take_transcript) {
+            metadata
+                .get_or_insert_with(DocMetadata::default)
+                .audio
+                .get_or_insert_with(DocAudioMetadata::default)
+                .duration_secs
+                .get_or_insert(transcript.duration_secs);
+            if chunk_plan.is_none() {
+                if let Some(timed) = plan_transcript_chunks(&transcript.segments) {
+                    chunk_plan = Some(timed.plan);
+                    chunk_segments = Some(timed.segments);
+                }
+            }
+        }
+
+        // Images record their dimensions, EXIF and a media manifest, and are embedded into
+        // the CLIP index when a provider is attached.
+        let mut clip_embedding = None;
+        if let Some(bytes) = payload_for_processing {
+            if let Some(probe) = probe_image(bytes) {
+                let filename = source_path_value
+                    .as_deref()
+                    .or(options.uri.as_deref())
+                    .and_then(|path| Path::new(path).file_name())
+                    .and_then(|name| name.to_str())
+                    .map(str::to_string);
+                apply_image_probe(
+                    metadata.get_or_insert_with(DocMetadata::default),
+                    probe,
+                    bytes.len() as u64,
+                    filename,
+                );
+                if let Some(provider) = self.clip_provider.as_ref() {
+                    clip_embedding = Some(provider.embed_image_bytes(bytes)?);
+                }
+            }
+        }
+
+        // PDFs opted into visual indexing get a child frame per rendered page.
+        let pdf_pages = match payload_for_processing {
+            Some(bytes) if options.clip_pdf_pages && detect_pdf_magic(Some(bytes)) => {
+                let provider = self
+                    .clip_provider
+                    .as_deref()
+                    .ok_or(MemvidError::ClipNotEnabled)?;
+                render_pdf_page_images(bytes, provider)?
+            }
+            _ => Vec::new(),
+        };
+
         if let Some(doc) = &extracted {
             if need_search_text {
                 if let Some(text) = &doc.text {
@@ -3483,7 +4182,7 @@ impl Memvid {
 
             for (idx, chunk_text) in plan.chunks.iter().enumerate() {
                 let (chunk_payload, chunk_encoding, chunk_length) =
-                    prepare_canonical_payload(chunk_text.as_bytes())?;
+                    prepare_canonical_payload(chunk_text.as_bytes(), compression_level)?;
                 let chunk_search_text = normalize_text(chunk_text, DEFAULT_SEARCH_TEXT_LIMIT)
                     .map(|n| n.text)
                     .filter(|text| !text.trim().is_empty());
@@ -3500,6 +4199,16 @@ impl Memvid {
                     .as_ref()
                     .and_then(|embeddings| embeddings.get(idx).cloned());
 
+                let mut chunk_doc_metadata = chunk_metadata.clone();
+                if let Some(segments) = chunk_segments.as_ref().and_then(|all| all.get(idx)) {
+                    chunk_doc_metadata
+                        .get_or_insert_with(DocMetadata::default)
+                        .audio
+                        .get_or_insert_with(DocAudioMetadata::default)
+                        .segments
+                        .clone_from(segments);
+                }
+
                 chunk_entries.push(WalEntryData {
                     timestamp,
                     kind: kind_value.clone(),
@@ -3510,7 +4219,7 @@ impl Memvid {
                     title: chunk_title,
                     canonical_encoding: chunk_encoding,
                     canonical_length: chunk_length,
-                    metadata: chunk_metadata.clone(),
+                    metadata: chunk_doc_metadata,
                     search_text: chunk_search_text,
                     tags: chunk_tags.clone(),
                     labels: chunk_labels.clone(),
@@ -3611,6 +4320,8 @@ impl Memvid {
             enrichment_state,
         };
 
+        // The id the frame will receive once the WAL is applied; derived records key on it.
+        let frame_id = self.next_frame_id();
         let parent_bytes = encode_to_vec(WalEntry::Frame(entry), wal_config())?;
         let parent_seq = self.append_wal_entry(&parent_bytes)?;
         self.pending_frame_inserts = self.pending_frame_inserts.saturating_add(1);
@@ -3619,9 +4330,6 @@ impl Memvid {
         // This is Phase 1 of progressive ingestion - frame is searchable but not fully enriched
         #[cfg(feature = "lex")]
         if options.instant_index && self.tantivy.is_some() {
-            // Create a minimal frame for indexing
-            let frame_id = parent_seq as FrameId;
-
             // Use triplet_text which was cloned before entry was created
             if let Some(ref text) = triplet_text {
                 if !text.trim().is_empty() {
@@ -3682,7 +4390,6 @@ impl Memvid {
         // Note: enrichment_state is already set in the WAL entry, so it will be correct after replay
         #[cfg(feature = "lex")]
         if needs_enrichment {
-            let frame_id = parent_seq as FrameId;
             self.toc.enrichment_queue.push(frame_id);
             tracing::debug!(
                 frame_id = frame_id,
@@ -3699,15 +4406,78 @@ impl Memvid {
             self.pending_frame_inserts = self.pending_frame_inserts.saturating_add(1);
         }
 
+        for page in pdf_pages {
+            let page_frame_id = self.next_frame_id();
+            let mut page_metadata = DocMetadata::default();
+            if let Some(probe) = probe_image(&page.png) {
+                apply_image_probe(&mut page_metadata, probe, page.png.len() as u64, None);
+            }
+            let (page_payload, page_encoding, page_length) =
+                prepare_canonical_payload(&page.png, compression_level)?;
+            let page_entry = WalEntryData {
+                timestamp,
+                kind: Some("image".to_string()),
+                track: None,
+                payload: page_payload,
+                embedding: None,
+                uri: uri_value
+                    .as_ref()
+                    .map(|uri| format!("{uri}#page-{}-image", page.page)),
+                title: title_value
+                    .as_ref()
+                    .map(|title| format!("{title} (page {} image)", page.page)),
+                canonical_encoding: page_encoding,
+                canonical_length: page_length,
+                metadata: Some(page_metadata),
+                search_text: None,
+                tags: Vec::new(),
+                labels: Vec::new(),
+                extra_metadata: BTreeMap::new(),
+                content_dates: Vec::new(),
+                chunk_manifest: None,
+                role: FrameRole::ExtractedImage,
+                parent_sequence: Some(parent_seq),
+                chunk_index: None,
+                chunk_count: None,
+                op: FrameWalOp::Insert,
+                target_frame_id: None,
+                supersedes_frame_id: None,
+                reuse_payload_from: None,
+                source_sha256: None,
+                source_path: None,
+                enrichment_state: crate::types::EnrichmentState::Enriched,
+            };
+            let page_bytes = encode_to_vec(WalEntry::Frame(page_entry), wal_config())?;
+            self.append_wal_entry(&page_bytes)?;
+            self.pending_frame_inserts = self.pending_frame_inserts.saturating_add(1);
+            if !self.clip_enabled {
+                self.enable_clip()?;
+            }
+            self.add_clip_embedding_with_page(page_frame_id, Some(page.page), page.embedding)?;
+        }
+
+        if let Some(embedding) = clip_embedding {
+            if !self.clip_enabled {
+                self.enable_clip()?;
+            }
+            self.add_clip_embedding(frame_id, embedding)?;
+        }
+
         self.dirty = true;
-        if self.wal.should_checkpoint() {
+        let defer_checkpoint = self
+            .put_batch
+            .as_ref()
+            .is_some_and(|batch| batch.disable_auto_checkpoint);
+        if !defer_checkpoint && self.wal.should_checkpoint() {
             self.commit()?;
         }
 
-        // Record the put action if a replay session is active
+        // Record the put action if a replay session is active (batches record one PutMany)
         #[cfg(feature = "replay")]
-        if let Some(input_bytes) = payload {
-            self.record_put_action(parent_seq, input_bytes);
+        if self.put_batch.is_none() {
+            if let Some(input_bytes) = payload {
+                self.record_put_action(frame_id, input_bytes);
+            }
         }
 
         // Extract triplets if enabled (default: true)
@@ -3716,7 +4486,6 @@ impl Memvid {
             if let Some(ref text) = triplet_text {
                 if !text.trim().is_empty() {
                     let extractor = TripletExtractor::default();
-                    let frame_id = parent_seq as FrameId;
                     let (cards, _stats) = extractor.extract(
                         frame_id,
                         text,
@@ -3737,10 +4506,45 @@ impl Memvid {
             }
         }
 
-        Ok(parent_seq)
+        Ok((parent_seq, frame_id))
     }
 }
 
+/// Split a batch request into the payload, embedding and options `put_internal` expects.
+fn put_request_into_parts(
+    request: PutRequest,
+    mut options: PutOptions,
+) -> (Vec<u8>, Option<Vec<f32>>, PutOptions) {
+    let PutRequest {
+        title,
+        label,
+        text,
+        uri,
+        metadata,
+        tags,
+        labels,
+        embedding,
+    } = request;
+
+    options.title = Some(title);
+    options.uri = uri;
+    options.search_text = Some(text.clone());
+    options.tags = tags;
+    if !label.trim().is_empty() {
+        options.labels.push(label);
+    }
+    merge_unique(&mut options.labels, labels);
+    options.extra_metadata = metadata
+        .into_iter()
+        .map(|(key, value)| match value {
+            serde_json::Value::String(text) => (key, text),
+            other => (key, other.to_string()),
+        })
+        .collect();
+
+    (text.into_bytes(), embedding, options)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub(crate) enum FrameWalOp {
@@ -3825,11 +4629,13 @@ pub(crate) struct WalEntryData {
     pub(crate) enrichment_state: crate::types::EnrichmentState,
 }
 
+/// Encode a payload for storage, zstd-compressing UTF-8 text at `level` (0 stores it plain).
 pub(crate) fn prepare_canonical_payload(
     payload: &[u8],
+    level: i32,
 ) -> Result<(Vec<u8>, CanonicalEncoding, Option<u64>)> {
-    if std::str::from_utf8(payload).is_ok() {
-        let compressed = zstd::encode_all(std::io::Cursor::new(payload), 3)?;
+    if level > 0 && std::str::from_utf8(payload).is_ok() {
+        let compressed = zstd::encode_all(std::io::Cursor::new(payload), level)?;
         Ok((
             compressed,
             CanonicalEncoding::Zstd,
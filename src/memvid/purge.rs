@@ -0,0 +1,209 @@
+//! Hard deletion of frames and everything derived from them.
+
+use std::collections::BTreeSet;
+use std::io::{Seek, SeekFrom};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use ed25519_dalek::SigningKey;
+
+use crate::Result;
+use crate::memvid::lifecycle::Memvid;
+use crate::signature::sign_purge_receipt;
+use crate::types::{
+    CanonicalEncoding, EnrichmentState, FrameId, FrameStatus, PurgeArtifacts, PurgeReceipt,
+    PurgeSelector, PurgedFrame,
+};
+
+impl Memvid {
+    /// Physically remove the selected frames and every artifact derived from them.
+    ///
+    /// Unlike [`Memvid::delete_frame`], which only hides a frame until the next vacuum,
+    /// a purge drops the frames' memory cards, Logic-Mesh mentions and edges, sketch
+    /// entries, CLIP embeddings and replay previews, strips their TOC entries down to the
+    /// id and version links, and finishes with a verified [`Memvid::vacuum`] that also
+    /// zeroes the WAL. Children of a selected frame are purged with it.
+    ///
+    /// The returned receipt lists what was removed and fingerprints the resulting file;
+    /// pass `signing_key` to have it signed.
+    pub fn purge_frames(
+        &mut self,
+        selector: &PurgeSelector,
+        signing_key: Option<&SigningKey>,
+    ) -> Result<PurgeReceipt> {
+        self.ensure_writable()?;
+        self.commit()?;
+        let targets = self.purge_targets(selector)?;
+
+        let mut frames = Vec::with_capacity(targets.len());
+        for &frame_id in &targets {
+            let frame = self.frame_by_id(frame_id)?;
+            frames.push(PurgedFrame {
+                frame_id,
+                checksum: hex::encode(frame.checksum),
+                payload_length: frame.payload_length,
+            });
+            match frame.status {
+                FrameStatus::Active => {
+                    self.delete_frame(frame_id)?;
+                }
+                FrameStatus::Superseded => {
+                    self.append_tombstone(&frame)?;
+                }
+                FrameStatus::Deleted => {}
+            }
+        }
+        self.commit()?;
+
+        let artifacts = self.scrub_derived_artifacts(&targets)?;
+        self.scrub_frame_entries(&targets);
+        self.dirty = true;
+        let vacuum = self.vacuum()?;
+
+        let mut hasher = blake3::Hasher::new();
+        let mut handle = self.file.try_clone()?;
+        handle.seek(SeekFrom::Start(0))?;
+        std::io::copy(&mut handle, &mut hasher)?;
+
+        let purged_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| {
+                i64::try_from(elapsed.as_secs()).unwrap_or(i64::MAX)
+            });
+        let mut receipt = PurgeReceipt {
+            purged_at,
+            generation: self.generation,
+            frames,
+            artifacts,
+            vacuum,
+            file_checksum: hasher.finalize().to_hex().to_string(),
+            signer: None,
+            signature: None,
+        };
+        if let Some(key) = signing_key {
+            sign_purge_receipt(key, &mut receipt)?;
+        }
+        tracing::info!(
+            frames = receipt.frames.len(),
+            memory_cards = artifacts.memory_cards,
+            mesh_nodes = artifacts.mesh_nodes,
+            "purge completed"
+        );
+        Ok(receipt)
+    }
+
+    fn purge_targets(&self, selector: &PurgeSelector) -> Result<BTreeSet<FrameId>> {
+        let mut selected: BTreeSet<FrameId> = match selector {
+            PurgeSelector::Frames(ids) => {
+                for &frame_id in ids {
+                    self.frame_by_id(frame_id)?;
+                }
+                ids.iter().copied().collect()
+            }
+            PurgeSelector::Uri(uri) => self
+                .toc
+                .frames
+                .iter()
+                .filter(|frame| frame.uri.as_deref() == Some(uri.as_str()))
+                .map(|frame| frame.id)
+                .collect(),
+            PurgeSelector::Filter(filter) => self
+                .toc
+                .frames
+                .iter()
+                .filter(|frame| filter.matches(frame))
+                .map(|frame| frame.id)
+                .collect(),
+        };
+        let children: Vec<FrameId> = self
+            .toc
+            .frames
+            .iter()
+            .filter(|frame| {
+                frame
+                    .parent_id
+                    .is_some_and(|parent| selected.contains(&parent))
+            })
+            .map(|frame| frame.id)
+            .collect();
+        selected.extend(children);
+        Ok(selected)
+    }
+
+    fn scrub_derived_artifacts(&mut self, targets: &BTreeSet<FrameId>) -> Result<PurgeArtifacts> {
+        let mut artifacts = PurgeArtifacts {
+            memory_cards: self.memories_track.remove_frames(targets),
+            ..PurgeArtifacts::default()
+        };
+        (artifacts.mesh_nodes, artifacts.mesh_edges) = self.logic_mesh.remove_frames(targets);
+        artifacts.sketch_entries = targets
+            .iter()
+            .filter(|&&frame_id| self.sketch_track.scrub(frame_id))
+            .count();
+        if let Some(index) = self.clip_index.as_mut() {
+            let before = index.len();
+            for &frame_id in targets {
+                index.remove(frame_id);
+            }
+            artifacts.clip_embeddings = before - index.len();
+        }
+        for &frame_id in targets {
+            self.toc.enrichment_queue.remove(frame_id);
+        }
+
+        #[cfg(feature = "replay")]
+        {
+            if self.toc.replay_manifest.is_some() && self.completed_sessions.is_empty() {
+                self.load_replay_sessions()?;
+            }
+            for session in &mut self.completed_sessions {
+                artifacts.replay_actions += session.redact_frames(targets);
+            }
+            if artifacts.replay_actions > 0 {
+                self.save_replay_sessions()?;
+            }
+            let active = self
+                .active_session
+                .as_mut()
+                .map_or(0, |active| active.session.redact_frames(targets));
+            if active > 0 {
+                self.save_active_session()?;
+                artifacts.replay_actions += active;
+            }
+        }
+        Ok(artifacts)
+    }
+
+    /// Reduce purged TOC entries to their id, timestamp and structural links.
+    fn scrub_frame_entries(&mut self, targets: &BTreeSet<FrameId>) {
+        for &frame_id in targets {
+            let Some(frame) = usize::try_from(frame_id)
+                .ok()
+                .and_then(|index| self.toc.frames.get_mut(index))
+            else {
+                continue;
+            };
+            frame.status = FrameStatus::Deleted;
+            frame.anchor_ts = None;
+            frame.anchor_source = None;
+            frame.kind = None;
+            frame.track = None;
+            frame.payload_offset = 0;
+            frame.payload_length = 0;
+            frame.checksum = [0; 32];
+            frame.uri = None;
+            frame.title = None;
+            frame.canonical_encoding = CanonicalEncoding::default();
+            frame.canonical_length = None;
+            frame.metadata = None;
+            frame.search_text = None;
+            frame.tags.clear();
+            frame.labels.clear();
+            frame.extra_metadata.clear();
+            frame.content_dates.clear();
+            frame.chunk_manifest = None;
+            frame.source_sha256 = None;
+            frame.source_path = None;
+            frame.enrichment_state = EnrichmentState::default();
+        }
+    }
+}
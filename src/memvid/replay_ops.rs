@@ -134,6 +134,28 @@ impl Memvid {
         }
     }
 
+    /// Record a `PutMany` action covering every frame of a batch.
+    #[cfg(feature = "replay")]
+    pub fn record_put_many_action(&mut self, frame_ids: Vec<u64>) {
+        if let Some(session) = self.active_session.as_mut() {
+            let action = ReplayAction::new(
+                session.session.next_sequence(),
+                ActionType::PutMany {
+                    count: frame_ids.len(),
+                    frame_ids: frame_ids.clone(),
+                },
+            )
+            .with_affected_frames(frame_ids);
+
+            session.record_action(action);
+
+            // Check if auto-checkpoint is due
+            if session.should_checkpoint() {
+                let _ = self.create_checkpoint();
+            }
+        }
+    }
+
     /// Record a Find action in the current session.
     #[cfg(feature = "replay")]
     pub fn record_find_action(
@@ -196,6 +218,40 @@ impl Memvid {
         }
     }
 
+    /// Record a tool call made by an agent framework (e.g. an MCP client).
+    ///
+    /// # Arguments
+    /// * `name` - The tool name
+    /// * `args` - The raw call arguments; hashed and previewed
+    /// * `output` - The raw tool result
+    /// * `duration_ms` - How long the call took
+    /// * `affected_frames` - Frame IDs written or returned by the call
+    #[cfg(feature = "replay")]
+    pub fn record_tool_call_action(
+        &mut self,
+        name: &str,
+        args: &[u8],
+        output: &[u8],
+        duration_ms: u64,
+        affected_frames: Vec<u64>,
+    ) {
+        if let Some(session) = self.active_session.as_mut() {
+            let action = ReplayAction::new(
+                session.session.next_sequence(),
+                ActionType::ToolCall {
+                    name: name.to_string(),
+                    args_hash: blake3::hash(args).into(),
+                },
+            )
+            .with_input(args)
+            .with_output(output)
+            .with_duration_ms(duration_ms)
+            .with_affected_frames(affected_frames);
+
+            session.record_action(action);
+        }
+    }
+
     /// List all completed sessions (in memory).
     #[cfg(feature = "replay")]
     pub fn list_sessions(&self) -> Vec<SessionSummary> {
@@ -276,6 +332,9 @@ impl Memvid {
         // Instead, append replay at the current footer boundary, which is always after any
         // embedded index / metadata bytes for the current generation.
         let segment_offset = self.header.footer_offset.max(self.data_end);
+        // The segment overwrites the current TOC, so it starts a new generation.
+        self.generation = self.generation.wrapping_add(1);
+        self.lower_backup_floor(segment_offset);
         tracing::debug!(
             "Writing replay segment: offset={}, size={}, footer_offset={}, data_end={}",
             segment_offset,
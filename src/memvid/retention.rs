@@ -0,0 +1,198 @@
+//! Retention policy evaluation for `Memvid`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::memvid::lifecycle::Memvid;
+use crate::types::{
+    FrameId, FrameStatus, RetentionLimit, RetentionPolicy, RetentionReport, RetentionRuleOutcome,
+};
+use crate::{MemvidError, Result};
+
+/// Positions in `toc.frames` of the versions sharing a URI (or, without one, a
+/// supersede chain), newest first.
+type VersionGroup = Vec<usize>;
+
+impl Memvid {
+    /// Retention rules stored in this memory.
+    #[must_use]
+    pub fn retention_policy(&self) -> &RetentionPolicy {
+        &self.toc.retention
+    }
+
+    /// Replace the stored retention rules; persisted on the next `commit`.
+    pub fn set_retention_policy(&mut self, policy: RetentionPolicy) -> Result<()> {
+        self.ensure_writable()?;
+        for rule in &policy.rules {
+            if rule.limit == (RetentionLimit::KeepVersions { count: 0 }) {
+                return Err(MemvidError::InvalidRetention {
+                    reason: format!("rule '{}' must keep at least one version", rule.name),
+                });
+            }
+        }
+        self.toc.retention = policy;
+        self.dirty = true;
+        Ok(())
+    }
+
+    /// Tombstone every frame the stored rules expire, then commit.
+    ///
+    /// Rules are judged per version group: all versions of a URI (or of a supersede chain
+    /// when there is no URI) are ordered newest first and the newest one decides age, so a
+    /// live document never loses its current version to an old predecessor's timestamp.
+    /// Scope is matched per frame. When the newest version is in scope, the versions the
+    /// limit drops expire with all their chunks and images; otherwise only the children of
+    /// those versions that match the scope themselves expire. When the policy sets
+    /// `vacuum_after`, the pass ends with a [`Memvid::vacuum`].
+    pub fn apply_retention(&mut self) -> Result<RetentionReport> {
+        self.ensure_writable()?;
+        self.commit()?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| {
+                i64::try_from(elapsed.as_secs()).unwrap_or(i64::MAX)
+            });
+        let policy = self.toc.retention.clone();
+        let groups = self.retention_version_groups();
+        let mut children: BTreeMap<FrameId, Vec<usize>> = BTreeMap::new();
+        for (index, frame) in self.toc.frames.iter().enumerate() {
+            if let Some(parent) = frame.parent_id
+                && frame.status != FrameStatus::Deleted
+            {
+                children.entry(parent).or_default().push(index);
+            }
+        }
+
+        let mut expired: BTreeSet<FrameId> = BTreeSet::new();
+        let mut outcomes = Vec::with_capacity(policy.rules.len());
+        for rule in &policy.rules {
+            let in_scope = |index: usize| {
+                rule.scope
+                    .as_ref()
+                    .is_none_or(|scope| scope.matches(&self.toc.frames[index]))
+            };
+            let mut count = 0;
+            for group in &groups {
+                let head = &self.toc.frames[group[0]];
+                let doomed: &[usize] = match rule.limit {
+                    RetentionLimit::MaxAge { seconds } => {
+                        let age = now.saturating_sub(head.timestamp);
+                        if u64::try_from(age).is_ok_and(|age| age > seconds) {
+                            group
+                        } else {
+                            &[]
+                        }
+                    }
+                    RetentionLimit::KeepVersions { count } => {
+                        group.get(count as usize..).unwrap_or_default()
+                    }
+                };
+                let whole_versions = in_scope(group[0]);
+                for &version in doomed {
+                    let version_id = self.toc.frames[version].id;
+                    let targets: Vec<usize> = if whole_versions {
+                        vec![version]
+                    } else {
+                        children
+                            .get(&version_id)
+                            .into_iter()
+                            .flatten()
+                            .copied()
+                            .filter(|&child| in_scope(child))
+                            .collect()
+                    };
+                    for index in targets {
+                        if expired.insert(self.toc.frames[index].id) {
+                            count += 1;
+                        }
+                    }
+                }
+            }
+            outcomes.push(RetentionRuleOutcome {
+                name: rule.name.clone(),
+                expired: count,
+            });
+        }
+
+        let mut tombstoned: Vec<FrameId> = self
+            .toc
+            .frames
+            .iter()
+            .filter(|frame| frame.status != FrameStatus::Deleted)
+            .filter(|frame| {
+                expired.contains(&frame.id)
+                    || frame
+                        .parent_id
+                        .is_some_and(|parent| expired.contains(&parent))
+            })
+            .map(|frame| frame.id)
+            .collect();
+        tombstoned.sort_unstable();
+        for &frame_id in &tombstoned {
+            let frame = self.frame_by_id(frame_id)?;
+            if frame.status == FrameStatus::Active {
+                self.delete_frame(frame_id)?;
+            } else {
+                self.append_tombstone(&frame)?;
+            }
+        }
+        self.commit()?;
+
+        let vacuum = if policy.vacuum_after && !tombstoned.is_empty() {
+            Some(self.vacuum()?)
+        } else {
+            None
+        };
+        tracing::info!(
+            expired = tombstoned.len(),
+            rules = outcomes.len(),
+            "retention pass completed"
+        );
+        Ok(RetentionReport {
+            tombstoned,
+            rules: outcomes,
+            vacuum,
+        })
+    }
+
+    fn retention_version_groups(&self) -> Vec<VersionGroup> {
+        let frames = &self.toc.frames;
+        let chain_root = |mut id: FrameId| {
+            // Bounded walk so a corrupt cycle cannot spin forever.
+            for _ in 0..frames.len() {
+                let previous = usize::try_from(id)
+                    .ok()
+                    .and_then(|index| frames.get(index))
+                    .and_then(|frame| frame.supersedes);
+                match previous {
+                    Some(previous) => id = previous,
+                    None => break,
+                }
+            }
+            id
+        };
+
+        let mut by_uri: BTreeMap<&str, VersionGroup> = BTreeMap::new();
+        let mut by_chain: BTreeMap<FrameId, VersionGroup> = BTreeMap::new();
+        for (index, frame) in frames.iter().enumerate() {
+            if frame.status == FrameStatus::Deleted || frame.parent_id.is_some() {
+                continue;
+            }
+            match frame.uri.as_deref() {
+                Some(uri) => by_uri.entry(uri).or_default().push(index),
+                None => by_chain
+                    .entry(chain_root(frame.id))
+                    .or_default()
+                    .push(index),
+            }
+        }
+        by_uri
+            .into_values()
+            .chain(by_chain.into_values())
+            .map(|mut group| {
+                group.sort_unstable_by(|a, b| b.cmp(a));
+                group
+            })
+            .collect()
+    }
+}
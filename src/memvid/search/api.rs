@@ -7,12 +7,20 @@ use std::io::{Read, Seek, SeekFrom, Write};
 #[cfg(feature = "lex")]
 use tempfile::TempDir;
 
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use super::cursor::{CursorLeg, Pager};
+use crate::clip::ClipEmbeddingProvider;
 use crate::memvid::lifecycle::Memvid;
+use crate::types::reranker::RerankerKind;
 use crate::types::{
-    AdaptiveConfig, AdaptiveResult, AdaptiveStats, EmbeddingQualityStats, Frame, FrameId,
-    FrameStatus, SearchHit, TimelineEntry, TimelineQuery, VecSegmentDescriptor,
-    compute_embedding_quality, find_adaptive_cutoff,
+    AdaptiveConfig, AdaptiveResult, AdaptiveStats, DocMetadata, EmbeddingQualityStats, FilterExpr,
+    FilterField, Frame, FrameId, FrameStatus, SearchHit, SearchRequest, SearchResponse,
+    TimelineEntry, TimelineQuery, VecSegmentDescriptor, compute_embedding_quality,
+    find_adaptive_cutoff,
 };
+use crate::vec::VecIndex;
 use crate::{LexSearchHit, MemvidError, Result, VecSearchHit};
 
 impl Memvid {
@@ -120,6 +128,19 @@ impl Memvid {
     }
 
     pub fn search_vec(&mut self, query: &[f32], limit: usize) -> Result<Vec<VecSearchHit>> {
+        self.search_vec_filtered(query, limit, None)
+    }
+
+    /// Vector search restricted to frames matching `filter`.
+    ///
+    /// The filter is applied while probing the index, so up to `limit` matching hits
+    /// are returned even when most nearest neighbours are filtered out.
+    pub fn search_vec_filtered(
+        &mut self,
+        query: &[f32],
+        limit: usize,
+        filter: Option<&FilterExpr>,
+    ) -> Result<Vec<VecSearchHit>> {
         if !self.vec_enabled {
             return Err(MemvidError::VecNotEnabled);
         }
@@ -151,8 +172,12 @@ impl Memvid {
         if !ensured_vec_index {
             self.ensure_vec_index()?;
         }
+        let allowed = filter.map(|filter| self.frame_ids_matching_filter(filter));
         let index = self.vec_index.as_ref().ok_or(MemvidError::VecNotEnabled)?;
-        Ok(index.search(query, limit))
+        Ok(match allowed {
+            Some(allowed) => filtered_vec_hits(index, query, limit, &allowed),
+            None => index.search(query, limit),
+        })
     }
 
     /// Enable CLIP visual embeddings index.
@@ -184,6 +209,14 @@ impl Memvid {
         Ok(())
     }
 
+    /// Attach the provider that embeds JPEG, PNG and WebP payloads passed to `put`.
+    ///
+    /// While attached, every image ingested gets a CLIP embedding, enabling the CLIP index
+    /// on first use.
+    pub fn set_clip_provider(&mut self, provider: Arc<dyn ClipEmbeddingProvider>) {
+        self.clip_provider = Some(provider);
+    }
+
     /// Add a CLIP embedding for a frame (legacy, no page info).
     ///
     /// This adds the visual embedding to the CLIP index for later semantic search.
@@ -266,6 +299,26 @@ impl Memvid {
         top_k: usize,
         snippet_chars: usize,
         scope: Option<&str>,
+    ) -> Result<crate::types::SearchResponse> {
+        self.vec_search_with_embedding_filtered(
+            query,
+            query_embedding,
+            top_k,
+            snippet_chars,
+            scope,
+            None,
+        )
+    }
+
+    /// Pure vector search restricted to frames matching `filter`.
+    pub fn vec_search_with_embedding_filtered(
+        &mut self,
+        query: &str,
+        query_embedding: &[f32],
+        top_k: usize,
+        snippet_chars: usize,
+        scope: Option<&str>,
+        filter: Option<&FilterExpr>,
     ) -> Result<crate::types::SearchResponse> {
         use super::helpers::{build_context, timestamp_to_rfc3339};
         use crate::types::{
@@ -311,10 +364,14 @@ impl Memvid {
             self.ensure_vec_index()?;
         }
 
+        let allowed = filter.map(|filter| self.frame_ids_matching_filter(filter));
         let vec_index = self.vec_index.as_ref().ok_or(MemvidError::VecNotEnabled)?;
 
         // Do pure vector search over entire index
-        let vec_hits = vec_index.search(query_embedding, top_k * 2);
+        let vec_hits = match allowed {
+            Some(ref allowed) => filtered_vec_hits(vec_index, query_embedding, top_k * 2, allowed),
+            None => vec_index.search(query_embedding, top_k * 2),
+        };
 
         if vec_hits.is_empty() {
             let elapsed_ms = start_time.elapsed().as_millis();
@@ -391,6 +448,7 @@ impl Memvid {
                 content_dates: frame.content_dates.clone(),
                 entities: Vec::new(),
                 extra_metadata: frame.extra_metadata.clone(),
+                audio_range: frame.metadata.as_ref().and_then(DocMetadata::audio_range),
                 #[cfg(feature = "temporal_track")]
                 temporal: None,
             };
@@ -407,6 +465,8 @@ impl Memvid {
                 chunk_text: Some(snippet),
                 score: Some(similarity_score),
                 metadata: Some(metadata),
+                lexical_score: None,
+                vector_score: None,
             });
 
             if hits.len() >= top_k {
@@ -437,6 +497,35 @@ impl Memvid {
         })
     }
 
+    /// Vector search driven by a [`SearchRequest`], with cursor pagination.
+    ///
+    /// Honours `top_k`, `snippet_chars`, `uri`, `scope`, `filter`, `as_of_frame`,
+    /// `as_of_ts` and `cursor`; `query` is only echoed back. `total_hits` counts the
+    /// neighbours retrieved up to and including the returned page.
+    pub fn vec_search_request(
+        &mut self,
+        request: &SearchRequest,
+        query_embedding: &[f32],
+    ) -> Result<SearchResponse> {
+        let pager = Pager::new(self, request, CursorLeg::Vector)?;
+        let filter = vector_filter_for(request);
+        let mut response = self.vec_search_with_embedding_filtered(
+            &request.query,
+            query_embedding,
+            pager.fetch_window(),
+            request.snippet_chars,
+            request.scope.as_deref(),
+            filter.as_ref(),
+        )?;
+        let (hits, next_cursor) = pager.page(std::mem::take(&mut response.hits))?;
+        response.hits = hits;
+        response.context = super::helpers::build_context(&response.hits);
+        response.next_cursor = next_cursor;
+        response.params.top_k = request.top_k;
+        response.params.cursor = request.cursor.clone();
+        Ok(response)
+    }
+
     /// Perform adaptive vector search that dynamically determines how many results to return.
     ///
     /// Unlike fixed `top_k` retrieval, adaptive search examines relevancy score distribution
@@ -464,18 +553,49 @@ impl Memvid {
         config: AdaptiveConfig,
         snippet_chars: usize,
         scope: Option<&str>,
+    ) -> Result<AdaptiveResult<SearchHit>> {
+        self.search_adaptive_with_reranker(
+            query,
+            query_embedding,
+            config,
+            snippet_chars,
+            scope,
+            None,
+            None,
+        )
+    }
+
+    /// Adaptive vector search with an optional filter and second-stage reranker.
+    ///
+    /// `filter` restricts candidates before retrieval. When `reranker` is set, the
+    /// over-retrieved candidates are reranked before the adaptive cutoff is computed,
+    /// so the cutoff operates on reranker scores.
+    pub fn search_adaptive_with_reranker(
+        &mut self,
+        query: &str,
+        query_embedding: &[f32],
+        config: AdaptiveConfig,
+        snippet_chars: usize,
+        scope: Option<&str>,
+        filter: Option<&FilterExpr>,
+        reranker: Option<RerankerKind>,
     ) -> Result<AdaptiveResult<SearchHit>> {
         use std::time::Instant;
 
         if !config.enabled {
             // Fall back to standard search with max_results as top_k
-            let response = self.vec_search_with_embedding(
+            let mut response = self.vec_search_with_embedding_filtered(
                 query,
                 query_embedding,
                 config.max_results,
                 snippet_chars,
                 scope,
+                filter,
             )?;
+            if let Some(kind) = reranker {
+                let hits = std::mem::take(&mut response.hits);
+                response.hits = self.rerank_hits(query, hits, kind, config.max_results)?;
+            }
             return Ok(AdaptiveResult {
                 results: response.hits,
                 stats: AdaptiveStats {
@@ -493,13 +613,18 @@ impl Memvid {
         let start_time = Instant::now();
 
         // Over-retrieve: get max_results to have enough candidates
-        let response = self.vec_search_with_embedding(
+        let mut response = self.vec_search_with_embedding_filtered(
             query,
             query_embedding,
             config.max_results,
             snippet_chars,
             scope,
+            filter,
         )?;
+        if let Some(kind) = reranker {
+            let hits = std::mem::take(&mut response.hits);
+            response.hits = self.rerank_hits(query, hits, kind, config.max_results)?;
+        }
 
         if response.hits.is_empty() {
             return Ok(AdaptiveResult::empty());
@@ -636,6 +761,16 @@ impl Memvid {
         Ok(matching_ids)
     }
 
+    /// Active frame IDs satisfying a structured search filter.
+    pub(crate) fn frame_ids_matching_filter(&self, filter: &FilterExpr) -> HashSet<FrameId> {
+        self.toc
+            .frames
+            .iter()
+            .filter(|frame| filter.admits(frame))
+            .map(|frame| frame.id)
+            .collect()
+    }
+
     pub fn timeline(&mut self, query: TimelineQuery) -> Result<Vec<TimelineEntry>> {
         let TimelineQuery {
             limit,
@@ -664,6 +799,61 @@ impl Memvid {
     }
 }
 
+/// Fold the request's `uri` and replay cutoffs into its structured filter so vector
+/// retrieval applies the same restrictions as the lexical engines.
+pub(crate) fn vector_filter_for(request: &SearchRequest) -> Option<FilterExpr> {
+    let mut clauses: Vec<FilterExpr> = request.filter.iter().cloned().collect();
+    if let Some(uri) = request.uri.as_deref() {
+        clauses.push(FilterExpr::eq(FilterField::Uri, uri));
+    }
+    if let Some(cutoff) = request.as_of_frame {
+        clauses.push(FilterExpr::range(
+            FilterField::FrameId,
+            None,
+            Some(&cutoff.to_string()),
+        ));
+    }
+    if let Some(cutoff) = request.as_of_ts {
+        clauses.push(FilterExpr::range(
+            FilterField::Timestamp,
+            None,
+            Some(&cutoff.to_string()),
+        ));
+    }
+    match clauses.len() {
+        0 => None,
+        1 => clauses.pop(),
+        _ => Some(FilterExpr::and(clauses)),
+    }
+}
+
+/// Nearest neighbours restricted to `allowed`, widening the probe until `limit` hits
+/// survive the filter or the index is exhausted.
+fn filtered_vec_hits(
+    index: &VecIndex,
+    query: &[f32],
+    limit: usize,
+    allowed: &HashSet<FrameId>,
+) -> Vec<VecSearchHit> {
+    if limit == 0 || allowed.is_empty() {
+        return Vec::new();
+    }
+    let mut probe = limit.saturating_mul(4).max(64);
+    loop {
+        let candidates = index.search(query, probe);
+        let exhausted = candidates.len() < probe;
+        let hits: Vec<VecSearchHit> = candidates
+            .into_iter()
+            .filter(|hit| allowed.contains(&hit.frame_id))
+            .take(limit)
+            .collect();
+        if hits.len() >= limit || exhausted {
+            return hits;
+        }
+        probe = probe.saturating_mul(4);
+    }
+}
+
 #[cfg(feature = "lex")]
 impl Memvid {
     #[allow(dead_code)]
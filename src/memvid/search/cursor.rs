@@ -0,0 +1,338 @@
+//! Opaque pagination cursors for search responses.
+//!
+//! A cursor records where the previous page ended: the TOC generation and frame count
+//! of the memory it was issued against, a fingerprint of the request, the candidate
+//! window being paged, the offset into that window's ranked results, and the frame ID
+//! and score of the last result returned. Reusing a cursor after the memory changed,
+//! with a different request, or when the recomputed ranking no longer agrees with the
+//! recorded anchor fails with [`MemvidError::InvalidCursor`] instead of silently
+//! skipping or repeating results.
+//!
+//! Engines whose ranking depends on the candidate set (Tantivy's relative recency boost)
+//! page through fixed-size candidate windows: the window is pinned by the cursor, and
+//! once it is exhausted the next cursor starts the following window.
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+
+use crate::memvid::lifecycle::Memvid;
+use crate::types::{FrameId, SearchHit, SearchRequest};
+use crate::{MemvidError, Result};
+
+const CURSOR_PREFIX: &str = "mvc1.";
+const CURSOR_BYTES: usize = 8 * 7 + 1 + 4;
+const ANCHOR_PRESENT: u8 = 0b01;
+const ANCHOR_SCORED: u8 = 0b10;
+const MALFORMED: MemvidError = MemvidError::InvalidCursor {
+    reason: "malformed cursor",
+};
+
+/// Result list a cursor pages through; cursors are not interchangeable between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CursorLeg {
+    Lexical,
+    Vector,
+    Hybrid,
+}
+
+impl CursorLeg {
+    fn tag(self) -> &'static [u8] {
+        match self {
+            Self::Lexical => b"lexical",
+            Self::Vector => b"vector",
+            Self::Hybrid => b"hybrid",
+        }
+    }
+}
+
+/// Last result of a page, re-checked when the next page is requested.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PageAnchor {
+    pub(crate) frame_id: FrameId,
+    pub(crate) score: Option<f32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CursorToken {
+    generation: u64,
+    frame_count: u64,
+    fingerprint: u64,
+    window_start: u64,
+    window_len: u64,
+    offset: u64,
+    anchor: Option<PageAnchor>,
+}
+
+impl CursorToken {
+    fn encode(&self) -> String {
+        let mut bytes = Vec::with_capacity(CURSOR_BYTES);
+        for word in [
+            self.generation,
+            self.frame_count,
+            self.fingerprint,
+            self.window_start,
+            self.window_len,
+            self.offset,
+            self.anchor.map_or(0, |anchor| anchor.frame_id),
+        ] {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        let (flags, score) = match self.anchor {
+            None => (0, 0.0),
+            Some(PageAnchor { score: None, .. }) => (ANCHOR_PRESENT, 0.0),
+            Some(PageAnchor {
+                score: Some(score), ..
+            }) => (ANCHOR_PRESENT | ANCHOR_SCORED, score),
+        };
+        bytes.push(flags);
+        bytes.extend_from_slice(&score.to_bits().to_le_bytes());
+        format!("{CURSOR_PREFIX}{}", URL_SAFE_NO_PAD.encode(bytes))
+    }
+
+    fn decode(token: &str) -> Result<Self> {
+        let bytes = token
+            .strip_prefix(CURSOR_PREFIX)
+            .and_then(|body| URL_SAFE_NO_PAD.decode(body).ok())
+            .filter(|bytes| bytes.len() == CURSOR_BYTES)
+            .ok_or(MALFORMED)?;
+        let word = |index: usize| {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(&bytes[index * 8..index * 8 + 8]);
+            u64::from_le_bytes(buf)
+        };
+        let mut score = [0u8; 4];
+        score.copy_from_slice(&bytes[57..61]);
+        let score = f32::from_bits(u32::from_le_bytes(score));
+        let anchor = match bytes[56] {
+            0 => None,
+            ANCHOR_PRESENT => Some(PageAnchor {
+                frame_id: word(6),
+                score: None,
+            }),
+            flags if flags == ANCHOR_PRESENT | ANCHOR_SCORED => Some(PageAnchor {
+                frame_id: word(6),
+                score: Some(score),
+            }),
+            _ => return Err(MALFORMED),
+        };
+        Ok(Self {
+            generation: word(0),
+            frame_count: word(1),
+            fingerprint: word(2),
+            window_start: word(3),
+            window_len: word(4),
+            offset: word(5),
+            anchor,
+        })
+    }
+}
+
+/// Pagination state for one search call.
+///
+/// Built from the request before ranking; engines skip [`Pager::offset`] results,
+/// call [`Pager::verify_anchor`] on the last skipped one, and issue the next cursor
+/// with [`Pager::next_cursor`].
+#[derive(Debug, Clone)]
+pub(crate) struct Pager {
+    generation: u64,
+    frame_count: u64,
+    fingerprint: u64,
+    top_k: usize,
+    window: (usize, usize),
+    resume: Option<CursorToken>,
+}
+
+impl Pager {
+    pub(crate) fn new(memvid: &Memvid, request: &SearchRequest, leg: CursorLeg) -> Result<Self> {
+        let generation = memvid.generation;
+        let frame_count = memvid.toc.frames.len() as u64;
+        let fingerprint = request_fingerprint(request, leg)?;
+        let resume = match request.cursor.as_deref().map(str::trim) {
+            None | Some("") => None,
+            Some(token) => {
+                let token = CursorToken::decode(token)?;
+                if token.fingerprint != fingerprint {
+                    return Err(MemvidError::InvalidCursor {
+                        reason: "cursor was issued for a different search request",
+                    });
+                }
+                if token.generation != generation || token.frame_count != frame_count {
+                    return Err(MemvidError::InvalidCursor {
+                        reason: "memory changed since the cursor was issued; restart from the first page",
+                    });
+                }
+                Some(token)
+            }
+        };
+        let window = resume.map_or((0, 0), |token| {
+            (to_usize(token.window_start), to_usize(token.window_len))
+        });
+        Ok(Self {
+            generation,
+            frame_count,
+            fingerprint,
+            top_k: request.top_k.max(1),
+            window,
+            resume,
+        })
+    }
+
+    /// Number of ranked results already returned from the current window.
+    pub(crate) fn offset(&self) -> usize {
+        self.resume.map_or(0, |token| to_usize(token.offset))
+    }
+
+    /// Candidate window `(start, len)` pinned by the cursor, if resuming a windowed engine.
+    pub(crate) fn pinned_window(&self) -> Option<(usize, usize)> {
+        self.resume
+            .filter(|token| token.window_len > 0)
+            .map(|_| self.window)
+    }
+
+    /// Record the candidate window this page was ranked from.
+    pub(crate) fn set_window(&mut self, start: usize, len: usize) {
+        self.window = (start, len);
+    }
+
+    /// Reject cursors that point past the end of the current result list.
+    pub(crate) fn check_total(&self, total: usize) -> Result<()> {
+        if self.offset() > total {
+            return Err(MemvidError::InvalidCursor {
+                reason: "cursor beyond total hits",
+            });
+        }
+        Ok(())
+    }
+
+    /// Check the result at position `offset - 1` against the cursor's anchor.
+    pub(crate) fn verify_anchor(&self, frame_id: FrameId, score: Option<f32>) -> Result<()> {
+        let Some(anchor) = self.resume.and_then(|token| token.anchor) else {
+            return Ok(());
+        };
+        let same_score = match (anchor.score, score) {
+            (Some(expected), Some(actual)) => expected.to_bits() == actual.to_bits(),
+            (None, None) => true,
+            _ => false,
+        };
+        if anchor.frame_id != frame_id || !same_score {
+            return Err(MemvidError::InvalidCursor {
+                reason: "result ordering changed since the cursor was issued; restart from the first page",
+            });
+        }
+        Ok(())
+    }
+
+    /// Cursor for the page after `produced` results, or `None` once all `total` are consumed.
+    pub(crate) fn next_cursor(
+        &self,
+        produced: usize,
+        total: usize,
+        last: Option<PageAnchor>,
+    ) -> Option<String> {
+        if produced >= total {
+            return None;
+        }
+        Some(self.token(self.window.0, produced, Some(last?)).encode())
+    }
+
+    /// Cursor for the first page of the candidate window starting at `start`.
+    pub(crate) fn next_window_cursor(&self, start: usize) -> String {
+        self.token(start, 0, None).encode()
+    }
+
+    fn token(&self, window_start: usize, offset: usize, anchor: Option<PageAnchor>) -> CursorToken {
+        CursorToken {
+            generation: self.generation,
+            frame_count: self.frame_count,
+            fingerprint: self.fingerprint,
+            window_start: window_start as u64,
+            window_len: self.window.1 as u64,
+            offset: offset as u64,
+            anchor,
+        }
+    }
+
+    /// Number of ranked results a caller must fetch to serve this page and detect a next one.
+    pub(crate) fn fetch_window(&self) -> usize {
+        self.offset().saturating_add(self.top_k).saturating_add(1)
+    }
+
+    /// Slice one page out of a fully ranked result list.
+    ///
+    /// Ranks on the returned page are rewritten to start at 1.
+    pub(crate) fn page(&self, ranked: Vec<SearchHit>) -> Result<(Vec<SearchHit>, Option<String>)> {
+        let total = ranked.len();
+        self.check_total(total)?;
+        let offset = self.offset();
+        if let Some(anchor) = offset.checked_sub(1).and_then(|index| ranked.get(index)) {
+            self.verify_anchor(anchor.frame_id, anchor.score)?;
+        }
+        let mut page: Vec<SearchHit> = ranked.into_iter().skip(offset).take(self.top_k).collect();
+        for (index, hit) in page.iter_mut().enumerate() {
+            hit.rank = index + 1;
+        }
+        let last = page.last().map(|hit| PageAnchor {
+            frame_id: hit.frame_id,
+            score: hit.score,
+        });
+        let next_cursor = self.next_cursor(offset + page.len(), total, last);
+        Ok((page, next_cursor))
+    }
+}
+
+fn to_usize(value: u64) -> usize {
+    usize::try_from(value).unwrap_or(usize::MAX)
+}
+
+/// Stable hash of every request field that shapes the ranked list, except the cursor.
+fn request_fingerprint(request: &SearchRequest, leg: CursorLeg) -> Result<u64> {
+    let mut shape = request.clone();
+    shape.cursor = None;
+    let encoded = serde_json::to_vec(&shape).map_err(|_| MemvidError::InvalidCursor {
+        reason: "search request cannot be fingerprinted",
+    })?;
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(leg.tag());
+    hasher.update(&encoded);
+    let digest = hasher.finalize();
+    let mut head = [0u8; 8];
+    head.copy_from_slice(&digest.as_bytes()[..8]);
+    Ok(u64::from_le_bytes(head))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_roundtrip_and_rejects_garbage() {
+        let token = CursorToken {
+            generation: 3,
+            frame_count: 42,
+            fingerprint: 0xdead_beef,
+            window_start: 20,
+            window_len: 20,
+            offset: 10,
+            anchor: Some(PageAnchor {
+                frame_id: 7,
+                score: Some(1.25),
+            }),
+        };
+        let encoded = token.encode();
+        assert!(encoded.starts_with(CURSOR_PREFIX));
+        assert_eq!(CursorToken::decode(&encoded).expect("decode"), token);
+
+        let window_start = CursorToken {
+            anchor: None,
+            offset: 0,
+            ..token
+        };
+        assert_eq!(
+            CursorToken::decode(&window_start.encode()).expect("decode"),
+            window_start
+        );
+
+        assert!(CursorToken::decode("10").is_err());
+        assert!(CursorToken::decode("mvc1.AAAA").is_err());
+    }
+}
@@ -1,14 +1,15 @@
 #![cfg(feature = "lex")]
 
+use super::cursor::{CursorLeg, PageAnchor, Pager};
 #[cfg(feature = "temporal_track")]
 use super::helpers::attach_temporal_metadata;
-use super::helpers::{build_context, empty_search_response, parse_cursor, timestamp_to_rfc3339};
+use super::helpers::{build_context, empty_search_response, timestamp_to_rfc3339};
 use crate::lex::{LexMatch, compute_snippet_slices};
 use crate::memvid::lifecycle::Memvid;
 use crate::search::{EvaluationContext, ParsedQuery};
 use crate::types::{
-    Frame, FrameId, SearchEngineKind, SearchHit, SearchHitMetadata, SearchParams, SearchRequest,
-    SearchResponse,
+    DocMetadata, Frame, FrameId, SearchEngineKind, SearchHit, SearchHitMetadata, SearchParams,
+    SearchRequest, SearchResponse,
 };
 use crate::{MemvidError, Result};
 use std::collections::HashSet;
@@ -80,11 +81,14 @@ pub(super) fn search_with_lex_fallback(
         ));
     }
 
-    let offset = parse_cursor(request.cursor.as_deref(), total_slices)?;
+    let pager = Pager::new(memvid, request, CursorLeg::Lexical)?;
+    pager.check_total(total_slices)?;
+    let offset = pager.offset();
     let effective_top_k = request.top_k.max(1);
 
     let mut hits = Vec::new();
     let mut produced = 0usize;
+    let mut last = None;
     for (matched, slices) in evaluated {
         let frame_meta = memvid
             .toc
@@ -116,10 +120,18 @@ pub(super) fn search_with_lex_fallback(
             .clone()
             .or_else(|| frame_meta.title.clone())
             .or_else(|| crate::infer_title_from_uri(&uri));
+        let anchor = PageAnchor {
+            frame_id: matched.frame_id,
+            score: Some(matched.score),
+        };
+        let produced_before = produced;
 
         for (start, end) in slices {
             if produced < offset {
                 produced += 1;
+                if produced == offset {
+                    pager.verify_anchor(anchor.frame_id, anchor.score)?;
+                }
                 continue;
             }
             if hits.len() == effective_top_k {
@@ -141,6 +153,10 @@ pub(super) fn search_with_lex_fallback(
                 content_dates: frame_meta.content_dates.clone(),
                 entities: Vec::new(),
                 extra_metadata: frame_meta.extra_metadata.clone(),
+                audio_range: frame_meta
+                    .metadata
+                    .as_ref()
+                    .and_then(DocMetadata::audio_range),
                 #[cfg(feature = "temporal_track")]
                 temporal: None,
             };
@@ -175,16 +191,17 @@ pub(super) fn search_with_lex_fallback(
                 chunk_text: Some(chunk_text),
                 score: Some(matched.score),
                 metadata: Some(metadata),
+                lexical_score: None,
+                vector_score: None,
             });
             produced += 1;
         }
+        if produced > produced_before && produced > offset {
+            last = Some(anchor);
+        }
     }
 
-    let next_cursor = if produced < total_slices {
-        Some(produced.to_string())
-    } else {
-        None
-    };
+    let next_cursor = pager.next_cursor(produced, total_slices, last);
 
     let elapsed_ms = start_time.elapsed().as_millis().max(1);
     #[cfg(feature = "temporal_track")]
@@ -253,7 +270,12 @@ pub(super) fn search_with_filters_only(
         });
     }
 
-    let offset = parse_cursor(request.cursor.as_deref(), total_hits)?;
+    let pager = Pager::new(memvid, request, CursorLeg::Lexical)?;
+    pager.check_total(total_hits)?;
+    let offset = pager.offset();
+    if let Some((frame_id, _, _)) = offset.checked_sub(1).and_then(|index| matches.get(index)) {
+        pager.verify_anchor(*frame_id, None)?;
+    }
     let effective_top_k = request.top_k.max(1);
     let mut hits = Vec::new();
     let mut produced = 0usize;
@@ -273,6 +295,7 @@ pub(super) fn search_with_filters_only(
             content_dates: frame.content_dates.clone(),
             entities: Vec::new(),
             extra_metadata: frame.extra_metadata.clone(),
+            audio_range: frame.metadata.as_ref().and_then(DocMetadata::audio_range),
             #[cfg(feature = "temporal_track")]
             temporal: None,
         };
@@ -298,15 +321,17 @@ pub(super) fn search_with_filters_only(
             chunk_text: Some(snippet),
             score: None,
             metadata: Some(metadata),
+            lexical_score: None,
+            vector_score: None,
         });
         produced += 1;
     }
 
-    let next_cursor = if offset + produced < total_hits {
-        Some((offset + produced).to_string())
-    } else {
-        None
-    };
+    let last = hits.last().map(|hit| PageAnchor {
+        frame_id: hit.frame_id,
+        score: None,
+    });
+    let next_cursor = pager.next_cursor(offset + produced, total_hits, last);
 
     let elapsed_ms = start_time.elapsed().as_millis().max(1);
     #[cfg(feature = "temporal_track")]
@@ -1,6 +1,8 @@
 // Safe unwrap: guaranteed non-empty vector operations.
 #![allow(clippy::unwrap_used)]
+#[cfg(feature = "temporal_track")]
 use crate::MemvidError;
+#[cfg(feature = "temporal_track")]
 use crate::Result;
 use crate::memvid::lifecycle::Memvid;
 #[cfg(not(feature = "temporal_track"))]
@@ -45,27 +47,6 @@ pub(super) fn timestamp_to_rfc3339(timestamp: i64) -> Option<String> {
         })
 }
 
-pub(super) fn parse_cursor(cursor: Option<&str>, total_hits: usize) -> Result<usize> {
-    let Some(token) = cursor else {
-        return Ok(0);
-    };
-    let trimmed = token.trim();
-    if trimmed.is_empty() {
-        return Ok(0);
-    }
-    let value = trimmed
-        .parse::<usize>()
-        .map_err(|_| MemvidError::InvalidCursor {
-            reason: "cursor not an integer",
-        })?;
-    if value > total_hits {
-        return Err(MemvidError::InvalidCursor {
-            reason: "cursor beyond total hits",
-        });
-    }
-    Ok(value)
-}
-
 /// Build context for LLM from search hits using a multi-document strategy.
 ///
 /// Key design decisions for deterministic, comprehensive context:
@@ -0,0 +1,291 @@
+//! Hybrid lexical + vector search.
+//!
+//! Runs the lexical engine and a vector k-NN over the same request and fuses the two
+//! ranked lists with a [`FusionMethod`]. Each leg contributes a fixed candidate pool sized
+//! from `top_k`, so the fused order (and therefore cursor paging) does not depend on how
+//! deep the caller has paged.
+
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
+
+use super::cursor::{CursorLeg, Pager};
+use super::helpers::{build_context, enrich_hits_with_entities};
+use crate::memvid::lifecycle::Memvid;
+use crate::types::reranker::RerankerKind;
+use crate::types::{
+    FrameId, FusionMethod, SearchEngineKind, SearchHit, SearchParams, SearchRequest, SearchResponse,
+};
+use crate::{MemvidError, Result, VecEmbedder};
+
+/// Minimum number of candidates each leg contributes to the fused list.
+const MIN_CANDIDATE_POOL: usize = 50;
+
+fn candidate_pool(top_k: usize) -> usize {
+    top_k.max(1).saturating_mul(4).max(MIN_CANDIDATE_POOL)
+}
+
+impl Memvid {
+    /// Search with the query embedded by `embedder`, fusing lexical and vector results.
+    ///
+    /// Behaves like [`Memvid::search`] when `embedder` is `None` or vector search is not
+    /// enabled for this memory.
+    pub fn search_with_embedder<E>(
+        &mut self,
+        request: SearchRequest,
+        embedder: Option<&E>,
+    ) -> Result<SearchResponse>
+    where
+        E: VecEmbedder + ?Sized,
+    {
+        let query_embedding = match embedder {
+            Some(embedder) if self.vec_enabled => Some(embedder.embed_query(&request.query)?),
+            _ => None,
+        };
+        self.search_with_embedding(request, query_embedding.as_deref())
+    }
+
+    /// Search with a precomputed query embedding, fusing lexical and vector results.
+    ///
+    /// Each leg contributes `max(4 * top_k, 50)` candidates, fused with `request.fusion`
+    /// (RRF with `k = 60` by default); cursors page through that fused list. `uri`,
+    /// `scope`, `filter`, `as_of_frame`, `as_of_ts` and temporal filters constrain both
+    /// legs, while field terms inside `query` constrain only the lexical leg. Each hit
+    /// carries the fused `score` plus the raw `lexical_score` and `vector_score` of the
+    /// legs that returned it.
+    ///
+    /// Behaves like [`Memvid::search`] when `query_embedding` is `None` or vector search
+    /// is not enabled for this memory.
+    pub fn search_with_embedding(
+        &mut self,
+        request: SearchRequest,
+        query_embedding: Option<&[f32]>,
+    ) -> Result<SearchResponse> {
+        match query_embedding {
+            Some(query_embedding) if self.vec_enabled => {
+                self.search_hybrid(request, query_embedding)
+            }
+            _ => self.search(request),
+        }
+    }
+
+    fn search_hybrid(
+        &mut self,
+        request: SearchRequest,
+        query_embedding: &[f32],
+    ) -> Result<SearchResponse> {
+        let start_time = Instant::now();
+        let pager = Pager::new(self, &request, CursorLeg::Hybrid)?;
+        let pool = candidate_pool(request.top_k);
+
+        let lexical = self
+            .search(SearchRequest {
+                top_k: pool,
+                cursor: None,
+                reranker: None,
+                ..request.clone()
+            })?
+            .hits;
+
+        let vector_filter = super::api::vector_filter_for(&request);
+        let scope = if request.uri.is_some() {
+            None
+        } else {
+            request.scope.as_deref()
+        };
+        #[allow(unused_mut)]
+        let mut vector = match self.vec_search_with_embedding_filtered(
+            &request.query,
+            query_embedding,
+            pool,
+            request.snippet_chars,
+            scope,
+            vector_filter.as_ref(),
+        ) {
+            Ok(response) => response.hits,
+            // Vector search is enabled but nothing has been embedded yet.
+            Err(MemvidError::VecNotEnabled) => Vec::new(),
+            Err(err) => return Err(err),
+        };
+        #[cfg(feature = "temporal_track")]
+        if let Some(temporal) = request.temporal.as_ref() {
+            if let Some(ids) = super::frame_ids_for_temporal_filter(self, temporal)? {
+                let allowed: HashSet<FrameId> = ids.into_iter().collect();
+                vector.retain(|hit| allowed.contains(&hit.frame_id));
+            }
+        }
+
+        let fused = fuse_hits(lexical, vector, request.fusion.unwrap_or_default());
+        let total_hits = fused.len();
+        let (mut hits, next_cursor) =
+            match request.reranker.filter(|kind| *kind != RerankerKind::None) {
+                // Reranked responses are a single page, as in `search`.
+                Some(kind) => (
+                    self.rerank_hits(&request.query, fused, kind, request.top_k)?,
+                    None,
+                ),
+                None => pager.page(fused)?,
+            };
+        if self.has_logic_mesh() {
+            enrich_hits_with_entities(&mut hits, self);
+        }
+
+        Ok(SearchResponse {
+            query: request.query.clone(),
+            elapsed_ms: start_time.elapsed().as_millis(),
+            total_hits,
+            params: SearchParams {
+                top_k: request.top_k,
+                snippet_chars: request.snippet_chars,
+                cursor: request.cursor.clone(),
+            },
+            context: build_context(&hits),
+            hits,
+            next_cursor,
+            engine: SearchEngineKind::Hybrid,
+        })
+    }
+}
+
+/// Fuse ranked lexical and vector hits into a single list with one hit per frame.
+///
+/// A frame's representative hit is its best lexical snippet when it has one, since that
+/// carries match offsets; vector-only frames keep their leading-text snippet. `score`
+/// is the fused score, ranks are rewritten from 1, and ties order by frame ID.
+pub(crate) fn fuse_hits(
+    lexical: Vec<SearchHit>,
+    vector: Vec<SearchHit>,
+    method: FusionMethod,
+) -> Vec<SearchHit> {
+    let mut fused: Vec<(f32, SearchHit)> = Vec::new();
+    let mut slots: HashMap<FrameId, usize> = HashMap::new();
+    for (is_vector, hits) in [(false, lexical), (true, vector)] {
+        let hits = best_per_frame(hits);
+        let normalized = normalized_scores(&hits);
+        for (index, (hit, normalized)) in hits.into_iter().zip(normalized).enumerate() {
+            #[allow(clippy::cast_precision_loss)]
+            let contribution = match method {
+                FusionMethod::Rrf { k } => 1.0 / (k.max(0.0) + (index + 1) as f32),
+                FusionMethod::Weighted { lexical, vector } => {
+                    normalized * if is_vector { vector } else { lexical }
+                }
+            };
+            let raw_score = hit.score;
+            let slot = if let Some(&slot) = slots.get(&hit.frame_id) {
+                slot
+            } else {
+                slots.insert(hit.frame_id, fused.len());
+                fused.push((0.0, hit));
+                fused.len() - 1
+            };
+            let (score, representative) = &mut fused[slot];
+            *score += contribution;
+            if is_vector {
+                representative.vector_score = raw_score;
+            } else {
+                representative.lexical_score = raw_score;
+            }
+        }
+    }
+
+    fused.sort_by(|a, b| {
+        b.0.partial_cmp(&a.0)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.1.frame_id.cmp(&b.1.frame_id))
+    });
+    fused
+        .into_iter()
+        .enumerate()
+        .map(|(index, (score, mut hit))| {
+            hit.rank = index + 1;
+            hit.score = Some(score);
+            hit
+        })
+        .collect()
+}
+
+/// Keep the first (best-ranked) hit for each frame.
+fn best_per_frame(hits: Vec<SearchHit>) -> Vec<SearchHit> {
+    let mut seen = HashSet::new();
+    hits.into_iter()
+        .filter(|hit| seen.insert(hit.frame_id))
+        .collect()
+}
+
+/// Min-max normalise hit scores to `[0, 1]`; a list with no spread normalises to 1.
+fn normalized_scores(hits: &[SearchHit]) -> Vec<f32> {
+    let scores: Vec<f32> = hits.iter().map(|hit| hit.score.unwrap_or(0.0)).collect();
+    let min = scores.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = scores.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let spread = max - min;
+    scores
+        .into_iter()
+        .map(|score| {
+            if spread > f32::EPSILON {
+                (score - min) / spread
+            } else {
+                1.0
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hit(frame_id: FrameId, score: f32) -> SearchHit {
+        SearchHit {
+            rank: 0,
+            frame_id,
+            uri: format!("mv2://doc/{frame_id}"),
+            title: None,
+            range: (0, 0),
+            text: String::new(),
+            matches: 1,
+            chunk_range: None,
+            chunk_text: None,
+            score: Some(score),
+            metadata: None,
+            lexical_score: None,
+            vector_score: None,
+        }
+    }
+
+    #[test]
+    fn rrf_rewards_frames_found_by_both_legs() {
+        let lexical = vec![hit(1, 9.0), hit(1, 8.0), hit(2, 5.0), hit(3, 1.0)];
+        let vector = vec![hit(3, 0.9), hit(4, 0.8)];
+        let fused = fuse_hits(lexical, vector, FusionMethod::default());
+
+        let order: Vec<FrameId> = fused.iter().map(|hit| hit.frame_id).collect();
+        assert_eq!(order, vec![3, 1, 2, 4]);
+        assert_eq!(fused[0].lexical_score, Some(1.0));
+        assert_eq!(fused[0].vector_score, Some(0.9));
+        assert_eq!(fused[1].lexical_score, Some(9.0));
+        assert_eq!(fused[1].vector_score, None);
+        assert_eq!(fused[3].lexical_score, None);
+        assert_eq!(fused[3].vector_score, Some(0.8));
+        assert!(fused.iter().enumerate().all(|(i, hit)| hit.rank == i + 1));
+    }
+
+    #[test]
+    fn weighted_fusion_follows_leg_weights() {
+        let lexical = vec![hit(1, 10.0), hit(2, 2.0)];
+        let vector = vec![hit(2, 0.9), hit(1, 0.1)];
+
+        let lexical_heavy = FusionMethod::Weighted {
+            lexical: 0.8,
+            vector: 0.2,
+        };
+        let fused = fuse_hits(lexical.clone(), vector.clone(), lexical_heavy);
+        assert_eq!(fused[0].frame_id, 1);
+
+        let vector_heavy = FusionMethod::Weighted {
+            lexical: 0.2,
+            vector: 0.8,
+        };
+        let fused = fuse_hits(lexical, vector, vector_heavy);
+        assert_eq!(fused[0].frame_id, 2);
+        assert_eq!(fused[0].score, Some(0.8));
+    }
+}
@@ -11,15 +11,21 @@ use std::collections::{BTreeSet, HashSet};
 use std::time::Instant;
 
 use crate::memvid::lifecycle::Memvid;
+#[cfg(feature = "lex")]
+use crate::types::reranker::RerankerKind;
 use crate::types::{FrameId, SearchEngineKind, SearchParams, SearchRequest, SearchResponse};
 use crate::{MemvidError, Result};
 
 mod api;
 mod builders;
+pub(crate) mod cursor;
 #[cfg(feature = "lex")]
 mod fallback;
 pub(crate) mod helpers;
 #[cfg(feature = "lex")]
+mod hybrid;
+pub(crate) mod rerank;
+#[cfg(feature = "lex")]
 mod tantivy;
 #[cfg(any(feature = "lex", feature = "temporal_track"))]
 mod time_filter;
@@ -31,6 +37,8 @@ pub use api::{
 
 #[cfg(feature = "lex")]
 use fallback::{search_with_filters_only, search_with_lex_fallback};
+#[cfg(feature = "lex")]
+use helpers::build_context;
 use helpers::empty_search_response;
 #[cfg(feature = "lex")]
 pub use tantivy::parse_content_date_to_timestamp;
@@ -47,6 +55,9 @@ impl Memvid {
         if !self.lex_enabled {
             return Err(MemvidError::LexNotEnabled);
         }
+        if let Some(kind) = request.reranker.filter(|kind| *kind != RerankerKind::None) {
+            return self.search_reranked(request, kind);
+        }
 
         let start_time = Instant::now();
         // parse_query can return structured tokens; we only keep non-empty, lower-cased terms.
@@ -179,6 +190,28 @@ impl Memvid {
             };
         }
 
+        // METADATA FILTER: Restrict candidates up front so engines rank only matching frames
+        if let Some(ref filter) = request.filter {
+            let filter_set = self.frame_ids_matching_filter(filter);
+            let filtered: HashSet<FrameId> = match candidate_filter {
+                Some(existing) => existing
+                    .into_iter()
+                    .filter(|id| filter_set.contains(id))
+                    .collect(),
+                None => filter_set,
+            };
+            if filtered.is_empty() {
+                let elapsed = start_time.elapsed().as_millis();
+                return Ok(empty_search_response(
+                    request.query.clone(),
+                    params.clone(),
+                    elapsed,
+                    SearchEngineKind::Tantivy,
+                ));
+            }
+            candidate_filter = Some(filtered);
+        }
+
         // SKETCH PRE-FILTER: Use sketch track for fast candidate generation if available
         // This dramatically reduces the number of documents sent to BM25/Tantivy
         if self.has_sketches() && has_text_terms && !request.no_sketch {
@@ -207,10 +240,14 @@ impl Memvid {
                     Some(existing) => {
                         // Intersection: keep only IDs that pass both filters
                         let filtered: HashSet<FrameId> = existing
-                            .into_iter()
+                            .iter()
                             .filter(|id| sketch_set.contains(id))
+                            .copied()
                             .collect();
-                        if filtered.is_empty() {
+                        if filtered.is_empty() && request.filter.is_some() {
+                            // A metadata filter is a hard constraint; ignore the sketch instead
+                            Some(existing)
+                        } else if filtered.is_empty() {
                             // Fall back to sketch-only if intersection is empty
                             Some(sketch_set)
                         } else {
@@ -277,6 +314,34 @@ impl Memvid {
     }
 }
 
+#[cfg(feature = "lex")]
+impl Memvid {
+    /// Over-fetch a candidate pool with the first-stage engine and rerank it.
+    ///
+    /// Reranked responses are a single page: `next_cursor` is always `None`.
+    fn search_reranked(
+        &mut self,
+        request: SearchRequest,
+        kind: RerankerKind,
+    ) -> Result<SearchResponse> {
+        let start_time = Instant::now();
+        let top_k = request.top_k;
+        let query = request.query.clone();
+        let mut first_stage = request;
+        first_stage.reranker = None;
+        first_stage.top_k = rerank::rerank_candidate_pool(top_k);
+
+        let mut response = self.search(first_stage)?;
+        let hits = std::mem::take(&mut response.hits);
+        response.hits = self.rerank_hits(&query, hits, kind, top_k)?;
+        response.context = build_context(&response.hits);
+        response.params.top_k = top_k;
+        response.next_cursor = None;
+        response.elapsed_ms = start_time.elapsed().as_millis();
+        Ok(response)
+    }
+}
+
 #[cfg(not(feature = "lex"))]
 impl Memvid {
     pub fn search(&mut self, _request: SearchRequest) -> Result<SearchResponse> {
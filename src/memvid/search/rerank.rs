@@ -0,0 +1,132 @@
+//! Second-stage reranking of search hits.
+//!
+//! Requests select a reranker by [`RerankerKind`]; the handle resolves the kind to a
+//! concrete [`Reranker`] (built-in BM25, a lazily loaded cross-encoder, or one registered
+//! by the caller) and reorders candidate hits by its scores.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::memvid::lifecycle::Memvid;
+use crate::rerank::Bm25Reranker;
+use crate::types::SearchHit;
+use crate::types::reranker::{Reranker, RerankerConfig, RerankerDocument, RerankerKind};
+use crate::{MemvidError, Result};
+
+/// Number of first-stage candidates handed to a reranker when the caller asks for fewer.
+pub(crate) fn rerank_candidate_pool(top_k: usize) -> usize {
+    top_k.max(RerankerConfig::default().max_candidates)
+}
+
+impl Memvid {
+    /// Register the reranker used when a request selects `kind`.
+    ///
+    /// Replaces any previously registered or lazily created reranker for that kind.
+    /// `Llm` and `OpenAI` kinds have no built-in implementation and must be registered
+    /// before use.
+    pub fn register_reranker(&mut self, kind: RerankerKind, reranker: Arc<dyn Reranker>) {
+        self.rerankers.insert(kind, reranker);
+    }
+
+    /// Resolve `kind` to a reranker, creating built-in implementations on first use.
+    ///
+    /// Returns `Ok(None)` for [`RerankerKind::None`].
+    pub(crate) fn reranker_for(&mut self, kind: RerankerKind) -> Result<Option<Arc<dyn Reranker>>> {
+        if kind == RerankerKind::None {
+            return Ok(None);
+        }
+        if let Some(reranker) = self.rerankers.get(&kind) {
+            return Ok(Some(Arc::clone(reranker)));
+        }
+        let reranker = build_default_reranker(kind)?;
+        self.rerankers.insert(kind, Arc::clone(&reranker));
+        Ok(Some(reranker))
+    }
+
+    /// Rerank `hits` for `query` with the reranker selected by `kind`.
+    ///
+    /// Returns at most `top_k` hits ordered by reranker score, with `score` and `rank`
+    /// rewritten. Hits past the reranker's `max_candidates` keep their first-stage order
+    /// and score after the reranked ones. Hits are returned unchanged (truncated to
+    /// `top_k`) for [`RerankerKind::None`].
+    pub fn rerank_hits(
+        &mut self,
+        query: &str,
+        hits: Vec<SearchHit>,
+        kind: RerankerKind,
+        top_k: usize,
+    ) -> Result<Vec<SearchHit>> {
+        let Some(reranker) = self.reranker_for(kind)? else {
+            let mut hits = hits;
+            hits.truncate(top_k);
+            return Ok(hits);
+        };
+        rerank_with(reranker.as_ref(), query, hits, top_k)
+    }
+}
+
+fn build_default_reranker(kind: RerankerKind) -> Result<Arc<dyn Reranker>> {
+    match kind {
+        RerankerKind::Bm25 => Ok(Arc::new(Bm25Reranker::default())),
+        #[cfg(feature = "vec")]
+        RerankerKind::CrossEncoder => Ok(Arc::new(crate::rerank::CrossEncoderReranker::new(
+            crate::rerank::CrossEncoderConfig::default(),
+        )?)),
+        #[cfg(not(feature = "vec"))]
+        RerankerKind::CrossEncoder => Err(MemvidError::FeatureUnavailable { feature: "vec" }),
+        RerankerKind::Llm | RerankerKind::OpenAI => Err(MemvidError::RerankFailed {
+            reason: format!("no reranker registered for {kind:?}").into_boxed_str(),
+        }),
+        RerankerKind::None => Err(MemvidError::RerankFailed {
+            reason: "reranker kind none has no implementation".into(),
+        }),
+    }
+}
+
+/// Reorder `hits` by the scores `reranker` assigns to their text; hits it did not score
+/// follow in their original order.
+pub(crate) fn rerank_with(
+    reranker: &dyn Reranker,
+    query: &str,
+    hits: Vec<SearchHit>,
+    top_k: usize,
+) -> Result<Vec<SearchHit>> {
+    if hits.is_empty() || top_k == 0 {
+        return Ok(Vec::new());
+    }
+
+    let documents: Vec<RerankerDocument> = hits
+        .iter()
+        .enumerate()
+        .map(|(index, hit)| RerankerDocument {
+            id: index as u64,
+            text: hit.chunk_text.clone().unwrap_or_else(|| hit.text.clone()),
+            metadata: hit.title.clone(),
+        })
+        .collect();
+
+    let results = reranker.rerank(query, &documents, top_k)?;
+
+    let mut slots: BTreeMap<u64, SearchHit> = hits
+        .into_iter()
+        .enumerate()
+        .map(|(index, hit)| (index as u64, hit))
+        .collect();
+    let mut reranked = Vec::with_capacity(results.len().min(top_k));
+    for result in results {
+        let Some(mut hit) = slots.remove(&result.id) else {
+            continue;
+        };
+        hit.score = Some(result.score);
+        hit.rank = reranked.len() + 1;
+        reranked.push(hit);
+        if reranked.len() == top_k {
+            return Ok(reranked);
+        }
+    }
+    for mut hit in slots.into_values().take(top_k - reranked.len()) {
+        hit.rank = reranked.len() + 1;
+        reranked.push(hit);
+    }
+    Ok(reranked)
+}
@@ -1,18 +1,17 @@
 // Safe unwrap: regex from validated patterns.
 #![allow(clippy::unwrap_used)]
 #![cfg(feature = "lex")]
+use super::cursor::{CursorLeg, PageAnchor, Pager};
 #[cfg(feature = "temporal_track")]
 use super::helpers::attach_temporal_metadata;
-use super::helpers::{
-    build_context, collect_token_occurrences, parse_cursor, timestamp_to_rfc3339,
-};
+use super::helpers::{build_context, collect_token_occurrences, timestamp_to_rfc3339};
 use crate::lex::compute_snippet_slices;
 use crate::memvid::frame::ChunkInfo;
 use crate::memvid::lifecycle::Memvid;
 use crate::search::{EvaluationContext, ParsedQuery};
 use crate::types::{
-    FrameId, SearchEngineKind, SearchHit, SearchHitMetadata, SearchParams, SearchRequest,
-    SearchResponse,
+    DocMetadata, FrameId, SearchEngineKind, SearchHit, SearchHitMetadata, SearchParams,
+    SearchRequest, SearchResponse,
 };
 use crate::{MemvidError, Result};
 use log::warn;
@@ -45,16 +44,19 @@ pub(super) fn try_tantivy_search(
     }
     let stemmed_tokens = stemmed_tokens;
 
-    let offset_hint = request
-        .cursor
-        .as_deref()
-        .and_then(|cursor| cursor.parse::<usize>().ok())
-        .unwrap_or(0);
-    let base_docs = request.top_k.max(1) + offset_hint;
-    let mut doc_limit = base_docs.saturating_mul(4).max(20);
-    if let Some(filter) = candidate_filter {
-        doc_limit = doc_limit.min(filter.len().max(1));
-    }
+    // Recency boosting below is relative to the candidate set, so the candidate window
+    // is fixed on the first page and carried by the cursor; widening it with the page
+    // offset would reorder results between pages.
+    let mut pager = Pager::new(memvid, request, CursorLeg::Lexical)?;
+    let (window_start, window_len) = pager.pinned_window().unwrap_or_else(|| {
+        let mut window_len = request.top_k.max(1).saturating_mul(4).max(20);
+        if let Some(filter) = candidate_filter {
+            window_len = window_len.min(filter.len().max(1));
+        }
+        (0, window_len)
+    });
+    pager.set_window(window_start, window_len);
+    let doc_limit = window_start.saturating_add(window_len);
     let uri_filter = request.uri.as_deref();
     let scope_filter = if uri_filter.is_some() {
         None
@@ -84,7 +86,20 @@ pub(super) fn try_tantivy_search(
         request.query,
         search_hits.len()
     );
-    if search_hits.is_empty() {
+    let more_windows = search_hits.len() >= doc_limit;
+    let search_hits: Vec<_> = search_hits.into_iter().skip(window_start).collect();
+    if window_start > 0 {
+        // Later windows never fall back to legacy lex: the first page already chose Tantivy.
+        if search_hits.is_empty() {
+            let elapsed = start_time.elapsed().as_millis();
+            return Ok(Some(super::helpers::empty_search_response(
+                request.query.clone(),
+                params.clone(),
+                elapsed,
+                SearchEngineKind::Tantivy,
+            )));
+        }
+    } else if search_hits.is_empty() {
         // Fall back to legacy lex search when Tantivy yields no hits. This avoids silent
         // zero-hit responses when the analyzer drops tokens (e.g., qtoken_123).
         // BUT only fall back if lex_index actually exists and has data.
@@ -231,6 +246,24 @@ pub(super) fn try_tantivy_search(
         evaluated = with_scores.into_iter().map(|(_, item)| item).collect();
     }
 
+    let total_slices: usize = evaluated
+        .iter()
+        .map(|(_, _, slices, _, _)| slices.len())
+        .sum();
+    if total_slices == 0 && window_start > 0 {
+        // Nothing in this window survived evaluation; hand back an empty page that
+        // continues with the next window.
+        let mut response = super::helpers::empty_search_response(
+            request.query.clone(),
+            params.clone(),
+            start_time.elapsed().as_millis(),
+            SearchEngineKind::Tantivy,
+        );
+        response.next_cursor =
+            more_windows.then(|| pager.next_window_cursor(window_start + window_len));
+        return Ok(Some(response));
+    }
+
     if evaluated.is_empty() {
         tracing::debug!("tantivy evaluation produced zero hits; falling back to legacy lex",);
         memvid.ensure_lex_index()?;
@@ -245,10 +278,6 @@ pub(super) fn try_tantivy_search(
         )?));
     }
 
-    let total_slices: usize = evaluated
-        .iter()
-        .map(|(_, _, slices, _, _)| slices.len())
-        .sum();
     if total_slices == 0 {
         tracing::debug!(
             "tantivy evaluation produced zero total slices; falling back to legacy lex",
@@ -265,11 +294,13 @@ pub(super) fn try_tantivy_search(
         )?));
     }
 
-    let offset = parse_cursor(request.cursor.as_deref(), total_slices)?;
+    pager.check_total(total_slices)?;
+    let offset = pager.offset();
     let effective_top_k = request.top_k.max(1);
 
     let mut hits = Vec::new();
     let mut produced = 0usize;
+    let mut last = None;
     for (hit, occurrences, slices, chunk_info, _timestamp) in evaluated {
         if hits.len() == effective_top_k && produced >= offset {
             break;
@@ -298,10 +329,18 @@ pub(super) fn try_tantivy_search(
         } = chunk_info;
         let chunk_bytes = chunk_text.as_bytes();
         let chunk_range = (chunk_start, chunk_end);
+        let anchor = PageAnchor {
+            frame_id: hit.frame_id,
+            score: Some(hit.score),
+        };
+        let produced_before = produced;
 
         for (start, end) in slices {
             if produced < offset {
                 produced += 1;
+                if produced == offset {
+                    pager.verify_anchor(anchor.frame_id, anchor.score)?;
+                }
                 continue;
             }
             if hits.len() == effective_top_k {
@@ -327,6 +366,10 @@ pub(super) fn try_tantivy_search(
                 content_dates: frame_meta.content_dates.clone(),
                 entities: Vec::new(),
                 extra_metadata: frame_meta.extra_metadata.clone(),
+                audio_range: frame_meta
+                    .metadata
+                    .as_ref()
+                    .and_then(DocMetadata::audio_range),
                 #[cfg(feature = "temporal_track")]
                 temporal: None,
             };
@@ -349,16 +392,19 @@ pub(super) fn try_tantivy_search(
                 chunk_text: Some(chunk_text.clone()),
                 score: Some(hit.score),
                 metadata: Some(metadata),
+                lexical_score: None,
+                vector_score: None,
             });
             produced += 1;
         }
+        if produced > produced_before && produced > offset {
+            last = Some(anchor);
+        }
     }
 
-    let next_cursor = if produced < total_slices {
-        Some(produced.to_string())
-    } else {
-        None
-    };
+    let next_cursor = pager
+        .next_cursor(produced, total_slices, last)
+        .or_else(|| more_windows.then(|| pager.next_window_cursor(window_start + window_len)));
     #[cfg(feature = "temporal_track")]
     attach_temporal_metadata(memvid, &mut hits)?;
     let elapsed_ms = start_time.elapsed().as_millis().max(1);
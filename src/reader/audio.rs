@@ -0,0 +1,105 @@
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use serde_json::json;
+
+use super::TRANSCRIPT_METADATA_KEY;
+use crate::{
+    DocumentFormat, DocumentReader, ExtractedDocument, MemvidError, ReaderDiagnostics, ReaderHint,
+    ReaderOutput, Result, WhisperConfig, WhisperTranscriber,
+};
+
+/// Transcribes audio payloads with Whisper.
+///
+/// The transcript becomes the document text and its timestamped segments travel in the
+/// metadata, so ingestion can chunk the recording by segment. The model is loaded on the
+/// first extraction and kept for the life of the reader.
+pub struct AudioReader {
+    config: WhisperConfig,
+    transcriber: Mutex<Option<WhisperTranscriber>>,
+}
+
+impl AudioReader {
+    #[must_use]
+    pub fn new(config: WhisperConfig) -> Self {
+        Self {
+            config,
+            transcriber: Mutex::new(None),
+        }
+    }
+
+    fn mime_for_extension(extension: Option<&str>) -> &'static str {
+        match extension.map(str::to_ascii_lowercase).as_deref() {
+            Some("wav") => "audio/wav",
+            Some("flac") => "audio/flac",
+            Some("ogg" | "oga" | "opus") => "audio/ogg",
+            Some("m4a" | "aac") => "audio/mp4",
+            _ => "audio/mpeg",
+        }
+    }
+}
+
+impl Default for AudioReader {
+    fn default() -> Self {
+        Self::new(WhisperConfig::default())
+    }
+}
+
+impl DocumentReader for AudioReader {
+    fn name(&self) -> &'static str {
+        "whisper"
+    }
+
+    fn supports(&self, hint: &ReaderHint<'_>) -> bool {
+        matches!(hint.format, Some(DocumentFormat::Audio))
+            || hint
+                .mime
+                .is_some_and(|mime| mime.to_ascii_lowercase().starts_with("audio/"))
+    }
+
+    fn extract(&self, bytes: &[u8], hint: &ReaderHint<'_>) -> Result<ReaderOutput> {
+        let start = Instant::now();
+        let extension = hint
+            .uri
+            .and_then(|uri| Path::new(uri).extension())
+            .and_then(|ext| ext.to_str());
+
+        let transcript = {
+            let mut slot = self
+                .transcriber
+                .lock()
+                .map_err(|_| MemvidError::ExtractionFailed {
+                    reason: "whisper transcriber lock poisoned".into(),
+                })?;
+            let transcriber = match &mut *slot {
+                Some(transcriber) => transcriber,
+                empty @ None => empty.insert(WhisperTranscriber::new(&self.config)?),
+            };
+            transcriber.transcribe_bytes(bytes, extension)?
+        };
+
+        let mut diagnostics = ReaderDiagnostics {
+            duration_ms: Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+            ..ReaderDiagnostics::default()
+        }
+        .with_metadata(json!({
+            "model": self.config.model_name,
+            "language": transcript.language,
+            "duration_secs": transcript.duration_secs,
+            "segments": transcript.segments.len(),
+        }));
+        if transcript.text.trim().is_empty() {
+            diagnostics.record_warning("whisper produced an empty transcript");
+        }
+
+        let mut document = ExtractedDocument::empty();
+        document.text = Some(transcript.text.trim().to_string());
+        document.mime_type = Some(hint.mime.map_or_else(
+            || Self::mime_for_extension(extension).to_string(),
+            str::to_string,
+        ));
+        document.metadata = json!({ TRANSCRIPT_METADATA_KEY: transcript });
+        Ok(ReaderOutput::new(document, self.name()).with_diagnostics(diagnostics))
+    }
+}
@@ -0,0 +1,403 @@
+//! Header-level probing of JPEG, PNG and WebP images: format, pixel dimensions and the
+//! EXIF camera/GPS fields that `put` records in [`DocMetadata`](crate::DocMetadata).
+//!
+//! Only container headers and the EXIF block are parsed; pixel data is never decoded.
+
+use crate::types::{DocExifMetadata, DocGpsMetadata};
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+const EXIF_PREFIX: &[u8] = b"Exif\0\0";
+
+const TAG_MAKE: u16 = 0x010F;
+const TAG_MODEL: u16 = 0x0110;
+const TAG_DATETIME: u16 = 0x0132;
+const TAG_EXIF_IFD: u16 = 0x8769;
+const TAG_GPS_IFD: u16 = 0x8825;
+const TAG_DATETIME_ORIGINAL: u16 = 0x9003;
+const TAG_LENS_MODEL: u16 = 0xA434;
+const TAG_GPS_LATITUDE_REF: u16 = 0x0001;
+const TAG_GPS_LATITUDE: u16 = 0x0002;
+const TAG_GPS_LONGITUDE_REF: u16 = 0x0003;
+const TAG_GPS_LONGITUDE: u16 = 0x0004;
+
+const TIFF_ASCII: u16 = 2;
+const TIFF_SHORT: u16 = 3;
+const TIFF_LONG: u16 = 4;
+const TIFF_RATIONAL: u16 = 5;
+
+/// Image containers recognised on ingest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    Jpeg,
+    Png,
+    WebP,
+}
+
+impl ImageFormat {
+    /// Identify the container from its leading bytes.
+    #[must_use]
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some(Self::Jpeg)
+        } else if bytes.starts_with(PNG_SIGNATURE) {
+            Some(Self::Png)
+        } else if bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP".as_slice()) {
+            Some(Self::WebP)
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub fn mime(self) -> &'static str {
+        match self {
+            Self::Jpeg => "image/jpeg",
+            Self::Png => "image/png",
+            Self::WebP => "image/webp",
+        }
+    }
+
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Jpeg => "jpeg",
+            Self::Png => "png",
+            Self::WebP => "webp",
+        }
+    }
+}
+
+/// What [`probe_image`] learned from an image's headers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageProbe {
+    pub format: ImageFormat,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub exif: Option<DocExifMetadata>,
+}
+
+/// Probe `bytes` as a JPEG, PNG or WebP image.
+///
+/// Returns `None` when the bytes are not one of those containers. Truncated or malformed
+/// headers leave the affected fields unset instead of failing.
+#[must_use]
+pub fn probe_image(bytes: &[u8]) -> Option<ImageProbe> {
+    let format = ImageFormat::detect(bytes)?;
+    let (dimensions, exif) = match format {
+        ImageFormat::Jpeg => probe_jpeg(bytes),
+        ImageFormat::Png => probe_png(bytes),
+        ImageFormat::WebP => probe_webp(bytes),
+    };
+    Some(ImageProbe {
+        format,
+        width: dimensions.map(|(width, _)| width),
+        height: dimensions.map(|(_, height)| height),
+        exif: exif.and_then(parse_exif),
+    })
+}
+
+type Dimensions = Option<(u32, u32)>;
+
+fn probe_jpeg(bytes: &[u8]) -> (Dimensions, Option<&[u8]>) {
+    let mut dimensions = None;
+    let mut exif = None;
+    let mut pos = 2;
+    while pos + 4 <= bytes.len() {
+        if bytes[pos] != 0xFF {
+            break;
+        }
+        let marker = bytes[pos + 1];
+        match marker {
+            // Fill byte before a marker.
+            0xFF => {
+                pos += 1;
+                continue;
+            }
+            // Markers without a payload.
+            0x01 | 0xD0..=0xD7 => {
+                pos += 2;
+                continue;
+            }
+            // Start of scan or end of image: the headers are over.
+            0xD9 | 0xDA => break,
+            _ => {}
+        }
+        let len = usize::from(u16::from_be_bytes([bytes[pos + 2], bytes[pos + 3]]));
+        let Some(segment) = len
+            .checked_sub(2)
+            .and_then(|body| bytes.get(pos + 4..pos + 4 + body))
+        else {
+            break;
+        };
+        match marker {
+            0xE1 if exif.is_none() => exif = segment.strip_prefix(EXIF_PREFIX),
+            // SOF0..SOF15, minus DHT (C4), JPG (C8) and DAC (CC).
+            0xC0..=0xCF if !matches!(marker, 0xC4 | 0xC8 | 0xCC) && segment.len() >= 5 => {
+                let height = u16::from_be_bytes([segment[1], segment[2]]);
+                let width = u16::from_be_bytes([segment[3], segment[4]]);
+                dimensions = Some((u32::from(width), u32::from(height)));
+            }
+            _ => {}
+        }
+        pos += 2 + len;
+    }
+    (dimensions, exif)
+}
+
+fn probe_png(bytes: &[u8]) -> (Dimensions, Option<&[u8]>) {
+    let mut dimensions = None;
+    let mut exif = None;
+    let mut pos = PNG_SIGNATURE.len();
+    while let Some(header) = bytes.get(pos..pos + 8) {
+        let len = u32::from_be_bytes([header[0], header[1], header[2], header[3]]);
+        let Ok(len) = usize::try_from(len) else {
+            break;
+        };
+        let kind = &header[4..8];
+        let Some(data) = bytes.get(pos + 8..(pos + 8).saturating_add(len)) else {
+            break;
+        };
+        match kind {
+            b"IHDR" if data.len() >= 8 => {
+                dimensions = Some((read_u32_be(&data[0..4]), read_u32_be(&data[4..8])));
+            }
+            b"eXIf" => exif = Some(data.strip_prefix(EXIF_PREFIX).unwrap_or(data)),
+            b"IDAT" if exif.is_some() => break,
+            b"IEND" => break,
+            _ => {}
+        }
+        // Chunk body is followed by a 4-byte CRC.
+        pos += 8 + len + 4;
+    }
+    (dimensions, exif)
+}
+
+fn probe_webp(bytes: &[u8]) -> (Dimensions, Option<&[u8]>) {
+    let mut dimensions = None;
+    let mut exif = None;
+    let mut pos = 12;
+    while let Some(header) = bytes.get(pos..pos + 8) {
+        let len = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+        let Ok(len) = usize::try_from(len) else {
+            break;
+        };
+        let kind = &header[0..4];
+        let Some(data) = bytes.get(pos + 8..(pos + 8).saturating_add(len)) else {
+            break;
+        };
+        match kind {
+            // Extended format: 24-bit canvas width and height, each stored minus one.
+            b"VP8X" if data.len() >= 10 => {
+                let width = read_u24_le(&data[4..7]) + 1;
+                let height = read_u24_le(&data[7..10]) + 1;
+                dimensions = Some((width, height));
+            }
+            // Lossy bitstream: keyframe start code, then 14-bit width and height.
+            b"VP8 "
+                if dimensions.is_none() && data.len() >= 10 && data[3..6] == [0x9D, 0x01, 0x2A] =>
+            {
+                let width = u16::from_le_bytes([data[6], data[7]]) & 0x3FFF;
+                let height = u16::from_le_bytes([data[8], data[9]]) & 0x3FFF;
+                dimensions = Some((u32::from(width), u32::from(height)));
+            }
+            // Lossless bitstream: signature byte, then 14-bit width and height minus one.
+            b"VP8L" if dimensions.is_none() && data.len() >= 5 && data[0] == 0x2F => {
+                let bits = u32::from_le_bytes([data[1], data[2], data[3], data[4]]);
+                dimensions = Some(((bits & 0x3FFF) + 1, ((bits >> 14) & 0x3FFF) + 1));
+            }
+            b"EXIF" => exif = Some(data.strip_prefix(EXIF_PREFIX).unwrap_or(data)),
+            _ => {}
+        }
+        // Chunks are padded to an even length.
+        pos += 8 + len + (len & 1);
+    }
+    (dimensions, exif)
+}
+
+/// Read the camera, lens, capture time and GPS position from a TIFF-structured EXIF block.
+fn parse_exif(tiff: &[u8]) -> Option<DocExifMetadata> {
+    let tiff = Tiff::new(tiff)?;
+    let ifd0 = tiff.ifd(tiff.u32_at(4)?)?;
+
+    let mut exif = DocExifMetadata {
+        make: ifd0.ascii(&tiff, TAG_MAKE),
+        model: ifd0.ascii(&tiff, TAG_MODEL),
+        ..DocExifMetadata::default()
+    };
+    let sub_ifd = ifd0
+        .offset(&tiff, TAG_EXIF_IFD)
+        .and_then(|offset| tiff.ifd(offset));
+    if let Some(sub_ifd) = &sub_ifd {
+        exif.lens = sub_ifd.ascii(&tiff, TAG_LENS_MODEL);
+        exif.datetime = sub_ifd.ascii(&tiff, TAG_DATETIME_ORIGINAL);
+    }
+    if exif.datetime.is_none() {
+        exif.datetime = ifd0.ascii(&tiff, TAG_DATETIME);
+    }
+    exif.gps = ifd0
+        .offset(&tiff, TAG_GPS_IFD)
+        .and_then(|offset| tiff.ifd(offset))
+        .and_then(|gps| {
+            let latitude = gps.coordinate(&tiff, TAG_GPS_LATITUDE, TAG_GPS_LATITUDE_REF, "S")?;
+            let longitude = gps.coordinate(&tiff, TAG_GPS_LONGITUDE, TAG_GPS_LONGITUDE_REF, "W")?;
+            Some(DocGpsMetadata {
+                latitude,
+                longitude,
+            })
+        });
+
+    let found = exif.make.is_some()
+        || exif.model.is_some()
+        || exif.lens.is_some()
+        || exif.datetime.is_some()
+        || exif.gps.is_some();
+    found.then_some(exif)
+}
+
+/// Byte-order aware view of a TIFF block.
+struct Tiff<'a> {
+    data: &'a [u8],
+    little_endian: bool,
+}
+
+impl<'a> Tiff<'a> {
+    fn new(data: &'a [u8]) -> Option<Self> {
+        let little_endian = match data.get(0..2)? {
+            b"II" => true,
+            b"MM" => false,
+            _ => return None,
+        };
+        let tiff = Self {
+            data,
+            little_endian,
+        };
+        (tiff.u16_at(2)? == 42).then_some(tiff)
+    }
+
+    fn bytes(&self, offset: usize, len: usize) -> Option<&'a [u8]> {
+        self.data.get(offset..offset.checked_add(len)?)
+    }
+
+    fn u16_at(&self, offset: usize) -> Option<u16> {
+        let bytes: [u8; 2] = self.bytes(offset, 2)?.try_into().ok()?;
+        Some(if self.little_endian {
+            u16::from_le_bytes(bytes)
+        } else {
+            u16::from_be_bytes(bytes)
+        })
+    }
+
+    fn u32_at(&self, offset: usize) -> Option<u32> {
+        let bytes: [u8; 4] = self.bytes(offset, 4)?.try_into().ok()?;
+        Some(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn ifd(&self, offset: u32) -> Option<Ifd> {
+        let offset = usize::try_from(offset).ok()?;
+        let count = usize::from(self.u16_at(offset)?);
+        let entries = (0..count)
+            .filter_map(|index| {
+                let entry = offset + 2 + index * 12;
+                Some(IfdEntry {
+                    tag: self.u16_at(entry)?,
+                    kind: self.u16_at(entry + 2)?,
+                    count: self.u32_at(entry + 4)?,
+                    value_offset: entry + 8,
+                })
+            })
+            .collect();
+        Some(Ifd { entries })
+    }
+}
+
+struct IfdEntry {
+    tag: u16,
+    kind: u16,
+    count: u32,
+    /// Position of the 4-byte value field, which holds the value itself when it fits.
+    value_offset: usize,
+}
+
+impl IfdEntry {
+    /// Offset of the entry's `count` values of `unit` bytes each, checked against the block.
+    fn data_start(&self, tiff: &Tiff<'_>, unit: usize) -> Option<usize> {
+        let len = usize::try_from(self.count).ok()?.checked_mul(unit)?;
+        let start = if len <= 4 {
+            self.value_offset
+        } else {
+            usize::try_from(tiff.u32_at(self.value_offset)?).ok()?
+        };
+        tiff.bytes(start, len).map(|_| start)
+    }
+}
+
+struct Ifd {
+    entries: Vec<IfdEntry>,
+}
+
+impl Ifd {
+    fn entry(&self, tag: u16) -> Option<&IfdEntry> {
+        self.entries.iter().find(|entry| entry.tag == tag)
+    }
+
+    fn ascii(&self, tiff: &Tiff<'_>, tag: u16) -> Option<String> {
+        let entry = self.entry(tag).filter(|entry| entry.kind == TIFF_ASCII)?;
+        let start = entry.data_start(tiff, 1)?;
+        let raw = tiff.bytes(start, usize::try_from(entry.count).ok()?)?;
+        let raw = raw.split(|byte| *byte == 0).next().unwrap_or(raw);
+        let text = String::from_utf8_lossy(raw).trim().to_string();
+        (!text.is_empty()).then_some(text)
+    }
+
+    /// Offset stored in a pointer tag such as the EXIF or GPS sub-IFD.
+    fn offset(&self, tiff: &Tiff<'_>, tag: u16) -> Option<u32> {
+        let entry = self.entry(tag)?;
+        match entry.kind {
+            TIFF_LONG => tiff.u32_at(entry.value_offset),
+            TIFF_SHORT => tiff.u16_at(entry.value_offset).map(u32::from),
+            _ => None,
+        }
+    }
+
+    /// Signed decimal degrees from a degrees/minutes/seconds rational triple and its
+    /// hemisphere reference, negated when the reference is `negative_ref`.
+    fn coordinate(
+        &self,
+        tiff: &Tiff<'_>,
+        tag: u16,
+        ref_tag: u16,
+        negative_ref: &str,
+    ) -> Option<f64> {
+        let entry = self
+            .entry(tag)
+            .filter(|entry| entry.kind == TIFF_RATIONAL && entry.count == 3)?;
+        let data = entry.data_start(tiff, 8)?;
+        let mut parts = [0.0f64; 3];
+        for (index, part) in parts.iter_mut().enumerate() {
+            let start = data + index * 8;
+            let numerator = f64::from(tiff.u32_at(start)?);
+            let denominator = f64::from(tiff.u32_at(start + 4)?);
+            if denominator == 0.0 {
+                return None;
+            }
+            *part = numerator / denominator;
+        }
+        let degrees = parts[0] + parts[1] / 60.0 + parts[2] / 3600.0;
+        let negative = self
+            .ascii(tiff, ref_tag)
+            .is_some_and(|hemisphere| hemisphere.eq_ignore_ascii_case(negative_ref));
+        Some(if negative { -degrees } else { degrees })
+    }
+}
+
+fn read_u32_be(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn read_u24_le(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0])
+}
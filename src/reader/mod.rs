@@ -1,6 +1,9 @@
 //! Document reader traits and registry for unified format ingestion.
 
+#[cfg(feature = "whisper")]
+mod audio;
 mod docx;
+mod image;
 mod passthrough;
 mod pdf;
 mod pptx;
@@ -9,7 +12,10 @@ mod xlsx;
 
 use serde_json::Value;
 
+#[cfg(feature = "whisper")]
+pub use audio::AudioReader;
 pub use docx::DocxReader;
+pub use image::{ImageFormat, ImageProbe, probe_image};
 pub use passthrough::PassthroughReader;
 pub use pdf::PdfReader;
 pub use pptx::PptxReader;
@@ -18,6 +24,10 @@ pub use xlsx::XlsxReader;
 
 use crate::{ExtractedDocument, Result};
 
+/// Key under which readers of timestamped media (see `AudioReader`) store the
+/// `TranscriptionResult` in `ExtractedDocument::metadata`.
+pub(crate) const TRANSCRIPT_METADATA_KEY: &str = "transcript";
+
 /// Soft classification of document formats used by the ingestion router.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DocumentFormat {
@@ -29,6 +39,7 @@ pub enum DocumentFormat {
     PlainText,
     Markdown,
     Html,
+    Audio,
     Unknown,
 }
 
@@ -44,6 +55,7 @@ impl DocumentFormat {
             Self::PlainText => "text",
             Self::Markdown => "markdown",
             Self::Html => "html",
+            Self::Audio => "audio",
             Self::Unknown => "unknown",
         }
     }
@@ -207,6 +219,8 @@ impl Default for ReaderRegistry {
         registry.register(XlsxReader);
         registry.register(XlsReader);
         registry.register(PptxReader);
+        #[cfg(feature = "whisper")]
+        registry.register(AudioReader::default());
         registry.register(PassthroughReader);
         registry
     }
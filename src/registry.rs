@@ -2,7 +2,7 @@ use std::convert::TryFrom;
 use std::env;
 use std::fmt;
 use std::io::ErrorKind;
-use std::io::{self, Read, Write};
+use std::io::{self, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
@@ -17,6 +17,7 @@ use crate::error::{LockOwnerHint, Result};
 
 const HEADER_SAMPLE_BYTES: usize = 4 * 1024;
 const REGISTRY_SUBDIR: &str = "locks";
+const SNAPSHOT_SUBDIR: &str = "snapshots";
 const ROOT_DIR: &str = ".memvid";
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -261,3 +262,208 @@ pub fn is_stale(record: &LockRecord, grace: Duration) -> bool {
 pub fn to_owner_hint(record: Option<LockRecord>) -> Option<LockOwnerHint> {
     record.map(|r| r.to_owner_hint())
 }
+
+/// A read snapshot pinned to one on-disk file version, published for writers to inspect.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SnapshotRecord {
+    pub pid: u32,
+    pub generation: u64,
+    pub footer_offset: u64,
+    pub file_path: String,
+    pub opened_at: String,
+    #[serde(default)]
+    pub last_heartbeat: String,
+}
+
+/// Registration of a live snapshot reader.
+///
+/// The lease file stays exclusively locked for the lifetime of the guard and carries a
+/// heartbeat. Writers drop leases that are no longer locked or whose heartbeat is older
+/// than their TTL, so a crashed reader cannot pin a file forever.
+#[derive(Debug)]
+pub struct SnapshotLease {
+    path: PathBuf,
+    file: File,
+    record: SnapshotRecord,
+}
+
+impl SnapshotLease {
+    /// Refresh the heartbeat, re-publishing the lease if a writer expired it.
+    pub fn renew(&mut self) -> Result<()> {
+        self.record.last_heartbeat = current_timestamp()?;
+        if !self.path.exists() {
+            self.file = open_lease_file(&self.path)?;
+        }
+        write_lease(&mut self.file, &self.record)
+    }
+
+    /// Move the lease to the footer the reader actually loaded.
+    pub fn pin(&mut self, generation: u64, footer_offset: u64) -> Result<()> {
+        self.record.generation = generation;
+        self.record.footer_offset = footer_offset;
+        self.renew()
+    }
+}
+
+impl Drop for SnapshotLease {
+    fn drop(&mut self) {
+        let _ = fs2::FileExt::unlock(self.file.file());
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Identity of the on-disk file version `file` refers to.
+///
+/// Copy-on-write rewrites swap a new inode in at the path, so unix keys use the inode.
+/// Elsewhere the key falls back to the canonical path, which treats every snapshot of the
+/// path as pinning the current version.
+pub fn snapshot_key(file: &std::fs::File, path: &Path) -> Result<String> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        let _ = path;
+        let metadata = file.metadata()?;
+        Ok(format!(
+            "unix-{:016x}-{:016x}",
+            metadata.dev(),
+            metadata.ino()
+        ))
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = file;
+        let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let digest = blake3::hash(canonical.to_string_lossy().as_bytes());
+        Ok(format!("path-{}", &digest.to_hex()[..32]))
+    }
+}
+
+fn snapshot_dir(key: &str) -> Result<PathBuf> {
+    let dir = registry_root()?.join(SNAPSHOT_SUBDIR).join(key);
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn open_lease_file(path: &Path) -> Result<File> {
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)?;
+    fs2::FileExt::lock_exclusive(file.file())?;
+    Ok(file)
+}
+
+fn write_lease(file: &mut File, record: &SnapshotRecord) -> Result<()> {
+    file.set_len(0)?;
+    file.seek(SeekFrom::Start(0))?;
+    serde_json::to_writer(&mut *file, record).map_err(io::Error::other)?;
+    file.flush()?;
+    Ok(())
+}
+
+/// Publish a snapshot record under `key` and hold its lease until the guard drops.
+pub fn register_snapshot(key: &str, record: &SnapshotRecord) -> Result<SnapshotLease> {
+    static NEXT_LEASE: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+    let sequence = NEXT_LEASE.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let path = snapshot_dir(key)?.join(format!("{}-{sequence}.json", record.pid));
+    let mut file = open_lease_file(&path)?;
+    write_lease(&mut file, record)?;
+    Ok(SnapshotLease {
+        path,
+        file,
+        record: record.clone(),
+    })
+}
+
+/// Snapshot records still held by live readers under `key`.
+///
+/// Records whose lease is no longer locked belong to exited readers, and records whose
+/// heartbeat is older than `ttl` belong to readers that stopped renewing; both are removed.
+pub fn live_snapshots(key: &str, ttl: Duration) -> Result<Vec<SnapshotRecord>> {
+    let dir = registry_root()?.join(SNAPSHOT_SUBDIR).join(key);
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut live = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+        let file = match File::open(&path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == ErrorKind::NotFound => continue,
+            Err(err) => return Err(err.into()),
+        };
+        match fs2::FileExt::try_lock_shared(file.file()) {
+            Ok(()) => {
+                // Nobody holds the lease: the reader is gone.
+                let _ = fs2::FileExt::unlock(file.file());
+                let _ = fs::remove_file(&path);
+            }
+            Err(err) if err.kind() == ErrorKind::WouldBlock => {
+                let Ok(record) = serde_json::from_reader::<_, SnapshotRecord>(file) else {
+                    // A held lease whose record is still being written counts as live.
+                    live.push(SnapshotRecord::default());
+                    continue;
+                };
+                if heartbeat_expired(&record.last_heartbeat, ttl) {
+                    let _ = fs::remove_file(&path);
+                } else {
+                    live.push(record);
+                }
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+    Ok(live)
+}
+
+fn heartbeat_expired(last_heartbeat: &str, ttl: Duration) -> bool {
+    match OffsetDateTime::parse(last_heartbeat, &Rfc3339) {
+        Ok(last) => {
+            Duration::try_from(OffsetDateTime::now_utc() - last).is_ok_and(|elapsed| elapsed > ttl)
+        }
+        // Leases written before heartbeats existed only rely on their lock.
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expired_snapshot_lease_is_dropped_until_renewed() {
+        let key = format!("test-expiry-{}", std::process::id());
+        let record = SnapshotRecord {
+            pid: std::process::id(),
+            generation: 3,
+            last_heartbeat: "2000-01-01T00:00:00Z".to_string(),
+            ..SnapshotRecord::default()
+        };
+        let mut lease = register_snapshot(&key, &record).unwrap();
+        let ttl = Duration::from_secs(60);
+
+        assert!(live_snapshots(&key, ttl).unwrap().is_empty());
+        lease.renew().unwrap();
+        let live = live_snapshots(&key, ttl).unwrap();
+        assert_eq!(live.len(), 1);
+        assert_eq!(live[0].generation, 3);
+
+        lease.pin(5, 4096).unwrap();
+        let live = live_snapshots(&key, ttl).unwrap();
+        assert_eq!((live[0].generation, live[0].footer_offset), (5, 4096));
+
+        drop(lease);
+        assert!(live_snapshots(&key, ttl).unwrap().is_empty());
+        let _ = fs::remove_dir(snapshot_dir(&key).unwrap());
+    }
+}
@@ -236,6 +236,9 @@ impl<'a> ReplayEngine<'a> {
                             as_of_frame: None,
                             as_of_ts: None,
                             no_sketch: false,
+                            reranker: None,
+                            filter: None,
+                            fusion: None,
                         };
                         match self.mem.search(search_request) {
                             Ok(response) => {
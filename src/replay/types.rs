@@ -4,7 +4,7 @@
 //! and replaying agent sessions in a deterministic manner.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use uuid::Uuid;
 
 /// Magic bytes for replay segment identification
@@ -344,6 +344,61 @@ impl ReplaySession {
         self.checkpoints.push(checkpoint);
     }
 
+    /// Blank the previews and hashes of every action that touched one of `frame_ids`, so
+    /// the session keeps its shape without retaining purged content. Returns the number of
+    /// actions redacted.
+    pub fn redact_frames(&mut self, frame_ids: &BTreeSet<u64>) -> usize {
+        let mut redacted = 0;
+        for action in &mut self.actions {
+            let named = match &action.action_type {
+                ActionType::Put { frame_id }
+                | ActionType::Update { frame_id }
+                | ActionType::Delete { frame_id } => frame_ids.contains(frame_id),
+                ActionType::PutMany { frame_ids: ids, .. } => {
+                    ids.iter().any(|id| frame_ids.contains(id))
+                }
+                _ => false,
+            };
+            if !named
+                && !action
+                    .affected_frames
+                    .iter()
+                    .any(|id| frame_ids.contains(id))
+            {
+                continue;
+            }
+            action.input_hash = [0; 32];
+            action.output_hash = [0; 32];
+            action.input_preview = String::from("[purged]");
+            action.output_preview = String::from("[purged]");
+            redacted += 1;
+        }
+        redacted
+    }
+
+    /// Rewrite the frame ids recorded by actions and checkpoints through `map`, as when the
+    /// session moves to another memory with its frames. Ids absent from `map` are kept.
+    pub fn remap_frames(&mut self, map: &BTreeMap<u64, u64>) {
+        let remap = |frame_id: &mut u64| {
+            if let Some(&mapped) = map.get(frame_id) {
+                *frame_id = mapped;
+            }
+        };
+        for action in &mut self.actions {
+            match &mut action.action_type {
+                ActionType::Put { frame_id }
+                | ActionType::Update { frame_id }
+                | ActionType::Delete { frame_id } => remap(frame_id),
+                ActionType::PutMany { frame_ids, .. } => frame_ids.iter_mut().for_each(remap),
+                _ => {}
+            }
+            action.affected_frames.iter_mut().for_each(remap);
+        }
+        for checkpoint in &mut self.checkpoints {
+            checkpoint.snapshot.frame_ids.iter_mut().for_each(remap);
+        }
+    }
+
     /// End the session
     pub fn end(&mut self) {
         if self.ended_secs.is_none() {
@@ -0,0 +1,648 @@
+//! Concrete `Reranker` implementations.
+//!
+//! - [`Bm25Reranker`]: rescoring with BM25 statistics computed over the candidate set.
+//!   Always available, no models required.
+//! - `CrossEncoderReranker`: scores query/document pairs with a local ONNX
+//!   cross-encoder (MS MARCO MiniLM by default). Requires the `vec` feature.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use memvid_core::{Bm25Reranker, Reranker, RerankerConfig, RerankerDocument};
+//!
+//! let reranker = Bm25Reranker::new(RerankerConfig::default());
+//! let docs = vec![RerankerDocument::new(1, "Berlin is the capital of Germany")];
+//! let results = reranker.rerank("capital of germany", &docs, 5)?;
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use crate::error::Result;
+use crate::types::reranker::{Reranker, RerankerConfig, RerankerDocument, RerankerResult};
+
+/// Default BM25 term-frequency saturation parameter.
+pub const BM25_K1: f32 = 1.2;
+/// Default BM25 length-normalization parameter.
+pub const BM25_B: f32 = 0.75;
+
+// ============================================================================
+// BM25 Reranker
+// ============================================================================
+
+/// Reranker that rescores candidates with BM25.
+///
+/// Document frequencies and average length are computed over the candidate set,
+/// so scores are comparable across candidates of a single query but not across
+/// queries. Scores are normalized to 0.0-1.0 relative to the best candidate.
+#[derive(Debug, Clone)]
+pub struct Bm25Reranker {
+    config: RerankerConfig,
+    k1: f32,
+    b: f32,
+}
+
+impl Default for Bm25Reranker {
+    fn default() -> Self {
+        Self::new(RerankerConfig::default())
+    }
+}
+
+impl Bm25Reranker {
+    /// Create a BM25 reranker with the default `k1`/`b` parameters.
+    #[must_use]
+    pub fn new(config: RerankerConfig) -> Self {
+        Self {
+            config,
+            k1: BM25_K1,
+            b: BM25_B,
+        }
+    }
+
+    /// Override the `k1` and `b` parameters.
+    #[must_use]
+    pub fn with_params(mut self, k1: f32, b: f32) -> Self {
+        self.k1 = k1;
+        self.b = b;
+        self
+    }
+
+    /// Access the configuration.
+    #[must_use]
+    pub fn config(&self) -> &RerankerConfig {
+        &self.config
+    }
+
+    fn document_text<'a>(&self, document: &'a RerankerDocument) -> std::borrow::Cow<'a, str> {
+        match document.metadata.as_deref() {
+            Some(metadata) if self.config.use_metadata => {
+                std::borrow::Cow::Owned(format!("{metadata} {}", document.text))
+            }
+            _ => std::borrow::Cow::Borrowed(document.text.as_str()),
+        }
+    }
+}
+
+impl Reranker for Bm25Reranker {
+    fn kind(&self) -> &'static str {
+        "bm25"
+    }
+
+    fn rerank(
+        &self,
+        query: &str,
+        documents: &[RerankerDocument],
+        top_k: usize,
+    ) -> Result<Vec<RerankerResult>> {
+        let candidates = &documents[..documents.len().min(self.config.max_candidates)];
+        let query_terms: Vec<String> = tokenize(query)
+            .into_iter()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        if candidates.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let tokenized: Vec<Vec<String>> = candidates
+            .iter()
+            .map(|doc| tokenize(&self.document_text(doc)))
+            .collect();
+        let doc_count = tokenized.len() as f32;
+        let avg_len = tokenized.iter().map(Vec::len).sum::<usize>() as f32 / doc_count;
+
+        let mut doc_freq: HashMap<&str, usize> = HashMap::new();
+        for tokens in &tokenized {
+            let unique: HashSet<&str> = tokens.iter().map(String::as_str).collect();
+            for term in &query_terms {
+                if unique.contains(term.as_str()) {
+                    *doc_freq.entry(term.as_str()).or_insert(0) += 1;
+                }
+            }
+        }
+
+        let raw_scores: Vec<f32> = tokenized
+            .iter()
+            .map(|tokens| {
+                let len = tokens.len() as f32;
+                let mut term_freq: HashMap<&str, usize> = HashMap::new();
+                for token in tokens {
+                    *term_freq.entry(token.as_str()).or_insert(0) += 1;
+                }
+                query_terms
+                    .iter()
+                    .map(|term| {
+                        let tf = term_freq.get(term.as_str()).copied().unwrap_or(0) as f32;
+                        if tf == 0.0 {
+                            return 0.0;
+                        }
+                        let df = doc_freq.get(term.as_str()).copied().unwrap_or(0) as f32;
+                        let idf = ((doc_count - df + 0.5) / (df + 0.5) + 1.0).ln();
+                        let norm = if avg_len > 0.0 {
+                            1.0 - self.b + self.b * len / avg_len
+                        } else {
+                            1.0
+                        };
+                        idf * tf * (self.k1 + 1.0) / (tf + self.k1 * norm)
+                    })
+                    .sum()
+            })
+            .collect();
+
+        let max_score = raw_scores.iter().copied().fold(0.0f32, f32::max);
+        let scores: Vec<f32> = raw_scores
+            .iter()
+            .map(|score| {
+                if max_score > f32::EPSILON {
+                    score / max_score
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+
+        Ok(rank_results(
+            candidates,
+            &scores,
+            top_k,
+            self.config.min_score,
+        ))
+    }
+}
+
+/// Lowercase alphanumeric tokenization shared by the rerankers.
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Sort candidates by score (stable on original rank) and assign new ranks.
+fn rank_results(
+    documents: &[RerankerDocument],
+    scores: &[f32],
+    top_k: usize,
+    min_score: f32,
+) -> Vec<RerankerResult> {
+    let mut results: Vec<RerankerResult> = documents
+        .iter()
+        .zip(scores)
+        .enumerate()
+        .map(|(idx, (doc, score))| RerankerResult {
+            id: doc.id,
+            score: *score,
+            original_rank: idx + 1,
+            new_rank: 0,
+        })
+        .filter(|result| result.score >= min_score)
+        .collect();
+    results.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.original_rank.cmp(&b.original_rank))
+    });
+    results.truncate(top_k);
+    for (idx, result) in results.iter_mut().enumerate() {
+        result.new_rank = idx + 1;
+    }
+    results
+}
+
+// ============================================================================
+// Cross-Encoder Reranker
+// ============================================================================
+
+#[cfg(feature = "vec")]
+pub use cross_encoder::{
+    CROSS_ENCODER_MODELS, CrossEncoderConfig, CrossEncoderModelInfo, CrossEncoderReranker,
+    default_cross_encoder_model_info, get_cross_encoder_model_info,
+};
+
+#[cfg(feature = "vec")]
+mod cross_encoder {
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+
+    use ndarray::Array;
+    use ort::session::{Session, builder::GraphOptimizationLevel};
+    use ort::value::Tensor;
+    use tokenizers::tokenizer::{Tokenizer, TruncationParams};
+    use tokenizers::{TruncationDirection, TruncationStrategy};
+
+    use super::rank_results;
+    use crate::types::reranker::{Reranker, RerankerConfig, RerankerDocument, RerankerResult};
+    use crate::{MemvidError, Result};
+
+    /// Available cross-encoder models with HuggingFace URLs
+    #[derive(Debug, Clone)]
+    pub struct CrossEncoderModelInfo {
+        /// Model identifier
+        pub name: &'static str,
+        /// HuggingFace URL for ONNX model
+        pub model_url: &'static str,
+        /// HuggingFace URL for tokenizer
+        pub tokenizer_url: &'static str,
+        /// Maximum token length for a query/document pair
+        pub max_tokens: usize,
+        /// Whether this is the default model
+        pub is_default: bool,
+    }
+
+    /// Cross-encoder model registry
+    pub static CROSS_ENCODER_MODELS: &[CrossEncoderModelInfo] = &[
+        // MS MARCO MiniLM-L6: Default, fast passage reranker
+        CrossEncoderModelInfo {
+            name: "ms-marco-MiniLM-L-6-v2",
+            model_url: "https://huggingface.co/cross-encoder/ms-marco-MiniLM-L-6-v2/resolve/main/onnx/model.onnx",
+            tokenizer_url: "https://huggingface.co/cross-encoder/ms-marco-MiniLM-L-6-v2/resolve/main/tokenizer.json",
+            max_tokens: 512,
+            is_default: true,
+        },
+        // MS MARCO MiniLM-L12: Slower, more accurate
+        CrossEncoderModelInfo {
+            name: "ms-marco-MiniLM-L-12-v2",
+            model_url: "https://huggingface.co/cross-encoder/ms-marco-MiniLM-L-12-v2/resolve/main/onnx/model.onnx",
+            tokenizer_url: "https://huggingface.co/cross-encoder/ms-marco-MiniLM-L-12-v2/resolve/main/tokenizer.json",
+            max_tokens: 512,
+            is_default: false,
+        },
+    ];
+
+    /// Get model info by name, defaults to ms-marco-MiniLM-L-6-v2
+    #[must_use]
+    pub fn get_cross_encoder_model_info(name: &str) -> &'static CrossEncoderModelInfo {
+        CROSS_ENCODER_MODELS
+            .iter()
+            .find(|m| m.name == name)
+            .unwrap_or_else(|| default_cross_encoder_model_info())
+    }
+
+    /// Get the default model info
+    #[must_use]
+    pub fn default_cross_encoder_model_info() -> &'static CrossEncoderModelInfo {
+        CROSS_ENCODER_MODELS
+            .iter()
+            .find(|m| m.is_default)
+            .unwrap_or(&CROSS_ENCODER_MODELS[0])
+    }
+
+    /// Configuration for the ONNX cross-encoder reranker
+    #[derive(Debug, Clone)]
+    pub struct CrossEncoderConfig {
+        /// Model name to use
+        pub model_name: String,
+        /// Directory containing `<model>.onnx` and `<model>_tokenizer.json`
+        pub models_dir: PathBuf,
+        /// Candidate and result limits
+        pub reranker: RerankerConfig,
+    }
+
+    impl Default for CrossEncoderConfig {
+        fn default() -> Self {
+            let models_dir = dirs_next::cache_dir()
+                .map(|p| p.join("memvid").join("rerank-models"))
+                .unwrap_or_else(|| PathBuf::from(".memvid-cache/rerank-models"));
+
+            Self {
+                model_name: default_cross_encoder_model_info().name.to_string(),
+                models_dir,
+                reranker: RerankerConfig::default(),
+            }
+        }
+    }
+
+    /// Reranker scoring query/document pairs with a local ONNX cross-encoder
+    ///
+    /// The model and tokenizer are loaded lazily on first use. Scores are the
+    /// sigmoid of the model's relevance logit.
+    pub struct CrossEncoderReranker {
+        config: CrossEncoderConfig,
+        model_info: &'static CrossEncoderModelInfo,
+        session: Mutex<Option<Session>>,
+        tokenizer: Mutex<Option<Tokenizer>>,
+    }
+
+    impl CrossEncoderReranker {
+        /// Create a new cross-encoder reranker
+        pub fn new(config: CrossEncoderConfig) -> Result<Self> {
+            let model_info = get_cross_encoder_model_info(&config.model_name);
+            Ok(Self {
+                config,
+                model_info,
+                session: Mutex::new(None),
+                tokenizer: Mutex::new(None),
+            })
+        }
+
+        /// Get model info
+        #[must_use]
+        pub fn model_info(&self) -> &'static CrossEncoderModelInfo {
+            self.model_info
+        }
+
+        /// Path of the ONNX model file
+        #[must_use]
+        pub fn model_path(&self) -> PathBuf {
+            self.config
+                .models_dir
+                .join(format!("{}.onnx", self.model_info.name))
+        }
+
+        /// Path of the tokenizer file
+        #[must_use]
+        pub fn tokenizer_path(&self) -> PathBuf {
+            self.config
+                .models_dir
+                .join(format!("{}_tokenizer.json", self.model_info.name))
+        }
+
+        fn missing_file_error(&self, path: &std::path::Path, url: &str) -> MemvidError {
+            MemvidError::RerankFailed {
+                reason: format!(
+                    "Cross-encoder file not found at {}. Please download manually:\n\
+                     mkdir -p {}\n\
+                     curl -L '{}' -o '{}'",
+                    path.display(),
+                    self.config.models_dir.display(),
+                    url,
+                    path.display()
+                )
+                .into(),
+            }
+        }
+
+        fn load(&self) -> Result<()> {
+            crate::text_embed::ensure_ort_init();
+
+            let mut session_guard = self
+                .session
+                .lock()
+                .map_err(|_| MemvidError::Lock("Failed to lock cross-encoder session".into()))?;
+            if session_guard.is_none() {
+                let model_path = self.model_path();
+                if !model_path.exists() {
+                    return Err(self.missing_file_error(&model_path, self.model_info.model_url));
+                }
+                let session = Session::builder()
+                    .and_then(|builder| {
+                        builder.with_optimization_level(GraphOptimizationLevel::Level3)
+                    })
+                    .and_then(|builder| builder.with_intra_threads(4))
+                    .and_then(|builder| builder.commit_from_file(&model_path))
+                    .map_err(|e| MemvidError::RerankFailed {
+                        reason: format!("Failed to load cross-encoder model: {e}").into(),
+                    })?;
+                *session_guard = Some(session);
+                tracing::info!(model = %self.model_info.name, "Cross-encoder model loaded");
+            }
+
+            let mut tokenizer_guard = self
+                .tokenizer
+                .lock()
+                .map_err(|_| MemvidError::Lock("Failed to lock cross-encoder tokenizer".into()))?;
+            if tokenizer_guard.is_none() {
+                let tokenizer_path = self.tokenizer_path();
+                if !tokenizer_path.exists() {
+                    return Err(
+                        self.missing_file_error(&tokenizer_path, self.model_info.tokenizer_url)
+                    );
+                }
+                let mut tokenizer = Tokenizer::from_file(&tokenizer_path).map_err(|e| {
+                    MemvidError::RerankFailed {
+                        reason: format!("Failed to load tokenizer: {e}").into(),
+                    }
+                })?;
+                tokenizer
+                    .with_truncation(Some(TruncationParams {
+                        max_length: self.model_info.max_tokens,
+                        strategy: TruncationStrategy::OnlySecond,
+                        stride: 0,
+                        direction: TruncationDirection::Right,
+                    }))
+                    .map_err(|e| MemvidError::RerankFailed {
+                        reason: format!("Failed to apply truncation config: {e}").into(),
+                    })?;
+                tokenizer.with_padding(None);
+                *tokenizer_guard = Some(tokenizer);
+            }
+            Ok(())
+        }
+
+        /// Score a single query/document pair (sigmoid of the relevance logit)
+        pub fn score_pair(&self, query: &str, document: &str) -> Result<f32> {
+            self.load()?;
+
+            let encoding = {
+                let tokenizer_guard = self
+                    .tokenizer
+                    .lock()
+                    .map_err(|_| MemvidError::Lock("Failed to lock tokenizer".into()))?;
+                let tokenizer =
+                    tokenizer_guard
+                        .as_ref()
+                        .ok_or_else(|| MemvidError::RerankFailed {
+                            reason: "Tokenizer not loaded".into(),
+                        })?;
+                tokenizer.encode((query, document), true).map_err(|e| {
+                    MemvidError::RerankFailed {
+                        reason: format!("Pair tokenization failed: {e}").into(),
+                    }
+                })?
+            };
+
+            let to_i64 = |values: &[u32]| values.iter().map(|v| i64::from(*v)).collect::<Vec<_>>();
+            let len = encoding.get_ids().len();
+            let tensor = |values: Vec<i64>| {
+                Array::from_shape_vec((1, len), values)
+                    .map_err(|e| MemvidError::RerankFailed {
+                        reason: format!("Failed to create input array: {e}").into(),
+                    })
+                    .and_then(|array| {
+                        Tensor::from_array(array).map_err(|e| MemvidError::RerankFailed {
+                            reason: format!("Failed to create input tensor: {e}").into(),
+                        })
+                    })
+            };
+            let input_ids = tensor(to_i64(encoding.get_ids()))?;
+            let attention_mask = tensor(to_i64(encoding.get_attention_mask()))?;
+            let token_type_ids = tensor(to_i64(encoding.get_type_ids()))?;
+
+            let mut session_guard = self
+                .session
+                .lock()
+                .map_err(|_| MemvidError::Lock("Failed to lock session".into()))?;
+            let session = session_guard
+                .as_mut()
+                .ok_or_else(|| MemvidError::RerankFailed {
+                    reason: "Session not loaded".into(),
+                })?;
+
+            let input_names: Vec<String> = session.inputs.iter().map(|i| i.name.clone()).collect();
+            let outputs = if input_names.len() >= 3 {
+                session.run(ort::inputs![
+                    input_names[0].clone() => input_ids,
+                    input_names[1].clone() => attention_mask,
+                    input_names[2].clone() => token_type_ids
+                ])
+            } else {
+                let first = input_names
+                    .first()
+                    .cloned()
+                    .unwrap_or_else(|| "input_ids".to_string());
+                let second = input_names
+                    .get(1)
+                    .cloned()
+                    .unwrap_or_else(|| "attention_mask".to_string());
+                session.run(ort::inputs![first => input_ids, second => attention_mask])
+            }
+            .map_err(|e| MemvidError::RerankFailed {
+                reason: format!("Cross-encoder inference failed: {e}").into(),
+            })?;
+
+            let (_shape, logits) =
+                outputs[0]
+                    .try_extract_tensor::<f32>()
+                    .map_err(|e| MemvidError::RerankFailed {
+                        reason: format!("Failed to extract logits: {e}").into(),
+                    })?;
+            let logit = logits
+                .first()
+                .copied()
+                .ok_or_else(|| MemvidError::RerankFailed {
+                    reason: "Cross-encoder returned no logits".into(),
+                })?;
+            Ok(1.0 / (1.0 + (-logit).exp()))
+        }
+    }
+
+    impl std::fmt::Debug for CrossEncoderReranker {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("CrossEncoderReranker")
+                .field("config", &self.config)
+                .field("model_info", &self.model_info)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl Reranker for CrossEncoderReranker {
+        fn kind(&self) -> &'static str {
+            "cross-encoder"
+        }
+
+        fn rerank(
+            &self,
+            query: &str,
+            documents: &[RerankerDocument],
+            top_k: usize,
+        ) -> Result<Vec<RerankerResult>> {
+            let candidates = &documents[..documents.len().min(self.config.reranker.max_candidates)];
+            let mut scores = Vec::with_capacity(candidates.len());
+            for document in candidates {
+                let text = match document.metadata.as_deref() {
+                    Some(metadata) if self.config.reranker.use_metadata => {
+                        format!("{metadata}\n{}", document.text)
+                    }
+                    _ => document.text.clone(),
+                };
+                scores.push(self.score_pair(query, &text)?);
+            }
+            Ok(rank_results(
+                candidates,
+                &scores,
+                top_k,
+                self.config.reranker.min_score,
+            ))
+        }
+
+        fn is_ready(&self) -> bool {
+            self.model_path().exists() && self.tokenizer_path().exists()
+        }
+
+        fn init(&mut self) -> Result<()> {
+            self.load()
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn docs() -> Vec<RerankerDocument> {
+        vec![
+            RerankerDocument::new(10, "The cafeteria serves lunch at noon every day"),
+            RerankerDocument::new(
+                11,
+                "Quarterly invoices are due on Friday. Late invoices incur fees.",
+            ),
+            RerankerDocument::with_metadata(12, "Payment schedule overview", "Invoices"),
+        ]
+    }
+
+    #[test]
+    fn bm25_promotes_matching_documents() {
+        let reranker = Bm25Reranker::default();
+        let results = reranker
+            .rerank("when are invoices due", &docs(), 10)
+            .unwrap();
+        assert_eq!(results[0].id, 11);
+        assert_eq!(results[0].original_rank, 2);
+        assert_eq!(results[0].new_rank, 1);
+        assert!((results[0].score - 1.0).abs() < f32::EPSILON);
+        assert!(results.iter().all(|r| (0.0..=1.0).contains(&r.score)));
+    }
+
+    #[test]
+    fn bm25_uses_metadata_when_configured() {
+        let plain = Bm25Reranker::default()
+            .rerank("invoices", &docs(), 10)
+            .unwrap();
+        let plain_score = plain.iter().find(|r| r.id == 12).unwrap().score;
+        assert!(plain_score.abs() < f32::EPSILON);
+
+        let with_metadata = Bm25Reranker::new(RerankerConfig::high_recall())
+            .rerank("invoices", &docs(), 10)
+            .unwrap();
+        let score = with_metadata.iter().find(|r| r.id == 12).unwrap().score;
+        assert!(score > 0.0);
+    }
+
+    #[test]
+    fn bm25_respects_top_k_and_min_score() {
+        let config = RerankerConfig {
+            min_score: 0.5,
+            ..RerankerConfig::default()
+        };
+        let results = Bm25Reranker::new(config)
+            .rerank("invoices due", &docs(), 1)
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, 11);
+    }
+
+    #[test]
+    fn bm25_handles_empty_input() {
+        let results = Bm25Reranker::default().rerank("query", &[], 5).unwrap();
+        assert!(results.is_empty());
+    }
+
+    #[cfg(feature = "vec")]
+    #[test]
+    fn cross_encoder_reports_missing_model() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = CrossEncoderConfig {
+            models_dir: dir.path().to_path_buf(),
+            ..CrossEncoderConfig::default()
+        };
+        let reranker = CrossEncoderReranker::new(config).unwrap();
+        assert_eq!(reranker.model_info().name, "ms-marco-MiniLM-L-6-v2");
+        assert!(!reranker.is_ready());
+        let err = reranker.rerank("query", &docs(), 3).unwrap_err();
+        assert!(err.to_string().contains("ms-marco-MiniLM-L-6-v2.onnx"));
+    }
+}
@@ -0,0 +1,372 @@
+//! JSON API behind the `memvid-serve` binary.
+//!
+//! [`Server`] owns one open [`Memvid`] (holding its file lock) and answers
+//! `(method, path, body)` requests with JSON, so services pay the open and index-decode
+//! cost once instead of per query. Bodies are the library's serde types, and writes go
+//! through [`Memvid::put_many`] as one WAL batch per request, committed before the
+//! response is returned. A server over a read-only snapshot refreshes it before each
+//! request, so it answers from the latest commit of whichever process writes the file.
+//! Requires the `serve` feature.
+//!
+//! | Method | Path | Body | Response |
+//! |--------|------|------|----------|
+//! | `GET`  | `/health` | | `{"status", "frames", "generation"}` |
+//! | `GET`  | `/stats` | | `Stats` |
+//! | `POST` | `/put` | `PutRequest` or an array of them | `{"frame_ids": [..]}` |
+//! | `POST` | `/search` | `SearchRequest`, optionally with `query_embedding` | `SearchResponse` |
+//! | `POST` | `/ask` | `AskRequest` | `AskResponse` |
+//! | `POST` | `/timeline` | `TimelineQuery` | `[TimelineEntry]` |
+//! | `GET`  | `/frames/{id}` | | `{"frame", "text"}` |
+//! | `GET`  | `/memory-cards/{entity}` | | `[MemoryCard]` |
+//! | `GET`  | `/memory-cards/{entity}/{slot}` | | `MemoryCard` |
+//! | `GET`  | `/mesh/stats` | | `LogicMeshStats` |
+//! | `GET`  | `/mesh/entities/{name}` | | `MeshNode` |
+//! | `GET`  | `/mesh/follow?start=..&link=..&hops=..` | | `[FollowResult]` |
+//!
+//! Failures are returned as `{"error": {"kind", "message"}}` with a 4xx or 5xx status.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::error::MemvidError;
+use crate::memvid::lifecycle::Memvid;
+use crate::types::{AskRequest, FrameId, PutRequest, SearchRequest, TimelineQuery, VecEmbedder};
+
+/// Status code and JSON body produced by [`Server::handle`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiResponse {
+    pub status: u16,
+    pub body: Value,
+}
+
+/// Body of `POST /search`: a search request plus an optional query embedding, which
+/// makes the search hybrid.
+#[derive(Debug, Deserialize)]
+struct SearchBody {
+    #[serde(flatten)]
+    request: SearchRequest,
+    #[serde(default)]
+    query_embedding: Option<Vec<f32>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum PutBody {
+    One(PutRequest),
+    Many(Vec<PutRequest>),
+}
+
+enum Failure {
+    Memvid(MemvidError),
+    BadRequest(String),
+    NotFound(String),
+    Internal(String),
+}
+
+impl From<MemvidError> for Failure {
+    fn from(err: MemvidError) -> Self {
+        Self::Memvid(err)
+    }
+}
+
+type Outcome = std::result::Result<Value, Failure>;
+
+/// Routes JSON API requests to an open memory.
+pub struct Server {
+    memvid: Memvid,
+}
+
+impl Server {
+    #[must_use]
+    pub fn new(memvid: Memvid) -> Self {
+        Self { memvid }
+    }
+
+    #[must_use]
+    pub fn memvid(&self) -> &Memvid {
+        &self.memvid
+    }
+
+    /// Release the memory, dropping the server.
+    #[must_use]
+    pub fn into_inner(self) -> Memvid {
+        self.memvid
+    }
+
+    /// Answer one request. `target` is the request path, optionally with a query string.
+    pub fn handle(&mut self, method: &str, target: &str, body: &[u8]) -> ApiResponse {
+        // A no-op for writable handles; snapshots re-pin when the generation moved.
+        if let Err(err) = self.memvid.refresh() {
+            return failure_response(&Failure::Memvid(err));
+        }
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let segments: Vec<String> = path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(percent_decode)
+            .collect();
+        let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+
+        let outcome = match (method, segments.as_slice()) {
+            ("GET", ["health"]) => Ok(json!({
+                "status": "ok",
+                "frames": self.memvid.toc.frames.len(),
+                "generation": self.memvid.generation(),
+            })),
+            ("GET", ["stats"]) => self
+                .memvid
+                .stats()
+                .map_err(Failure::from)
+                .and_then(|stats| to_json(&stats)),
+            ("POST", ["put"]) => self.put(body),
+            ("POST", ["search"]) => self.search(body),
+            ("POST", ["ask"]) => self.ask(body),
+            ("POST", ["timeline"]) => self.timeline(body),
+            ("GET", ["frames", id]) => self.frame(id),
+            ("GET", ["memory-cards", entity]) => to_json(&self.memvid.get_entity_memories(entity)),
+            ("GET", ["memory-cards", entity, slot]) => {
+                match self.memvid.get_current_memory(entity, slot) {
+                    Some(card) => to_json(card),
+                    None => Err(Failure::NotFound(format!(
+                        "no memory card for {entity}/{slot}"
+                    ))),
+                }
+            }
+            ("GET", ["mesh", "stats"]) => to_json(&self.memvid.logic_mesh_stats()),
+            ("GET", ["mesh", "entities", name]) => match self.memvid.find_entity(name) {
+                Some(node) => to_json(node),
+                None => Err(Failure::NotFound(format!("no entity named {name}"))),
+            },
+            ("GET", ["mesh", "follow"]) => self.follow(query),
+            _ => Err(Failure::NotFound(format!("no route for {method} {path}"))),
+        };
+
+        match outcome {
+            Ok(body) => ApiResponse { status: 200, body },
+            Err(failure) => failure_response(&failure),
+        }
+    }
+
+    fn put(&mut self, body: &[u8]) -> Outcome {
+        let requests = match parse::<PutBody>(body)? {
+            PutBody::One(request) => vec![request],
+            PutBody::Many(requests) => requests,
+        };
+        let frame_ids = self.memvid.put_many_committed(requests)?;
+        Ok(json!({ "frame_ids": frame_ids }))
+    }
+
+    fn search(&mut self, body: &[u8]) -> Outcome {
+        let SearchBody {
+            request,
+            query_embedding,
+        } = parse(body)?;
+        let response = self
+            .memvid
+            .search_with_embedding(request, query_embedding.as_deref())?;
+        to_json(&response)
+    }
+
+    fn ask(&mut self, body: &[u8]) -> Outcome {
+        let request: AskRequest = parse(body)?;
+        let response = self.memvid.ask(request, None::<&dyn VecEmbedder>)?;
+        to_json(&response)
+    }
+
+    fn timeline(&mut self, body: &[u8]) -> Outcome {
+        let query: TimelineQuery = if body.iter().all(u8::is_ascii_whitespace) {
+            TimelineQuery::default()
+        } else {
+            parse(body)?
+        };
+        to_json(&self.memvid.timeline(query)?)
+    }
+
+    fn frame(&mut self, id: &str) -> Outcome {
+        let frame_id: FrameId = id
+            .parse()
+            .map_err(|_| Failure::BadRequest(format!("invalid frame id: {id}")))?;
+        let frame = self.memvid.frame_by_id(frame_id)?;
+        let text = self.memvid.frame_text_by_id(frame_id)?;
+        Ok(json!({ "frame": frame, "text": text }))
+    }
+
+    fn follow(&self, query: &str) -> Outcome {
+        let mut start = None;
+        let mut link = None;
+        let mut hops = 1usize;
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = query_decode(value);
+            match key {
+                "start" => start = Some(value),
+                "link" => link = Some(value),
+                "hops" => {
+                    hops = value
+                        .parse()
+                        .map_err(|_| Failure::BadRequest(format!("invalid hops: {value}")))?;
+                }
+                _ => {}
+            }
+        }
+        let (Some(start), Some(link)) = (start, link) else {
+            return Err(Failure::BadRequest(
+                "follow requires `start` and `link` query parameters".to_string(),
+            ));
+        };
+        to_json(&self.memvid.follow(&start, &link, hops))
+    }
+}
+
+fn parse<T: DeserializeOwned>(body: &[u8]) -> std::result::Result<T, Failure> {
+    serde_json::from_slice(body).map_err(|err| Failure::BadRequest(format!("invalid body: {err}")))
+}
+
+fn to_json<T: Serialize + ?Sized>(value: &T) -> Outcome {
+    serde_json::to_value(value)
+        .map_err(|err| Failure::Internal(format!("response serialization failed: {err}")))
+}
+
+fn failure_response(failure: &Failure) -> ApiResponse {
+    let (status, kind, message) = match failure {
+        Failure::BadRequest(message) => (400, "BadRequest".to_string(), message.clone()),
+        Failure::NotFound(message) => (404, "NotFound".to_string(), message.clone()),
+        Failure::Internal(message) => (500, "Internal".to_string(), message.clone()),
+        Failure::Memvid(err) => (error_status(err), error_kind(err), err.to_string()),
+    };
+    ApiResponse {
+        status,
+        body: json!({ "error": { "kind": kind, "message": message } }),
+    }
+}
+
+fn error_status(err: &MemvidError) -> u16 {
+    match err {
+        MemvidError::FrameNotFound { .. } | MemvidError::FrameNotFoundByUri { .. } => 404,
+        MemvidError::Lock(_) | MemvidError::Locked(_) => 409,
+        MemvidError::InvalidQuery { .. }
+        | MemvidError::InvalidCursor { .. }
+        | MemvidError::InvalidFrame { .. }
+        | MemvidError::VecDimensionMismatch { .. }
+        | MemvidError::SchemaValidation { .. }
+        | MemvidError::CapacityExceeded { .. }
+        | MemvidError::LexNotEnabled
+        | MemvidError::VecNotEnabled
+        | MemvidError::ClipNotEnabled
+        | MemvidError::LogicMeshNotEnabled
+        | MemvidError::FeatureUnavailable { .. } => 400,
+        _ => 500,
+    }
+}
+
+/// Variant name of `err`, used as a stable machine-readable error kind.
+fn error_kind(err: &MemvidError) -> String {
+    format!("{err:?}")
+        .chars()
+        .take_while(char::is_ascii_alphanumeric)
+        .collect()
+}
+
+/// Decode a query-string value, where `+` also stands for a space.
+fn query_decode(raw: &str) -> String {
+    percent_decode(&raw.replace('+', " "))
+}
+
+/// Decode `%XX` escapes in a path segment; `+` is kept literally.
+fn percent_decode(raw: &str) -> String {
+    let bytes = raw.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'%' if index + 2 < bytes.len() => {
+                let hex = |byte: u8| char::from(byte).to_digit(16);
+                let escaped = hex(bytes[index + 1])
+                    .zip(hex(bytes[index + 2]))
+                    .and_then(|(high, low)| u8::try_from(high * 16 + low).ok());
+                if let Some(byte) = escaped {
+                    decoded.push(byte);
+                    index += 3;
+                    continue;
+                }
+                decoded.push(b'%');
+            }
+            byte => decoded.push(byte),
+        }
+        index += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn server(dir: &TempDir) -> Server {
+        let mut memvid = Memvid::create(dir.path().join("serve.mv2")).expect("create");
+        memvid.enable_lex().expect("enable lex");
+        Server::new(memvid)
+    }
+
+    #[test]
+    fn put_then_search_frame_and_timeline() {
+        let dir = TempDir::new().expect("tempdir");
+        let mut server = server(&dir);
+
+        let put = server.handle(
+            "POST",
+            "/put",
+            br#"[
+                {"title": "Rust", "label": "notes", "text": "Rust ownership and borrowing"},
+                {"title": "Go", "label": "notes", "text": "Go channels and goroutines"}
+            ]"#,
+        );
+        assert_eq!(put.status, 200, "{}", put.body);
+        let frame_ids = put.body["frame_ids"].as_array().expect("frame ids");
+        assert_eq!(frame_ids.len(), 2);
+
+        let search = server.handle(
+            "POST",
+            "/search",
+            br#"{"query": "ownership", "top_k": 5, "snippet_chars": 120}"#,
+        );
+        assert_eq!(search.status, 200, "{}", search.body);
+        assert_eq!(search.body["hits"].as_array().map(Vec::len), Some(1));
+
+        let first = frame_ids[0].as_u64().expect("frame id");
+        let frame = server.handle("GET", &format!("/frames/{first}"), b"");
+        assert_eq!(frame.status, 200, "{}", frame.body);
+        assert!(
+            frame.body["text"]
+                .as_str()
+                .is_some_and(|text| text.contains("ownership"))
+        );
+
+        let timeline = server.handle("POST", "/timeline", b"");
+        assert_eq!(timeline.body.as_array().map(Vec::len), Some(2));
+    }
+
+    #[test]
+    fn errors_map_to_statuses() {
+        let dir = TempDir::new().expect("tempdir");
+        let mut server = server(&dir);
+
+        assert_eq!(server.handle("GET", "/nope", b"").status, 404);
+        assert_eq!(server.handle("GET", "/frames/abc", b"").status, 400);
+        assert_eq!(server.handle("POST", "/search", b"{").status, 400);
+
+        let missing = server.handle("GET", "/frames/99", b"");
+        assert_eq!(missing.status, 404);
+        assert_eq!(missing.body["error"]["kind"], "FrameNotFound");
+
+        let follow = server.handle("GET", "/mesh/follow?start=Alice%20Smith", b"");
+        assert_eq!(follow.status, 400);
+        assert_eq!(percent_decode("Alice%20Smith+Jr"), "Alice Smith+Jr");
+        assert_eq!(query_decode("Alice%20Smith+Jr%2B"), "Alice Smith Jr+");
+        let literal = server.handle("GET", "/mesh/entities/C++", b"");
+        assert_eq!(literal.status, 404);
+        assert_eq!(literal.body["error"]["message"], "no entity named C++");
+    }
+}
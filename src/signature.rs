@@ -1,11 +1,12 @@
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
-use ed25519_dalek::{Signature, VerifyingKey};
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
 use serde::Serialize;
 use std::convert::TryInto;
 use uuid::Uuid;
 
 use crate::error::{MemvidError, Result};
+use crate::types::PurgeReceipt;
 
 const SIGNING_SCHEMA_VERSION: u8 = 1;
 
@@ -28,6 +29,12 @@ struct ModelSignaturePayload<'a> {
     size_bytes: u64,
 }
 
+#[derive(Serialize)]
+struct PurgeSignaturePayload<'a> {
+    version: u8,
+    receipt: &'a PurgeReceipt,
+}
+
 fn ticket_message_bytes(
     memory_id: &Uuid,
     issuer: &str,
@@ -103,6 +110,54 @@ pub fn verify_model_manifest(
         })
 }
 
+/// Canonical bytes a purge receipt signature covers: the receipt minus its signature.
+fn purge_message_bytes(receipt: &PurgeReceipt) -> Result<Vec<u8>> {
+    let unsigned = PurgeReceipt {
+        signer: None,
+        signature: None,
+        ..receipt.clone()
+    };
+    let payload = PurgeSignaturePayload {
+        version: SIGNING_SCHEMA_VERSION,
+        receipt: &unsigned,
+    };
+    serde_json::to_vec(&payload).map_err(|err| MemvidError::PurgeReceiptInvalid {
+        reason: format!("failed to serialize purge receipt: {err}").into_boxed_str(),
+    })
+}
+
+/// Sign `receipt` in place, recording the signer's public key alongside the signature.
+pub fn sign_purge_receipt(signing_key: &SigningKey, receipt: &mut PurgeReceipt) -> Result<()> {
+    let message = purge_message_bytes(receipt)?;
+    let signature = signing_key.sign(&message);
+    receipt.signer = Some(BASE64_STANDARD.encode(signing_key.verifying_key().as_bytes()));
+    receipt.signature = Some(BASE64_STANDARD.encode(signature.to_bytes()));
+    Ok(())
+}
+
+pub fn verify_purge_receipt(verifying_key: &VerifyingKey, receipt: &PurgeReceipt) -> Result<()> {
+    let encoded = receipt
+        .signature
+        .as_deref()
+        .ok_or_else(|| MemvidError::PurgeReceiptInvalid {
+            reason: "receipt is not signed".into(),
+        })?;
+    let bytes =
+        BASE64_STANDARD
+            .decode(encoded)
+            .map_err(|err| MemvidError::PurgeReceiptInvalid {
+                reason: format!("invalid base64 signature: {err}").into_boxed_str(),
+            })?;
+    let signature =
+        to_signature(&bytes).map_err(|reason| MemvidError::PurgeReceiptInvalid { reason })?;
+    let message = purge_message_bytes(receipt)?;
+    verifying_key
+        .verify_strict(&message, &signature)
+        .map_err(|_| MemvidError::PurgeReceiptInvalid {
+            reason: "purge receipt signature mismatch".into(),
+        })
+}
+
 fn to_signature(bytes: &[u8]) -> std::result::Result<Signature, Box<str>> {
     let array: [u8; 64] = bytes
         .try_into()
@@ -133,7 +188,6 @@ pub fn parse_ed25519_public_key_base64(encoded: &str) -> Result<VerifyingKey> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ed25519_dalek::{Signer, SigningKey};
 
     fn test_signing_key() -> SigningKey {
         let seed = [7u8; 32];
@@ -203,6 +257,26 @@ mod tests {
         assert_eq!(parsed.as_bytes(), verifying.as_bytes());
     }
 
+    #[test]
+    fn purge_receipt_roundtrip() {
+        let signing = test_signing_key();
+        let mut receipt = PurgeReceipt {
+            purged_at: 1_700_000_000,
+            generation: 3,
+            frames: Vec::new(),
+            artifacts: crate::types::PurgeArtifacts::default(),
+            vacuum: crate::types::VacuumReport::default(),
+            file_checksum: "00".repeat(32),
+            signer: None,
+            signature: None,
+        };
+        sign_purge_receipt(&signing, &mut receipt).unwrap();
+        verify_purge_receipt(&signing.verifying_key(), &receipt).unwrap();
+
+        receipt.generation += 1;
+        assert!(verify_purge_receipt(&signing.verifying_key(), &receipt).is_err());
+    }
+
     /// End-to-end test verifying the signature flow works correctly.
     /// Uses a test keypair (NOT production keys).
     #[test]
@@ -139,6 +139,7 @@ fn store_table_impl(
         dedup: false,
         instant_index: false,    // Tables are batch operations, commit at end
         extraction_budget_ms: 0, // No budget for table metadata
+        clip_pdf_pages: false,
     };
 
     let meta_frame_id = mem.next_frame_id();
@@ -211,6 +212,7 @@ fn store_table_impl(
             dedup: false,
             instant_index: false, // Tables are batch operations, commit at end
             extraction_budget_ms: 0, // No budget for table rows
+            clip_pdf_pages: false,
         };
 
         let should_embed = embed_rows && embedder.is_some();
@@ -68,6 +68,9 @@ mod tests {
                         as_of_frame: None,
                         as_of_ts: None,
                         no_sketch: false,
+                        reranker: None,
+                        filter: None,
+                        fusion: None,
                     })
                     .expect("search must succeed");
 
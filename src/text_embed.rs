@@ -127,7 +127,7 @@ static ORT_INIT: Lazy<()> = Lazy::new(|| {
 });
 
 /// Ensure ONNX Runtime is initialized (call this before any ONNX operations)
-fn ensure_ort_init() {
+pub(crate) fn ensure_ort_init() {
     Lazy::force(&ORT_INIT);
 }
 
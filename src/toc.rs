@@ -56,6 +56,78 @@ struct LegacyTocV2 {
     pub toc_checksum: [u8; 32],
 }
 
+/// TOC format without `retention` (pre-retention policies).
+/// Used for backwards compatibility with files written before retention rules existed.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct LegacyTocV3 {
+    pub toc_version: u64,
+    pub segments: Vec<SegmentMeta>,
+    pub frames: Vec<Frame>,
+    pub indexes: IndexManifests,
+    pub time_index: Option<TimeIndexManifest>,
+    pub temporal_track: Option<TemporalTrackManifest>,
+    pub memories_track: Option<crate::types::MemoriesTrackManifest>,
+    pub logic_mesh: Option<crate::types::LogicMeshManifest>,
+    pub sketch_track: Option<crate::types::SketchTrackManifest>,
+    pub segment_catalog: SegmentCatalog,
+    pub ticket_ref: TicketRef,
+    pub memory_binding: Option<MemoryBinding>,
+    pub replay_manifest: Option<crate::replay::ReplayManifest>,
+    pub enrichment_queue: crate::types::EnrichmentQueueManifest,
+    // Note: retention NOT present in this version
+    pub merkle_root: [u8; 32],
+    pub toc_checksum: [u8; 32],
+}
+
+/// TOC format without `generation_marks` (pre-incremental backups).
+/// Used for backwards compatibility with files written before backup marks existed.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct LegacyTocV4 {
+    pub toc_version: u64,
+    pub segments: Vec<SegmentMeta>,
+    pub frames: Vec<Frame>,
+    pub indexes: IndexManifests,
+    pub time_index: Option<TimeIndexManifest>,
+    pub temporal_track: Option<TemporalTrackManifest>,
+    pub memories_track: Option<crate::types::MemoriesTrackManifest>,
+    pub logic_mesh: Option<crate::types::LogicMeshManifest>,
+    pub sketch_track: Option<crate::types::SketchTrackManifest>,
+    pub segment_catalog: SegmentCatalog,
+    pub ticket_ref: TicketRef,
+    pub memory_binding: Option<MemoryBinding>,
+    pub replay_manifest: Option<crate::replay::ReplayManifest>,
+    pub enrichment_queue: crate::types::EnrichmentQueueManifest,
+    pub retention: crate::types::RetentionPolicy,
+    // Note: generation_marks NOT present in this version
+    pub merkle_root: [u8; 32],
+    pub toc_checksum: [u8; 32],
+}
+
+/// TOC format without `entity_aliases` (pre-entity resolution).
+/// Used for backwards compatibility with files written before alias tables existed.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct LegacyTocV5 {
+    pub toc_version: u64,
+    pub segments: Vec<SegmentMeta>,
+    pub frames: Vec<Frame>,
+    pub indexes: IndexManifests,
+    pub time_index: Option<TimeIndexManifest>,
+    pub temporal_track: Option<TemporalTrackManifest>,
+    pub memories_track: Option<crate::types::MemoriesTrackManifest>,
+    pub logic_mesh: Option<crate::types::LogicMeshManifest>,
+    pub sketch_track: Option<crate::types::SketchTrackManifest>,
+    pub segment_catalog: SegmentCatalog,
+    pub ticket_ref: TicketRef,
+    pub memory_binding: Option<MemoryBinding>,
+    pub replay_manifest: Option<crate::replay::ReplayManifest>,
+    pub enrichment_queue: crate::types::EnrichmentQueueManifest,
+    pub retention: crate::types::RetentionPolicy,
+    pub generation_marks: Vec<crate::types::GenerationMark>,
+    // Note: entity_aliases NOT present in this version
+    pub merkle_root: [u8; 32],
+    pub toc_checksum: [u8; 32],
+}
+
 impl From<LegacyTocV1> for Toc {
     fn from(legacy: LegacyTocV1) -> Self {
         Toc {
@@ -73,6 +145,9 @@ impl From<LegacyTocV1> for Toc {
             memory_binding: legacy.memory_binding,
             replay_manifest: None,                // Default for legacy files
             enrichment_queue: Default::default(), // Default for legacy files
+            retention: Default::default(),        // Default for legacy files
+            generation_marks: Vec::new(),
+            entity_aliases: Default::default(),
             merkle_root: legacy.merkle_root,
             toc_checksum: legacy.toc_checksum,
         }
@@ -96,6 +171,87 @@ impl From<LegacyTocV2> for Toc {
             memory_binding: legacy.memory_binding,
             replay_manifest: None, // Default for pre-replay files
             enrichment_queue: Default::default(), // Default for legacy files
+            retention: Default::default(), // Default for legacy files
+            generation_marks: Vec::new(),
+            entity_aliases: Default::default(),
+            merkle_root: legacy.merkle_root,
+            toc_checksum: legacy.toc_checksum,
+        }
+    }
+}
+
+impl From<LegacyTocV3> for Toc {
+    fn from(legacy: LegacyTocV3) -> Self {
+        Toc {
+            toc_version: legacy.toc_version,
+            segments: legacy.segments,
+            frames: legacy.frames,
+            indexes: legacy.indexes,
+            time_index: legacy.time_index,
+            temporal_track: legacy.temporal_track,
+            memories_track: legacy.memories_track,
+            logic_mesh: legacy.logic_mesh,
+            sketch_track: legacy.sketch_track,
+            segment_catalog: legacy.segment_catalog,
+            ticket_ref: legacy.ticket_ref,
+            memory_binding: legacy.memory_binding,
+            replay_manifest: legacy.replay_manifest,
+            enrichment_queue: legacy.enrichment_queue,
+            retention: Default::default(), // Default for pre-retention files
+            generation_marks: Vec::new(),
+            entity_aliases: Default::default(),
+            merkle_root: legacy.merkle_root,
+            toc_checksum: legacy.toc_checksum,
+        }
+    }
+}
+
+impl From<LegacyTocV4> for Toc {
+    fn from(legacy: LegacyTocV4) -> Self {
+        Toc {
+            toc_version: legacy.toc_version,
+            segments: legacy.segments,
+            frames: legacy.frames,
+            indexes: legacy.indexes,
+            time_index: legacy.time_index,
+            temporal_track: legacy.temporal_track,
+            memories_track: legacy.memories_track,
+            logic_mesh: legacy.logic_mesh,
+            sketch_track: legacy.sketch_track,
+            segment_catalog: legacy.segment_catalog,
+            ticket_ref: legacy.ticket_ref,
+            memory_binding: legacy.memory_binding,
+            replay_manifest: legacy.replay_manifest,
+            enrichment_queue: legacy.enrichment_queue,
+            retention: legacy.retention,
+            generation_marks: Vec::new(), // Default for pre-backup-marks files
+            entity_aliases: Default::default(),
+            merkle_root: legacy.merkle_root,
+            toc_checksum: legacy.toc_checksum,
+        }
+    }
+}
+
+impl From<LegacyTocV5> for Toc {
+    fn from(legacy: LegacyTocV5) -> Self {
+        Toc {
+            toc_version: legacy.toc_version,
+            segments: legacy.segments,
+            frames: legacy.frames,
+            indexes: legacy.indexes,
+            time_index: legacy.time_index,
+            temporal_track: legacy.temporal_track,
+            memories_track: legacy.memories_track,
+            logic_mesh: legacy.logic_mesh,
+            sketch_track: legacy.sketch_track,
+            segment_catalog: legacy.segment_catalog,
+            ticket_ref: legacy.ticket_ref,
+            memory_binding: legacy.memory_binding,
+            replay_manifest: legacy.replay_manifest,
+            enrichment_queue: legacy.enrichment_queue,
+            retention: legacy.retention,
+            generation_marks: legacy.generation_marks,
+            entity_aliases: Default::default(), // Default for pre-alias files
             merkle_root: legacy.merkle_root,
             toc_checksum: legacy.toc_checksum,
         }
@@ -121,6 +277,45 @@ impl Toc {
             return Ok(toc);
         }
 
+        // Try V5 format (with generation_marks, without entity_aliases)
+        if let Ok((legacy, bytes_read)) =
+            decode_from_slice::<LegacyTocV5, _>(bytes, canonical_config())
+        {
+            if bytes_read != bytes.len() {
+                return Err(MemvidError::InvalidToc {
+                    reason: "unexpected trailing bytes in V5 format".into(),
+                });
+            }
+            tracing::debug!("Decoded TOC V5 format (pre-entity-aliases)");
+            return Ok(legacy.into());
+        }
+
+        // Try V4 format (with retention, without generation_marks)
+        if let Ok((legacy, bytes_read)) =
+            decode_from_slice::<LegacyTocV4, _>(bytes, canonical_config())
+        {
+            if bytes_read != bytes.len() {
+                return Err(MemvidError::InvalidToc {
+                    reason: "unexpected trailing bytes in V4 format".into(),
+                });
+            }
+            tracing::debug!("Decoded TOC V4 format (pre-generation-marks)");
+            return Ok(legacy.into());
+        }
+
+        // Try V3 format (with replay_manifest, without retention)
+        if let Ok((legacy, bytes_read)) =
+            decode_from_slice::<LegacyTocV3, _>(bytes, canonical_config())
+        {
+            if bytes_read != bytes.len() {
+                return Err(MemvidError::InvalidToc {
+                    reason: "unexpected trailing bytes in V3 format".into(),
+                });
+            }
+            tracing::debug!("Decoded TOC V3 format (pre-retention)");
+            return Ok(legacy.into());
+        }
+
         // Try V2 format (with memories_track/logic_mesh, without replay_manifest)
         if let Ok((legacy, bytes_read)) =
             decode_from_slice::<LegacyTocV2, _>(bytes, canonical_config())
@@ -156,6 +351,21 @@ impl Toc {
         if let Ok((toc, _)) = decode_from_slice::<Toc, _>(bytes, canonical_config()) {
             return Ok(toc);
         }
+        // Try V5 format (with generation_marks, without entity_aliases)
+        if let Ok((legacy, _)) = decode_from_slice::<LegacyTocV5, _>(bytes, canonical_config()) {
+            tracing::debug!("Decoded TOC V5 format (pre-entity-aliases) in lenient mode");
+            return Ok(legacy.into());
+        }
+        // Try V4 format (with retention, without generation_marks)
+        if let Ok((legacy, _)) = decode_from_slice::<LegacyTocV4, _>(bytes, canonical_config()) {
+            tracing::debug!("Decoded TOC V4 format (pre-generation-marks) in lenient mode");
+            return Ok(legacy.into());
+        }
+        // Try V3 format (with replay_manifest, without retention)
+        if let Ok((legacy, _)) = decode_from_slice::<LegacyTocV3, _>(bytes, canonical_config()) {
+            tracing::debug!("Decoded TOC V3 format (pre-retention) in lenient mode");
+            return Ok(legacy.into());
+        }
         // Try V2 format (with memories_track/logic_mesh, without replay_manifest)
         if let Ok((legacy, _)) = decode_from_slice::<LegacyTocV2, _>(bytes, canonical_config()) {
             tracing::debug!("Decoded TOC V2 format (pre-replay_manifest) in lenient mode");
@@ -179,6 +389,27 @@ impl LegacyTocV1 {
     }
 }
 
+impl LegacyTocV5 {
+    /// Encode V5 TOC format for checksum verification.
+    fn encode(&self) -> Result<Vec<u8>> {
+        Ok(encode_to_vec(self, canonical_config())?)
+    }
+}
+
+impl LegacyTocV4 {
+    /// Encode V4 TOC format for checksum verification.
+    fn encode(&self) -> Result<Vec<u8>> {
+        Ok(encode_to_vec(self, canonical_config())?)
+    }
+}
+
+impl LegacyTocV3 {
+    /// Encode V3 TOC format for checksum verification.
+    fn encode(&self) -> Result<Vec<u8>> {
+        Ok(encode_to_vec(self, canonical_config())?)
+    }
+}
+
 impl LegacyTocV2 {
     /// Encode V2 TOC format for checksum verification.
     fn encode(&self) -> Result<Vec<u8>> {
@@ -207,6 +438,96 @@ impl Toc {
             return Ok(());
         }
 
+        // Try V5 format (without entity_aliases)
+        // Only try if no aliases are recorded (indicates pre-alias origin)
+        if self.entity_aliases.is_empty() {
+            let legacy_v5 = LegacyTocV5 {
+                toc_version: self.toc_version,
+                segments: self.segments.clone(),
+                frames: self.frames.clone(),
+                indexes: self.indexes.clone(),
+                time_index: self.time_index.clone(),
+                temporal_track: self.temporal_track.clone(),
+                memories_track: self.memories_track.clone(),
+                logic_mesh: self.logic_mesh.clone(),
+                sketch_track: self.sketch_track.clone(),
+                segment_catalog: self.segment_catalog.clone(),
+                ticket_ref: self.ticket_ref.clone(),
+                memory_binding: self.memory_binding.clone(),
+                replay_manifest: self.replay_manifest.clone(),
+                enrichment_queue: self.enrichment_queue.clone(),
+                retention: self.retention.clone(),
+                generation_marks: self.generation_marks.clone(),
+                merkle_root: self.merkle_root,
+                toc_checksum: [0u8; 32],
+            };
+            let v5_bytes = legacy_v5.encode()?;
+            let v5_digest = Self::calculate_checksum(&v5_bytes);
+            if v5_digest == self.toc_checksum {
+                tracing::debug!("TOC checksum verified using V5 format (pre-entity-aliases)");
+                return Ok(());
+            }
+        }
+
+        // Try V4 format (without generation_marks)
+        // Only try if no marks are recorded (indicates pre-backup-marks origin)
+        if self.generation_marks.is_empty() {
+            let legacy_v4 = LegacyTocV4 {
+                toc_version: self.toc_version,
+                segments: self.segments.clone(),
+                frames: self.frames.clone(),
+                indexes: self.indexes.clone(),
+                time_index: self.time_index.clone(),
+                temporal_track: self.temporal_track.clone(),
+                memories_track: self.memories_track.clone(),
+                logic_mesh: self.logic_mesh.clone(),
+                sketch_track: self.sketch_track.clone(),
+                segment_catalog: self.segment_catalog.clone(),
+                ticket_ref: self.ticket_ref.clone(),
+                memory_binding: self.memory_binding.clone(),
+                replay_manifest: self.replay_manifest.clone(),
+                enrichment_queue: self.enrichment_queue.clone(),
+                retention: self.retention.clone(),
+                merkle_root: self.merkle_root,
+                toc_checksum: [0u8; 32],
+            };
+            let v4_bytes = legacy_v4.encode()?;
+            let v4_digest = Self::calculate_checksum(&v4_bytes);
+            if v4_digest == self.toc_checksum {
+                tracing::debug!("TOC checksum verified using V4 format (pre-generation-marks)");
+                return Ok(());
+            }
+        }
+
+        // Try V3 format (without retention)
+        // Only try if no retention rules are set (indicates pre-retention origin)
+        if self.retention == crate::types::RetentionPolicy::default() {
+            let legacy_v3 = LegacyTocV3 {
+                toc_version: self.toc_version,
+                segments: self.segments.clone(),
+                frames: self.frames.clone(),
+                indexes: self.indexes.clone(),
+                time_index: self.time_index.clone(),
+                temporal_track: self.temporal_track.clone(),
+                memories_track: self.memories_track.clone(),
+                logic_mesh: self.logic_mesh.clone(),
+                sketch_track: self.sketch_track.clone(),
+                segment_catalog: self.segment_catalog.clone(),
+                ticket_ref: self.ticket_ref.clone(),
+                memory_binding: self.memory_binding.clone(),
+                replay_manifest: self.replay_manifest.clone(),
+                enrichment_queue: self.enrichment_queue.clone(),
+                merkle_root: self.merkle_root,
+                toc_checksum: [0u8; 32],
+            };
+            let v3_bytes = legacy_v3.encode()?;
+            let v3_digest = Self::calculate_checksum(&v3_bytes);
+            if v3_digest == self.toc_checksum {
+                tracing::debug!("TOC checksum verified using V3 format (pre-retention)");
+                return Ok(());
+            }
+        }
+
         // Try V2 format (with memories_track/logic_mesh, without replay_manifest)
         // Only try if replay_manifest is None (indicates pre-replay origin)
         if self.replay_manifest.is_none() {
@@ -369,6 +690,9 @@ mod tests {
             memory_binding: None,
             replay_manifest: None,
             enrichment_queue: Default::default(),
+            retention: Default::default(),
+            generation_marks: Vec::new(),
+            entity_aliases: Default::default(),
             merkle_root: [0x55; 32],
             toc_checksum: [0u8; 32],
         }
@@ -399,6 +723,112 @@ mod tests {
         matches!(err, MemvidError::ChecksumMismatch { .. });
     }
 
+    #[test]
+    fn decode_pre_retention_toc() {
+        let toc = sample_toc();
+        let legacy = LegacyTocV3 {
+            toc_version: toc.toc_version,
+            segments: toc.segments.clone(),
+            frames: toc.frames.clone(),
+            indexes: toc.indexes.clone(),
+            time_index: toc.time_index.clone(),
+            temporal_track: toc.temporal_track.clone(),
+            memories_track: None,
+            logic_mesh: None,
+            sketch_track: None,
+            segment_catalog: toc.segment_catalog.clone(),
+            ticket_ref: toc.ticket_ref.clone(),
+            memory_binding: None,
+            replay_manifest: None,
+            enrichment_queue: Default::default(),
+            merkle_root: toc.merkle_root,
+            toc_checksum: [0u8; 32],
+        };
+        let mut legacy_bytes = legacy.encode().expect("encode legacy toc");
+        let checksum = Toc::calculate_checksum(&legacy_bytes);
+        let legacy = LegacyTocV3 {
+            toc_checksum: checksum,
+            ..legacy
+        };
+        legacy_bytes = legacy.encode().expect("encode legacy toc");
+
+        let decoded = Toc::decode(&legacy_bytes).expect("decode legacy toc");
+        assert!(decoded.retention.is_empty());
+        assert_eq!(decoded.frames.len(), 2);
+        decoded.verify_checksum().expect("legacy checksum matches");
+    }
+
+    #[test]
+    fn decode_pre_generation_marks_toc() {
+        let toc = sample_toc();
+        let legacy = LegacyTocV4 {
+            toc_version: toc.toc_version,
+            segments: toc.segments.clone(),
+            frames: toc.frames.clone(),
+            indexes: toc.indexes.clone(),
+            time_index: toc.time_index.clone(),
+            temporal_track: toc.temporal_track.clone(),
+            memories_track: None,
+            logic_mesh: None,
+            sketch_track: None,
+            segment_catalog: toc.segment_catalog.clone(),
+            ticket_ref: toc.ticket_ref.clone(),
+            memory_binding: None,
+            replay_manifest: None,
+            enrichment_queue: Default::default(),
+            retention: Default::default(),
+            merkle_root: toc.merkle_root,
+            toc_checksum: [0u8; 32],
+        };
+        let checksum = Toc::calculate_checksum(&legacy.encode().expect("encode legacy toc"));
+        let legacy = LegacyTocV4 {
+            toc_checksum: checksum,
+            ..legacy
+        };
+        let legacy_bytes = legacy.encode().expect("encode legacy toc");
+
+        let decoded = Toc::decode(&legacy_bytes).expect("decode legacy toc");
+        assert!(decoded.generation_marks.is_empty());
+        assert_eq!(decoded.frames.len(), 2);
+        decoded.verify_checksum().expect("legacy checksum matches");
+    }
+
+    #[test]
+    fn decode_pre_entity_aliases_toc() {
+        let toc = sample_toc();
+        let legacy = LegacyTocV5 {
+            toc_version: toc.toc_version,
+            segments: toc.segments.clone(),
+            frames: toc.frames.clone(),
+            indexes: toc.indexes.clone(),
+            time_index: toc.time_index.clone(),
+            temporal_track: toc.temporal_track.clone(),
+            memories_track: None,
+            logic_mesh: None,
+            sketch_track: None,
+            segment_catalog: toc.segment_catalog.clone(),
+            ticket_ref: toc.ticket_ref.clone(),
+            memory_binding: None,
+            replay_manifest: None,
+            enrichment_queue: Default::default(),
+            retention: Default::default(),
+            generation_marks: Vec::new(),
+            merkle_root: toc.merkle_root,
+            toc_checksum: [0u8; 32],
+        };
+        let checksum = Toc::calculate_checksum(&legacy.encode().expect("encode legacy toc"));
+        let legacy = LegacyTocV5 {
+            toc_checksum: checksum,
+            ..legacy
+        };
+        let legacy_bytes = legacy.encode().expect("encode legacy toc");
+
+        let decoded = Toc::decode(&legacy_bytes).expect("decode legacy toc");
+        assert!(decoded.entity_aliases.is_empty());
+        assert_eq!(decoded.frames.len(), 2);
+        decoded.verify_checksum().expect("legacy checksum matches");
+    }
+
     #[test]
     fn reject_trailing_bytes() {
         let toc = stamp_checksum(sample_toc());
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 
 use super::adaptive::AdaptiveConfig;
 use super::common::FrameId;
+use super::filter::FilterExpr;
+use super::reranker::RerankerKind;
 #[cfg(feature = "temporal_track")]
 use super::search::SearchHitTemporal;
 use super::search::SearchResponse;
@@ -74,6 +76,12 @@ pub struct AskRequest {
     /// Adaptive retrieval configuration. When set, dynamically determines how many
     /// results to retrieve based on relevancy score distribution.
     pub adaptive: Option<AdaptiveConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Second-stage reranker applied to fused candidates before adaptive cutoff.
+    pub reranker: Option<RerankerKind>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Structured filter applied to both lexical and vector retrieval.
+    pub filter: Option<FilterExpr>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -0,0 +1,625 @@
+//! Entity aliases persisted in the TOC.
+//!
+//! Memory cards and Logic-Mesh nodes key entities by their lowercased name, so "Bob Smith",
+//! "Bob" and "bsmith@corp.com" start out as three entities. An [`EntityAliasTable`] maps
+//! each alias to the canonical entity it stands for, and lookups such as
+//! `Memvid::get_current_memory`, `Memvid::follow` and `GraphMatcher` expand a name to its
+//! whole alias group.
+//!
+//! Links come from `Memvid::merge_entities` or from the deterministic heuristics run by
+//! `Memvid::resolve_entities`. `Memvid::split_entity` removes a link and remembers the
+//! separated pair so the heuristics never propose it again.
+//!
+//! ```ignore
+//! mem.resolve_entities()?;                         // "bsmith@corp.com" -> "Bob Smith"
+//! mem.merge_entities("Bob Smith", "Robert")?;      // manual link
+//! let employer = mem.get_current_memory("bob", "employer");
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::common::FrameId;
+use super::logic_mesh::{EntityKind, LinkType, LogicMesh};
+use super::memories_track::MemoriesTrack;
+
+/// Frames an address must share with a person before co-occurrence alone links them.
+const CO_OCCURRENCE_MIN_FRAMES: usize = 3;
+
+/// Why an alias is linked to its canonical entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AliasSource {
+    /// Linked by `Memvid::merge_entities`.
+    Manual,
+    /// A Logic-Mesh `email` edge joins the address to a person.
+    EmailEdge,
+    /// The address's local part spells the person's name (`bsmith`, `bob.smith`).
+    EmailName,
+    /// Exactly one person shares several frames with the address, or shares a frame and
+    /// has a name the local part carries (`carol.ops` for "Carol Jones").
+    CoOccurrence,
+    /// The name's words all appear in exactly one longer name ("Bob" in "Bob Smith").
+    NameContainment,
+}
+
+/// One alias and the canonical entity it resolves to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntityAlias {
+    /// Normalized (trimmed, lowercased) alias name.
+    pub alias: String,
+    /// Display name of the canonical entity.
+    pub canonical: String,
+    pub source: AliasSource,
+}
+
+/// Alias-to-canonical mapping for entity names.
+///
+/// Groups are flat: a canonical entity is never itself an alias, so every name resolves in
+/// one lookup. Names are compared after trimming and lowercasing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntityAliasTable {
+    /// Sorted by `alias`.
+    aliases: Vec<EntityAlias>,
+    /// Normalized name pairs kept apart by `split`, smaller name first.
+    separated: Vec<(String, String)>,
+}
+
+impl EntityAliasTable {
+    /// The key names are compared by, matching how memory cards and mesh nodes are keyed.
+    #[must_use]
+    pub fn normalize(name: &str) -> String {
+        name.trim().to_lowercase()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.aliases.is_empty() && self.separated.is_empty()
+    }
+
+    /// Number of aliases.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.aliases.len()
+    }
+
+    /// All aliases, ordered by alias name.
+    pub fn iter(&self) -> impl Iterator<Item = &EntityAlias> {
+        self.aliases.iter()
+    }
+
+    fn position(&self, key: &str) -> std::result::Result<usize, usize> {
+        self.aliases
+            .binary_search_by(|entry| entry.alias.as_str().cmp(key))
+    }
+
+    /// The canonical entity `name` is an alias of, if any.
+    #[must_use]
+    pub fn canonical(&self, name: &str) -> Option<&str> {
+        self.position(&Self::normalize(name))
+            .ok()
+            .map(|index| self.aliases[index].canonical.as_str())
+    }
+
+    /// The canonical entity for `name`, or `name` itself when it is not an alias.
+    #[must_use]
+    pub fn resolve<'a>(&'a self, name: &'a str) -> &'a str {
+        self.canonical(name).unwrap_or(name)
+    }
+
+    /// Every name in `name`'s group: the canonical entity first, then its aliases.
+    #[must_use]
+    pub fn group(&self, name: &str) -> Vec<String> {
+        let root = self.resolve(name).trim();
+        let root_key = Self::normalize(root);
+        let mut names = vec![root.to_string()];
+        names.extend(
+            self.aliases
+                .iter()
+                .filter(|entry| Self::normalize(&entry.canonical) == root_key)
+                .map(|entry| entry.alias.clone()),
+        );
+        names
+    }
+
+    /// Whether `split` separated these two names.
+    #[must_use]
+    pub fn is_separated(&self, a: &str, b: &str) -> bool {
+        let pair = separated_pair(&Self::normalize(a), &Self::normalize(b));
+        self.separated.binary_search(&pair).is_ok()
+    }
+
+    /// Link `alias` to `canonical`, returning whether the table changed.
+    ///
+    /// `canonical` is resolved to the head of its group first. A [`AliasSource::Manual`]
+    /// link always wins: it merges `alias`'s whole group into `canonical`'s, promotes
+    /// `canonical` to the head when both names already share a group, and forgets an earlier
+    /// split of the pair. Any other source only links names that are not aliased yet and
+    /// were never separated.
+    pub fn link(&mut self, alias: &str, canonical: &str, source: AliasSource) -> bool {
+        let alias_key = Self::normalize(alias);
+        let canonical = canonical.trim();
+        let canonical_key = Self::normalize(canonical);
+        if alias_key.is_empty() || canonical_key.is_empty() || alias_key == canonical_key {
+            return false;
+        }
+
+        if source == AliasSource::Manual {
+            let pair = separated_pair(&alias_key, &canonical_key);
+            self.separated.retain(|kept| *kept != pair);
+            let alias_root = Self::normalize(self.resolve(&alias_key));
+            let root = self.resolve(canonical).to_string();
+            let root_key = Self::normalize(&root);
+            if alias_root != root_key {
+                self.absorb(&alias_root, &root, source);
+                return true;
+            }
+            if root_key == canonical_key {
+                return false;
+            }
+            if let Ok(index) = self.position(&canonical_key) {
+                self.aliases.remove(index);
+            }
+            self.absorb(&root_key, canonical, source);
+            return true;
+        }
+
+        if self.position(&alias_key).is_ok() {
+            return false;
+        }
+        let root = self.resolve(canonical).to_string();
+        let root_key = Self::normalize(&root);
+        if root_key == alias_key
+            || self.is_separated(&alias_key, &root_key)
+            || self.is_separated(&alias_key, &canonical_key)
+        {
+            return false;
+        }
+        self.absorb(&alias_key, &root, source);
+        true
+    }
+
+    /// Move the group headed by `old_root` under `root` and make `old_root` an alias of it.
+    fn absorb(&mut self, old_root: &str, root: &str, source: AliasSource) {
+        for entry in &mut self.aliases {
+            if Self::normalize(&entry.canonical) == old_root {
+                entry.canonical = root.to_string();
+            }
+        }
+        let entry = EntityAlias {
+            alias: old_root.to_string(),
+            canonical: root.to_string(),
+            source,
+        };
+        match self.position(old_root) {
+            Ok(index) => self.aliases[index] = entry,
+            Err(index) => self.aliases.insert(index, entry),
+        }
+    }
+
+    /// Detach `name` from its group, returning the links removed.
+    ///
+    /// An alias is unlinked from its canonical entity; a canonical entity loses all of its
+    /// aliases. Every removed pair is remembered so heuristics do not link it again.
+    pub fn split(&mut self, name: &str) -> Vec<EntityAlias> {
+        let key = Self::normalize(name);
+        let removed = if let Ok(index) = self.position(&key) {
+            vec![self.aliases.remove(index)]
+        } else {
+            let (removed, kept) = std::mem::take(&mut self.aliases)
+                .into_iter()
+                .partition(|entry| Self::normalize(&entry.canonical) == key);
+            self.aliases = kept;
+            removed
+        };
+        for entry in &removed {
+            let pair = separated_pair(&entry.alias, &Self::normalize(&entry.canonical));
+            if let Err(index) = self.separated.binary_search(&pair) {
+                self.separated.insert(index, pair);
+            }
+        }
+        removed
+    }
+
+    /// Link aliases found by deterministic heuristics over the mesh and memory cards.
+    ///
+    /// Rules run in order, each seeing the links made before it:
+    /// 1. an address joined to exactly one person by an `email` edge;
+    /// 2. an address whose local part spells exactly one person's name (`bsmith`,
+    ///    `bob.smith`, `smithb`), ties broken by shared frames;
+    /// 3. a person name whose words all appear in exactly one longer person name, ties
+    ///    broken by shared frames;
+    /// 4. an address appearing in frames with exactly one person.
+    ///
+    /// Candidates are visited in name order, so the same memory always yields the same
+    /// links. Returns the links added.
+    pub fn infer(&mut self, mesh: &LogicMesh, memories: &MemoriesTrack) -> Vec<EntityAlias> {
+        let candidates = collect_candidates(mesh, memories);
+        let emails: Vec<&str> = candidates
+            .iter()
+            .filter(|(key, candidate)| candidate.is_email(key))
+            .map(|(key, _)| key.as_str())
+            .collect();
+        let people: Vec<&str> = candidates
+            .iter()
+            .filter(|(key, candidate)| candidate.is_person(key))
+            .map(|(key, _)| key.as_str())
+            .collect();
+        let mut added = Vec::new();
+
+        let mut edge_people: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+        let names: BTreeMap<u64, String> = mesh
+            .nodes
+            .iter()
+            .map(|node| (node.id, Self::normalize(&node.canonical_name)))
+            .collect();
+        for edge in mesh
+            .edges
+            .iter()
+            .filter(|edge| edge.link == LinkType::Email)
+        {
+            let (Some(from), Some(to)) = (names.get(&edge.from_node), names.get(&edge.to_node))
+            else {
+                continue;
+            };
+            for (address, person) in [(from, to), (to, from)] {
+                if let (Some(&address), Some(&person)) = (
+                    emails.iter().find(|key| **key == address.as_str()),
+                    people.iter().find(|key| **key == person.as_str()),
+                ) {
+                    edge_people.entry(address).or_default().insert(person);
+                }
+            }
+        }
+        for (address, linked) in &edge_people {
+            if let [person] = linked.iter().collect::<Vec<_>>()[..] {
+                self.link_inferred(
+                    address,
+                    &candidates[*person].display,
+                    AliasSource::EmailEdge,
+                    &mut added,
+                );
+            }
+        }
+
+        for &address in &emails {
+            if self.canonical(address).is_some() {
+                continue;
+            }
+            let spelled = local_part(address);
+            let matches: Vec<&str> = people
+                .iter()
+                .copied()
+                .filter(|person| name_spellings(person).contains(&spelled))
+                .collect();
+            if let Some(person) = self.pick(address, &matches, &candidates) {
+                let display = &candidates[person].display;
+                self.link_inferred(address, display, AliasSource::EmailName, &mut added);
+            }
+        }
+
+        for &short in &people {
+            if self.canonical(short).is_some() {
+                continue;
+            }
+            let words: Vec<&str> = short.split_whitespace().collect();
+            let matches: Vec<&str> = people
+                .iter()
+                .copied()
+                .filter(|long| {
+                    let long_words: Vec<&str> = long.split_whitespace().collect();
+                    long_words.len() > words.len()
+                        && words.iter().all(|word| long_words.contains(word))
+                })
+                .collect();
+            if let Some(long) = self.pick(short, &matches, &candidates) {
+                let display = &candidates[long].display;
+                self.link_inferred(short, display, AliasSource::NameContainment, &mut added);
+            }
+        }
+
+        for &address in &emails {
+            if self.canonical(address).is_some() {
+                continue;
+            }
+            let frames = &candidates[address].frames;
+            let spelled = local_part(address);
+            let mut roots: BTreeMap<String, &str> = BTreeMap::new();
+            for &person in &people {
+                // A shared mailbox (`ops@`) sits next to whoever wrote to it, so one frame
+                // only counts when the local part also carries the person's name.
+                let shared = candidates[person].frames.intersection(frames).count();
+                let named = || {
+                    name_words(person)
+                        .iter()
+                        .any(|word| word.chars().count() >= 3 && spelled.contains(word.as_str()))
+                };
+                if shared >= CO_OCCURRENCE_MIN_FRAMES || (shared > 0 && named()) {
+                    roots
+                        .entry(Self::normalize(self.resolve(person)))
+                        .or_insert(person);
+                }
+            }
+            if let [person] = roots.into_values().collect::<Vec<_>>()[..] {
+                let display = &candidates[person].display;
+                self.link_inferred(address, display, AliasSource::CoOccurrence, &mut added);
+            }
+        }
+        added
+    }
+
+    fn link_inferred(
+        &mut self,
+        alias: &str,
+        canonical: &str,
+        source: AliasSource,
+        added: &mut Vec<EntityAlias>,
+    ) {
+        if self.link(alias, canonical, source) {
+            added.push(EntityAlias {
+                alias: alias.to_string(),
+                canonical: self.resolve(canonical).to_string(),
+                source,
+            });
+        }
+    }
+
+    /// The single group among `matches`, or the single one sharing a frame with `name`.
+    fn pick<'a>(
+        &self,
+        name: &str,
+        matches: &[&'a str],
+        candidates: &BTreeMap<String, Candidate>,
+    ) -> Option<&'a str> {
+        let mut roots: BTreeMap<String, &'a str> = BTreeMap::new();
+        for &candidate in matches {
+            roots
+                .entry(Self::normalize(self.resolve(candidate)))
+                .or_insert(candidate);
+        }
+        if roots.len() == 1 {
+            return roots.into_values().next();
+        }
+        let frames = &candidates[name].frames;
+        let sharing: Vec<&'a str> = roots
+            .into_values()
+            .filter(|candidate| !candidates[*candidate].frames.is_disjoint(frames))
+            .collect();
+        match sharing[..] {
+            [only] => Some(only),
+            _ => None,
+        }
+    }
+}
+
+/// An entity name seen in the mesh or in memory cards.
+struct Candidate {
+    display: String,
+    kind: Option<EntityKind>,
+    frames: BTreeSet<FrameId>,
+}
+
+impl Candidate {
+    fn is_email(&self, key: &str) -> bool {
+        self.kind == Some(EntityKind::Email) || (self.kind.is_none() && looks_like_email(key))
+    }
+
+    /// Mesh persons, and card entities made only of words.
+    fn is_person(&self, key: &str) -> bool {
+        match self.kind {
+            Some(kind) => kind == EntityKind::Person,
+            None => key
+                .chars()
+                .all(|c| c.is_alphabetic() || matches!(c, ' ' | '-' | '\'')),
+        }
+    }
+}
+
+fn collect_candidates(mesh: &LogicMesh, memories: &MemoriesTrack) -> BTreeMap<String, Candidate> {
+    let mut candidates: BTreeMap<String, Candidate> = BTreeMap::new();
+    for node in &mesh.nodes {
+        let candidate = candidates
+            .entry(EntityAliasTable::normalize(&node.canonical_name))
+            .or_insert_with(|| Candidate {
+                display: node.display_name.trim().to_string(),
+                kind: Some(node.kind),
+                frames: BTreeSet::new(),
+            });
+        candidate.frames.extend(node.frame_ids.iter().copied());
+    }
+    for entity in memories.entities() {
+        for card in memories.get_entity_cards(&entity) {
+            let candidate = candidates
+                .entry(EntityAliasTable::normalize(&card.entity))
+                .or_insert_with(|| Candidate {
+                    display: card.entity.trim().to_string(),
+                    kind: None,
+                    frames: BTreeSet::new(),
+                });
+            candidate.frames.insert(card.source_frame_id);
+        }
+    }
+    candidates.retain(|key, _| !key.is_empty());
+    candidates
+}
+
+fn separated_pair(a: &str, b: &str) -> (String, String) {
+    if a <= b {
+        (a.to_string(), b.to_string())
+    } else {
+        (b.to_string(), a.to_string())
+    }
+}
+
+fn looks_like_email(key: &str) -> bool {
+    key.split_once('@').is_some_and(|(local, domain)| {
+        !local.is_empty()
+            && domain.contains('.')
+            && !domain.contains('@')
+            && !key.contains(char::is_whitespace)
+    })
+}
+
+/// The address's local part without `+tags` or separators: `bob.smith+work@x` -> `bobsmith`.
+fn local_part(address: &str) -> String {
+    let local = address.split('@').next().unwrap_or_default();
+    let local = local.split('+').next().unwrap_or_default();
+    local
+        .chars()
+        .filter(|c| !matches!(c, '.' | '_' | '-'))
+        .collect()
+}
+
+/// A name's words with punctuation removed.
+fn name_words(name: &str) -> Vec<String> {
+    name.split_whitespace()
+        .map(|word| word.chars().filter(|c| c.is_alphanumeric()).collect())
+        .filter(|word: &String| !word.is_empty())
+        .collect()
+}
+
+/// The ways an address local part commonly spells a person's name.
+fn name_spellings(name: &str) -> Vec<String> {
+    let words = name_words(name);
+    let (Some(first), Some(last)) = (words.first(), words.last()) else {
+        return Vec::new();
+    };
+    if words.len() == 1 {
+        return vec![first.clone()];
+    }
+    let initial = |word: &str| word.chars().next().map(String::from).unwrap_or_default();
+    vec![
+        words.concat(),
+        format!("{first}{last}"),
+        format!("{last}{first}"),
+        format!("{}{last}", initial(first)),
+        format!("{first}{}", initial(last)),
+        format!("{last}{}", initial(first)),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::logic_mesh::test_node as node;
+    use crate::types::{MemoryCardBuilder, MeshEdge};
+
+    #[test]
+    fn manual_links_flatten_and_promote() {
+        let mut table = EntityAliasTable::default();
+        assert!(table.link("Bob", "Bob Smith", AliasSource::Manual));
+        assert!(table.link("Bobby", "bob", AliasSource::Manual));
+        assert_eq!(table.resolve("BOBBY"), "Bob Smith");
+        assert_eq!(table.group("bob"), vec!["Bob Smith", "bob", "bobby"]);
+
+        assert!(table.link("Bob Smith", "Robert Smith", AliasSource::Manual));
+        assert_eq!(table.resolve("bob"), "Robert Smith");
+        assert!(table.link("Robert Smith", "Bob", AliasSource::Manual));
+        assert_eq!(table.resolve("robert smith"), "Bob");
+        assert_eq!(table.canonical("bob"), None);
+        assert_eq!(table.len(), 3);
+    }
+
+    #[test]
+    fn split_blocks_heuristic_relinking() {
+        let mut table = EntityAliasTable::default();
+        assert!(table.link("bob", "Bob Smith", AliasSource::NameContainment));
+        assert!(!table.link("bob", "Bob Jones", AliasSource::NameContainment));
+        assert_eq!(table.split("bob").len(), 1);
+        assert!(table.is_separated("Bob Smith", "bob"));
+        assert!(!table.link("bob", "Bob Smith", AliasSource::NameContainment));
+        assert!(table.link("bob", "Bob Smith", AliasSource::Manual));
+        assert!(!table.is_separated("bob", "bob smith"));
+    }
+
+    #[test]
+    fn infer_links_emails_and_short_names() {
+        let mut mesh = LogicMesh::new();
+        let bob = node("Bob Smith", EntityKind::Person, 1);
+        let carol = node("Carol Jones", EntityKind::Person, 2);
+        let bob_mail = node("bsmith@corp.com", EntityKind::Email, 1);
+        let carol_mail = node("carol.j@corp.com", EntityKind::Email, 2);
+        let edge = MeshEdge::new(carol.id, carol_mail.id, LinkType::Email, 0.9, 2);
+        for entry in [bob, carol, bob_mail, carol_mail] {
+            mesh.merge_node(entry);
+        }
+        mesh.merge_edge(edge);
+        mesh.finalize();
+
+        let mut memories = MemoriesTrack::new();
+        memories.add_card(
+            MemoryCardBuilder::new()
+                .fact()
+                .entity("Bob")
+                .slot("employer")
+                .value("Acme")
+                .source(1, None)
+                .engine("test", "1")
+                .build(0)
+                .unwrap(),
+        );
+        memories.add_card(
+            MemoryCardBuilder::new()
+                .fact()
+                .entity("ops@corp.com")
+                .slot("owner")
+                .value("Carol")
+                .source(2, None)
+                .engine("test", "1")
+                .build(0)
+                .unwrap(),
+        );
+
+        let mut table = EntityAliasTable::default();
+        let added = table.infer(&mesh, &memories);
+        let sources: Vec<(&str, &str, AliasSource)> = added
+            .iter()
+            .map(|entry| (entry.alias.as_str(), entry.canonical.as_str(), entry.source))
+            .collect();
+        assert_eq!(
+            sources,
+            vec![
+                ("carol.j@corp.com", "Carol Jones", AliasSource::EmailEdge),
+                ("bsmith@corp.com", "Bob Smith", AliasSource::EmailName),
+                ("bob", "Bob Smith", AliasSource::NameContainment),
+            ]
+        );
+        // The shared mailbox sits in one frame with Carol; that alone is no evidence.
+        assert_eq!(table.canonical("ops@corp.com"), None);
+        assert!(table.infer(&mesh, &memories).is_empty());
+    }
+
+    #[test]
+    fn co_occurrence_needs_several_frames_or_a_name() {
+        let mut mesh = LogicMesh::new();
+        for frame_id in 1..=3 {
+            mesh.merge_node(node("Dana Reyes", EntityKind::Person, frame_id));
+            mesh.merge_node(node("dispatch@corp.com", EntityKind::Email, frame_id));
+        }
+        mesh.merge_node(node("Dana Reyes", EntityKind::Person, 4));
+        mesh.merge_node(node("reyes.oncall@corp.com", EntityKind::Email, 4));
+        mesh.merge_node(node("Eli Park", EntityKind::Person, 5));
+        mesh.merge_node(node("helpdesk@corp.com", EntityKind::Email, 5));
+        mesh.finalize();
+
+        let mut table = EntityAliasTable::default();
+        let added = table.infer(&mesh, &MemoriesTrack::new());
+        let sources: Vec<(&str, &str, AliasSource)> = added
+            .iter()
+            .map(|entry| (entry.alias.as_str(), entry.canonical.as_str(), entry.source))
+            .collect();
+        assert_eq!(
+            sources,
+            vec![
+                ("dispatch@corp.com", "Dana Reyes", AliasSource::CoOccurrence),
+                (
+                    "reyes.oncall@corp.com",
+                    "Dana Reyes",
+                    AliasSource::CoOccurrence
+                ),
+            ]
+        );
+        assert_eq!(table.canonical("helpdesk@corp.com"), None);
+    }
+}
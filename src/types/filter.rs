@@ -0,0 +1,336 @@
+//! Structured metadata filters for search and ask.
+//!
+//! A [`FilterExpr`] is evaluated against frames before ranking, so engines only score
+//! frames that already satisfy it and `top_k` is honoured exactly. Filters can target
+//! built-in frame fields or arbitrary `extra_metadata` keys:
+//!
+//! ```ignore
+//! let filter = FilterExpr::and(vec![
+//!     FilterExpr::eq(FilterField::metadata("tenant"), "acme"),
+//!     FilterExpr::any_of(FilterField::Tag, ["invoice", "receipt"]),
+//!     FilterExpr::range(FilterField::Timestamp, Some("1700000000"), None),
+//! ]);
+//! ```
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use super::common::{FrameRole, FrameStatus};
+use super::frame::Frame;
+
+/// Frame attribute a [`FilterExpr`] compares against.
+///
+/// Multi-valued fields (`Tag`, `Label`) match when any of their values matches.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterField {
+    Uri,
+    Title,
+    Kind,
+    Track,
+    /// Frame role: `document`, `document_chunk` or `extracted_image`.
+    Role,
+    Tag,
+    Label,
+    /// MIME type from the frame's document metadata.
+    Mime,
+    /// Ingestion timestamp (Unix seconds).
+    Timestamp,
+    FrameId,
+    /// Key in `Frame::extra_metadata`.
+    Metadata(String),
+}
+
+impl FilterField {
+    /// Field addressing an `extra_metadata` key.
+    #[must_use]
+    pub fn metadata(key: impl Into<String>) -> Self {
+        Self::Metadata(key.into())
+    }
+
+    fn values<'a>(&self, frame: &'a Frame) -> Vec<Cow<'a, str>> {
+        match self {
+            Self::Uri => frame
+                .uri
+                .as_deref()
+                .map(Cow::Borrowed)
+                .into_iter()
+                .collect(),
+            Self::Title => frame
+                .title
+                .as_deref()
+                .map(Cow::Borrowed)
+                .into_iter()
+                .collect(),
+            Self::Kind => frame
+                .kind
+                .as_deref()
+                .map(Cow::Borrowed)
+                .into_iter()
+                .collect(),
+            Self::Track => frame
+                .track
+                .as_deref()
+                .map(Cow::Borrowed)
+                .into_iter()
+                .collect(),
+            Self::Role => vec![Cow::Borrowed(role_name(frame.role))],
+            Self::Tag => frame
+                .tags
+                .iter()
+                .map(|tag| Cow::Borrowed(tag.as_str()))
+                .collect(),
+            Self::Label => frame
+                .labels
+                .iter()
+                .map(|label| Cow::Borrowed(label.as_str()))
+                .collect(),
+            Self::Mime => frame
+                .metadata
+                .as_ref()
+                .and_then(|meta| meta.mime.as_deref())
+                .map(Cow::Borrowed)
+                .into_iter()
+                .collect(),
+            Self::Timestamp => vec![Cow::Owned(frame.timestamp.to_string())],
+            Self::FrameId => vec![Cow::Owned(frame.id.to_string())],
+            Self::Metadata(key) => frame
+                .extra_metadata
+                .get(key)
+                .map(|value| Cow::Borrowed(value.as_str()))
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+/// Boolean filter over frame fields and `extra_metadata` keys.
+///
+/// Values are strings; `Range` compares numerically when both sides parse as numbers
+/// and lexicographically otherwise (which orders RFC 3339 dates correctly).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterExpr {
+    Eq {
+        field: FilterField,
+        value: String,
+    },
+    In {
+        field: FilterField,
+        values: Vec<String>,
+    },
+    /// Unset bounds are still serialized, so a filter stored in the bincode TOC (as a
+    /// retention scope) decodes field for field.
+    Range {
+        field: FilterField,
+        #[serde(default)]
+        gt: Option<String>,
+        #[serde(default)]
+        gte: Option<String>,
+        #[serde(default)]
+        lt: Option<String>,
+        #[serde(default)]
+        lte: Option<String>,
+    },
+    Exists {
+        field: FilterField,
+    },
+    /// Matches when every child matches (an empty list matches everything).
+    And(Vec<FilterExpr>),
+    /// Matches when any child matches (an empty list matches nothing).
+    Or(Vec<FilterExpr>),
+    Not(Box<FilterExpr>),
+}
+
+impl FilterExpr {
+    #[must_use]
+    pub fn eq(field: FilterField, value: impl Into<String>) -> Self {
+        Self::Eq {
+            field,
+            value: value.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn any_of<I, S>(field: FilterField, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::In {
+            field,
+            values: values.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Inclusive range; either bound may be omitted.
+    #[must_use]
+    pub fn range(field: FilterField, gte: Option<&str>, lte: Option<&str>) -> Self {
+        Self::Range {
+            field,
+            gt: None,
+            gte: gte.map(str::to_string),
+            lt: None,
+            lte: lte.map(str::to_string),
+        }
+    }
+
+    #[must_use]
+    pub fn exists(field: FilterField) -> Self {
+        Self::Exists { field }
+    }
+
+    #[must_use]
+    pub fn and(children: Vec<FilterExpr>) -> Self {
+        Self::And(children)
+    }
+
+    #[must_use]
+    pub fn or(children: Vec<FilterExpr>) -> Self {
+        Self::Or(children)
+    }
+
+    #[must_use]
+    pub fn negate(inner: FilterExpr) -> Self {
+        Self::Not(Box::new(inner))
+    }
+
+    /// Evaluate the filter against a frame.
+    #[must_use]
+    pub fn matches(&self, frame: &Frame) -> bool {
+        match self {
+            Self::Eq { field, value } => field.values(frame).iter().any(|v| v == value),
+            Self::In { field, values } => field
+                .values(frame)
+                .iter()
+                .any(|v| values.iter().any(|candidate| v == candidate)),
+            Self::Range {
+                field,
+                gt,
+                gte,
+                lt,
+                lte,
+            } => field.values(frame).iter().any(|v| {
+                let within = |bound: &Option<String>, accept: fn(Ordering) -> bool| {
+                    bound
+                        .as_deref()
+                        .is_none_or(|bound| accept(compare_values(v, bound)))
+                };
+                within(gt, Ordering::is_gt)
+                    && within(gte, Ordering::is_ge)
+                    && within(lt, Ordering::is_lt)
+                    && within(lte, Ordering::is_le)
+            }),
+            Self::Exists { field } => !field.values(frame).is_empty(),
+            Self::And(children) => children.iter().all(|child| child.matches(frame)),
+            Self::Or(children) => children.iter().any(|child| child.matches(frame)),
+            Self::Not(inner) => !inner.matches(frame),
+        }
+    }
+
+    /// Whether `frame` is an active frame satisfying the filter.
+    #[must_use]
+    pub fn admits(&self, frame: &Frame) -> bool {
+        frame.status == FrameStatus::Active && self.matches(frame)
+    }
+}
+
+fn role_name(role: FrameRole) -> &'static str {
+    match role {
+        FrameRole::Document => "document",
+        FrameRole::DocumentChunk => "document_chunk",
+        FrameRole::ExtractedImage => "extracted_image",
+    }
+}
+
+fn compare_values(value: &str, bound: &str) -> Ordering {
+    match (value.trim().parse::<f64>(), bound.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        _ => value.cmp(bound),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Frame {
+        Frame {
+            id: 7,
+            timestamp: 1_700_000_000,
+            anchor_ts: None,
+            anchor_source: None,
+            kind: Some("invoice".to_string()),
+            track: None,
+            payload_offset: 0,
+            payload_length: 0,
+            checksum: [0u8; 32],
+            uri: Some("mv2://docs/7".to_string()),
+            title: None,
+            canonical_encoding: crate::types::CanonicalEncoding::default(),
+            canonical_length: None,
+            metadata: None,
+            search_text: None,
+            tags: vec!["finance".to_string(), "q3".to_string()],
+            labels: Vec::new(),
+            extra_metadata: [("tenant", "acme"), ("amount", "120.5")]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            content_dates: Vec::new(),
+            chunk_manifest: None,
+            role: FrameRole::Document,
+            parent_id: None,
+            chunk_index: None,
+            chunk_count: None,
+            status: FrameStatus::Active,
+            supersedes: None,
+            superseded_by: None,
+            source_sha256: None,
+            source_path: None,
+            enrichment_state: crate::types::EnrichmentState::Searchable,
+        }
+    }
+
+    #[test]
+    fn field_predicates() {
+        let frame = frame();
+        assert!(FilterExpr::eq(FilterField::metadata("tenant"), "acme").matches(&frame));
+        assert!(!FilterExpr::eq(FilterField::metadata("tenant"), "globex").matches(&frame));
+        assert!(FilterExpr::any_of(FilterField::Tag, ["q3", "q4"]).matches(&frame));
+        assert!(FilterExpr::exists(FilterField::Kind).matches(&frame));
+        assert!(!FilterExpr::exists(FilterField::Track).matches(&frame));
+        assert!(FilterExpr::eq(FilterField::Role, "document").matches(&frame));
+    }
+
+    #[test]
+    fn ranges_compare_numbers_numerically() {
+        let frame = frame();
+        let amount = FilterField::metadata("amount");
+        assert!(FilterExpr::range(amount.clone(), Some("100"), Some("200")).matches(&frame));
+        assert!(!FilterExpr::range(amount, Some("99.9"), Some("120")).matches(&frame));
+        assert!(
+            FilterExpr::range(FilterField::Timestamp, Some("1600000000"), None).matches(&frame)
+        );
+    }
+
+    #[test]
+    fn boolean_combinators_and_serde() {
+        let frame = frame();
+        let filter = FilterExpr::and(vec![
+            FilterExpr::eq(FilterField::metadata("tenant"), "acme"),
+            FilterExpr::negate(FilterExpr::eq(FilterField::Tag, "archived")),
+            FilterExpr::or(vec![
+                FilterExpr::eq(FilterField::Kind, "receipt"),
+                FilterExpr::eq(FilterField::Kind, "invoice"),
+            ]),
+        ]);
+        assert!(filter.matches(&frame));
+
+        let json = serde_json::to_string(&filter).expect("serialize");
+        let decoded: FilterExpr = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(decoded, filter);
+    }
+}
@@ -20,6 +20,7 @@ use super::{
 
 /// Timeline query parameters for scanning frames chronologically or in reverse.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
 pub struct TimelineQuery {
     pub limit: Option<NonZeroU64>,
     pub since: Option<i64>,
@@ -210,8 +211,8 @@ pub struct Frame {
     pub supersedes: Option<FrameId>,
     #[serde(default)]
     pub superseded_by: Option<FrameId>,
-    /// SHA-256 hash of original source file (set when --no-raw is used).
-    /// Allows verification of source without storing the raw binary.
+    /// Hash of the original source file (set when --no-raw is used or a source path is
+    /// given). Allows verification of source without storing the raw binary.
     #[serde(default)]
     pub source_sha256: Option<[u8; 32]>,
     /// Original source file path (set when --no-raw is used).
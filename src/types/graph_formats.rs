@@ -0,0 +1,1012 @@
+//! GraphML, Graphviz DOT and N-Triples renderings of the Logic-Mesh and memory cards.
+//!
+//! Entities are named by IRIs under `urn:memvid:`, so a mesh node and the memory cards
+//! about the same (lowercased) entity land on one node:
+//!
+//! | IRI                              | Meaning                                    |
+//! |----------------------------------|--------------------------------------------|
+//! | `urn:memvid:entity:<name>`       | Entity, by canonical name                  |
+//! | `urn:memvid:kind:<kind>`         | [`EntityKind`], the entity's `rdf:type`    |
+//! | `urn:memvid:link:<link>`         | Mesh relationship ([`LinkType`])           |
+//! | `urn:memvid:slot:<slot>`         | Memory card slot; the object is a literal  |
+//! | `urn:memvid:confidence`, `frame` | Provenance of entities and statements      |
+//!
+//! Name segments are percent-encoded. In N-Triples each edge and card is also written as
+//! an `rdf:Statement` carrying its confidence and source frame, which is how
+//! [`LogicMesh::import_ntriples`] restores them. GraphML and DOT carry the same data as
+//! node and edge attributes, with card values drawn as separate value nodes.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write as _;
+use std::io::{BufRead, Write};
+
+use quick_xml::escape::escape;
+use serde::{Deserialize, Serialize};
+
+use super::common::FrameId;
+use super::logic_mesh::{EntityKind, LinkType, LogicMesh, MeshEdge, MeshNode};
+use super::memories_track::MemoriesTrack;
+use super::memory_card::MemoryCard;
+use crate::{MemvidError, Result};
+
+const ENTITY_PREFIX: &str = "urn:memvid:entity:";
+const KIND_PREFIX: &str = "urn:memvid:kind:";
+const LINK_PREFIX: &str = "urn:memvid:link:";
+const SLOT_PREFIX: &str = "urn:memvid:slot:";
+const VALUE_PREFIX: &str = "urn:memvid:value:";
+const CONFIDENCE_IRI: &str = "urn:memvid:confidence";
+const FRAME_IRI: &str = "urn:memvid:frame";
+const CARD_KIND_IRI: &str = "urn:memvid:card_kind";
+const VERSION_IRI: &str = "urn:memvid:version";
+const POLARITY_IRI: &str = "urn:memvid:polarity";
+const SOURCE_URI_IRI: &str = "urn:memvid:source_uri";
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const RDF_STATEMENT: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#Statement";
+const RDF_SUBJECT: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#subject";
+const RDF_PREDICATE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#predicate";
+const RDF_OBJECT: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#object";
+const RDFS_LABEL: &str = "http://www.w3.org/2000/01/rdf-schema#label";
+const XSD_DECIMAL: &str = "http://www.w3.org/2001/XMLSchema#decimal";
+const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+
+/// Output format of [`LogicMesh::write_graph`] and friends.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphFormat {
+    /// GraphML XML, as read by Gephi, yEd and networkx.
+    #[default]
+    GraphMl,
+    /// Graphviz DOT.
+    Dot,
+    /// RDF N-Triples, one statement per line.
+    NTriples,
+}
+
+impl GraphFormat {
+    /// Conventional file extension, without the dot.
+    #[must_use]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::GraphMl => "graphml",
+            Self::Dot => "dot",
+            Self::NTriples => "nt",
+        }
+    }
+}
+
+/// Counts reported by [`LogicMesh::import_ntriples`].
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NTriplesImport {
+    /// Entities merged into the mesh.
+    pub nodes: usize,
+    /// Relationships merged into the mesh.
+    pub edges: usize,
+    /// Triples that describe neither an entity nor a relationship, such as literal-valued
+    /// properties (including memory card slots).
+    pub skipped: usize,
+}
+
+impl LogicMesh {
+    /// Write the mesh in `format`.
+    pub fn write_graph<W: Write>(&self, writer: W, format: GraphFormat) -> Result<()> {
+        write_graph(writer, format, Some(self), None)
+    }
+
+    /// Read N-Triples and merge the entities and relationships they describe.
+    ///
+    /// IRIs and blank nodes that carry an `rdf:type` or `rdfs:label`, or are linked to
+    /// another IRI, become entities: `urn:memvid:` names and kinds are taken as written,
+    /// other IRIs are named by their label or last path segment and typed by the last
+    /// segment of their class. Every IRI-to-IRI triple becomes an edge whose link is the
+    /// predicate's last segment. Confidence and frame come from `urn:memvid:confidence` and
+    /// `urn:memvid:frame` (on the entity, or on an `rdf:Statement` reifying the edge) and
+    /// otherwise default to 1.0 and `frame_id`.
+    pub fn import_ntriples<R: BufRead>(
+        &mut self,
+        reader: R,
+        frame_id: FrameId,
+    ) -> Result<NTriplesImport> {
+        let mut triples = Vec::new();
+        for (index, line) in reader.lines().enumerate() {
+            if let Some(triple) = parse_triple(&line?, index + 1)? {
+                triples.push(triple);
+            }
+        }
+        let import = mesh_from_triples(&triples, frame_id);
+        let report = NTriplesImport {
+            nodes: import.nodes.len(),
+            edges: import.edges.len(),
+            skipped: import.skipped,
+        };
+        for node in import.nodes {
+            self.merge_node(node);
+        }
+        for edge in import.edges {
+            self.merge_edge(edge);
+        }
+        self.finalize();
+        Ok(report)
+    }
+}
+
+impl MemoriesTrack {
+    /// Write the memory cards in `format` as entity → slot → value triples.
+    pub fn write_graph<W: Write>(&self, writer: W, format: GraphFormat) -> Result<()> {
+        write_graph(writer, format, None, Some(self))
+    }
+}
+
+/// Write whichever of the mesh and the cards are given as one graph.
+pub(crate) fn write_graph<W: Write>(
+    mut writer: W,
+    format: GraphFormat,
+    mesh: Option<&LogicMesh>,
+    track: Option<&MemoriesTrack>,
+) -> Result<()> {
+    let cards = track.map_or(&[][..], MemoriesTrack::cards);
+    let mesh = mesh.map_or_else(|| (&[][..], &[][..]), |m| (&m.nodes[..], &m.edges[..]));
+    let text = match format {
+        GraphFormat::GraphMl => render_graphml(&GraphView::new(mesh.0, mesh.1, cards)),
+        GraphFormat::Dot => render_dot(&GraphView::new(mesh.0, mesh.1, cards)),
+        GraphFormat::NTriples => render_ntriples(mesh.0, mesh.1, cards),
+    };
+    writer.write_all(text.as_bytes())?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// A node of the GraphML/DOT rendering.
+struct ViewNode {
+    key: String,
+    label: String,
+    kind: &'static str,
+    confidence: Option<f32>,
+    frames: Vec<FrameId>,
+}
+
+/// An edge of the GraphML/DOT rendering; `card` is set for memory card edges.
+struct ViewEdge<'a> {
+    from: String,
+    to: String,
+    label: String,
+    confidence: Option<f32>,
+    frame_id: FrameId,
+    card: Option<&'a MemoryCard>,
+}
+
+struct GraphView<'a> {
+    nodes: Vec<ViewNode>,
+    edges: Vec<ViewEdge<'a>>,
+}
+
+impl<'a> GraphView<'a> {
+    fn new(nodes: &[MeshNode], edges: &[MeshEdge], cards: &'a [MemoryCard]) -> Self {
+        let mut view = GraphView {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        };
+        let mut index: HashMap<String, usize> = HashMap::new();
+        let mut keys: HashMap<u64, String> = HashMap::new();
+        for node in nodes {
+            let key = entity_iri(&node.canonical_name);
+            keys.insert(node.id, key.clone());
+            index.insert(key.clone(), view.nodes.len());
+            view.nodes.push(ViewNode {
+                key,
+                label: node.display_name.clone(),
+                kind: node.kind.as_str(),
+                confidence: Some(node.confidence_f32()),
+                frames: node.frame_ids.clone(),
+            });
+        }
+        for edge in edges {
+            let (Some(from), Some(to)) = (keys.get(&edge.from_node), keys.get(&edge.to_node))
+            else {
+                continue;
+            };
+            view.edges.push(ViewEdge {
+                from: from.clone(),
+                to: to.clone(),
+                label: edge.link.as_str().to_string(),
+                confidence: Some(edge.confidence_f32()),
+                frame_id: edge.frame_id,
+                card: None,
+            });
+        }
+        for card in cards {
+            let from = entity_iri(&card.entity.to_lowercase());
+            let to = format!("{VALUE_PREFIX}{}", percent_encode(&card.value));
+            for (key, label, kind) in [
+                (&from, &card.entity, EntityKind::Other.as_str()),
+                (&to, &card.value, "value"),
+            ] {
+                let position = *index.entry(key.clone()).or_insert_with(|| {
+                    view.nodes.push(ViewNode {
+                        key: key.clone(),
+                        label: label.clone(),
+                        kind,
+                        confidence: None,
+                        frames: Vec::new(),
+                    });
+                    view.nodes.len() - 1
+                });
+                let frames = &mut view.nodes[position].frames;
+                if kind != "value" && !frames.contains(&card.source_frame_id) {
+                    frames.push(card.source_frame_id);
+                }
+            }
+            view.edges.push(ViewEdge {
+                from,
+                to,
+                label: card.slot.clone(),
+                confidence: card.confidence,
+                frame_id: card.source_frame_id,
+                card: Some(card),
+            });
+        }
+        view
+    }
+}
+
+fn render_graphml(view: &GraphView<'_>) -> String {
+    const KEYS: [(&str, &str, &str, &str); 11] = [
+        ("label", "node", "label", "string"),
+        ("kind", "node", "kind", "string"),
+        ("node_confidence", "node", "confidence", "double"),
+        ("frames", "node", "frames", "string"),
+        ("link", "edge", "link", "string"),
+        ("confidence", "edge", "confidence", "double"),
+        ("frame", "edge", "frame", "long"),
+        ("origin", "edge", "origin", "string"),
+        ("card_kind", "edge", "card_kind", "string"),
+        ("version", "edge", "version", "string"),
+        ("source_uri", "edge", "source_uri", "string"),
+    ];
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+    );
+    for (id, scope, name, kind) in KEYS {
+        let _ = writeln!(
+            out,
+            "  <key id=\"{id}\" for=\"{scope}\" attr.name=\"{name}\" attr.type=\"{kind}\"/>"
+        );
+    }
+    out.push_str("  <graph id=\"memvid\" edgedefault=\"directed\">\n");
+    let data = |out: &mut String, key: &str, value: &str| {
+        let _ = writeln!(out, "      <data key=\"{key}\">{}</data>", escape(value));
+    };
+    for node in &view.nodes {
+        let _ = writeln!(out, "    <node id=\"{}\">", escape(node.key.as_str()));
+        data(&mut out, "label", &node.label);
+        data(&mut out, "kind", node.kind);
+        if let Some(confidence) = node.confidence {
+            data(&mut out, "node_confidence", &format!("{confidence:.2}"));
+        }
+        if !node.frames.is_empty() {
+            data(&mut out, "frames", &join_frames(&node.frames, " "));
+        }
+        out.push_str("    </node>\n");
+    }
+    for (index, edge) in view.edges.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "    <edge id=\"e{index}\" source=\"{}\" target=\"{}\">",
+            escape(edge.from.as_str()),
+            escape(edge.to.as_str())
+        );
+        data(&mut out, "link", &edge.label);
+        if let Some(confidence) = edge.confidence {
+            data(&mut out, "confidence", &format!("{confidence:.2}"));
+        }
+        data(&mut out, "frame", &edge.frame_id.to_string());
+        if let Some(card) = edge.card {
+            data(&mut out, "origin", "memory_card");
+            data(&mut out, "card_kind", card.kind.as_str());
+            data(&mut out, "version", card.version_relation.as_str());
+            if let Some(uri) = &card.source_uri {
+                data(&mut out, "source_uri", uri);
+            }
+        } else {
+            data(&mut out, "origin", "logic_mesh");
+        }
+        out.push_str("    </edge>\n");
+    }
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+fn render_dot(view: &GraphView<'_>) -> String {
+    let mut out = String::from("digraph memvid {\n");
+    for node in &view.nodes {
+        let mut attrs = vec![
+            format!("label={}", dot_quote(&node.label)),
+            format!("kind={}", dot_quote(node.kind)),
+        ];
+        if let Some(confidence) = node.confidence {
+            attrs.push(format!("confidence={confidence:.2}"));
+        }
+        if !node.frames.is_empty() {
+            attrs.push(format!(
+                "frames={}",
+                dot_quote(&join_frames(&node.frames, " "))
+            ));
+        }
+        if node.kind == "value" {
+            attrs.push("shape=box".to_string());
+        }
+        let _ = writeln!(out, "  {} [{}];", dot_quote(&node.key), attrs.join(", "));
+    }
+    for edge in &view.edges {
+        let mut attrs = vec![format!("label={}", dot_quote(&edge.label))];
+        if let Some(confidence) = edge.confidence {
+            attrs.push(format!("confidence={confidence:.2}"));
+        }
+        attrs.push(format!("frame={}", edge.frame_id));
+        if let Some(card) = edge.card {
+            attrs.push("origin=\"memory_card\"".to_string());
+            attrs.push(format!("card_kind={}", dot_quote(card.kind.as_str())));
+            attrs.push(format!(
+                "version={}",
+                dot_quote(card.version_relation.as_str())
+            ));
+            attrs.push("style=dashed".to_string());
+        } else {
+            attrs.push("origin=\"logic_mesh\"".to_string());
+        }
+        let _ = writeln!(
+            out,
+            "  {} -> {} [{}];",
+            dot_quote(&edge.from),
+            dot_quote(&edge.to),
+            attrs.join(", ")
+        );
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn render_ntriples(nodes: &[MeshNode], edges: &[MeshEdge], cards: &[MemoryCard]) -> String {
+    let mut out = String::new();
+    let mut line = |subject: &str, predicate: &str, object: &str| {
+        let _ = writeln!(out, "{subject} <{predicate}> {object} .");
+    };
+    let iri = |value: &str| format!("<{value}>");
+    let decimal = |value: f32| format!("\"{value:.2}\"^^<{XSD_DECIMAL}>");
+    let integer = |value: FrameId| format!("\"{value}\"^^<{XSD_INTEGER}>");
+
+    let mut keys: HashMap<u64, String> = HashMap::new();
+    for node in nodes {
+        let subject = iri(&entity_iri(&node.canonical_name));
+        keys.insert(node.id, subject.clone());
+        line(
+            &subject,
+            RDF_TYPE,
+            &iri(&format!("{KIND_PREFIX}{}", node.kind.as_str())),
+        );
+        line(&subject, RDFS_LABEL, &nt_literal(&node.display_name));
+        line(&subject, CONFIDENCE_IRI, &decimal(node.confidence_f32()));
+        for frame_id in &node.frame_ids {
+            line(&subject, FRAME_IRI, &integer(*frame_id));
+        }
+    }
+    for (index, edge) in edges.iter().enumerate() {
+        let (Some(from), Some(to)) = (keys.get(&edge.from_node), keys.get(&edge.to_node)) else {
+            continue;
+        };
+        let predicate = format!("{LINK_PREFIX}{}", percent_encode(edge.link.as_str()));
+        line(from, &predicate, to);
+        let statement = format!("_:e{index}");
+        line(&statement, RDF_TYPE, &iri(RDF_STATEMENT));
+        line(&statement, RDF_SUBJECT, from);
+        line(&statement, RDF_PREDICATE, &iri(&predicate));
+        line(&statement, RDF_OBJECT, to);
+        line(&statement, CONFIDENCE_IRI, &decimal(edge.confidence_f32()));
+        line(&statement, FRAME_IRI, &integer(edge.frame_id));
+    }
+    for card in cards {
+        let subject = iri(&entity_iri(&card.entity.to_lowercase()));
+        let predicate = format!("{SLOT_PREFIX}{}", percent_encode(&card.slot));
+        let value = nt_literal(&card.value);
+        line(&subject, &predicate, &value);
+        let statement = format!("_:c{}", card.id);
+        line(&statement, RDF_TYPE, &iri(RDF_STATEMENT));
+        line(&statement, RDF_SUBJECT, &subject);
+        line(&statement, RDF_PREDICATE, &iri(&predicate));
+        line(&statement, RDF_OBJECT, &value);
+        line(&statement, FRAME_IRI, &integer(card.source_frame_id));
+        if let Some(confidence) = card.confidence {
+            line(&statement, CONFIDENCE_IRI, &decimal(confidence));
+        }
+        line(&statement, CARD_KIND_IRI, &nt_literal(card.kind.as_str()));
+        line(
+            &statement,
+            VERSION_IRI,
+            &nt_literal(card.version_relation.as_str()),
+        );
+        if let Some(polarity) = card.polarity {
+            line(&statement, POLARITY_IRI, &nt_literal(polarity.as_str()));
+        }
+        if let Some(uri) = &card.source_uri {
+            line(&statement, SOURCE_URI_IRI, &nt_literal(uri));
+        }
+    }
+    out
+}
+
+fn entity_iri(canonical_name: &str) -> String {
+    format!("{ENTITY_PREFIX}{}", percent_encode(canonical_name))
+}
+
+fn join_frames(frames: &[FrameId], separator: &str) -> String {
+    frames
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+fn dot_quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => {}
+            _ => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn nt_literal(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            _ => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Percent-encode everything but RFC 3986 unreserved characters.
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            let _ = write!(encoded, "%{byte:02X}");
+        }
+    }
+    encoded
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            if let Some(byte) = value
+                .get(index + 1..index + 3)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                decoded.push(byte);
+                index += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[index]);
+        index += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Last segment of an IRI, after the final `#`, `/` or `:`, percent-decoded.
+fn local_name(iri: &str) -> String {
+    let start = iri
+        .rfind(['#', '/', ':'])
+        .map_or(0, |position| position + 1);
+    percent_decode(&iri[start..])
+}
+
+fn entity_kind(name: &str) -> EntityKind {
+    [
+        EntityKind::Person,
+        EntityKind::Organization,
+        EntityKind::Project,
+        EntityKind::Email,
+        EntityKind::Date,
+        EntityKind::Location,
+        EntityKind::Product,
+        EntityKind::Event,
+        EntityKind::Money,
+        EntityKind::Url,
+    ]
+    .into_iter()
+    .find(|kind| kind.as_str().eq_ignore_ascii_case(name))
+    .unwrap_or_else(|| EntityKind::from_label(name))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Term {
+    Iri(String),
+    Blank(String),
+    Literal(String),
+}
+
+impl Term {
+    fn is_resource(&self) -> bool {
+        !matches!(self, Self::Literal(_))
+    }
+}
+
+type Triple = (Term, String, Term);
+
+/// Parse one N-Triples line; `None` for blank lines and comments.
+fn parse_triple(line: &str, number: usize) -> Result<Option<Triple>> {
+    let error = |reason: &str| MemvidError::InvalidNTriples {
+        line: number,
+        reason: reason.to_string(),
+    };
+    let mut rest = line.trim();
+    if rest.is_empty() || rest.starts_with('#') {
+        return Ok(None);
+    }
+    let subject = parse_term(&mut rest).map_err(error)?;
+    let Term::Iri(predicate) = parse_term(&mut rest).map_err(error)? else {
+        return Err(error("predicate must be an IRI"));
+    };
+    let object = parse_term(&mut rest).map_err(error)?;
+    if matches!(subject, Term::Literal(_)) {
+        return Err(error("subject cannot be a literal"));
+    }
+    let Some(after) = rest.trim_start().strip_prefix('.') else {
+        return Err(error("expected '.' at end of triple"));
+    };
+    let after = after.trim_start();
+    if !(after.is_empty() || after.starts_with('#')) {
+        return Err(error("unexpected text after '.'"));
+    }
+    Ok(Some((subject, predicate, object)))
+}
+
+/// Parse the next term and advance `rest` past it.
+fn parse_term(rest: &mut &str) -> std::result::Result<Term, &'static str> {
+    let text = rest.trim_start();
+    if let Some(body) = text.strip_prefix('<') {
+        let end = body.find('>').ok_or("unterminated IRI")?;
+        *rest = &body[end + 1..];
+        return Ok(Term::Iri(unescape(&body[..end])?));
+    }
+    if let Some(body) = text.strip_prefix("_:") {
+        let end = body
+            .find(|ch: char| ch.is_whitespace() || ch == '.')
+            .unwrap_or(body.len());
+        if end == 0 {
+            return Err("empty blank node label");
+        }
+        *rest = &body[end..];
+        return Ok(Term::Blank(body[..end].to_string()));
+    }
+    if let Some(body) = text.strip_prefix('"') {
+        let mut escaped = false;
+        let end = body
+            .char_indices()
+            .find(|&(_, ch)| {
+                let closes = ch == '"' && !escaped;
+                escaped = ch == '\\' && !escaped;
+                closes
+            })
+            .map(|(index, _)| index)
+            .ok_or("unterminated literal")?;
+        let value = unescape(&body[..end])?;
+        let mut after = &body[end + 1..];
+        if let Some(tagged) = after.strip_prefix('@') {
+            let tag_end = tagged
+                .find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '-'))
+                .unwrap_or(tagged.len());
+            after = &tagged[tag_end..];
+        } else if let Some(typed) = after.strip_prefix("^^<") {
+            let type_end = typed.find('>').ok_or("unterminated datatype IRI")?;
+            after = &typed[type_end + 1..];
+        }
+        *rest = after;
+        return Ok(Term::Literal(value));
+    }
+    Err("expected an IRI, blank node or literal")
+}
+
+/// Resolve `\"`, `\\`, `\n`, `\r`, `\t`, `\uXXXX` and `\UXXXXXXXX` escapes.
+fn unescape(text: &str) -> std::result::Result<String, &'static str> {
+    let mut value = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            value.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => value.push('\n'),
+            Some('r') => value.push('\r'),
+            Some('t') => value.push('\t'),
+            Some('b') => value.push('\u{8}'),
+            Some('f') => value.push('\u{c}'),
+            Some(ch @ ('"' | '\'' | '\\')) => value.push(ch),
+            Some(marker @ ('u' | 'U')) => {
+                let digits: String = chars
+                    .by_ref()
+                    .take(if marker == 'u' { 4 } else { 8 })
+                    .collect();
+                let code = u32::from_str_radix(&digits, 16).map_err(|_| "invalid \\u escape")?;
+                value.push(char::from_u32(code).ok_or("invalid \\u escape")?);
+            }
+            _ => return Err("invalid escape sequence"),
+        }
+    }
+    Ok(value)
+}
+
+/// Nodes and edges built from parsed triples.
+struct MeshImport {
+    nodes: Vec<MeshNode>,
+    edges: Vec<MeshEdge>,
+    skipped: usize,
+}
+
+/// What the triples say about one entity or reified statement.
+#[derive(Default)]
+struct Facts<'a> {
+    label: Option<String>,
+    kind: Option<EntityKind>,
+    confidence: Option<f32>,
+    frames: Vec<FrameId>,
+    /// `rdf:subject`, `rdf:predicate` and `rdf:object` of a reified statement.
+    subject: Option<&'a Term>,
+    predicate: Option<&'a str>,
+    object: Option<&'a Term>,
+}
+
+impl<'a> Facts<'a> {
+    /// Record a descriptive triple; false when it is not one.
+    fn record(&mut self, predicate: &'a str, object: &'a Term) -> bool {
+        match (predicate, object) {
+            (RDF_TYPE, Term::Iri(class)) => {
+                if class != RDF_STATEMENT {
+                    self.kind = Some(entity_kind(&local_name(class)));
+                }
+            }
+            (RDFS_LABEL, Term::Literal(label)) => self.label = Some(label.clone()),
+            (CONFIDENCE_IRI, Term::Literal(value)) => self.confidence = value.trim().parse().ok(),
+            (FRAME_IRI, Term::Literal(value)) => {
+                if let Ok(frame_id) = value.trim().parse() {
+                    self.frames.push(frame_id);
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+fn mesh_from_triples(triples: &[Triple], default_frame: FrameId) -> MeshImport {
+    let statement_ids: HashSet<&Term> = triples
+        .iter()
+        .filter(|(_, predicate, object)| {
+            predicate == RDF_SUBJECT
+                || predicate == RDF_TYPE
+                    && matches!(object, Term::Iri(class) if class == RDF_STATEMENT)
+        })
+        .map(|(subject, _, _)| subject)
+        .collect();
+
+    let mut statements: HashMap<&Term, Facts<'_>> = HashMap::new();
+    let mut entities: BTreeMap<&Term, Facts<'_>> = BTreeMap::new();
+    let mut links: Vec<(&Term, &str, &Term)> = Vec::new();
+    let mut skipped = 0;
+    for (subject, predicate, object) in triples {
+        if statement_ids.contains(subject) {
+            let statement = statements.entry(subject).or_default();
+            match (predicate.as_str(), object) {
+                (RDF_SUBJECT, _) => statement.subject = Some(object),
+                (RDF_PREDICATE, Term::Iri(iri)) => statement.predicate = Some(iri),
+                (RDF_OBJECT, _) => statement.object = Some(object),
+                _ => {
+                    if !statement.record(predicate, object) {
+                        skipped += 1;
+                    }
+                }
+            }
+        } else if !entities
+            .entry(subject)
+            .or_default()
+            .record(predicate, object)
+        {
+            if object.is_resource() {
+                entities.entry(object).or_default();
+                links.push((subject, predicate, object));
+            } else {
+                skipped += 1;
+            }
+        }
+    }
+    // Subjects of literal-valued triples alone (such as card entities) are not entities.
+    let linked: HashSet<&Term> = links
+        .iter()
+        .flat_map(|(from, _, to)| [*from, *to])
+        .collect();
+    entities.retain(|term, facts| {
+        facts.label.is_some() || facts.kind.is_some() || linked.contains(term)
+    });
+
+    let mut ids: HashMap<&Term, u64> = HashMap::new();
+    let mut nodes = Vec::new();
+    for (term, facts) in &entities {
+        let (canonical, fallback) = match term {
+            Term::Iri(iri) => match iri.strip_prefix(ENTITY_PREFIX) {
+                Some(name) => (Some(percent_decode(name)), percent_decode(name)),
+                None => (None, local_name(iri)),
+            },
+            Term::Blank(label) | Term::Literal(label) => (None, label.clone()),
+        };
+        let display = facts.label.clone().unwrap_or(fallback);
+        let canonical = canonical.unwrap_or_else(|| display.to_lowercase());
+        let frames = if facts.frames.is_empty() {
+            vec![default_frame]
+        } else {
+            facts.frames.clone()
+        };
+        let mut node = MeshNode::new(
+            canonical,
+            display,
+            facts.kind.unwrap_or(EntityKind::Other),
+            facts.confidence.unwrap_or(1.0),
+            frames[0],
+            0,
+            0,
+        );
+        // Byte spans do not survive the round trip.
+        node.frame_ids = frames;
+        node.mentions.clear();
+        ids.insert(*term, node.id);
+        nodes.push(node);
+    }
+
+    let provenance: HashMap<(&Term, &str, &Term), &Facts<'_>> = statements
+        .values()
+        .filter_map(|facts| Some(((facts.subject?, facts.predicate?, facts.object?), facts)))
+        .collect();
+    let edges = links
+        .into_iter()
+        .filter_map(|(from, predicate, to)| {
+            let facts = provenance.get(&(from, predicate, to));
+            let link = match predicate.strip_prefix(LINK_PREFIX) {
+                Some(name) => LinkType::from_str(&percent_decode(name)),
+                None => LinkType::from_str(&local_name(predicate)),
+            };
+            Some(MeshEdge::new(
+                *ids.get(from)?,
+                *ids.get(to)?,
+                link,
+                facts.and_then(|facts| facts.confidence).unwrap_or(1.0),
+                facts
+                    .and_then(|facts| facts.frames.first().copied())
+                    .unwrap_or(default_frame),
+            ))
+        })
+        .collect();
+    MeshImport {
+        nodes,
+        edges,
+        skipped,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::memory_card::MemoryCardBuilder;
+
+    fn sample_mesh() -> LogicMesh {
+        let mut mesh = LogicMesh::new();
+        let alice = MeshNode::new(
+            "alice".into(),
+            "Alice".into(),
+            EntityKind::Person,
+            0.9,
+            1,
+            0,
+            5,
+        );
+        let bob = MeshNode::new(
+            "bob".into(),
+            "Bob".into(),
+            EntityKind::Person,
+            0.8,
+            1,
+            10,
+            3,
+        );
+        let project = MeshNode::new(
+            "project x".into(),
+            "Project \"X\"".into(),
+            EntityKind::Product,
+            0.7,
+            2,
+            0,
+            9,
+        );
+        let edges = [
+            MeshEdge::new(alice.id, bob.id, LinkType::Manager, 0.9, 1),
+            MeshEdge::new(
+                bob.id,
+                project.id,
+                LinkType::Custom("works on".into()),
+                0.6,
+                2,
+            ),
+        ];
+        mesh.merge_node(alice);
+        mesh.merge_node(bob);
+        mesh.merge_node(project);
+        for edge in edges {
+            mesh.merge_edge(edge);
+        }
+        mesh.finalize();
+        mesh
+    }
+
+    fn sample_track() -> MemoriesTrack {
+        let mut track = MemoriesTrack::new();
+        track.add_card(
+            MemoryCardBuilder::new()
+                .fact()
+                .entity("Alice")
+                .slot("employer")
+                .value("Smith & Sons")
+                .source(3, Some("mv2://docs/hr".into()))
+                .engine("rules-v1", "1.0.0")
+                .build(0)
+                .unwrap(),
+        );
+        track
+    }
+
+    fn render(
+        format: GraphFormat,
+        mesh: Option<&LogicMesh>,
+        track: Option<&MemoriesTrack>,
+    ) -> String {
+        let mut out = Vec::new();
+        write_graph(&mut out, format, mesh, track).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn ntriples_roundtrip_restores_mesh() {
+        let mesh = sample_mesh();
+        let text = render(GraphFormat::NTriples, Some(&mesh), Some(&sample_track()));
+        assert!(text.contains(
+            "<urn:memvid:entity:project%20x> <http://www.w3.org/2000/01/rdf-schema#label> \"Project \\\"X\\\"\" ."
+        ));
+        assert!(
+            text.contains(
+                "<urn:memvid:entity:alice> <urn:memvid:slot:employer> \"Smith & Sons\" ."
+            )
+        );
+
+        let mut restored = LogicMesh::new();
+        let report = restored.import_ntriples(text.as_bytes(), 99).unwrap();
+        assert_eq!((report.nodes, report.edges), (3, 2));
+        // The card's slot triple and its provenance are not mesh data.
+        assert!(report.skipped > 0);
+        for node in &mesh.nodes {
+            let copy = restored.find_node_by_id(node.id).expect("node restored");
+            assert_eq!(copy.display_name, node.display_name);
+            assert_eq!(copy.kind, node.kind);
+            assert_eq!(copy.confidence, node.confidence);
+            assert_eq!(copy.frame_ids, node.frame_ids);
+        }
+        assert_eq!(restored.edges, mesh.edges);
+    }
+
+    #[test]
+    fn imports_foreign_ntriples() {
+        let text = r#"
+# people and employers
+<http://example.org/people/alice> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://schema.org/Person> .
+<http://example.org/people/alice> <http://www.w3.org/2000/01/rdf-schema#label> "Alice Smith"@en .
+<http://example.org/people/alice> <http://schema.org/email> "alice@example.org"^^<http://www.w3.org/2001/XMLSchema#string> .
+<http://example.org/people/alice> <http://schema.org/works_at> <http://example.org/org/Acme%20Corp> .
+_:b1 <http://example.org/knows> <http://example.org/people/alice> .
+"#;
+        let mut mesh = LogicMesh::new();
+        let report = mesh.import_ntriples(text.as_bytes(), 7).unwrap();
+        assert_eq!(
+            report,
+            NTriplesImport {
+                nodes: 3,
+                edges: 2,
+                skipped: 1
+            }
+        );
+
+        let alice = mesh.find_node("alice smith").expect("labelled entity");
+        assert_eq!(alice.kind, EntityKind::Person);
+        assert_eq!(alice.frame_ids, vec![7]);
+        let acme = mesh.find_node("acme corp").expect("named by IRI segment");
+        assert_eq!(acme.kind, EntityKind::Other);
+        let employer = mesh
+            .edges
+            .iter()
+            .find(|edge| edge.to_node == acme.id)
+            .expect("edge");
+        assert_eq!(employer.link, LinkType::Employer);
+        assert_eq!(employer.confidence_f32(), 1.0);
+        assert_eq!(mesh.follow("b1", "knows", 1).len(), 1);
+    }
+
+    #[test]
+    fn ntriples_errors_name_the_line() {
+        let mut mesh = LogicMesh::new();
+        let text = "<urn:a> <urn:b> <urn:c> .\n<urn:a> \"literal\" <urn:c> .\n";
+        let err = mesh.import_ntriples(text.as_bytes(), 0).unwrap_err();
+        assert!(
+            matches!(err, MemvidError::InvalidNTriples { line: 2, .. }),
+            "{err}"
+        );
+        let err = mesh
+            .import_ntriples("<urn:a> <urn:b> <urn:c>\n".as_bytes(), 0)
+            .unwrap_err();
+        assert!(matches!(err, MemvidError::InvalidNTriples { line: 1, .. }));
+        assert!(mesh.is_empty());
+    }
+
+    #[test]
+    fn graphml_and_dot_render_mesh_and_cards() {
+        let mesh = sample_mesh();
+        let track = sample_track();
+        let graphml = render(GraphFormat::GraphMl, Some(&mesh), Some(&track));
+        let mut reader = quick_xml::Reader::from_str(&graphml);
+        let (mut nodes, mut edges) = (0, 0);
+        loop {
+            match reader.read_event().expect("well-formed GraphML") {
+                quick_xml::events::Event::Start(tag) if tag.name().as_ref() == b"node" => {
+                    nodes += 1;
+                }
+                quick_xml::events::Event::Start(tag) if tag.name().as_ref() == b"edge" => {
+                    edges += 1;
+                }
+                quick_xml::events::Event::Eof => break,
+                _ => {}
+            }
+        }
+        // Alice's card joins her mesh node; its value is a node of its own.
+        assert_eq!((nodes, edges), (4, 3));
+        assert!(graphml.contains("<data key=\"label\">Smith &amp; Sons</data>"));
+        assert!(graphml.contains("<data key=\"source_uri\">mv2://docs/hr</data>"));
+        assert!(graphml.contains("<data key=\"frames\">1 3</data>"));
+
+        let dot = render(GraphFormat::Dot, Some(&mesh), Some(&track));
+        assert!(dot.starts_with("digraph memvid {\n"));
+        assert!(dot.contains(
+            "\"urn:memvid:entity:alice\" -> \"urn:memvid:entity:bob\" [label=\"manager\", confidence=0.90, frame=1, origin=\"logic_mesh\"];"
+        ));
+        assert!(dot.contains("[label=\"Project \\\"X\\\"\", kind=\"product\""));
+        assert!(dot.contains("label=\"employer\", frame=3, origin=\"memory_card\""));
+
+        let cards_only = render(GraphFormat::Dot, None, Some(&track));
+        assert!(cards_only.contains("[label=\"Alice\", kind=\"other\", frames=\"3\"];"));
+    }
+}
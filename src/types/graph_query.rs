@@ -2,6 +2,8 @@
 //!
 //! Enables combining graph traversal with vector similarity for relational queries.
 
+use std::cmp::Ordering;
+
 use serde::{Deserialize, Serialize};
 
 use super::common::FrameId;
@@ -95,11 +97,80 @@ impl PatternTerm {
     }
 }
 
+/// Comparison applied by a [`ValueFilter`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    /// Case-insensitive substring match.
+    Contains,
+}
+
+/// A `FILTER`-style condition on the value bound to a variable.
+///
+/// Values compare as numbers when both sides parse as numbers, otherwise as
+/// case-insensitive strings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ValueFilter {
+    /// Variable name, without the leading `?`.
+    pub variable: String,
+    pub op: CompareOp,
+    pub value: String,
+}
+
+impl ValueFilter {
+    /// Create a filter; a leading `?` on `variable` is ignored.
+    #[must_use]
+    pub fn new(variable: &str, op: CompareOp, value: impl Into<String>) -> Self {
+        Self {
+            variable: variable.trim_start_matches('?').to_string(),
+            op,
+            value: value.into(),
+        }
+    }
+
+    /// Check a bound value against the filter.
+    #[must_use]
+    pub fn matches(&self, bound: &str) -> bool {
+        let ordering = match (
+            bound.trim().parse::<f64>(),
+            self.value.trim().parse::<f64>(),
+        ) {
+            (Ok(left), Ok(right)) => left.partial_cmp(&right),
+            _ => Some(bound.to_lowercase().cmp(&self.value.to_lowercase())),
+        };
+        match self.op {
+            CompareOp::Eq => ordering == Some(Ordering::Equal),
+            CompareOp::Ne => ordering != Some(Ordering::Equal),
+            CompareOp::Lt => ordering == Some(Ordering::Less),
+            CompareOp::Le => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+            CompareOp::Gt => ordering == Some(Ordering::Greater),
+            CompareOp::Ge => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+            CompareOp::Contains => bound.to_lowercase().contains(&self.value.to_lowercase()),
+        }
+    }
+}
+
 /// A graph pattern for filtering - conjunction of triple patterns.
+///
+/// Variables shared between triples join: a solution binds each variable to one value
+/// across all of them. Optional triples extend a solution when they match and leave it
+/// as is otherwise; filters then drop solutions whose bindings fail a condition.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GraphPattern {
     /// Triple patterns to match (all must match - AND semantics)
     pub triples: Vec<TriplePattern>,
+    /// Triple patterns that add bindings when they match (SPARQL `OPTIONAL`)
+    #[serde(default)]
+    pub optional: Vec<TriplePattern>,
+    /// Conditions on bound values, all of which must hold
+    #[serde(default)]
+    pub filters: Vec<ValueFilter>,
 }
 
 impl GraphPattern {
@@ -114,11 +185,22 @@ impl GraphPattern {
         self.triples.push(pattern);
     }
 
+    /// Add an optional triple pattern.
+    pub fn add_optional(&mut self, pattern: TriplePattern) {
+        self.optional.push(pattern);
+    }
+
+    /// Add a filter on a bound value.
+    pub fn add_filter(&mut self, filter: ValueFilter) {
+        self.filters.push(filter);
+    }
+
     /// Create from a single triple pattern.
     #[must_use]
     pub fn single(pattern: TriplePattern) -> Self {
         Self {
             triples: vec![pattern],
+            ..Self::default()
         }
     }
 
@@ -132,7 +214,7 @@ impl GraphPattern {
     #[must_use]
     pub fn variables(&self) -> Vec<&str> {
         let mut vars = Vec::new();
-        for triple in &self.triples {
+        for triple in self.triples.iter().chain(&self.optional) {
             if let Some(v) = triple.subject.variable_name() {
                 if !vars.contains(&v) {
                     vars.push(v);
@@ -153,6 +235,36 @@ impl GraphPattern {
     }
 }
 
+/// A graph query parsed from text, e.g.
+/// `MATCH (?p)-[:employer]->("acme") WHERE ?p.location = "berlin" RETURN ?p LIMIT 10`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GraphQuery {
+    /// Triples, optional triples and filters from `MATCH`, `OPTIONAL MATCH` and `WHERE`
+    pub pattern: GraphPattern,
+    /// Variables named by `RETURN`, without `?` (empty returns every binding)
+    #[serde(default)]
+    pub returns: Vec<String>,
+    /// Maximum number of results from `LIMIT`
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+impl GraphQuery {
+    /// Parse the textual query syntax.
+    ///
+    /// # Errors
+    /// Returns `MemvidError::InvalidGraphQuery` with the byte range of the offending input.
+    pub fn parse(text: &str) -> crate::Result<Self> {
+        crate::graph_parser::parse_graph_query(text)
+    }
+
+    /// A graph-only plan for this query, for use with `hybrid_search`.
+    #[must_use]
+    pub fn plan(&self) -> QueryPlan {
+        QueryPlan::graph_only(self.pattern.clone(), self.limit.unwrap_or(usize::MAX))
+    }
+}
+
 /// Query plan for graph-aware retrieval.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum QueryPlan {
@@ -320,6 +432,16 @@ mod tests {
         assert!(vars.contains(&"company"));
     }
 
+    #[test]
+    fn test_value_filter_compares_numbers_and_text() {
+        assert!(ValueFilter::new("?age", CompareOp::Gt, "9").matches("10"));
+        assert!(!ValueFilter::new("age", CompareOp::Lt, "9").matches("10"));
+        assert!(ValueFilter::new("city", CompareOp::Eq, "Berlin").matches("berlin"));
+        assert!(ValueFilter::new("city", CompareOp::Ne, "Paris").matches("Berlin"));
+        assert!(ValueFilter::new("org", CompareOp::Contains, "acme").matches("ACME Corp"));
+        assert_eq!(ValueFilter::new("?age", CompareOp::Ge, "1").variable, "age");
+    }
+
     #[test]
     fn test_query_plan_types() {
         let vector_plan = QueryPlan::vector_only(Some("test".into()), None, 10);
@@ -0,0 +1,107 @@
+//! Options and report for merging one memory into another.
+//!
+//! `Memvid::import_from` copies the frames of a source `.mv2` into the destination,
+//! assigning fresh frame ids, and carries over the memory cards and Logic-Mesh entries
+//! derived from them.
+//!
+//! ```ignore
+//! let mut source = Memvid::open_read_only("alice.mv2")?;
+//! let mut shared = Memvid::open("team.mv2")?;
+//! let report = shared.import_from(&mut source, ImportOptions::new(UriConflict::Version))?;
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::common::FrameId;
+
+/// What to do when an imported document's URI already names an active frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UriConflict {
+    /// Keep the destination frame and leave the source document out.
+    #[default]
+    Skip,
+    /// Tombstone the destination frame and import the source document.
+    Replace,
+    /// Import the source document as a new version superseding the destination frame.
+    Version,
+}
+
+/// Controls `Memvid::import_from`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportOptions {
+    #[serde(default)]
+    pub on_conflict: UriConflict,
+    /// Also copy superseded versions, so the source's supersede chains survive.
+    #[serde(default)]
+    pub include_superseded: bool,
+    /// Copy memory cards extracted from the imported frames.
+    #[serde(default = "default_true")]
+    pub memories: bool,
+    /// Merge Logic-Mesh nodes and edges found in the imported frames.
+    #[serde(default = "default_true")]
+    pub logic_mesh: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        Self {
+            on_conflict: UriConflict::default(),
+            include_superseded: false,
+            memories: true,
+            logic_mesh: true,
+        }
+    }
+}
+
+impl ImportOptions {
+    #[must_use]
+    pub fn new(on_conflict: UriConflict) -> Self {
+        Self {
+            on_conflict,
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn with_superseded(mut self, include_superseded: bool) -> Self {
+        self.include_superseded = include_superseded;
+        self
+    }
+}
+
+/// Result of `Memvid::import_from` and `Memvid::import`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportReport {
+    /// Source frame id to the id it received in the destination.
+    pub frame_map: BTreeMap<FrameId, FrameId>,
+    /// Source documents left out because their URI was taken (chunks not counted).
+    pub skipped: Vec<FrameId>,
+    /// Destination frames tombstoned by [`UriConflict::Replace`], including their chunks.
+    pub replaced: Vec<FrameId>,
+    /// Imported documents that superseded a destination frame under [`UriConflict::Version`].
+    pub versioned: usize,
+    pub embeddings_copied: usize,
+    /// Source embeddings left behind because the vector models or dimensions differ.
+    pub embeddings_dropped: usize,
+    pub memory_cards: usize,
+    pub mesh_nodes: usize,
+    pub mesh_edges: usize,
+    /// Replay sessions restored from an interchange stream.
+    #[serde(default)]
+    pub replay_sessions: usize,
+}
+
+impl ImportReport {
+    /// Number of frames written to the destination, chunks included.
+    #[must_use]
+    pub fn imported(&self) -> usize {
+        self.frame_map.len()
+    }
+}
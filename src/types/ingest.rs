@@ -0,0 +1,107 @@
+//! Options and report for mirroring a directory into a memory.
+//!
+//! `Memvid::ingest_dir` walks a folder (and the `.zip` archives inside it), stores each
+//! file under an `mv2://` URI derived from its relative path, and on later runs brings the
+//! memory back in line with the folder.
+//!
+//! ```ignore
+//! let mut mem = Memvid::open("team.mv2")?;
+//! let report = mem.ingest_dir("./docs", IngestOptions::new("docs"))?;
+//! println!("{} added, {} updated, {} deleted", report.added.len(), report.updated.len(), report.deleted.len());
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// Controls `Memvid::ingest_dir`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IngestOptions {
+    /// First URI segment after `mv2://`; defaults to the directory's name.
+    #[serde(default)]
+    pub uri_prefix: Option<String>,
+    /// Only ingest files with these extensions (case-insensitive, without the dot).
+    /// Empty means every file.
+    #[serde(default)]
+    pub extensions: Vec<String>,
+    /// Walk into dot-files and dot-directories.
+    #[serde(default)]
+    pub include_hidden: bool,
+    /// Ingest `.zip` archives as the files they contain instead of as one document.
+    #[serde(default = "default_true")]
+    pub expand_archives: bool,
+    /// Delete documents under the prefix whose file no longer exists.
+    #[serde(default = "default_true")]
+    pub delete_missing: bool,
+    /// Tags added to every ingested document.
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for IngestOptions {
+    fn default() -> Self {
+        Self {
+            uri_prefix: None,
+            extensions: Vec::new(),
+            include_hidden: false,
+            expand_archives: true,
+            delete_missing: true,
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl IngestOptions {
+    #[must_use]
+    pub fn new(uri_prefix: impl Into<String>) -> Self {
+        Self {
+            uri_prefix: Some(uri_prefix.into()),
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn extensions<I, S>(mut self, extensions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.extensions = extensions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    #[must_use]
+    pub fn expand_archives(mut self, enabled: bool) -> Self {
+        self.expand_archives = enabled;
+        self
+    }
+
+    #[must_use]
+    pub fn delete_missing(mut self, enabled: bool) -> Self {
+        self.delete_missing = enabled;
+        self
+    }
+}
+
+/// Result of `Memvid::ingest_dir`, listing the URIs touched by the run.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IngestReport {
+    /// Files that had no document yet.
+    pub added: Vec<String>,
+    /// Files whose content changed; each got a new version superseding the old one.
+    pub updated: Vec<String>,
+    /// Files whose content hash matched the stored document.
+    pub unchanged: usize,
+    /// Documents tombstoned because their file is gone.
+    pub deleted: Vec<String>,
+}
+
+impl IngestReport {
+    /// Whether the run wrote anything.
+    #[must_use]
+    pub fn changed(&self) -> bool {
+        !(self.added.is_empty() && self.updated.is_empty() && self.deleted.is_empty())
+    }
+}
@@ -0,0 +1,132 @@
+//! Portable JSONL interchange format written by `Memvid::export` and read by `Memvid::import`.
+//!
+//! A stream is UTF-8 text with one JSON object per line. Every object carries a `type`
+//! tag naming its record:
+//!
+//! | `type`           | Contents                                                        |
+//! |------------------|-----------------------------------------------------------------|
+//! | `header`         | Always first: format name, format version, vector model         |
+//! | `frame`          | Full [`Frame`] metadata, base64 canonical payload, embedding    |
+//! | `memory_card`    | One [`MemoryCard`]                                              |
+//! | `mesh_node`      | One Logic-Mesh [`MeshNode`]                                     |
+//! | `mesh_edge`      | One Logic-Mesh [`MeshEdge`]                                     |
+//! | `replay_session` | One recorded replay session (`replay` feature)                  |
+//! | `trailer`        | Always last: record counts, so truncated streams are rejected   |
+//!
+//! Frames appear in id order and reference each other (`parent_id`, `supersedes`) by
+//! their ids in the exporting memory; importers renumber them. Payloads are the decoded
+//! canonical bytes, independent of the on-disk compression. Chunk manifests travel inside
+//! the frame metadata, and tables are ordinary frames on the `tables` track. Readers must
+//! reject a stream whose `version` is newer than [`INTERCHANGE_VERSION`] and should ignore
+//! fields they do not know.
+
+use serde::{Deserialize, Serialize};
+
+use super::frame::Frame;
+use super::logic_mesh::{MeshEdge, MeshNode};
+use super::memory_card::MemoryCard;
+
+/// Value of the header's `format` field.
+pub const INTERCHANGE_FORMAT: &str = "memvid-jsonl";
+/// Interchange format version written by this build.
+pub const INTERCHANGE_VERSION: u32 = 1;
+
+/// First record of a stream.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InterchangeHeader {
+    pub format: String,
+    pub version: u32,
+    /// Version of the `memvid-core` crate that wrote the stream.
+    pub writer: String,
+    /// Unix timestamp (seconds) of the export.
+    pub exported_at: i64,
+    /// Embedding model bound to the exporting memory's vector index.
+    #[serde(default)]
+    pub vec_model: Option<String>,
+    #[serde(default)]
+    pub vec_dimension: Option<u32>,
+}
+
+/// A frame with its payload and embedding.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrameRecord {
+    pub frame: Frame,
+    /// Base64 (standard alphabet) canonical payload; absent for frames stored without
+    /// bytes, such as chunked documents whose text lives in their chunks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedding: Option<Vec<f32>>,
+}
+
+/// Record counts closing a stream.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InterchangeCounts {
+    pub frames: usize,
+    pub embeddings: usize,
+    pub memory_cards: usize,
+    pub mesh_nodes: usize,
+    pub mesh_edges: usize,
+    #[serde(default)]
+    pub replay_sessions: usize,
+}
+
+/// One line of an interchange stream.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InterchangeRecord {
+    Header(InterchangeHeader),
+    Frame(Box<FrameRecord>),
+    MemoryCard(Box<MemoryCard>),
+    MeshNode(MeshNode),
+    MeshEdge(MeshEdge),
+    /// A serialized `ReplaySession`, kept opaque so the format does not depend on the
+    /// `replay` feature.
+    ReplaySession(serde_json::Value),
+    Trailer(InterchangeCounts),
+}
+
+/// Controls `Memvid::export`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportOptions {
+    /// Export only active frames. By default superseded and deleted frames are written
+    /// too (deleted ones without payload) so a restore reproduces the frame ids.
+    #[serde(default)]
+    pub active_only: bool,
+    #[serde(default = "default_true")]
+    pub embeddings: bool,
+    #[serde(default = "default_true")]
+    pub memories: bool,
+    #[serde(default = "default_true")]
+    pub logic_mesh: bool,
+    /// Include recorded replay sessions (only available with the `replay` feature).
+    #[serde(default = "default_true")]
+    pub replay: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            active_only: false,
+            embeddings: true,
+            memories: true,
+            logic_mesh: true,
+            replay: true,
+        }
+    }
+}
+
+impl ExportOptions {
+    /// Active frames and their derived records only, for feeding other stores.
+    #[must_use]
+    pub fn active_only() -> Self {
+        Self {
+            active_only: true,
+            ..Self::default()
+        }
+    }
+}
@@ -4,7 +4,7 @@
 //! during ingestion, allowing Memvid to follow facts instead of guessing with vectors.
 
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 
 use super::common::FrameId;
@@ -75,6 +75,20 @@ impl MeshNode {
     }
 }
 
+/// Test fixture: a node for `name` at 0.9 confidence, mentioned once in `frame_id`.
+#[cfg(test)]
+pub(crate) fn test_node(name: &str, kind: EntityKind, frame_id: FrameId) -> MeshNode {
+    MeshNode::new(
+        name.to_lowercase(),
+        name.to_string(),
+        kind,
+        0.9,
+        frame_id,
+        0,
+        1,
+    )
+}
+
 /// Entity classification.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
@@ -547,6 +561,29 @@ impl LogicMesh {
         }
     }
 
+    /// Remove everything learned from `frame_ids`: their mentions, the edges they produced,
+    /// and nodes left with no other source frame. Returns `(nodes_removed, edges_removed)`.
+    pub fn remove_frames(&mut self, frame_ids: &BTreeSet<FrameId>) -> (usize, usize) {
+        let nodes_before = self.nodes.len();
+        let edges_before = self.edges.len();
+        for node in &mut self.nodes {
+            node.frame_ids.retain(|fid| !frame_ids.contains(fid));
+            node.mentions.retain(|(fid, _, _)| !frame_ids.contains(fid));
+        }
+        self.nodes.retain(|node| !node.frame_ids.is_empty());
+        let live: HashSet<u64> = self.nodes.iter().map(|node| node.id).collect();
+        self.edges.retain(|edge| {
+            !frame_ids.contains(&edge.frame_id)
+                && live.contains(&edge.from_node)
+                && live.contains(&edge.to_node)
+        });
+        self.finalize();
+        (
+            nodes_before - self.nodes.len(),
+            edges_before - self.edges.len(),
+        )
+    }
+
     /// Prepare the mesh for serialization (sort and rebuild adjacency).
     pub fn finalize(&mut self) {
         self.nodes.sort_by_key(|n| n.id);
@@ -788,6 +788,15 @@ pub struct Toc {
     /// Tracks frames needing background Phase 2 work (full extraction + embeddings).
     #[serde(default)]
     pub enrichment_queue: EnrichmentQueueManifest,
+    /// Declarative retention rules applied by `apply_retention`.
+    #[serde(default)]
+    pub retention: super::retention::RetentionPolicy,
+    /// Recent commits and the lowest data offset each rewrote, for incremental backups.
+    #[serde(default)]
+    pub generation_marks: Vec<super::backup::GenerationMark>,
+    /// Entity aliases expanded by memory, mesh and graph lookups.
+    #[serde(default)]
+    pub entity_aliases: super::entity_alias::EntityAliasTable,
     pub merkle_root: [u8; 32],
     pub toc_checksum: [u8; 32],
 }
@@ -4,7 +4,7 @@
 //! extracted memory cards along with indices for fast lookup and enrichment
 //! tracking metadata.
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 use serde::{Deserialize, Serialize};
 
@@ -240,6 +240,11 @@ impl EnrichmentManifest {
         self.frames.keys().copied().collect()
     }
 
+    /// Forget a frame's enrichment record.
+    pub fn remove_frame(&mut self, frame_id: FrameId) {
+        self.frames.remove(&frame_id);
+    }
+
     /// Clear all enrichment records.
     pub fn clear(&mut self) {
         self.frames.clear();
@@ -559,6 +564,22 @@ impl MemoriesTrack {
         Ok(track)
     }
 
+    /// Drop every card extracted from one of `frame_ids`, along with those frames'
+    /// enrichment records. Returns the number of cards removed.
+    pub fn remove_frames(&mut self, frame_ids: &BTreeSet<FrameId>) -> usize {
+        let before = self.cards.len();
+        self.cards
+            .retain(|card| !frame_ids.contains(&card.source_frame_id));
+        for &frame_id in frame_ids {
+            self.enrichment_manifest.remove_frame(frame_id);
+        }
+        self.slot_index.clear();
+        for card in &self.cards {
+            self.slot_index.insert(card);
+        }
+        before - self.cards.len()
+    }
+
     /// Clear all cards and reset the track.
     pub fn clear(&mut self) {
         self.cards.clear();
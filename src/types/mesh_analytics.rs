@@ -0,0 +1,657 @@
+//! Graph analytics over the Logic-Mesh: paths, neighborhoods, centrality and communities.
+//!
+//! Paths, neighborhoods and communities treat edges as undirected, since a relationship
+//! connects its two entities whichever way it was extracted; PageRank follows edge
+//! direction. Every result carries the frames of the edges it relies on.
+
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use super::common::FrameId;
+use super::logic_mesh::{LinkType, LogicMesh, MeshEdge, MeshNode};
+
+/// PageRank damping factor.
+const PAGERANK_DAMPING: f64 = 0.85;
+/// PageRank stops after this many iterations even if it has not converged.
+const PAGERANK_MAX_ITERATIONS: usize = 100;
+/// PageRank stops once no score moves by more than this.
+const PAGERANK_TOLERANCE: f64 = 1e-9;
+/// Label propagation stops after this many rounds even if labels still change.
+const LABEL_PROPAGATION_MAX_ROUNDS: usize = 50;
+
+/// One edge crossed by a [`MeshPath`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MeshPathStep {
+    /// Entity the step leaves (display name).
+    pub from: String,
+    /// Entity the step reaches (display name).
+    pub to: String,
+    pub link: LinkType,
+    /// The stored edge points from `to` to `from`, i.e. the step walks it backwards.
+    pub reversed: bool,
+    /// Edge confidence (0.0-1.0).
+    pub confidence: f32,
+    /// Frame the relationship was extracted from.
+    pub frame_id: FrameId,
+}
+
+/// The shortest chain of relationships connecting two entities.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct MeshPath {
+    pub steps: Vec<MeshPathStep>,
+    /// Frames of the edges on the path, in path order without repeats.
+    pub frame_ids: Vec<FrameId>,
+}
+
+impl MeshPath {
+    /// Number of edges on the path.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// True when both ends are the same entity.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+/// Which centrality [`LogicMesh::centrality`] computes.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CentralityMeasure {
+    /// Number of distinct neighbours, normalised by `nodes - 1`.
+    #[default]
+    Degree,
+    /// PageRank over directed edges (damping 0.85).
+    PageRank,
+}
+
+/// Centrality of one entity.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CentralityScore {
+    pub node_id: u64,
+    /// Display name.
+    pub name: String,
+    pub score: f32,
+    /// Frames of the edges touching the entity, ascending.
+    pub frame_ids: Vec<FrameId>,
+}
+
+/// How [`LogicMesh::communities`] groups entities.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CommunityMethod {
+    /// Connected components.
+    #[default]
+    Components,
+    /// Label propagation weighted by edge confidence, which splits loosely joined clusters.
+    LabelPropagation,
+}
+
+/// A group of entities found by [`LogicMesh::communities`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MeshCommunity {
+    pub node_ids: Vec<u64>,
+    /// Display names, in `node_ids` order.
+    pub names: Vec<String>,
+    /// Frames of the edges inside the community, ascending.
+    pub frame_ids: Vec<FrameId>,
+}
+
+/// Edge indexes by node, ignoring direction. Built per call because the mesh's own
+/// adjacency is only refreshed by `finalize`.
+fn undirected_adjacency(edges: &[MeshEdge]) -> HashMap<u64, Vec<usize>> {
+    let mut adjacency: HashMap<u64, Vec<usize>> = HashMap::new();
+    for (index, edge) in edges.iter().enumerate() {
+        adjacency.entry(edge.from_node).or_default().push(index);
+        if edge.to_node != edge.from_node {
+            adjacency.entry(edge.to_node).or_default().push(index);
+        }
+    }
+    adjacency
+}
+
+fn other_end(edge: &MeshEdge, node: u64) -> u64 {
+    if edge.from_node == node {
+        edge.to_node
+    } else {
+        edge.from_node
+    }
+}
+
+fn push_unique(frame_ids: &mut Vec<FrameId>, frame_id: FrameId) {
+    if !frame_ids.contains(&frame_id) {
+        frame_ids.push(frame_id);
+    }
+}
+
+impl LogicMesh {
+    /// Shortest chain of relationships between two entities (looked up as by `find_node`),
+    /// optionally only through edges of the given link types.
+    ///
+    /// Returns `None` when either entity is unknown or they are not connected.
+    #[must_use]
+    pub fn shortest_path(
+        &self,
+        from: &str,
+        to: &str,
+        links: Option<&[LinkType]>,
+    ) -> Option<MeshPath> {
+        let start = self.find_node(from)?.id;
+        let goal = self.find_node(to)?.id;
+        let adjacency = undirected_adjacency(&self.edges);
+        let allowed = |edge: &MeshEdge| {
+            links.is_none_or(|links| links.iter().any(|link| link.as_str() == edge.link.as_str()))
+        };
+
+        // Breadth-first search remembering the edge each node was reached through.
+        let mut reached: HashMap<u64, Option<usize>> = HashMap::from([(start, None)]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            if node == goal {
+                break;
+            }
+            for &index in adjacency.get(&node).map_or(&[][..], Vec::as_slice) {
+                let edge = &self.edges[index];
+                if !allowed(edge) {
+                    continue;
+                }
+                let next = other_end(edge, node);
+                if let Entry::Vacant(slot) = reached.entry(next) {
+                    slot.insert(Some(index));
+                    queue.push_back(next);
+                }
+            }
+        }
+        if !reached.contains_key(&goal) {
+            return None;
+        }
+
+        let names: HashMap<u64, &str> = self
+            .nodes
+            .iter()
+            .map(|node| (node.id, node.display_name.as_str()))
+            .collect();
+        let mut steps = Vec::new();
+        let mut node = goal;
+        while let Some(&Some(index)) = reached.get(&node) {
+            let edge = &self.edges[index];
+            let previous = other_end(edge, node);
+            steps.push(MeshPathStep {
+                from: names
+                    .get(&previous)
+                    .copied()
+                    .unwrap_or_default()
+                    .to_string(),
+                to: names.get(&node).copied().unwrap_or_default().to_string(),
+                link: edge.link.clone(),
+                reversed: edge.from_node != previous,
+                confidence: edge.confidence_f32(),
+                frame_id: edge.frame_id,
+            });
+            node = previous;
+        }
+        steps.reverse();
+        let mut frame_ids = Vec::new();
+        for step in &steps {
+            push_unique(&mut frame_ids, step.frame_id);
+        }
+        Some(MeshPath { steps, frame_ids })
+    }
+
+    /// The entities within `hops` relationships of `start` and the edges between them, as a
+    /// finalized mesh of its own. Returns `None` when `start` is unknown.
+    #[must_use]
+    pub fn neighborhood(&self, start: &str, hops: usize) -> Option<LogicMesh> {
+        let start = self.find_node(start)?.id;
+        let adjacency = undirected_adjacency(&self.edges);
+        let mut depth: HashMap<u64, usize> = HashMap::from([(start, 0)]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            let next_depth = depth[&node] + 1;
+            if next_depth > hops {
+                continue;
+            }
+            for &index in adjacency.get(&node).map_or(&[][..], Vec::as_slice) {
+                let next = other_end(&self.edges[index], node);
+                if let Entry::Vacant(slot) = depth.entry(next) {
+                    slot.insert(next_depth);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let mut subgraph = LogicMesh::new();
+        subgraph.nodes = self
+            .nodes
+            .iter()
+            .filter(|node| depth.contains_key(&node.id))
+            .cloned()
+            .collect();
+        subgraph.edges = self
+            .edges
+            .iter()
+            .filter(|edge| depth.contains_key(&edge.from_node) && depth.contains_key(&edge.to_node))
+            .cloned()
+            .collect();
+        subgraph.finalize();
+        Some(subgraph)
+    }
+
+    /// Score every entity, highest first (ties by name).
+    #[must_use]
+    pub fn centrality(&self, measure: CentralityMeasure) -> Vec<CentralityScore> {
+        let scores = match measure {
+            CentralityMeasure::Degree => self.degree_scores(),
+            CentralityMeasure::PageRank => self.pagerank_scores(),
+        };
+        let mut frames: HashMap<u64, BTreeSet<FrameId>> = HashMap::new();
+        for edge in &self.edges {
+            frames
+                .entry(edge.from_node)
+                .or_default()
+                .insert(edge.frame_id);
+            frames
+                .entry(edge.to_node)
+                .or_default()
+                .insert(edge.frame_id);
+        }
+        let mut ranked: Vec<CentralityScore> = self
+            .nodes
+            .iter()
+            .map(|node| CentralityScore {
+                node_id: node.id,
+                name: node.display_name.clone(),
+                #[allow(clippy::cast_possible_truncation)]
+                score: scores.get(&node.id).copied().unwrap_or(0.0) as f32,
+                frame_ids: frames
+                    .remove(&node.id)
+                    .map(|ids| ids.into_iter().collect())
+                    .unwrap_or_default(),
+            })
+            .collect();
+        ranked.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        ranked
+    }
+
+    fn degree_scores(&self) -> HashMap<u64, f64> {
+        let mut neighbours: HashMap<u64, BTreeSet<u64>> = HashMap::new();
+        for edge in &self.edges {
+            if edge.from_node == edge.to_node {
+                continue;
+            }
+            neighbours
+                .entry(edge.from_node)
+                .or_default()
+                .insert(edge.to_node);
+            neighbours
+                .entry(edge.to_node)
+                .or_default()
+                .insert(edge.from_node);
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let scale = self.nodes.len().saturating_sub(1).max(1) as f64;
+        neighbours
+            .into_iter()
+            .map(|(node, set)| {
+                #[allow(clippy::cast_precision_loss)]
+                let degree = set.len() as f64;
+                (node, degree / scale)
+            })
+            .collect()
+    }
+
+    fn pagerank_scores(&self) -> HashMap<u64, f64> {
+        let count = self.nodes.len();
+        if count == 0 {
+            return HashMap::new();
+        }
+        let index: HashMap<u64, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(position, node)| (node.id, position))
+            .collect();
+        let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); count];
+        for edge in &self.edges {
+            if let (Some(&from), Some(&to)) = (index.get(&edge.from_node), index.get(&edge.to_node))
+            {
+                outgoing[from].push(to);
+            }
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let n = count as f64;
+        let mut rank = vec![1.0 / n; count];
+        for _ in 0..PAGERANK_MAX_ITERATIONS {
+            // Rank held by nodes without outgoing edges is spread over every node.
+            let dangling: f64 = rank
+                .iter()
+                .zip(&outgoing)
+                .filter(|(_, targets)| targets.is_empty())
+                .map(|(value, _)| value)
+                .sum();
+            let base = (1.0 - PAGERANK_DAMPING) / n + PAGERANK_DAMPING * dangling / n;
+            let mut next = vec![base; count];
+            for (from, targets) in outgoing.iter().enumerate() {
+                if targets.is_empty() {
+                    continue;
+                }
+                #[allow(clippy::cast_precision_loss)]
+                let share = PAGERANK_DAMPING * rank[from] / targets.len() as f64;
+                for &to in targets {
+                    next[to] += share;
+                }
+            }
+            let delta = rank
+                .iter()
+                .zip(&next)
+                .map(|(old, new)| (old - new).abs())
+                .fold(0.0, f64::max);
+            rank = next;
+            if delta < PAGERANK_TOLERANCE {
+                break;
+            }
+        }
+        self.nodes.iter().map(|node| node.id).zip(rank).collect()
+    }
+
+    /// Group entities into communities, largest first. Entities without edges form
+    /// communities of their own.
+    #[must_use]
+    pub fn communities(&self, method: CommunityMethod) -> Vec<MeshCommunity> {
+        let labels = match method {
+            CommunityMethod::Components => self.component_labels(),
+            CommunityMethod::LabelPropagation => self.propagated_labels(),
+        };
+        let mut groups: BTreeMap<u64, Vec<&MeshNode>> = BTreeMap::new();
+        for node in &self.nodes {
+            groups
+                .entry(labels.get(&node.id).copied().unwrap_or(node.id))
+                .or_default()
+                .push(node);
+        }
+
+        let mut communities: Vec<MeshCommunity> = groups
+            .into_values()
+            .map(|mut members| {
+                members.sort_by(|a, b| a.display_name.cmp(&b.display_name));
+                let ids: BTreeSet<u64> = members.iter().map(|node| node.id).collect();
+                let frame_ids: BTreeSet<FrameId> = self
+                    .edges
+                    .iter()
+                    .filter(|edge| ids.contains(&edge.from_node) && ids.contains(&edge.to_node))
+                    .map(|edge| edge.frame_id)
+                    .collect();
+                MeshCommunity {
+                    node_ids: members.iter().map(|node| node.id).collect(),
+                    names: members
+                        .iter()
+                        .map(|node| node.display_name.clone())
+                        .collect(),
+                    frame_ids: frame_ids.into_iter().collect(),
+                }
+            })
+            .collect();
+        communities.sort_by(|a, b| {
+            b.node_ids
+                .len()
+                .cmp(&a.node_ids.len())
+                .then_with(|| a.names.cmp(&b.names))
+        });
+        communities
+    }
+
+    /// Each node labelled with the smallest node id in its connected component.
+    fn component_labels(&self) -> HashMap<u64, u64> {
+        let adjacency = undirected_adjacency(&self.edges);
+        let mut labels: HashMap<u64, u64> = HashMap::new();
+        let mut ids: Vec<u64> = self.nodes.iter().map(|node| node.id).collect();
+        ids.sort_unstable();
+        for &root in &ids {
+            if labels.contains_key(&root) {
+                continue;
+            }
+            labels.insert(root, root);
+            let mut queue = VecDeque::from([root]);
+            while let Some(node) = queue.pop_front() {
+                for &index in adjacency.get(&node).map_or(&[][..], Vec::as_slice) {
+                    let next = other_end(&self.edges[index], node);
+                    if let Entry::Vacant(slot) = labels.entry(next) {
+                        slot.insert(root);
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        labels
+    }
+
+    /// Asynchronous label propagation in node id order: each node takes the label with the
+    /// highest summed edge confidence among its neighbours, keeping its own on a tie and
+    /// otherwise preferring the smallest label, until no label changes.
+    fn propagated_labels(&self) -> HashMap<u64, u64> {
+        let adjacency = undirected_adjacency(&self.edges);
+        let mut ids: Vec<u64> = self.nodes.iter().map(|node| node.id).collect();
+        ids.sort_unstable();
+        let mut labels: HashMap<u64, u64> = ids.iter().map(|&id| (id, id)).collect();
+        for _ in 0..LABEL_PROPAGATION_MAX_ROUNDS {
+            let mut changed = false;
+            for &node in &ids {
+                let mut weights: BTreeMap<u64, u32> = BTreeMap::new();
+                for &index in adjacency.get(&node).map_or(&[][..], Vec::as_slice) {
+                    let edge = &self.edges[index];
+                    let neighbour = other_end(edge, node);
+                    if neighbour == node {
+                        continue;
+                    }
+                    if let Some(&label) = labels.get(&neighbour) {
+                        *weights.entry(label).or_default() += u32::from(edge.confidence.max(1));
+                    }
+                }
+                let Some(&best) = weights.values().max() else {
+                    continue;
+                };
+                let current = labels[&node];
+                if weights.get(&current) == Some(&best) {
+                    continue;
+                }
+                if let Some((&label, _)) = weights.iter().find(|(_, weight)| **weight == best) {
+                    labels.insert(node, label);
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        labels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::logic_mesh::{EntityKind, compute_node_id, test_node as node};
+
+    fn id(name: &str, kind: EntityKind) -> u64 {
+        compute_node_id(&name.to_lowercase(), kind)
+    }
+
+    /// Two clusters: Alice, Bob and Project X tightly linked; Carol and Dave linked to each
+    /// other and to Bob by one weak edge. Erin has no edges.
+    fn sample_mesh() -> LogicMesh {
+        use EntityKind::{Person, Project};
+        let mut mesh = LogicMesh::new();
+        for (name, kind) in [
+            ("Alice", Person),
+            ("Bob", Person),
+            ("Project X", Project),
+            ("Carol", Person),
+            ("Dave", Person),
+            ("Erin", Person),
+        ] {
+            mesh.merge_node(node(name, kind, 0));
+        }
+        let edges = [
+            ("Alice", Person, "Bob", Person, LinkType::Manager, 0.9, 1),
+            (
+                "Bob",
+                Person,
+                "Project X",
+                Project,
+                LinkType::Member,
+                0.9,
+                2,
+            ),
+            (
+                "Alice",
+                Person,
+                "Project X",
+                Project,
+                LinkType::Owner,
+                0.8,
+                3,
+            ),
+            ("Bob", Person, "Carol", Person, LinkType::Related, 0.2, 4),
+            ("Carol", Person, "Dave", Person, LinkType::Manager, 0.9, 5),
+            ("Dave", Person, "Carol", Person, LinkType::Related, 0.9, 6),
+        ];
+        for (from, from_kind, to, to_kind, link, confidence, frame) in edges {
+            mesh.merge_edge(MeshEdge::new(
+                id(from, from_kind),
+                id(to, to_kind),
+                link,
+                confidence,
+                frame,
+            ));
+        }
+        mesh.finalize();
+        mesh
+    }
+
+    #[test]
+    fn shortest_path_walks_edges_both_ways() {
+        let mesh = sample_mesh();
+        let path = mesh.shortest_path("alice", "dave", None).expect("path");
+        let hops: Vec<(&str, &str)> = path
+            .steps
+            .iter()
+            .map(|step| (step.from.as_str(), step.to.as_str()))
+            .collect();
+        assert_eq!(
+            hops,
+            vec![("Alice", "Bob"), ("Bob", "Carol"), ("Carol", "Dave")]
+        );
+        assert_eq!(path.frame_ids.len(), 3);
+        assert_eq!(path.frame_ids[..2], [1, 4]);
+        assert!(!path.steps[0].reversed);
+
+        let back = mesh
+            .shortest_path("Project X", "Alice", None)
+            .expect("path");
+        assert_eq!(back.len(), 1);
+        assert!(back.steps[0].reversed);
+        assert_eq!(back.frame_ids, vec![3]);
+
+        let managers = [LinkType::Manager];
+        assert!(
+            mesh.shortest_path("alice", "dave", Some(&managers))
+                .is_none()
+        );
+        let path = mesh
+            .shortest_path(
+                "alice",
+                "project x",
+                Some(&[LinkType::Manager, LinkType::Member]),
+            )
+            .expect("path");
+        assert_eq!(path.frame_ids, vec![1, 2]);
+        assert!(mesh.shortest_path("alice", "erin", None).is_none());
+        assert!(mesh.shortest_path("alice", "nobody", None).is_none());
+        assert!(
+            mesh.shortest_path("bob", "Bob", None)
+                .expect("self")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn neighborhood_extracts_k_hop_subgraph() {
+        let mesh = sample_mesh();
+        let one_hop = mesh.neighborhood("carol", 1).expect("carol");
+        let mut names: Vec<&str> = one_hop
+            .nodes
+            .iter()
+            .map(|node| node.display_name.as_str())
+            .collect();
+        names.sort_unstable();
+        assert_eq!(names, vec!["Bob", "Carol", "Dave"]);
+        assert_eq!(one_hop.edges.len(), 3);
+
+        let two_hops = mesh.neighborhood("carol", 2).expect("carol");
+        assert_eq!(two_hops.nodes.len(), 5);
+        assert_eq!(two_hops.edges.len(), 6);
+        assert_eq!(two_hops.follow("alice", "manager", 1).len(), 1);
+        assert!(mesh.neighborhood("nobody", 2).is_none());
+    }
+
+    #[test]
+    fn centrality_ranks_hubs_first() {
+        let mesh = sample_mesh();
+        let degree = mesh.centrality(CentralityMeasure::Degree);
+        assert_eq!(degree[0].name, "Bob");
+        assert!((degree[0].score - 0.6).abs() < 1e-6);
+        assert_eq!(degree[0].frame_ids, vec![1, 2, 4]);
+        assert_eq!(degree.last().map(|score| score.name.as_str()), Some("Erin"));
+
+        let pagerank = mesh.centrality(CentralityMeasure::PageRank);
+        let total: f32 = pagerank.iter().map(|score| score.score).sum();
+        assert!((total - 1.0).abs() < 1e-4);
+        // Project X only receives edges, Alice only sends them.
+        let rank_of = |name: &str| {
+            pagerank
+                .iter()
+                .position(|score| score.name == name)
+                .expect("ranked")
+        };
+        assert!(rank_of("Project X") < rank_of("Alice"));
+    }
+
+    #[test]
+    fn communities_by_components_and_label_propagation() {
+        let mesh = sample_mesh();
+        let components = mesh.communities(CommunityMethod::Components);
+        assert_eq!(components.len(), 2);
+        assert_eq!(
+            components[0].names,
+            vec!["Alice", "Bob", "Carol", "Dave", "Project X"]
+        );
+        assert_eq!(components[0].frame_ids.len(), 6);
+        assert_eq!(components[1].names, vec!["Erin"]);
+        assert!(components[1].frame_ids.is_empty());
+
+        let propagated = mesh.communities(CommunityMethod::LabelPropagation);
+        let groups: Vec<Vec<&str>> = propagated
+            .iter()
+            .map(|community| community.names.iter().map(String::as_str).collect())
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                vec!["Alice", "Bob", "Project X"],
+                vec!["Carol", "Dave"],
+                vec!["Erin"]
+            ]
+        );
+        assert_eq!(propagated[1].frame_ids, vec![5, 6]);
+    }
+}
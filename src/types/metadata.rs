@@ -59,6 +59,12 @@ impl DocMetadata {
             && self.audio.as_ref().is_none_or(DocAudioMetadata::is_empty)
             && self.media.is_none()
     }
+
+    /// Stretch of audio this document covers; see [`DocAudioMetadata::time_range`].
+    #[must_use]
+    pub fn audio_range(&self) -> Option<AudioSegmentMetadata> {
+        self.audio.as_ref().and_then(DocAudioMetadata::time_range)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -90,6 +96,24 @@ impl DocAudioMetadata {
             && self.segments.is_empty()
             && self.tags.is_empty()
     }
+
+    /// Span from the first segment's start to the last segment's end, or the whole
+    /// recording when no segments are recorded.
+    #[must_use]
+    pub fn time_range(&self) -> Option<AudioSegmentMetadata> {
+        let (Some(first), Some(last)) = (self.segments.first(), self.segments.last()) else {
+            return self.duration_secs.map(|duration| AudioSegmentMetadata {
+                start_seconds: 0.0,
+                end_seconds: duration,
+                label: None,
+            });
+        };
+        Some(AudioSegmentMetadata {
+            start_seconds: first.start_seconds,
+            end_seconds: last.end_seconds,
+            label: None,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
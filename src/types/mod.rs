@@ -3,23 +3,34 @@
 pub mod adaptive;
 pub mod ask;
 pub mod audit;
+pub mod backup;
 pub mod binding;
 pub mod common;
 pub mod embedding;
 pub mod embedding_identity;
+pub mod entity_alias;
+pub mod filter;
 pub mod frame;
+pub mod graph_formats;
 pub mod graph_query;
+pub mod import;
+pub mod ingest;
+pub mod interchange;
 pub mod logic_mesh;
 pub mod manifest;
 pub mod memories_track;
 pub mod memory_card;
+pub mod mesh_analytics;
 pub mod metadata;
 pub mod options;
+pub mod purge;
 pub mod reranker;
+pub mod retention;
 pub mod schema;
 pub mod search;
 pub mod sketch_track;
 pub mod structure;
+pub mod synthesis;
 #[cfg(feature = "temporal_track")]
 pub mod temporal;
 pub mod ticket;
@@ -30,14 +41,22 @@ pub use ask::{
     AskRetriever, AskStats, VecEmbedder,
 };
 pub use audit::{AuditOptions, AuditReport, SourceSpan};
+pub use backup::{BACKUP_MAGIC, BackupReport, GenerationMark, MAX_GENERATION_MARKS};
 pub use binding::{FileInfo, MemoryBinding};
 pub use common::{
     CanonicalEncoding, EnrichmentState, EnrichmentTask, FrameId, FrameRole, FrameStatus,
     MemvidHandle, Open, Sealed, Tier,
 };
 // AnchorSource always exported - not feature-gated to maintain binary compatibility
+pub use filter::{FilterExpr, FilterField};
 pub use frame::AnchorSource;
 pub use frame::{Frame, Stats, TimelineEntry, TimelineQuery, TimelineQueryBuilder};
+pub use import::{ImportOptions, ImportReport, UriConflict};
+pub use ingest::{IngestOptions, IngestReport};
+pub use interchange::{
+    ExportOptions, FrameRecord, INTERCHANGE_FORMAT, INTERCHANGE_VERSION, InterchangeCounts,
+    InterchangeHeader, InterchangeRecord,
+};
 // Serialized manifest types - always exported for binary compatibility
 pub use manifest::TemporalSegmentDescriptor;
 pub use manifest::TemporalTrackManifest;
@@ -49,18 +68,27 @@ pub use manifest::{
     TimeSegmentDescriptor, Toc, VecIndexManifest, VecSegmentDescriptor, VectorCompression,
 };
 // Logic-Mesh types for entity-relationship graph traversal
+pub use entity_alias::{AliasSource, EntityAlias, EntityAliasTable};
+pub use graph_formats::{GraphFormat, NTriplesImport};
 pub use logic_mesh::{
     EdgeDirection, EntityKind, FollowResult, LOGIC_MESH_MAGIC, LOGIC_MESH_VERSION, LinkType,
     LogicMesh, LogicMeshStats, MeshEdge, MeshNode,
 };
+pub use mesh_analytics::{
+    CentralityMeasure, CentralityScore, CommunityMethod, MeshCommunity, MeshPath, MeshPathStep,
+};
 pub use metadata::{
     AudioSegmentMetadata, DocAudioMetadata, DocExifMetadata, DocGpsMetadata, DocMetadata,
     MediaManifest, TextChunkManifest, TextChunkRange,
 };
 pub use options::{PutManyOpts, PutOptions, PutOptionsBuilder, PutRequest};
+pub use purge::{PurgeArtifacts, PurgeReceipt, PurgeSelector, PurgedFrame};
+pub use retention::{
+    RetentionLimit, RetentionPolicy, RetentionReport, RetentionRule, RetentionRuleOutcome,
+};
 pub use search::{
-    SearchEngineKind, SearchHit, SearchHitEntity, SearchHitMetadata, SearchParams, SearchRequest,
-    SearchResponse,
+    FusionMethod, SearchEngineKind, SearchHit, SearchHitEntity, SearchHitMetadata, SearchParams,
+    SearchRequest, SearchResponse,
 };
 #[cfg(feature = "temporal_track")]
 pub use search::{SearchHitTemporal, SearchHitTemporalAnchor, SearchHitTemporalMention};
@@ -75,8 +103,8 @@ pub use verification::{
     DOCTOR_PLAN_VERSION, DoctorActionDetail, DoctorActionKind, DoctorActionPlan,
     DoctorActionReport, DoctorActionStatus, DoctorFinding, DoctorFindingCode, DoctorMetrics,
     DoctorOptions, DoctorPhaseDuration, DoctorPhaseKind, DoctorPhasePlan, DoctorPhaseReport,
-    DoctorPhaseStatus, DoctorPlan, DoctorReport, DoctorSeverity, DoctorStatus, VerificationCheck,
-    VerificationReport, VerificationStatus,
+    DoctorPhaseStatus, DoctorPlan, DoctorReport, DoctorSeverity, DoctorStatus, VacuumReport,
+    VerificationCheck, VerificationReport, VerificationStatus,
 };
 // Memory card types for structured memory extraction
 pub use memories_track::{
@@ -103,6 +131,12 @@ pub use structure::{
     ElementType, StructuredCell, StructuredChunk, StructuredCodeBlock, StructuredDocument,
     StructuredHeading, StructuredList, StructuredRow, StructuredTable, TableChunkingStrategy,
 };
+// Answer synthesis types for `ask`
+pub use synthesis::{
+    AnswerSynthesizer, BudgetedFragment, DEFAULT_SYNTHESIS_SYSTEM_PROMPT, ExtractiveSynthesizer,
+    SynthesisConfig, SynthesisPrompt, SynthesizedAnswer, budget_fragments, build_synthesis_prompt,
+    estimate_tokens, parse_cited_answer,
+};
 // Adaptive retrieval types for dynamic result set sizing
 pub use adaptive::{
     AdaptiveConfig, AdaptiveResult, AdaptiveStats, CutoffStrategy, EmbeddingQualityStats,
@@ -110,7 +144,8 @@ pub use adaptive::{
 };
 // Graph-aware query types for hybrid retrieval
 pub use graph_query::{
-    GraphMatchResult, GraphPattern, HybridSearchHit, PatternTerm, QueryPlan, TriplePattern,
+    CompareOp, GraphMatchResult, GraphPattern, GraphQuery, HybridSearchHit, PatternTerm, QueryPlan,
+    TriplePattern, ValueFilter,
 };
 // Schema types for predicate validation
 pub use schema::{
@@ -52,7 +52,8 @@ pub struct PutOptions {
     /// Saves storage for documents where only searchable text is needed.
     #[serde(default)]
     pub no_raw: bool,
-    /// Original source file path (for --no-raw reference tracking).
+    /// Original source file path (for --no-raw reference tracking). When set, the hash of
+    /// the payload is recorded as the frame's `source_sha256`.
     #[serde(default)]
     pub source_path: Option<String>,
     /// Skip ingestion if a frame with matching BLAKE3 hash already exists.
@@ -71,6 +72,11 @@ pub struct PutOptions {
     /// Default: 350ms (optimized for sub-second total ingestion).
     #[serde(default = "default_extraction_budget_ms")]
     pub extraction_budget_ms: u64,
+    /// Render the pages of a PDF into `ExtractedImage` child frames and embed them into the
+    /// CLIP index with their page number. Needs the `clip` feature and a provider attached
+    /// with `Memvid::set_clip_provider`.
+    #[serde(default)]
+    pub clip_pdf_pages: bool,
 }
 
 fn default_extraction_budget_ms() -> u64 {
@@ -101,6 +107,7 @@ impl Default for PutOptions {
             dedup: false,
             instant_index: true, // Instant searchability by default
             extraction_budget_ms: default_extraction_budget_ms(),
+            clip_pdf_pages: false,
         }
     }
 }
@@ -253,6 +260,13 @@ impl PutOptionsBuilder {
         self
     }
 
+    /// Index the pages of a PDF visually, as CLIP-embedded page images.
+    #[must_use]
+    pub fn clip_pdf_pages(mut self, enabled: bool) -> Self {
+        self.inner.clip_pdf_pages = enabled;
+        self
+    }
+
     #[must_use]
     pub fn build(self) -> PutOptions {
         self.inner
@@ -0,0 +1,77 @@
+//! Hard deletion ("right to be forgotten") selectors and receipts.
+//!
+//! `Memvid::delete_frame` only hides a frame; `Memvid::purge_frames` removes its payload
+//! and everything derived from it, then returns a [`PurgeReceipt`] that can be signed and
+//! handed to whoever asked for the deletion.
+
+use serde::{Deserialize, Serialize};
+
+use super::common::FrameId;
+use super::filter::FilterExpr;
+use super::verification::VacuumReport;
+
+/// Which frames `Memvid::purge_frames` removes.
+///
+/// Chunks and other children of a selected frame are always purged with it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PurgeSelector {
+    /// Exact frame ids, whatever their status.
+    Frames(Vec<FrameId>),
+    /// Every version ever stored under this URI.
+    Uri(String),
+    /// Every frame whose metadata matches the filter.
+    Filter(FilterExpr),
+}
+
+/// One frame removed by a purge.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PurgedFrame {
+    pub frame_id: FrameId,
+    /// Hex BLAKE3 of the stored payload, so the requester can match it to their copy.
+    pub checksum: String,
+    pub payload_length: u64,
+}
+
+/// Derived records dropped alongside the purged frames.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PurgeArtifacts {
+    pub memory_cards: usize,
+    pub mesh_nodes: usize,
+    pub mesh_edges: usize,
+    pub sketch_entries: usize,
+    pub clip_embeddings: usize,
+    /// Replay actions whose previews and hashes were redacted.
+    pub replay_actions: usize,
+}
+
+/// Evidence that a purge completed, returned by `Memvid::purge_frames`.
+///
+/// `file_checksum` fingerprints the compacted file, so an auditor can confirm the receipt
+/// describes the bytes they hold. When a signing key is supplied the receipt carries an
+/// Ed25519 signature over every other field; check it with `verify_purge_receipt`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PurgeReceipt {
+    /// Unix timestamp (seconds) when the purge finished.
+    pub purged_at: i64,
+    /// Commit generation of the purged file.
+    pub generation: u64,
+    pub frames: Vec<PurgedFrame>,
+    pub artifacts: PurgeArtifacts,
+    pub vacuum: VacuumReport,
+    /// Hex BLAKE3 of the whole file after compaction.
+    pub file_checksum: String,
+    /// Base64 Ed25519 public key of the signer.
+    #[serde(default)]
+    pub signer: Option<String>,
+    /// Base64 Ed25519 signature.
+    #[serde(default)]
+    pub signature: Option<String>,
+}
+
+impl PurgeReceipt {
+    #[must_use]
+    pub fn is_signed(&self) -> bool {
+        self.signature.is_some()
+    }
+}
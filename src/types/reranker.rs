@@ -23,6 +23,8 @@
 //! }
 //! ```
 
+use serde::{Deserialize, Serialize};
+
 use crate::error::Result;
 
 /// A document candidate for reranking.
@@ -181,7 +183,8 @@ pub trait Reranker: Send + Sync {
 }
 
 /// Enum wrapper for reranker kinds.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum RerankerKind {
     /// No reranking.
     None,
@@ -192,6 +195,7 @@ pub enum RerankerKind {
     /// LLM-based reranking.
     Llm,
     /// OpenAI-based reranking.
+    #[serde(rename = "openai")]
     OpenAI,
 }
 
@@ -0,0 +1,120 @@
+//! Declarative retention rules persisted in the TOC.
+//!
+//! A [`RetentionPolicy`] lives alongside the frames it governs, so every process that
+//! opens the memory sees the same rules. Rules are only evaluated when
+//! `Memvid::apply_retention` runs; nothing expires implicitly on open or search.
+//!
+//! ```ignore
+//! let policy = RetentionPolicy::new(vec![
+//!     RetentionRule::max_age("chat-90d", 90 * 86_400)
+//!         .with_scope(FilterExpr::eq(FilterField::Track, "chat")),
+//!     RetentionRule::keep_versions("notes-last-3", 3)
+//!         .with_scope(FilterExpr::eq(FilterField::Kind, "note")),
+//! ]);
+//! mem.set_retention_policy(policy)?;
+//! let report = mem.apply_retention()?;
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use super::common::FrameId;
+use super::filter::FilterExpr;
+use super::verification::VacuumReport;
+
+/// What a [`RetentionRule`] keeps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetentionLimit {
+    /// Expire frames whose timestamp is more than `seconds` in the past.
+    MaxAge { seconds: u64 },
+    /// Keep the newest `count` versions of each URI and expire the older ones.
+    KeepVersions { count: u32 },
+}
+
+/// One named retention rule.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetentionRule {
+    /// Label reported back by `apply_retention`.
+    pub name: String,
+    /// Frames the rule applies to; `None` covers every frame. Chunks and extracted
+    /// images are matched on their own fields, so a `role` filter can target them.
+    #[serde(default)]
+    pub scope: Option<FilterExpr>,
+    pub limit: RetentionLimit,
+}
+
+impl RetentionRule {
+    #[must_use]
+    pub fn max_age(name: impl Into<String>, seconds: u64) -> Self {
+        Self {
+            name: name.into(),
+            scope: None,
+            limit: RetentionLimit::MaxAge { seconds },
+        }
+    }
+
+    #[must_use]
+    pub fn keep_versions(name: impl Into<String>, count: u32) -> Self {
+        Self {
+            name: name.into(),
+            scope: None,
+            limit: RetentionLimit::KeepVersions { count },
+        }
+    }
+
+    #[must_use]
+    pub fn with_scope(mut self, scope: FilterExpr) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+}
+
+/// Retention rules stored in the TOC.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RetentionPolicy {
+    #[serde(default)]
+    pub rules: Vec<RetentionRule>,
+    /// Run `vacuum` after a pass that expired at least one frame, so the bytes are gone
+    /// from disk rather than only hidden.
+    #[serde(default)]
+    pub vacuum_after: bool,
+}
+
+impl RetentionPolicy {
+    #[must_use]
+    pub fn new(rules: Vec<RetentionRule>) -> Self {
+        Self {
+            rules,
+            vacuum_after: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_vacuum(mut self, vacuum_after: bool) -> Self {
+        self.vacuum_after = vacuum_after;
+        self
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+/// Per-rule outcome of a retention pass.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetentionRuleOutcome {
+    pub name: String,
+    /// Frames this rule expired; children of an expired version are not counted.
+    pub expired: usize,
+}
+
+/// Result of `Memvid::apply_retention`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetentionReport {
+    /// Every frame tombstoned by the pass, including chunks of expired documents.
+    pub tombstoned: Vec<FrameId>,
+    pub rules: Vec<RetentionRuleOutcome>,
+    /// Present when the policy asked for compaction and something expired.
+    pub vacuum: Option<VacuumReport>,
+}
@@ -3,8 +3,11 @@
 use serde::{Deserialize, Serialize};
 
 use super::common::FrameId;
+use super::filter::FilterExpr;
 #[cfg(feature = "temporal_track")]
 use super::frame::AnchorSource;
+use super::metadata::AudioSegmentMetadata;
+use super::reranker::RerankerKind;
 #[cfg(feature = "temporal_track")]
 use super::temporal::{TemporalFilter, TemporalMentionFlags, TemporalMentionKind};
 
@@ -35,6 +38,22 @@ impl Default for SearchEngineKind {
     }
 }
 
+/// How hybrid search combines the lexical and vector result lists.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FusionMethod {
+    /// Reciprocal rank fusion: each leg contributes `1 / (k + rank)`.
+    Rrf { k: f32 },
+    /// Weighted sum of each leg's scores after min-max normalisation to `[0, 1]`.
+    Weighted { lexical: f32, vector: f32 },
+}
+
+impl Default for FusionMethod {
+    fn default() -> Self {
+        Self::Rrf { k: 60.0 }
+    }
+}
+
 /// Search request accepted by the core; supports lexical, hybrid, and temporal filters.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchRequest {
@@ -65,6 +84,15 @@ pub struct SearchRequest {
     #[serde(default)]
     /// Disable sketch pre-filtering for this query.
     pub no_sketch: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Second-stage reranker applied to the candidate set before truncating to `top_k`.
+    pub reranker: Option<RerankerKind>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Structured filter over frame fields and `extra_metadata`, applied before ranking.
+    pub filter: Option<FilterExpr>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Fusion used when the search runs hybrid; defaults to RRF with `k = 60`.
+    pub fusion: Option<FusionMethod>,
 }
 
 /// A single ranked hit with snippet metadata.
@@ -86,6 +114,12 @@ pub struct SearchHit {
     pub score: Option<f32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<SearchHitMetadata>,
+    /// Raw lexical (BM25) score when the hit came through the lexical leg of a hybrid search.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lexical_score: Option<f32>,
+    /// Raw vector similarity when the hit came through the vector leg of a hybrid search.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vector_score: Option<f32>,
 }
 
 /// Entity reference in search hit metadata.
@@ -121,6 +155,9 @@ pub struct SearchHitMetadata {
     /// Custom user-defined metadata stored with the frame via `PutOptions.extra_metadata`.
     #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
     pub extra_metadata: std::collections::BTreeMap<String, String>,
+    /// Stretch of the source recording the hit covers, for frames ingested from audio.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio_range: Option<AudioSegmentMetadata>,
     #[cfg(feature = "temporal_track")]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub temporal: Option<SearchHitTemporal>,
@@ -901,6 +901,18 @@ impl SketchTrack {
         self.entries.insert(frame_id, entry);
     }
 
+    /// Blank a frame's sketch entry. Entries are stored by position, so the slot is kept
+    /// and zeroed rather than removed. Returns whether an entry was present.
+    pub fn scrub(&mut self, frame_id: FrameId) -> bool {
+        match self.entries.get_mut(&frame_id) {
+            Some(entry) => {
+                *entry = SketchEntry::new(frame_id, self.variant);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Get a sketch entry by frame ID.
     #[must_use]
     pub fn get(&self, frame_id: FrameId) -> Option<&SketchEntry> {
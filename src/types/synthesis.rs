@@ -0,0 +1,486 @@
+//! Answer synthesis trait and the built-in extractive synthesizer.
+//!
+//! `Memvid::ask` retrieves context fragments and hands them to an
+//! `AnswerSynthesizer`, which turns them into a final answer. Answers cite
+//! fragments with `[n]` markers, where `n` matches `AskCitation.index`.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! let synthesizer = ExtractiveSynthesizer::default();
+//! let response = mem.ask_with_synthesizer(request, None::<&MyEmbedder>, &synthesizer)?;
+//! println!("{}", response.answer.unwrap_or_default());
+//! ```
+
+use std::collections::BTreeSet;
+
+use super::ask::AskContextFragment;
+use crate::error::Result;
+
+/// Default system prompt used when building LLM prompts.
+pub const DEFAULT_SYNTHESIS_SYSTEM_PROMPT: &str = "You answer questions using only the numbered \
+context passages provided. Cite every claim with the passage number in square brackets, \
+e.g. [1] or [2][3]. If the passages do not contain the answer, say so.";
+
+/// Configuration shared by answer synthesizers.
+#[derive(Debug, Clone)]
+pub struct SynthesisConfig {
+    /// Maximum number of context fragments passed to the synthesizer.
+    pub max_fragments: usize,
+    /// Approximate token budget for all context fragments combined.
+    pub max_context_tokens: usize,
+    /// Approximate token budget for the generated answer.
+    pub max_answer_tokens: usize,
+    /// Maximum number of sentences the extractive synthesizer emits.
+    pub max_sentences: usize,
+    /// System prompt override for LLM synthesizers.
+    pub system_prompt: Option<String>,
+}
+
+impl Default for SynthesisConfig {
+    fn default() -> Self {
+        Self {
+            max_fragments: 8,
+            max_context_tokens: 3000,
+            max_answer_tokens: 512,
+            max_sentences: 3,
+            system_prompt: None,
+        }
+    }
+}
+
+impl SynthesisConfig {
+    /// Set the context token budget.
+    #[must_use]
+    pub fn with_max_context_tokens(mut self, tokens: usize) -> Self {
+        self.max_context_tokens = tokens;
+        self
+    }
+
+    /// Set the maximum number of fragments.
+    #[must_use]
+    pub fn with_max_fragments(mut self, fragments: usize) -> Self {
+        self.max_fragments = fragments;
+        self
+    }
+
+    /// Override the system prompt.
+    #[must_use]
+    pub fn with_system_prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.system_prompt = Some(prompt.into());
+        self
+    }
+}
+
+/// A context fragment after token budgeting, ready to be placed in a prompt.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetedFragment {
+    /// Citation index (1-based, matches `AskCitation.index`).
+    pub index: usize,
+    /// Fragment URI.
+    pub uri: String,
+    /// Optional fragment title.
+    pub title: Option<String>,
+    /// Fragment text, possibly truncated to fit the budget.
+    pub text: String,
+    /// Whether the text was truncated.
+    pub truncated: bool,
+}
+
+/// Prompt produced for chat-style LLM synthesizers.
+#[derive(Debug, Clone)]
+pub struct SynthesisPrompt {
+    /// System message.
+    pub system: String,
+    /// User message containing the numbered context and the question.
+    pub user: String,
+    /// Fragments included in the prompt.
+    pub fragments: Vec<BudgetedFragment>,
+}
+
+/// Answer returned by a synthesizer.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SynthesizedAnswer {
+    /// Answer text with `[n]` citation markers.
+    pub text: String,
+    /// Citation indices referenced by the answer, in ascending order.
+    pub cited: Vec<usize>,
+}
+
+/// Trait for turning retrieved context into an answer.
+///
+/// Fragments are passed in rank order; the fragment at position `i` is cited
+/// as `[i + 1]`.
+pub trait AnswerSynthesizer {
+    /// Return the synthesizer kind identifier.
+    fn kind(&self) -> &'static str;
+
+    /// Synthesize an answer to `question` from `fragments`.
+    ///
+    /// Returns `Ok(None)` when no answer can be produced from the context.
+    fn synthesize(
+        &self,
+        question: &str,
+        fragments: &[AskContextFragment],
+    ) -> Result<Option<SynthesizedAnswer>>;
+}
+
+/// Estimate the token count of `text` (roughly four characters per token).
+#[must_use]
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+/// Select and truncate fragments so the combined text fits the token budget.
+///
+/// Fragments keep their original citation index even when earlier ones are
+/// skipped for being empty.
+#[must_use]
+pub fn budget_fragments(
+    fragments: &[AskContextFragment],
+    config: &SynthesisConfig,
+) -> Vec<BudgetedFragment> {
+    let mut remaining = config.max_context_tokens;
+    let mut budgeted = Vec::new();
+    for (idx, fragment) in fragments.iter().enumerate() {
+        if budgeted.len() >= config.max_fragments || remaining == 0 {
+            break;
+        }
+        let text = fragment
+            .text
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        if text.is_empty() {
+            continue;
+        }
+        let tokens = estimate_tokens(&text);
+        let (text, truncated) = if tokens <= remaining {
+            remaining -= tokens;
+            (text, false)
+        } else {
+            let keep_chars = remaining.saturating_mul(4);
+            remaining = 0;
+            let truncated: String = text.chars().take(keep_chars).collect();
+            let truncated = match truncated.rfind(' ') {
+                Some(pos) if pos > 0 => truncated[..pos].to_string(),
+                _ => truncated,
+            };
+            (truncated, true)
+        };
+        if text.is_empty() {
+            break;
+        }
+        budgeted.push(BudgetedFragment {
+            index: idx + 1,
+            uri: fragment.uri.clone(),
+            title: fragment.title.clone(),
+            text,
+            truncated,
+        });
+    }
+    budgeted
+}
+
+/// Build a chat prompt with numbered context passages.
+#[must_use]
+pub fn build_synthesis_prompt(
+    question: &str,
+    fragments: &[AskContextFragment],
+    config: &SynthesisConfig,
+) -> SynthesisPrompt {
+    let budgeted = budget_fragments(fragments, config);
+    let mut user = String::from("Context passages:\n\n");
+    for fragment in &budgeted {
+        user.push_str(&format!("[{}] ", fragment.index));
+        if let Some(title) = fragment.title.as_deref().filter(|t| !t.trim().is_empty()) {
+            user.push_str(&format!("{} ({})\n", title.trim(), fragment.uri));
+        } else {
+            user.push_str(&format!("{}\n", fragment.uri));
+        }
+        user.push_str(&fragment.text);
+        if fragment.truncated {
+            user.push_str(" ...");
+        }
+        user.push_str("\n\n");
+    }
+    user.push_str(&format!("Question: {}\nAnswer:", question.trim()));
+
+    SynthesisPrompt {
+        system: config
+            .system_prompt
+            .clone()
+            .unwrap_or_else(|| DEFAULT_SYNTHESIS_SYSTEM_PROMPT.to_string()),
+        user,
+        fragments: budgeted,
+    }
+}
+
+/// Parse `[n]` citation markers from generated text.
+///
+/// Markers referencing indices outside `valid` are removed from the text so the
+/// answer never cites a passage that was not provided. Grouped markers such as
+/// `[1, 3]` are normalised to `[1][3]`.
+#[must_use]
+pub fn parse_cited_answer(text: &str, valid: &BTreeSet<usize>) -> SynthesizedAnswer {
+    let mut output = String::with_capacity(text.len());
+    let mut cited = BTreeSet::new();
+    let mut rest = text;
+    while let Some(open) = rest.find('[') {
+        output.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let Some(close) = after.find(']') else {
+            output.push_str(&rest[open..]);
+            rest = "";
+            break;
+        };
+        let inner = &after[..close];
+        let indices: Option<Vec<usize>> = inner
+            .split(',')
+            .map(|part| part.trim().parse::<usize>().ok())
+            .collect();
+        match indices {
+            Some(indices) if !inner.trim().is_empty() => {
+                for index in indices.into_iter().filter(|idx| valid.contains(idx)) {
+                    output.push_str(&format!("[{index}]"));
+                    cited.insert(index);
+                }
+            }
+            _ => {
+                output.push('[');
+                output.push_str(inner);
+                output.push(']');
+            }
+        }
+        rest = &after[close + 1..];
+    }
+    output.push_str(rest);
+
+    let text = output
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace(" .", ".");
+    SynthesizedAnswer {
+        text,
+        cited: cited.into_iter().collect(),
+    }
+}
+
+/// Deterministic synthesizer that extracts the sentences best matching the question.
+///
+/// Used as the default for `Memvid::ask` and as the fallback when an LLM
+/// synthesizer fails.
+#[derive(Debug, Clone, Default)]
+pub struct ExtractiveSynthesizer {
+    config: SynthesisConfig,
+}
+
+impl ExtractiveSynthesizer {
+    /// Create an extractive synthesizer with a custom configuration.
+    #[must_use]
+    pub fn new(config: SynthesisConfig) -> Self {
+        Self { config }
+    }
+
+    /// Access the configuration.
+    #[must_use]
+    pub fn config(&self) -> &SynthesisConfig {
+        &self.config
+    }
+}
+
+impl AnswerSynthesizer for ExtractiveSynthesizer {
+    fn kind(&self) -> &'static str {
+        "extractive"
+    }
+
+    fn synthesize(
+        &self,
+        question: &str,
+        fragments: &[AskContextFragment],
+    ) -> Result<Option<SynthesizedAnswer>> {
+        let budgeted = budget_fragments(fragments, &self.config);
+        if budgeted.is_empty() {
+            return Ok(None);
+        }
+
+        let question_terms: BTreeSet<String> = question
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|token| token.len() > 2)
+            .map(str::to_lowercase)
+            .collect();
+
+        // (score, fragment position, sentence position, citation index, sentence)
+        let mut candidates: Vec<(usize, usize, usize, usize, String)> = Vec::new();
+        for (fragment_pos, fragment) in budgeted.iter().enumerate() {
+            for (sentence_pos, sentence) in split_sentences(&fragment.text).into_iter().enumerate()
+            {
+                let overlap = sentence
+                    .split(|c: char| !c.is_alphanumeric())
+                    .filter(|token| question_terms.contains(&token.to_lowercase()))
+                    .collect::<BTreeSet<_>>()
+                    .len();
+                candidates.push((
+                    overlap,
+                    fragment_pos,
+                    sentence_pos,
+                    fragment.index,
+                    sentence,
+                ));
+            }
+        }
+
+        let matched = candidates.iter().any(|candidate| candidate.0 > 0);
+        let mut selected: Vec<(usize, usize, usize, usize, String)> = if matched {
+            let mut ranked: Vec<_> = candidates
+                .into_iter()
+                .filter(|candidate| candidate.0 > 0)
+                .collect();
+            ranked.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)).then(a.2.cmp(&b.2)));
+            ranked.truncate(self.config.max_sentences.max(1));
+            ranked
+        } else {
+            // No lexical overlap: fall back to the leading sentence of the top fragments.
+            candidates
+                .into_iter()
+                .filter(|candidate| candidate.2 == 0)
+                .take(self.config.max_sentences.max(1))
+                .collect()
+        };
+        // Present selected sentences in retrieval order for readability.
+        selected.sort_by(|a, b| a.1.cmp(&b.1).then(a.2.cmp(&b.2)));
+
+        let mut cited = BTreeSet::new();
+        let segments: Vec<String> = selected
+            .into_iter()
+            .map(|(_, _, _, index, sentence)| {
+                cited.insert(index);
+                format!("{sentence} [{index}]")
+            })
+            .collect();
+        if segments.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(SynthesizedAnswer {
+            text: segments.join(" "),
+            cited: cited.into_iter().collect(),
+        }))
+    }
+}
+
+fn split_sentences(text: &str) -> Vec<String> {
+    let mut sentences = Vec::new();
+    let mut current = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        current.push(ch);
+        let boundary =
+            matches!(ch, '.' | '!' | '?') && chars.peek().is_none_or(|next| next.is_whitespace());
+        if boundary {
+            let trimmed = current.trim();
+            if !trimmed.is_empty() {
+                sentences.push(trimmed.to_string());
+            }
+            current.clear();
+        }
+    }
+    let trimmed = current.trim();
+    if !trimmed.is_empty() {
+        sentences.push(trimmed.to_string());
+    }
+    sentences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AskContextFragmentKind;
+
+    fn fragment(rank: usize, text: &str) -> AskContextFragment {
+        AskContextFragment {
+            rank,
+            frame_id: rank as u64,
+            uri: format!("mv2://doc/{rank}"),
+            title: None,
+            score: None,
+            matches: 0,
+            range: None,
+            chunk_range: None,
+            text: text.to_string(),
+            kind: Some(AskContextFragmentKind::Full),
+            #[cfg(feature = "temporal_track")]
+            temporal: None,
+        }
+    }
+
+    #[test]
+    fn budget_truncates_to_token_limit() {
+        let fragments = vec![
+            fragment(1, "alpha beta gamma delta"),
+            fragment(2, &"word ".repeat(200)),
+            fragment(3, "never reached"),
+        ];
+        let config = SynthesisConfig::default().with_max_context_tokens(20);
+        let budgeted = budget_fragments(&fragments, &config);
+        assert_eq!(budgeted.len(), 2);
+        assert!(!budgeted[0].truncated);
+        assert!(budgeted[1].truncated);
+        let total: usize = budgeted.iter().map(|f| estimate_tokens(&f.text)).sum();
+        assert!(total <= 20);
+    }
+
+    #[test]
+    fn prompt_numbers_passages_by_citation_index() {
+        let fragments = vec![fragment(1, ""), fragment(2, "Berlin is the capital.")];
+        let prompt = build_synthesis_prompt(
+            "What is the capital?",
+            &fragments,
+            &SynthesisConfig::default(),
+        );
+        assert_eq!(prompt.fragments.len(), 1);
+        assert_eq!(prompt.fragments[0].index, 2);
+        assert!(
+            prompt
+                .user
+                .contains("[2] mv2://doc/2\nBerlin is the capital.")
+        );
+        assert!(
+            prompt
+                .user
+                .ends_with("Question: What is the capital?\nAnswer:")
+        );
+        assert_eq!(prompt.system, DEFAULT_SYNTHESIS_SYSTEM_PROMPT);
+    }
+
+    #[test]
+    fn parse_drops_unknown_citations() {
+        let valid: BTreeSet<usize> = [1, 2].into_iter().collect();
+        let parsed = parse_cited_answer("Paris [1, 7]. Also [2] and [note] [9].", &valid);
+        assert_eq!(parsed.text, "Paris [1]. Also [2] and [note].");
+        assert_eq!(parsed.cited, vec![1, 2]);
+    }
+
+    #[test]
+    fn extractive_prefers_matching_sentences() {
+        let fragments = vec![
+            fragment(1, "The weather is nice. Invoices are due on Friday."),
+            fragment(2, "Unrelated note about lunch."),
+        ];
+        let answer = ExtractiveSynthesizer::default()
+            .synthesize("When are invoices due?", &fragments)
+            .unwrap()
+            .expect("answer");
+        assert_eq!(answer.text, "Invoices are due on Friday. [1]");
+        assert_eq!(answer.cited, vec![1]);
+    }
+
+    #[test]
+    fn extractive_returns_none_without_context() {
+        let answer = ExtractiveSynthesizer::default()
+            .synthesize("anything?", &[fragment(1, "   ")])
+            .unwrap();
+        assert!(answer.is_none());
+    }
+}
@@ -343,6 +343,31 @@ pub struct VerificationCheck {
     pub details: Option<String>,
 }
 
+/// Outcome of a `vacuum` or `vacuum_incremental` pass.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VacuumReport {
+    /// File size before the pass, in bytes.
+    pub bytes_before: u64,
+    /// File size after the pass, in bytes.
+    pub bytes_after: u64,
+    /// Bytes returned to the filesystem (never negative).
+    pub bytes_reclaimed: u64,
+    /// Active frame payloads relocated by this pass.
+    pub frames_moved: usize,
+    /// Payload bytes relocated by this pass.
+    pub bytes_moved: u64,
+    /// Active frames still waiting to be packed; zero once compaction is complete.
+    pub frames_remaining: usize,
+}
+
+impl VacuumReport {
+    /// True when every active payload is packed and no further pass is needed.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.frames_remaining == 0
+    }
+}
+
 /// Status for a verification check or overall verification run.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
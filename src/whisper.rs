@@ -288,7 +288,7 @@ mod audio {
     use symphonia::core::audio::SampleBuffer;
     use symphonia::core::codecs::DecoderOptions;
     use symphonia::core::formats::FormatOptions;
-    use symphonia::core::io::MediaSourceStream;
+    use symphonia::core::io::{MediaSource, MediaSourceStream};
     use symphonia::core::meta::MetadataOptions;
     use symphonia::core::probe::Hint;
 
@@ -301,12 +301,33 @@ mod audio {
             path: path.to_path_buf(),
             cause: e.to_string(),
         })?;
+        let extension = path.extension().and_then(|e| e.to_str());
+        decode_media(Box::new(file), extension).map_err(|cause| {
+            WhisperError::AudioDecodeError {
+                path: path.to_path_buf(),
+                cause,
+            }
+            .into()
+        })
+    }
 
-        let mss = MediaSourceStream::new(Box::new(file), Default::default());
+    /// Decode in-memory audio (e.g. an ingested payload) to f32 samples, resampling to
+    /// 16kHz mono. `extension` helps the format probe, as the file extension does above.
+    pub fn decode_audio_bytes(bytes: &[u8], extension: Option<&str>) -> Result<(Vec<f32>, f32)> {
+        let source = std::io::Cursor::new(bytes.to_vec());
+        decode_media(Box::new(source), extension)
+            .map_err(|cause| WhisperError::AudioBytesDecodeError { cause }.into())
+    }
+
+    fn decode_media(
+        source: Box<dyn MediaSource>,
+        extension: Option<&str>,
+    ) -> std::result::Result<(Vec<f32>, f32), String> {
+        let mss = MediaSourceStream::new(source, Default::default());
 
         // Create a hint based on file extension
         let mut hint = Hint::new();
-        if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        if let Some(ext) = extension {
             hint.with_extension(ext);
         }
 
@@ -315,10 +336,7 @@ mod audio {
         let metadata_opts = MetadataOptions::default();
         let probed = symphonia::default::get_probe()
             .format(&hint, mss, &format_opts, &metadata_opts)
-            .map_err(|e| WhisperError::AudioDecodeError {
-                path: path.to_path_buf(),
-                cause: format!("Failed to probe audio format: {}", e),
-            })?;
+            .map_err(|e| format!("Failed to probe audio format: {}", e))?;
 
         let mut format = probed.format;
 
@@ -327,10 +345,7 @@ mod audio {
             .tracks()
             .iter()
             .find(|t| t.codec_params.codec != symphonia::core::codecs::CODEC_TYPE_NULL)
-            .ok_or_else(|| WhisperError::AudioDecodeError {
-                path: path.to_path_buf(),
-                cause: "No audio track found".to_string(),
-            })?;
+            .ok_or_else(|| "No audio track found".to_string())?;
 
         let track_id = track.id;
         let sample_rate = track.codec_params.sample_rate.unwrap_or(44100);
@@ -340,10 +355,7 @@ mod audio {
         let decoder_opts = DecoderOptions::default();
         let mut decoder = symphonia::default::get_codecs()
             .make(&track.codec_params, &decoder_opts)
-            .map_err(|e| WhisperError::AudioDecodeError {
-                path: path.to_path_buf(),
-                cause: format!("Failed to create decoder: {}", e),
-            })?;
+            .map_err(|e| format!("Failed to create decoder: {}", e))?;
 
         let mut samples: Vec<f32> = Vec::new();
 
@@ -721,6 +733,16 @@ mod inference {
             self.transcribe_pcm(&pcm_data, duration_secs)
         }
 
+        /// Transcribe in-memory audio, such as a payload passed to `put`.
+        pub fn transcribe_bytes(
+            &mut self,
+            bytes: &[u8],
+            extension: Option<&str>,
+        ) -> Result<TranscriptionResult> {
+            let (pcm_data, duration_secs) = super::decode_audio_bytes(bytes, extension)?;
+            self.transcribe_pcm(&pcm_data, duration_secs)
+        }
+
         /// Transcribe PCM audio samples (16kHz mono f32)
         pub fn transcribe_pcm(
             &mut self,
@@ -0,0 +1,122 @@
+//! Integration tests for incremental backups.
+//! Tests: full + incremental round trip, chain validation
+
+mod common;
+
+use common::put;
+use memvid_core::{Memvid, MemvidError};
+use tempfile::TempDir;
+
+fn backup(mem: &mut Memvid, since: u64) -> (Vec<u8>, u64) {
+    let mut stream = Vec::new();
+    let report = mem.backup_since(since, &mut stream).unwrap();
+    (stream, report.generation)
+}
+
+/// Test restoring a full backup plus increments reproduces the file at each generation.
+#[test]
+fn incremental_backups_restore_byte_identical() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("source.mv2");
+    let mut mem = Memvid::create(&path).unwrap();
+    put(&mut mem, "mv2://a", "alpha notes");
+    let bulk: String = (0..10_000u64)
+        .map(|i| format!("{:x} ", i.wrapping_mul(0x9e37_79b9_7f4a_7c15)))
+        .collect();
+    put(&mut mem, "mv2://b", &bulk);
+
+    let (full, full_generation) = backup(&mut mem, 0);
+    let at_full = std::fs::read(&path).unwrap();
+
+    put(
+        &mut mem,
+        "mv2://c",
+        &"gamma notes keep growing. ".repeat(500),
+    );
+    let mut first = Vec::new();
+    let report = mem.backup_since(full_generation, &mut first).unwrap();
+    let first_generation = report.generation;
+    assert!(!report.is_full());
+    assert!(first_generation > full_generation);
+    assert!(report.bytes < report.file_len, "{report:?}");
+
+    let c = mem.frame_by_uri("mv2://c").unwrap().id;
+    mem.delete_frame(c).unwrap();
+    mem.commit().unwrap();
+    put(&mut mem, "mv2://d", "delta notes");
+    let (second, _) = backup(&mut mem, first_generation);
+    let at_second = std::fs::read(&path).unwrap();
+
+    let restored = dir.path().join("full.mv2");
+    let report = Memvid::restore(&restored, full.as_slice(), Vec::<&[u8]>::new()).unwrap();
+    assert!(report.is_full());
+    assert_eq!(std::fs::read(&restored).unwrap(), at_full);
+
+    let restored = dir.path().join("latest.mv2");
+    Memvid::restore(
+        &restored,
+        full.as_slice(),
+        [first.as_slice(), second.as_slice()],
+    )
+    .unwrap();
+    assert_eq!(std::fs::read(&restored).unwrap(), at_second);
+    let reopened = Memvid::open_read_only(&restored).unwrap();
+    assert_eq!(reopened.frame_count(), mem.frame_count());
+    assert!(reopened.frame_by_uri("mv2://d").is_ok());
+}
+
+/// Test unavailable generations and broken chains are rejected.
+#[test]
+fn backup_rejects_invalid_generations_and_chains() {
+    let dir = TempDir::new().unwrap();
+    let mut mem = Memvid::create(dir.path().join("source.mv2")).unwrap();
+    put(&mut mem, "mv2://a", "alpha");
+    let (full, full_generation) = backup(&mut mem, 0);
+
+    let err = mem
+        .backup_since(full_generation + 1, Vec::new())
+        .unwrap_err();
+    assert!(
+        matches!(err, MemvidError::BackupUnavailable { .. }),
+        "{err}"
+    );
+
+    put(&mut mem, "mv2://b", "beta");
+    let (first, first_generation) = backup(&mut mem, full_generation);
+    put(&mut mem, "mv2://c", "gamma");
+    let (second, _) = backup(&mut mem, first_generation);
+
+    // An increment cannot serve as the base.
+    let err = Memvid::restore(
+        dir.path().join("a.mv2"),
+        first.as_slice(),
+        [second.as_slice()],
+    )
+    .unwrap_err();
+    assert!(matches!(err, MemvidError::InvalidBackup { .. }), "{err}");
+
+    // Skipping the first increment leaves a gap.
+    let err = Memvid::restore(
+        dir.path().join("b.mv2"),
+        full.as_slice(),
+        [second.as_slice()],
+    )
+    .unwrap_err();
+    assert!(matches!(err, MemvidError::InvalidBackup { .. }), "{err}");
+
+    // A damaged stream fails the final hash check.
+    let mut damaged = first.clone();
+    let middle = damaged.len() / 2;
+    damaged[middle] ^= 0xff;
+    let err = Memvid::restore(
+        dir.path().join("c.mv2"),
+        full.as_slice(),
+        [damaged.as_slice()],
+    )
+    .unwrap_err();
+    assert!(matches!(err, MemvidError::InvalidBackup { .. }), "{err}");
+
+    let truncated = &first[..first.len() - 1];
+    let err = Memvid::restore(dir.path().join("d.mv2"), full.as_slice(), [truncated]).unwrap_err();
+    assert!(matches!(err, MemvidError::InvalidBackup { .. }), "{err}");
+}
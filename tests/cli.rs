@@ -0,0 +1,118 @@
+//! End-to-end checks for the `memvid` command-line interface.
+
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+use serde_json::Value;
+use tempfile::TempDir;
+
+fn memvid(file: &Path, args: &[&str], stdin: Option<&str>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_memvid"))
+        .arg("--file")
+        .arg(file)
+        .args(args)
+        .env_remove("MEMVID_MEMORY")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut input = child.stdin.take().unwrap();
+    if let Some(stdin) = stdin {
+        input.write_all(stdin.as_bytes()).unwrap();
+    }
+    drop(input);
+    let output = child.wait_with_output().unwrap();
+    assert!(
+        output.status.success(),
+        "memvid {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    output
+}
+
+fn json(output: &Output) -> Value {
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn put_find_and_timeline_against_selected_file() {
+    let dir = TempDir::new().unwrap();
+    let docs = dir.path().join("docs");
+    fs::create_dir_all(docs.join("sub")).unwrap();
+    fs::write(docs.join("rust.md"), "Rust ownership and borrowing").unwrap();
+    fs::write(docs.join("sub/go.md"), "Go channels and goroutines").unwrap();
+    fs::write(docs.join("sub/skip.txt"), "Not matched by the glob").unwrap();
+    let file = dir.path().join("cli.mv2");
+
+    memvid(&file, &["create"], None);
+    let glob = format!("{}/**/*.md", docs.display());
+    let put = json(&memvid(
+        &file,
+        &["--json", "put", &glob, "--label", "notes"],
+        None,
+    ));
+    assert_eq!(put["added"].as_array().map(Vec::len), Some(2));
+    assert_eq!(put["added"][0]["uri"], "mv2://docs/rust.md");
+    assert_eq!(put["added"][1]["uri"], "mv2://docs/sub/go.md");
+    assert_eq!(put["added"][1]["frame_id"], 1);
+    let tea = json(&memvid(
+        &file,
+        &["--json", "put", "-", "--title", "Tea", "--label", "drinks"],
+        Some("Green tea notes"),
+    ));
+    let tea_id = tea["added"][0]["frame_id"].as_u64().unwrap();
+
+    let found = json(&memvid(
+        &file,
+        &["find", "ownership", "--mode", "lex", "--json"],
+        None,
+    ));
+    assert_eq!(found["hits"][0]["title"], "rust");
+
+    let filtered = json(&memvid(
+        &file,
+        &["--json", "find", "notes", "--where", "label=drinks"],
+        None,
+    ));
+    let titles: Vec<&str> = filtered["hits"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|hit| hit["title"].as_str())
+        .collect();
+    assert_eq!(titles, ["Tea"]);
+    assert_eq!(filtered["hits"][0]["frame_id"].as_u64(), Some(tea_id));
+
+    let timeline = json(&memvid(&file, &["--json", "timeline"], None));
+    assert_eq!(timeline.as_array().map(Vec::len), Some(3));
+
+    let verify = json(&memvid(&file, &["--json", "verify"], None));
+    assert_eq!(verify["overall_status"], "passed");
+
+    let plan = json(&memvid(&file, &["doctor", "--plan"], None));
+    assert_eq!(plan["version"], 1);
+}
+
+#[test]
+fn double_dash_keeps_global_flags_in_command_arguments() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("cli.mv2");
+
+    memvid(&file, &["create"], None);
+    memvid(
+        &file,
+        &["save", "--", "run", "git", "commit", "-m", "wip", "--json"],
+        None,
+    );
+
+    let found = json(&memvid(
+        &file,
+        &["--json", "find", "--mode", "lex", "commit"],
+        None,
+    ));
+    let text = found["hits"][0]["text"].as_str().unwrap();
+    assert!(text.contains("commit -m wip --json"), "{text}");
+}
@@ -0,0 +1,42 @@
+//! Fixtures shared by the integration tests.
+//!
+//! Each test binary compiles this module on its own and uses only part of it.
+#![allow(dead_code)]
+
+use memvid_core::types::{EntityKind, MeshNode};
+use memvid_core::{Memvid, PutOptions};
+
+/// Put `text` under `uri` (also used as its title) without triplet extraction, commit,
+/// and return the new frame ID.
+pub fn put(mem: &mut Memvid, uri: &str, text: &str) -> u64 {
+    let opts = PutOptions {
+        extract_triplets: false,
+        ..Default::default()
+    };
+    put_with(mem, uri, text, opts)
+}
+
+/// [`put`] with caller-supplied options; `uri` and `title` are overridden.
+pub fn put_with(mem: &mut Memvid, uri: &str, text: &str, opts: PutOptions) -> u64 {
+    let opts = PutOptions {
+        uri: Some(uri.to_string()),
+        title: Some(uri.to_string()),
+        ..opts
+    };
+    mem.put_bytes_with_options(text.as_bytes(), opts).unwrap();
+    mem.commit().unwrap();
+    mem.frame_by_uri(uri).unwrap().id
+}
+
+/// A Logic-Mesh node for `name` at 0.9 confidence, mentioned once in `frame_id`.
+pub fn entity(name: &str, kind: EntityKind, frame_id: u64) -> MeshNode {
+    MeshNode::new(
+        name.to_lowercase(),
+        name.to_string(),
+        kind,
+        0.9,
+        frame_id,
+        0,
+        1,
+    )
+}
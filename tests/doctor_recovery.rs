@@ -70,6 +70,9 @@ fn doctor_rebuilds_tantivy_index() {
                 as_of_frame: None,
                 as_of_ts: None,
                 no_sketch: false,
+                reranker: None,
+                filter: None,
+                fusion: None,
             })
             .unwrap();
 
@@ -121,6 +124,9 @@ fn doctor_rebuilds_tantivy_index() {
                 as_of_frame: None,
                 as_of_ts: None,
                 no_sketch: false,
+                reranker: None,
+                filter: None,
+                fusion: None,
             })
             .unwrap();
 
@@ -272,6 +278,9 @@ fn open_file_with_tantivy_segments_enables_lex() {
             as_of_frame: None,
             as_of_ts: None,
             no_sketch: false,
+            reranker: None,
+            filter: None,
+            fusion: None,
         });
 
         assert!(
@@ -363,6 +372,9 @@ fn doctor_rebuild_produces_searchable_index() {
                 as_of_frame: None,
                 as_of_ts: None,
                 no_sketch: false,
+                reranker: None,
+                filter: None,
+                fusion: None,
             })
             .unwrap();
 
@@ -390,6 +402,9 @@ fn doctor_rebuild_produces_searchable_index() {
                 as_of_frame: None,
                 as_of_ts: None,
                 no_sketch: false,
+                reranker: None,
+                filter: None,
+                fusion: None,
             })
             .unwrap();
 
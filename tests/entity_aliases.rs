@@ -0,0 +1,121 @@
+//! Integration tests for entity alias resolution through `Memvid`.
+//! Tests: heuristic linking persisted across reopen, alias expansion in memory lookups,
+//! `follow` and graph queries, and manual merge/split
+
+mod common;
+
+use common::{entity, put};
+use memvid_core::types::{EntityKind, MeshEdge};
+use memvid_core::{AliasSource, LinkType, MemoryCardBuilder, Memvid, MemvidError};
+use tempfile::TempDir;
+
+fn employer(mem: &mut Memvid, who: &str, org: &str, date: i64, frame_id: u64) {
+    mem.put_memory_card(
+        MemoryCardBuilder::new()
+            .fact()
+            .entity(who)
+            .slot("employer")
+            .value(org)
+            .document_date(date)
+            .source(frame_id, None)
+            .engine("test", "1.0.0")
+            .build(0)
+            .unwrap(),
+    )
+    .unwrap();
+}
+
+/// Test "Bob Smith", "Bob" and "bsmith@corp.com" answer as one person once resolved.
+#[test]
+fn resolved_aliases_expand_lookups_after_reopen() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("aliases.mv2");
+    let mut mem = Memvid::create(&path).unwrap();
+    let frames = [
+        put(
+            &mut mem,
+            "mv2://notes/org",
+            "Bob Smith (bsmith@corp.com) manages Carol Jones at Acme.",
+        ),
+        put(&mut mem, "mv2://notes/move", "Bob moved to Globex."),
+    ];
+
+    let bob = entity("Bob Smith", EntityKind::Person, frames[0]);
+    let address = entity("bsmith@corp.com", EntityKind::Email, frames[0]);
+    let carol = entity("Carol Jones", EntityKind::Person, frames[0]);
+    let manages = MeshEdge::new(bob.id, carol.id, LinkType::Manager, 0.9, frames[0]);
+    mem.add_mesh_nodes(vec![bob, address, carol]);
+    mem.add_mesh_edge(manages);
+    employer(&mut mem, "Bob Smith", "Acme", 1_000, frames[0]);
+    employer(&mut mem, "Bob", "Globex", 2_000, frames[1]);
+    assert!(mem.follow("bsmith@corp.com", "manager", 1).is_empty());
+
+    let added = mem.resolve_entities().unwrap();
+    let links: Vec<(&str, &str, AliasSource)> = added
+        .iter()
+        .map(|link| (link.alias.as_str(), link.canonical.as_str(), link.source))
+        .collect();
+    assert_eq!(
+        links,
+        vec![
+            ("bsmith@corp.com", "Bob Smith", AliasSource::EmailName),
+            ("bob", "Bob Smith", AliasSource::NameContainment),
+        ]
+    );
+    mem.commit().unwrap();
+    drop(mem);
+
+    let mem = Memvid::open_read_only(&path).unwrap();
+    assert_eq!(mem.entity_aliases().len(), 2);
+    assert_eq!(mem.resolve_entity("BOB"), "Bob Smith");
+    for name in ["bob smith", "Bob", "bsmith@corp.com"] {
+        let current = mem.get_current_memory(name, "employer").unwrap();
+        assert_eq!(current.value, "Globex");
+    }
+    let reports = mem.follow("bsmith@corp.com", "manager", 1);
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].node, "Carol Jones");
+
+    let managers = mem
+        .graph_query(r#"MATCH ("bob")-[:manager]->(?who) RETURN ?who"#)
+        .unwrap();
+    assert_eq!(managers.len(), 1);
+    assert_eq!(managers[0].entity, "Carol Jones");
+    let employers = mem
+        .graph_query("MATCH (?p)-[:employer]->(?org) RETURN ?p, ?org")
+        .unwrap();
+    assert_eq!(employers.len(), 1);
+    assert_eq!(employers[0].bindings["p"], "Bob Smith");
+    assert_eq!(employers[0].bindings["org"], "Globex");
+}
+
+/// Test a split survives re-resolution and manual merges can bring names back together.
+#[test]
+fn split_and_merge_entities() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("split.mv2");
+    let mut mem = Memvid::create(&path).unwrap();
+    let frame = put(&mut mem, "mv2://notes/join", "Bob Smith joined Acme.");
+    employer(&mut mem, "Bob Smith", "Acme", 1_000, frame);
+    employer(&mut mem, "Bob", "Globex", 2_000, frame);
+
+    assert_eq!(mem.resolve_entities().unwrap().len(), 1);
+    assert_eq!(mem.split_entity("Bob").unwrap().len(), 1);
+    assert!(mem.resolve_entities().unwrap().is_empty());
+    mem.commit().unwrap();
+    drop(mem);
+
+    let mut mem = Memvid::open(&path).unwrap();
+    assert!(mem.entity_aliases().is_separated("bob", "bob smith"));
+    let current = mem.get_current_memory("Bob Smith", "employer").unwrap();
+    assert_eq!(current.value, "Acme");
+
+    assert!(mem.merge_entities("Bob Smith", "Bob").unwrap());
+    assert!(!mem.merge_entities("bob smith", "bob").unwrap());
+    let current = mem.get_current_memory("Bob Smith", "employer").unwrap();
+    assert_eq!(current.value, "Globex");
+    assert!(matches!(
+        mem.merge_entities("Bob", " bob "),
+        Err(MemvidError::InvalidEntityAlias { .. })
+    ));
+}
@@ -0,0 +1,243 @@
+//! Integration tests for merging one memory into another.
+//! Tests: frame copy and id remapping, URI conflict policies, embedding carry-over
+
+mod common;
+
+use common::put;
+use memvid_core::types::{EntityKind, LinkType, MeshEdge, MeshNode};
+use memvid_core::{
+    FrameRole, FrameStatus, ImportOptions, MemoryCardBuilder, Memvid, PutOptions, UriConflict,
+};
+use tempfile::TempDir;
+
+fn active_uri(mem: &Memvid, uri: &str) -> Vec<u64> {
+    (0..mem.frame_count() as u64)
+        .map(|frame_id| mem.frame_by_id(frame_id).unwrap())
+        .filter(|frame| frame.status == FrameStatus::Active && frame.uri.as_deref() == Some(uri))
+        .map(|frame| frame.id)
+        .collect()
+}
+
+/// Test frames, chunks, memory cards and mesh entries arrive with remapped ids.
+#[test]
+fn import_copies_frames_cards_and_mesh() {
+    let dir = TempDir::new().unwrap();
+    let source_path = dir.path().join("alice.mv2");
+    let target_path = dir.path().join("team.mv2");
+
+    let mut source = Memvid::create(&source_path).unwrap();
+    let note = put(&mut source, "mv2://alice/note", "alice prefers green tea");
+    let report_text = "quarterly report on the quokka census. ".repeat(200);
+    put(&mut source, "mv2://alice/report", &report_text);
+    put(&mut source, "mv2://alice/gone", "deleted before the merge");
+    let gone = source.frame_by_uri("mv2://alice/gone").unwrap().id;
+    source.delete_frame(gone).unwrap();
+    source
+        .put_memory_card(
+            MemoryCardBuilder::new()
+                .preference()
+                .entity("alice")
+                .slot("drink")
+                .value("green tea")
+                .source(note, None)
+                .engine("test", "1.0.0")
+                .build(0)
+                .unwrap(),
+        )
+        .unwrap();
+    let alice = MeshNode::new(
+        "alice".into(),
+        "Alice".into(),
+        EntityKind::Person,
+        0.9,
+        note,
+        0,
+        5,
+    );
+    let tea = MeshNode::new(
+        "green tea".into(),
+        "green tea".into(),
+        EntityKind::Product,
+        0.7,
+        note,
+        14,
+        9,
+    );
+    let edge = MeshEdge::new(alice.id, tea.id, LinkType::from_str("prefers"), 0.8, note);
+    source.logic_mesh_mut().merge_node(alice);
+    source.logic_mesh_mut().merge_node(tea);
+    source.logic_mesh_mut().merge_edge(edge);
+    source.commit().unwrap();
+
+    let mut target = Memvid::create(&target_path).unwrap();
+    put(&mut target, "mv2://team/readme", "shared team memory");
+    let report = target
+        .import_from(&mut source, ImportOptions::default())
+        .unwrap();
+    assert!(!report.frame_map.contains_key(&gone));
+    assert_eq!(report.memory_cards, 1);
+    assert_eq!(report.mesh_nodes, 2);
+    assert_eq!(report.mesh_edges, 1);
+    drop(target);
+
+    let mut target = Memvid::open(&target_path).unwrap();
+    let imported_note = target.frame_by_uri("mv2://alice/note").unwrap();
+    assert_eq!(report.frame_map[&note], imported_note.id);
+    assert!(
+        target
+            .frame_text_by_id(imported_note.id)
+            .unwrap()
+            .starts_with("alice prefers green tea")
+    );
+
+    let report_frame = target.frame_by_uri("mv2://alice/report").unwrap();
+    let chunks: Vec<_> = (0..target.frame_count() as u64)
+        .map(|frame_id| target.frame_by_id(frame_id).unwrap())
+        .filter(|frame| frame.role == FrameRole::DocumentChunk)
+        .collect();
+    assert!(!chunks.is_empty());
+    assert!(
+        chunks
+            .iter()
+            .all(|chunk| chunk.parent_id == Some(report_frame.id))
+    );
+    assert!(
+        target
+            .frame_text_by_id(report_frame.id)
+            .unwrap()
+            .starts_with("quarterly report on the quokka census.")
+    );
+
+    let cards = target.memories().cards();
+    assert_eq!(cards.len(), 1);
+    assert_eq!(cards[0].source_frame_id, imported_note.id);
+    let mesh = target.logic_mesh();
+    assert_eq!(mesh.nodes.len(), 2);
+    assert!(
+        mesh.nodes
+            .iter()
+            .all(|node| node.frame_ids == vec![imported_note.id])
+    );
+    assert_eq!(mesh.edges[0].frame_id, imported_note.id);
+}
+
+/// Test skip, replace and version each settle a URI clash their own way.
+#[test]
+fn import_resolves_uri_conflicts() {
+    let dir = TempDir::new().unwrap();
+    let mut source = Memvid::create(dir.path().join("source.mv2")).unwrap();
+    put(&mut source, "mv2://shared/plan", "the new plan");
+
+    for policy in [
+        UriConflict::Skip,
+        UriConflict::Replace,
+        UriConflict::Version,
+    ] {
+        let path = dir.path().join(format!("{policy:?}.mv2"));
+        let mut target = Memvid::create(&path).unwrap();
+        let original = put(&mut target, "mv2://shared/plan", "the old plan");
+
+        let report = target
+            .import_from(&mut source, ImportOptions::new(policy))
+            .unwrap();
+        let active = active_uri(&target, "mv2://shared/plan");
+        assert_eq!(active.len(), 1, "{policy:?}");
+        let text = target.frame_text_by_id(active[0]).unwrap();
+        let status = target.frame_by_id(original).unwrap().status;
+        match policy {
+            UriConflict::Skip => {
+                assert_eq!(report.skipped.len(), 1);
+                assert!(report.frame_map.is_empty());
+                assert!(text.starts_with("the old plan"));
+            }
+            UriConflict::Replace => {
+                assert_eq!(report.replaced, vec![original]);
+                assert_eq!(status, FrameStatus::Deleted);
+                assert!(text.starts_with("the new plan"));
+            }
+            UriConflict::Version => {
+                assert_eq!(report.versioned, 1);
+                assert_eq!(status, FrameStatus::Superseded);
+                let head = target.frame_by_id(active[0]).unwrap();
+                assert_eq!(head.supersedes, Some(original));
+                assert!(text.starts_with("the new plan"));
+            }
+        }
+    }
+}
+
+/// Test supersede chains survive when history is requested.
+#[test]
+fn import_with_history_keeps_supersede_chain() {
+    let dir = TempDir::new().unwrap();
+    let mut source = Memvid::create(dir.path().join("source.mv2")).unwrap();
+    let first = put(&mut source, "mv2://doc/spec", "spec version one");
+    source
+        .update_frame(
+            first,
+            Some(b"spec version two".to_vec()),
+            PutOptions::default(),
+            None,
+        )
+        .unwrap();
+    source.commit().unwrap();
+
+    let mut target = Memvid::create(dir.path().join("target.mv2")).unwrap();
+    let report = target
+        .import_from(&mut source, ImportOptions::default().with_superseded(true))
+        .unwrap();
+    assert_eq!(report.imported(), 2);
+    let old = target.frame_by_id(report.frame_map[&first]).unwrap();
+    assert_eq!(old.status, FrameStatus::Superseded);
+    let head = target
+        .frame_by_id(old.superseded_by.expect("successor"))
+        .unwrap();
+    assert_eq!(head.supersedes, Some(old.id));
+    let source_head = source.frame_by_uri("mv2://doc/spec").unwrap();
+    assert_eq!(head.checksum, source_head.checksum);
+    assert_eq!(
+        target.frame_text_by_id(head.id).unwrap(),
+        source.frame_text_by_id(source_head.id).unwrap()
+    );
+}
+
+/// Test embeddings are reused only when the vector models agree.
+#[test]
+fn import_carries_embeddings_for_matching_model() {
+    let dir = TempDir::new().unwrap();
+    let mut source = Memvid::create(dir.path().join("source.mv2")).unwrap();
+    source.enable_vec().unwrap();
+    source.set_vec_model("test-embedder").unwrap();
+    source
+        .put_with_embedding_and_options(
+            b"vector note",
+            vec![0.25, 0.5, 0.75, 1.0],
+            PutOptions {
+                uri: Some("mv2://vec/note".into()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    source.commit().unwrap();
+    let source_id = source.frame_by_uri("mv2://vec/note").unwrap().id;
+
+    let mut same = Memvid::create(dir.path().join("same.mv2")).unwrap();
+    same.set_vec_model("test-embedder").unwrap();
+    let report = same
+        .import_from(&mut source, ImportOptions::default())
+        .unwrap();
+    assert_eq!(report.embeddings_copied, 1);
+    assert_eq!(
+        same.frame_embedding(report.frame_map[&source_id]).unwrap(),
+        Some(vec![0.25, 0.5, 0.75, 1.0])
+    );
+
+    let mut other = Memvid::create(dir.path().join("other.mv2")).unwrap();
+    other.set_vec_model("another-embedder").unwrap();
+    let report = other
+        .import_from(&mut source, ImportOptions::default())
+        .unwrap();
+    assert_eq!(report.embeddings_copied, 0);
+    assert_eq!(report.embeddings_dropped, 1);
+    assert_eq!(report.imported(), 1);
+}
@@ -0,0 +1,142 @@
+//! Integration tests for mirroring a directory into a memory.
+//! Tests: URI mapping, zip expansion, unchanged/updated/deleted re-sync, filters
+
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use memvid_core::{FrameStatus, IngestOptions, Memvid};
+use tempfile::TempDir;
+use zip::write::SimpleFileOptions;
+
+fn write_zip(path: &Path, entries: &[(&str, &str)]) {
+    let mut writer = zip::ZipWriter::new(fs::File::create(path).unwrap());
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+    for (name, text) in entries {
+        writer.start_file(*name, options).unwrap();
+        writer.write_all(text.as_bytes()).unwrap();
+    }
+    writer.finish().unwrap();
+}
+
+fn active_uris(mem: &Memvid) -> Vec<String> {
+    let mut uris: Vec<String> = (0..mem.frame_count() as u64)
+        .map(|frame_id| mem.frame_by_id(frame_id).unwrap())
+        .filter(|frame| frame.status == FrameStatus::Active && frame.parent_id.is_none())
+        .filter_map(|frame| frame.uri)
+        .collect();
+    uris.sort();
+    uris
+}
+
+/// Test a second run skips unchanged files, supersedes changed ones and deletes removed ones.
+#[test]
+fn ingest_dir_resyncs_tree() {
+    let dir = TempDir::new().unwrap();
+    let docs = dir.path().join("docs");
+    fs::create_dir_all(docs.join("guide")).unwrap();
+    fs::write(docs.join("readme.md"), "# Readme\n\nThe quokka handbook.").unwrap();
+    fs::write(docs.join("guide/setup.txt"), "Install the burrow first.").unwrap();
+    fs::write(docs.join(".hidden.txt"), "not mirrored").unwrap();
+    write_zip(
+        &docs.join("archive.zip"),
+        &[("notes/old.txt", "Notes from the archive."), ("empty/", "")],
+    );
+
+    let mut mem = Memvid::create(dir.path().join("docs.mv2")).unwrap();
+    let report = mem.ingest_dir(&docs, IngestOptions::new("docs")).unwrap();
+    assert_eq!(
+        report.added,
+        vec![
+            "mv2://docs/archive.zip/notes/old.txt",
+            "mv2://docs/guide/setup.txt",
+            "mv2://docs/readme.md",
+        ]
+    );
+    assert_eq!(active_uris(&mem), report.added);
+    let setup = mem.frame_by_uri("mv2://docs/guide/setup.txt").unwrap();
+    assert_eq!(setup.title.as_deref(), Some("setup.txt"));
+    assert!(setup.source_sha256.is_some());
+    assert!(
+        setup
+            .source_path
+            .as_deref()
+            .is_some_and(|path| path.ends_with("setup.txt"))
+    );
+
+    let unchanged = mem.ingest_dir(&docs, IngestOptions::new("docs")).unwrap();
+    assert!(!unchanged.changed());
+    assert_eq!(unchanged.unchanged, 3);
+
+    fs::write(
+        docs.join("guide/setup.txt"),
+        "Install the burrow, then the den.",
+    )
+    .unwrap();
+    fs::remove_file(docs.join("readme.md")).unwrap();
+    let report = mem.ingest_dir(&docs, IngestOptions::new("docs")).unwrap();
+    assert!(report.added.is_empty());
+    assert_eq!(report.updated, vec!["mv2://docs/guide/setup.txt"]);
+    assert_eq!(report.deleted, vec!["mv2://docs/readme.md"]);
+    assert_eq!(report.unchanged, 1);
+
+    let updated = mem.frame_by_uri("mv2://docs/guide/setup.txt").unwrap();
+    assert_eq!(updated.supersedes, Some(setup.id));
+    assert!(
+        mem.frame_text_by_id(updated.id)
+            .unwrap()
+            .contains("then the den")
+    );
+    assert_eq!(
+        active_uris(&mem),
+        vec![
+            "mv2://docs/archive.zip/notes/old.txt",
+            "mv2://docs/guide/setup.txt",
+        ]
+    );
+}
+
+/// Test the prefix defaults to the directory name and filters leave other files alone.
+#[test]
+fn ingest_dir_filters_and_default_prefix() {
+    let dir = TempDir::new().unwrap();
+    let handbook = dir.path().join("handbook");
+    fs::create_dir_all(&handbook).unwrap();
+    fs::write(handbook.join("intro.md"), "Welcome to the handbook.").unwrap();
+    fs::write(handbook.join("data.csv"), "a,b\n1,2\n").unwrap();
+    write_zip(
+        &handbook.join("bundle.zip"),
+        &[("extra.md", "Bundled page.")],
+    );
+
+    let mut mem = Memvid::create(dir.path().join("handbook.mv2")).unwrap();
+    mem.put_bytes_with_options(
+        b"outside the mirrored prefix",
+        memvid_core::PutOptions {
+            uri: Some("mv2://notes/keep".into()),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    mem.commit().unwrap();
+
+    let options = IngestOptions::default()
+        .extensions(["md"])
+        .expand_archives(false);
+    let report = mem.ingest_dir(&handbook, options.clone()).unwrap();
+    assert_eq!(report.added, vec!["mv2://handbook/intro.md"]);
+
+    let options = options.expand_archives(true);
+    let report = mem.ingest_dir(&handbook, options).unwrap();
+    assert_eq!(report.added, vec!["mv2://handbook/bundle.zip/extra.md"]);
+    assert_eq!(report.unchanged, 1);
+    assert!(report.deleted.is_empty());
+    assert_eq!(
+        active_uris(&mem),
+        vec![
+            "mv2://handbook/bundle.zip/extra.md",
+            "mv2://handbook/intro.md",
+            "mv2://notes/keep",
+        ]
+    );
+}
@@ -0,0 +1,260 @@
+//! Integration tests for the JSONL interchange format.
+//! Tests: export/import round trip, stream validation, graph export and N-Triples import
+
+mod common;
+
+use common::put;
+use memvid_core::types::{EntityKind, MeshEdge, MeshNode};
+use memvid_core::{
+    ExportOptions, FrameStatus, GraphFormat, INTERCHANGE_VERSION, InterchangeRecord, LinkType,
+    MemoryCardBuilder, Memvid, MemvidError, PutOptions,
+};
+use tempfile::TempDir;
+
+fn exported(mem: &mut Memvid, options: ExportOptions) -> Vec<u8> {
+    let mut stream = Vec::new();
+    mem.export(&mut stream, options).unwrap();
+    stream
+}
+
+/// Test a restore into an empty memory reproduces frames, history and derived records.
+#[test]
+fn export_import_round_trip() {
+    let dir = TempDir::new().unwrap();
+    let mut source = Memvid::create(dir.path().join("source.mv2")).unwrap();
+    source.enable_vec().unwrap();
+    source.set_vec_model("test-embedder").unwrap();
+    source
+        .put_with_embedding_and_options(
+            b"vector note",
+            vec![0.125, -0.5, 0.75, 1.0],
+            PutOptions {
+                uri: Some("mv2://notes/vector".into()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    source.commit().unwrap();
+    let spec = put(&mut source, "mv2://docs/spec", "spec version one");
+    source
+        .update_frame(
+            spec,
+            Some(b"spec version two".to_vec()),
+            PutOptions::default(),
+            None,
+        )
+        .unwrap();
+    source.commit().unwrap();
+    put(
+        &mut source,
+        "mv2://docs/report",
+        &"the quokka census counted many quokkas. ".repeat(200),
+    );
+    let gone = put(&mut source, "mv2://docs/gone", "removed before export");
+    source.delete_frame(gone).unwrap();
+    source
+        .put_memory_card(
+            MemoryCardBuilder::new()
+                .fact()
+                .entity("spec")
+                .slot("version")
+                .value("two")
+                .source(spec, None)
+                .engine("test", "1.0.0")
+                .build(0)
+                .unwrap(),
+        )
+        .unwrap();
+    source.logic_mesh_mut().merge_node(MeshNode::new(
+        "quokka".into(),
+        "Quokka".into(),
+        EntityKind::Other,
+        0.8,
+        spec,
+        0,
+        6,
+    ));
+    source.commit().unwrap();
+
+    let stream = exported(&mut source, ExportOptions::default());
+    let first_line = std::str::from_utf8(&stream)
+        .unwrap()
+        .lines()
+        .next()
+        .unwrap();
+    assert!(matches!(
+        serde_json::from_str::<InterchangeRecord>(first_line).unwrap(),
+        InterchangeRecord::Header(header) if header.version == INTERCHANGE_VERSION
+    ));
+
+    let mut restored = Memvid::create(dir.path().join("restored.mv2")).unwrap();
+    let report = restored.import(stream.as_slice()).unwrap();
+    assert_eq!(report.imported(), source.frame_count());
+    assert!(report.frame_map.iter().all(|(from, to)| from == to));
+    assert_eq!(report.embeddings_copied, 1);
+    assert_eq!(report.memory_cards, 1);
+    assert_eq!(report.mesh_nodes, 1);
+
+    for frame_id in 0..source.frame_count() as u64 {
+        let original = source.frame_by_id(frame_id).unwrap();
+        let copy = restored.frame_by_id(frame_id).unwrap();
+        assert_eq!(copy.status, original.status, "frame {frame_id}");
+        assert_eq!(copy.uri, original.uri);
+        assert_eq!(copy.role, original.role);
+        assert_eq!(copy.parent_id, original.parent_id);
+        assert_eq!(copy.supersedes, original.supersedes);
+        if original.status != FrameStatus::Deleted {
+            assert_eq!(
+                restored.frame_text_by_id(frame_id).unwrap(),
+                source.frame_text_by_id(frame_id).unwrap()
+            );
+        }
+    }
+    let vector = source.frame_by_uri("mv2://notes/vector").unwrap().id;
+    assert_eq!(
+        restored.frame_embedding(vector).unwrap(),
+        Some(vec![0.125, -0.5, 0.75, 1.0])
+    );
+    assert_eq!(restored.memories().cards()[0].source_frame_id, spec);
+
+    // Exporting the restored memory yields the same records, bar the header timestamp.
+    let again = exported(&mut restored, ExportOptions::default());
+    let body = |bytes: &[u8]| -> Vec<String> {
+        std::str::from_utf8(bytes)
+            .unwrap()
+            .lines()
+            .skip(1)
+            .filter(|line| line.contains(r#""type":"frame""#))
+            .map(|line| {
+                let mut value: serde_json::Value = serde_json::from_str(line).unwrap();
+                for field in ["payload_offset", "payload_length", "checksum"] {
+                    value["frame"][field] = serde_json::Value::Null;
+                }
+                value.to_string()
+            })
+            .collect()
+    };
+    assert_eq!(body(&again), body(&stream));
+}
+
+/// Test truncated and newer streams are rejected before anything is written.
+#[test]
+fn import_rejects_invalid_streams() {
+    let dir = TempDir::new().unwrap();
+    let mut source = Memvid::create(dir.path().join("source.mv2")).unwrap();
+    put(&mut source, "mv2://a", "alpha");
+    put(&mut source, "mv2://b", "beta");
+    let stream = String::from_utf8(exported(&mut source, ExportOptions::active_only())).unwrap();
+    let lines: Vec<&str> = stream.lines().collect();
+
+    let mut target = Memvid::create(dir.path().join("target.mv2")).unwrap();
+    let truncated = lines[..lines.len() - 1].join("\n");
+    let err = target.import(truncated.as_bytes()).unwrap_err();
+    assert!(
+        matches!(err, MemvidError::InvalidInterchange { .. }),
+        "{err}"
+    );
+
+    let newer = stream.replacen(
+        &format!(r#""version":{INTERCHANGE_VERSION}"#),
+        &format!(r#""version":{}"#, INTERCHANGE_VERSION + 1),
+        1,
+    );
+    let err = target.import(newer.as_bytes()).unwrap_err();
+    assert!(
+        matches!(err, MemvidError::InvalidInterchange { line: 1, .. }),
+        "{err}"
+    );
+
+    let headless = lines[1..].join("\n");
+    assert!(target.import(headless.as_bytes()).is_err());
+    assert_eq!(target.frame_count(), 0);
+
+    let report = target.import(stream.as_bytes()).unwrap();
+    assert_eq!(report.imported(), 2);
+    assert!(
+        target
+            .frame_text_by_id(target.frame_by_uri("mv2://b").unwrap().id)
+            .unwrap()
+            .starts_with("beta")
+    );
+}
+
+/// Test the knowledge graph exports to every format and N-Triples loads back into a mesh.
+#[test]
+fn graph_export_and_ntriples_import() {
+    let dir = TempDir::new().unwrap();
+    let mut source = Memvid::create(dir.path().join("graph.mv2")).unwrap();
+    let memo = put(&mut source, "mv2://docs/memo", "Alice manages Bob at Acme.");
+    let alice = MeshNode::new(
+        "alice".into(),
+        "Alice".into(),
+        EntityKind::Person,
+        0.9,
+        memo,
+        0,
+        5,
+    );
+    let bob = MeshNode::new(
+        "bob".into(),
+        "Bob".into(),
+        EntityKind::Person,
+        0.9,
+        memo,
+        14,
+        3,
+    );
+    source.add_mesh_edge(MeshEdge::new(
+        alice.id,
+        bob.id,
+        LinkType::Manager,
+        0.8,
+        memo,
+    ));
+    source.add_mesh_nodes(vec![alice, bob]);
+    source
+        .put_memory_card(
+            MemoryCardBuilder::new()
+                .fact()
+                .entity("alice")
+                .slot("employer")
+                .value("Acme")
+                .source(memo, None)
+                .engine("test", "1.0.0")
+                .build(0)
+                .unwrap(),
+        )
+        .unwrap();
+    source.commit().unwrap();
+
+    let render = |format| {
+        let mut out = Vec::new();
+        source.export_graph(&mut out, format).unwrap();
+        String::from_utf8(out).unwrap()
+    };
+    let graphml = render(GraphFormat::GraphMl);
+    assert!(graphml.contains("<node id=\"urn:memvid:entity:alice\">"));
+    assert!(graphml.contains("<data key=\"link\">employer</data>"));
+    assert!(render(GraphFormat::Dot).contains("-> \"urn:memvid:entity:bob\" [label=\"manager\""));
+    let triples = render(GraphFormat::NTriples);
+    assert!(triples.contains("<urn:memvid:entity:alice> <urn:memvid:slot:employer> \"Acme\" ."));
+
+    let mut target = Memvid::create(dir.path().join("target.mv2")).unwrap();
+    let report = target.import_ntriples(triples.as_bytes(), 0).unwrap();
+    assert_eq!((report.nodes, report.edges), (2, 1));
+    target.commit().unwrap();
+    drop(target);
+
+    let target = Memvid::open_read_only(dir.path().join("target.mv2")).unwrap();
+    let managed = target.follow("alice", "manager", 1);
+    assert_eq!(managed.len(), 1);
+    assert_eq!(managed[0].node, "Bob");
+    assert_eq!(target.logic_mesh().edges[0].frame_id, memo);
+
+    let mut target = Memvid::open(dir.path().join("target.mv2")).unwrap();
+    let err = target
+        .import_ntriples("<urn:a> <urn:b> .\n".as_bytes(), 0)
+        .unwrap_err();
+    assert!(matches!(err, MemvidError::InvalidNTriples { line: 1, .. }));
+    assert_eq!(target.mesh_edge_count(), 1);
+}
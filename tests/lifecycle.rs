@@ -1,7 +1,7 @@
 //! Integration tests for Memvid lifecycle operations.
 //! Tests: create, open, open_read_only, commit, stats, verify
 
-use memvid_core::{Memvid, PutOptions, VerificationStatus};
+use memvid_core::{FrameStatus, Memvid, PutOptions, VerificationStatus};
 use std::fs;
 use tempfile::TempDir;
 
@@ -266,3 +266,93 @@ fn commit_without_changes() {
         "Commit without changes should not significantly change file size"
     );
 }
+
+/// Test that a read-only snapshot is isolated from a concurrent writer until refreshed.
+#[test]
+fn snapshot_reader_isolated_until_refresh() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    let mut writer = Memvid::create(&path).unwrap();
+    writer
+        .put_bytes_with_options(b"First content", PutOptions::default())
+        .unwrap();
+    writer.commit().unwrap();
+
+    // Readers do not block the writer (and vice versa).
+    let mut reader = Memvid::open_read_only(&path).unwrap();
+    let generation = reader.generation();
+    assert_eq!(reader.stats().unwrap().frame_count, 1);
+
+    for i in 0..3 {
+        let content = format!("Later content {i}");
+        writer
+            .put_bytes_with_options(content.as_bytes(), PutOptions::default())
+            .unwrap();
+        writer.commit().unwrap();
+    }
+
+    assert_eq!(reader.generation(), generation);
+    assert_eq!(
+        reader.stats().unwrap().frame_count,
+        1,
+        "snapshot should not observe later commits"
+    );
+
+    assert!(
+        reader.refresh().unwrap(),
+        "refresh should pick up new commits"
+    );
+    assert!(reader.generation() > generation);
+    assert_eq!(reader.stats().unwrap().frame_count, 4);
+    assert!(!reader.refresh().unwrap(), "no commits since last refresh");
+
+    assert!(
+        reader
+            .put_bytes_with_options(b"nope", PutOptions::default())
+            .is_err(),
+        "snapshot readers cannot write"
+    );
+}
+
+/// Test that a snapshot still reads a frame the writer deletes and vacuums away.
+#[test]
+fn snapshot_reads_deleted_frame_across_vacuum() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    let mut writer = Memvid::create(&path).unwrap();
+    let doomed_opts = PutOptions {
+        uri: Some("mv2://doomed".to_string()),
+        ..Default::default()
+    };
+    writer
+        .put_bytes_with_options(b"Doomed alpha content", doomed_opts)
+        .unwrap();
+    writer
+        .put_bytes_with_options(b"Surviving beta content", PutOptions::default())
+        .unwrap();
+    writer.commit().unwrap();
+    let doomed = writer.frame_by_uri("mv2://doomed").unwrap().id;
+
+    let mut reader = Memvid::open_read_only(&path).unwrap();
+
+    writer.delete_frame(doomed).unwrap();
+    writer.commit().unwrap();
+    writer.vacuum().unwrap();
+    writer
+        .put_bytes_with_options(b"Gamma content written after vacuum", PutOptions::default())
+        .unwrap();
+    writer.commit().unwrap();
+
+    let text = reader.frame_text_by_id(doomed).unwrap();
+    assert!(text.starts_with("Doomed alpha content"), "{text}");
+    assert_eq!(reader.stats().unwrap().frame_count, 2);
+
+    assert!(reader.refresh().unwrap());
+    assert_ne!(
+        reader.frame_by_id(doomed).unwrap().status,
+        FrameStatus::Active
+    );
+    assert_eq!(reader.stats().unwrap().active_frame_count, 2);
+}
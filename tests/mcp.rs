@@ -0,0 +1,126 @@
+//! Drives the `memvid-mcp` binary over stdio with a scripted MCP client.
+#![cfg(feature = "mcp")]
+
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Command, Stdio};
+
+use memvid_core::Memvid;
+use memvid_core::replay::ActionType;
+use serde_json::{Value, json};
+use tempfile::TempDir;
+
+#[test]
+fn scripted_client_session_is_recorded() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("agent.mv2");
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_memvid-mcp"))
+        .arg(&path)
+        .args(["--create", "--record", "scripted"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+
+    let mut next_id = 0;
+    let mut request = |method: &str, params: Value| -> Value {
+        next_id += 1;
+        let message =
+            json!({ "jsonrpc": "2.0", "id": next_id, "method": method, "params": params });
+        writeln!(stdin, "{message}").unwrap();
+        let mut line = String::new();
+        stdout.read_line(&mut line).unwrap();
+        let reply: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(reply["id"], next_id);
+        reply
+    };
+    let tool = |reply: &Value| -> Value {
+        assert_eq!(reply["result"]["isError"], false, "{reply}");
+        let text = reply["result"]["content"][0]["text"].as_str().unwrap();
+        serde_json::from_str(text).unwrap()
+    };
+
+    let init = request("initialize", json!({ "protocolVersion": "2024-11-05" }));
+    assert_eq!(init["result"]["serverInfo"]["name"], "memvid");
+
+    let tools = request("tools/list", json!({}));
+    let names: Vec<&str> = tools["result"]["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tool| tool["name"].as_str().unwrap())
+        .collect();
+    for expected in [
+        "memory_put",
+        "memory_search",
+        "memory_ask",
+        "memory_get_current",
+        "memory_timeline",
+        "mesh_follow",
+    ] {
+        assert!(names.contains(&expected), "missing tool {expected}");
+    }
+
+    let put = tool(&request(
+        "tools/call",
+        json!({ "name": "memory_put", "arguments": {
+            "title": "Standup", "label": "notes",
+            "text": "Alice moved the launch to Thursday."
+        }}),
+    ));
+    let frame_id = put["frame_id"].as_u64().unwrap();
+
+    let search = tool(&request(
+        "tools/call",
+        json!({ "name": "memory_search", "arguments": { "query": "launch" } }),
+    ));
+    assert_eq!(search["hits"][0]["frame_id"].as_u64(), Some(frame_id));
+
+    let timeline = tool(&request(
+        "tools/call",
+        json!({ "name": "memory_timeline", "arguments": {} }),
+    ));
+    assert_eq!(timeline.as_array().map(Vec::len), Some(1));
+
+    let current = tool(&request(
+        "tools/call",
+        json!({ "name": "memory_get_current", "arguments": { "entity": "alice", "slot": "employer" } }),
+    ));
+    assert!(current.is_null());
+
+    let follow = tool(&request(
+        "tools/call",
+        json!({ "name": "mesh_follow", "arguments": { "start": "Alice", "link": "works_at" } }),
+    ));
+    assert_eq!(follow, json!([]));
+
+    drop(stdin);
+    assert!(child.wait().unwrap().success());
+
+    let mut reopened = Memvid::open_read_only(&path).unwrap();
+    reopened.load_replay_sessions().unwrap();
+    let sessions = reopened.list_sessions();
+    assert_eq!(sessions.len(), 1);
+    let session = reopened.get_session(sessions[0].session_id).unwrap();
+    let calls: Vec<&str> = session
+        .actions
+        .iter()
+        .filter_map(|action| match &action.action_type {
+            ActionType::ToolCall { name, .. } => Some(name.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        calls,
+        [
+            "memory_put",
+            "memory_search",
+            "memory_timeline",
+            "memory_get_current",
+            "mesh_follow"
+        ]
+    );
+}
@@ -0,0 +1,68 @@
+//! Integration tests for Logic-Mesh analytics through `Memvid`.
+//! Tests: paths with supporting frames after reopen, neighborhoods, centrality, communities
+
+mod common;
+
+use common::entity;
+use memvid_core::types::{EntityKind, MeshEdge};
+use memvid_core::{CentralityMeasure, CommunityMethod, LinkType, Memvid, PutOptions};
+use tempfile::TempDir;
+
+/// Test "how is Alice connected to Project X" is answered with the frames behind each hop.
+#[test]
+fn mesh_path_survives_reopen_with_supporting_frames() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("mesh.mv2");
+    let mut mem = Memvid::create(&path).unwrap();
+    let mut frames = Vec::new();
+    for text in [
+        "Alice manages Bob.",
+        "Bob is a member of Project X.",
+        "Carol works at Acme.",
+    ] {
+        frames.push(
+            mem.put_bytes_with_options(text.as_bytes(), PutOptions::default())
+                .unwrap(),
+        );
+    }
+    mem.commit().unwrap();
+
+    let alice = entity("Alice", EntityKind::Person, frames[0]);
+    let bob = entity("Bob", EntityKind::Person, frames[0]);
+    let project = entity("Project X", EntityKind::Project, frames[1]);
+    let carol = entity("Carol", EntityKind::Person, frames[2]);
+    let acme = entity("Acme", EntityKind::Organization, frames[2]);
+    let edges = vec![
+        MeshEdge::new(alice.id, bob.id, LinkType::Manager, 0.9, frames[0]),
+        MeshEdge::new(bob.id, project.id, LinkType::Member, 0.8, frames[1]),
+        MeshEdge::new(carol.id, acme.id, LinkType::Employer, 0.9, frames[2]),
+    ];
+    mem.add_mesh_nodes(vec![alice, bob, project, carol, acme]);
+    mem.add_mesh_edges(edges);
+    mem.commit().unwrap();
+    drop(mem);
+
+    let mem = Memvid::open_read_only(&path).unwrap();
+    let found = mem.mesh_path("alice", "project x", None).unwrap();
+    assert_eq!(found.len(), 2);
+    assert_eq!(found.frame_ids, vec![frames[0], frames[1]]);
+    assert_eq!(found.steps[1].link, LinkType::Member);
+    assert!(
+        mem.mesh_path("alice", "project x", Some(&[LinkType::Manager]))
+            .is_none()
+    );
+    assert!(mem.mesh_path("alice", "acme", None).is_none());
+
+    let around_bob = mem.mesh_neighborhood("bob", 1).unwrap();
+    assert_eq!(around_bob.nodes.len(), 3);
+    assert_eq!(around_bob.edges.len(), 2);
+
+    let ranked = mem.mesh_centrality(CentralityMeasure::Degree);
+    assert_eq!(ranked[0].name, "Bob");
+    assert_eq!(ranked[0].frame_ids, vec![frames[0], frames[1]]);
+
+    let communities = mem.mesh_communities(CommunityMethod::Components);
+    assert_eq!(communities.len(), 2);
+    assert_eq!(communities[0].names, vec!["Alice", "Bob", "Project X"]);
+    assert_eq!(communities[1].frame_ids, vec![frames[2]]);
+}
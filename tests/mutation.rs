@@ -1,11 +1,14 @@
 //! Integration tests for Memvid mutation operations.
-//! Tests: put, put_bytes_with_options, update, delete
+//! Tests: put, put_bytes_with_options, put_many, update, delete, image and PDF page ingestion
 
 use memvid_core::{
-    EmbeddingIdentitySummary, MEMVID_EMBEDDING_MODEL_KEY, MEMVID_EMBEDDING_PROVIDER_KEY, Memvid,
-    MemvidError, PutOptions, TimelineQuery,
+    ClipEmbeddingProvider, EmbeddingIdentitySummary, MEMVID_EMBEDDING_MODEL_KEY,
+    MEMVID_EMBEDDING_PROVIDER_KEY, Memvid, MemvidError, PutManyOpts, PutOptions, PutRequest,
+    TimelineQuery, VerificationStatus,
 };
 use std::num::NonZeroU64;
+use std::path::Path;
+use std::sync::Arc;
 use tempfile::TempDir;
 
 /// Test basic put operation with bytes.
@@ -402,6 +405,91 @@ fn put_moderately_large_content() {
     // The important thing is no panic/error occurred
 }
 
+/// Build a batch request for a chat message.
+fn chat_request(i: usize) -> PutRequest {
+    PutRequest {
+        title: format!("Message {i}"),
+        label: "chat".to_string(),
+        text: format!("chat message number {i} about batch ingestion"),
+        uri: Some(format!("mv2://chat/{i}")),
+        metadata: [("speaker".to_string(), serde_json::json!("alice"))]
+            .into_iter()
+            .collect(),
+        tags: vec!["conversation".to_string()],
+        labels: vec!["chat".to_string(), "inbox".to_string()],
+        embedding: None,
+    }
+}
+
+/// Test batch ingestion writes every request and is published by one commit.
+#[test]
+fn put_many_batch() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    {
+        let mut mem = Memvid::create(&path).unwrap();
+        let requests: Vec<PutRequest> = (0..200).map(chat_request).collect();
+        let opts = PutManyOpts {
+            compression_level: 1,
+            ..Default::default()
+        };
+
+        let frame_ids = mem.put_many(requests, opts).unwrap();
+        assert_eq!(frame_ids.len(), 200);
+        assert!(frame_ids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(
+            mem.put_many(Vec::new(), PutManyOpts::default())
+                .unwrap()
+                .is_empty()
+        );
+        mem.commit().unwrap();
+        let frame = mem.frame_by_id(frame_ids[42]).unwrap();
+        assert_eq!(frame.uri.as_deref(), Some("mv2://chat/42"));
+    }
+
+    let mem = Memvid::open_read_only(&path).unwrap();
+    assert_eq!(mem.stats().unwrap().frame_count, 200);
+
+    let frame = mem.frame_by_uri("mv2://chat/42").unwrap();
+    assert_eq!(frame.title.as_deref(), Some("Message 42"));
+    assert_eq!(frame.labels, vec!["chat".to_string(), "inbox".to_string()]);
+    assert!(frame.tags.contains(&"conversation".to_string()));
+    assert_eq!(
+        frame.extra_metadata.get("speaker").map(String::as_str),
+        Some("alice")
+    );
+}
+
+/// Test batch ingestion records a single `PutMany` replay action.
+#[test]
+#[cfg(feature = "replay")]
+fn put_many_records_replay_action() {
+    use memvid_core::ActionType;
+
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    let mut mem = Memvid::create(&path).unwrap();
+    mem.start_session(Some("batch".to_string()), None).unwrap();
+    let frame_ids = mem
+        .put_many((0..5).map(chat_request).collect(), PutManyOpts::default())
+        .unwrap();
+    let session = mem.end_session().unwrap();
+
+    assert_eq!(session.actions.len(), 1);
+    match &session.actions[0].action_type {
+        ActionType::PutMany {
+            frame_ids: recorded,
+            count,
+        } => {
+            assert_eq!(*count, 5);
+            assert_eq!(recorded, &frame_ids);
+        }
+        other => panic!("unexpected action: {other:?}"),
+    }
+}
+
 /// Test timeline iteration.
 #[test]
 fn timeline_iteration() {
@@ -434,3 +522,360 @@ fn timeline_iteration() {
 
     assert_eq!(entries.len(), 3, "Should have 3 timeline entries");
 }
+
+/// Roughly 2KB of poorly compressible text, kept under the chunking threshold.
+fn bulky_text(seed: usize) -> String {
+    (0..80)
+        .map(|i| {
+            format!(
+                "note{seed} line{i} {:x} ",
+                (seed * 7919 + i * 104_729) % 65_521
+            )
+        })
+        .collect()
+}
+
+/// Test vacuum swaps in a smaller file that keeps surviving frames readable.
+#[test]
+fn vacuum_shrinks_file_after_deletes() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    let mut mem = Memvid::create(&path).unwrap();
+    for i in 0..6 {
+        let opts = PutOptions {
+            uri: Some(format!("mv2://doc{i}")),
+            ..Default::default()
+        };
+        mem.put_bytes_with_options(bulky_text(i).as_bytes(), opts)
+            .unwrap();
+        mem.commit().unwrap();
+    }
+    for i in 0..4 {
+        let frame_id = mem.frame_by_uri(&format!("mv2://doc{i}")).unwrap().id;
+        mem.delete_frame(frame_id).unwrap();
+    }
+
+    let kept: Vec<_> = (4..6)
+        .map(|i| mem.frame_by_uri(&format!("mv2://doc{i}")).unwrap().id)
+        .collect();
+    let before: Vec<_> = kept
+        .iter()
+        .map(|&id| mem.frame_canonical_payload(id).unwrap())
+        .collect();
+
+    let report = mem.vacuum().unwrap();
+    assert!(report.is_complete());
+    assert!(report.bytes_reclaimed > 0, "{report:?}");
+    assert_eq!(report.bytes_after, std::fs::metadata(&path).unwrap().len());
+    drop(mem);
+
+    let verify = Memvid::verify(&path, true).unwrap();
+    assert_eq!(verify.overall_status, VerificationStatus::Passed);
+    let mut mem = Memvid::open(&path).unwrap();
+    for (&id, bytes) in kept.iter().zip(&before) {
+        assert_eq!(&mem.frame_canonical_payload(id).unwrap(), bytes);
+    }
+}
+
+/// Test budgeted vacuum passes converge on a fully packed file.
+#[test]
+fn vacuum_incremental_respects_budget() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    let mut mem = Memvid::create(&path).unwrap();
+    for i in 0..5 {
+        let opts = PutOptions {
+            uri: Some(format!("mv2://doc{i}")),
+            ..Default::default()
+        };
+        mem.put_bytes_with_options(bulky_text(i).as_bytes(), opts)
+            .unwrap();
+        mem.commit().unwrap();
+    }
+    let size_before = std::fs::metadata(&path).unwrap().len();
+    for i in 0..3 {
+        let id = mem.frame_by_uri(&format!("mv2://doc{i}")).unwrap().id;
+        mem.delete_frame(id).unwrap();
+    }
+    let kept: Vec<_> = (3..5)
+        .map(|i| mem.frame_by_uri(&format!("mv2://doc{i}")).unwrap().id)
+        .collect();
+    let before: Vec<_> = kept
+        .iter()
+        .map(|&id| mem.frame_canonical_payload(id).unwrap())
+        .collect();
+
+    let mut passes = 0;
+    loop {
+        let report = mem.vacuum_incremental(1).unwrap();
+        passes += 1;
+        assert!(report.frames_moved <= 1, "{report:?}");
+        if report.is_complete() {
+            break;
+        }
+    }
+    assert!(passes > 1);
+    assert_eq!(mem.vacuum_incremental(1).unwrap().frames_moved, 0);
+    assert!(std::fs::metadata(&path).unwrap().len() < size_before);
+
+    for (&id, bytes) in kept.iter().zip(&before) {
+        assert_eq!(&mem.frame_canonical_payload(id).unwrap(), bytes);
+    }
+    drop(mem);
+    let verify = Memvid::verify(&path, true).unwrap();
+    assert_eq!(verify.overall_status, VerificationStatus::Passed);
+}
+
+/// CLIP provider that embeds every image as a fixed vector.
+struct FixedClipProvider;
+
+impl ClipEmbeddingProvider for FixedClipProvider {
+    fn kind(&self) -> &str {
+        "fixed"
+    }
+
+    fn model(&self) -> &str {
+        "fixed-4"
+    }
+
+    fn dimension(&self) -> usize {
+        4
+    }
+
+    fn embed_image_file(&self, path: &Path) -> memvid_core::Result<Vec<f32>> {
+        self.embed_image_bytes(&std::fs::read(path)?)
+    }
+
+    fn embed_image_bytes(&self, _bytes: &[u8]) -> memvid_core::Result<Vec<f32>> {
+        Ok(vec![1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn embed_query(&self, _text: &str) -> memvid_core::Result<Vec<f32>> {
+        Ok(vec![1.0, 0.0, 0.0, 0.0])
+    }
+}
+
+/// Big-endian TIFF block: Make and Model in IFD0, and a GPS IFD at 51°30'N 0°7'30"W.
+fn exif_block() -> Vec<u8> {
+    fn entry(tiff: &mut Vec<u8>, tag: u16, kind: u16, count: u32, value: u32) {
+        tiff.extend_from_slice(&tag.to_be_bytes());
+        tiff.extend_from_slice(&kind.to_be_bytes());
+        tiff.extend_from_slice(&count.to_be_bytes());
+        tiff.extend_from_slice(&value.to_be_bytes());
+    }
+    let mut tiff = b"MM\0\x2a\0\0\0\x08".to_vec();
+    // IFD0 at 8 holds 3 entries and ends at 8 + 2 + 36 + 4 = 50.
+    tiff.extend_from_slice(&3u16.to_be_bytes());
+    entry(&mut tiff, 0x010F, 2, 6, 50);
+    entry(&mut tiff, 0x0110, 2, 3, u32::from_be_bytes(*b"X1\0\0"));
+    entry(&mut tiff, 0x8825, 4, 1, 56);
+    tiff.extend_from_slice(&[0; 4]);
+    tiff.extend_from_slice(b"Fjord\0");
+    // GPS IFD at 56 holds 4 entries and ends at 56 + 2 + 48 + 4 = 110.
+    tiff.extend_from_slice(&4u16.to_be_bytes());
+    entry(&mut tiff, 1, 2, 2, u32::from_be_bytes(*b"N\0\0\0"));
+    entry(&mut tiff, 2, 5, 3, 110);
+    entry(&mut tiff, 3, 2, 2, u32::from_be_bytes(*b"W\0\0\0"));
+    entry(&mut tiff, 4, 5, 3, 134);
+    tiff.extend_from_slice(&[0; 4]);
+    for (numerator, denominator) in [(51, 1), (30, 1), (0, 1), (0, 1), (7, 1), (30, 1)] {
+        tiff.extend_from_slice(&u32::to_be_bytes(numerator));
+        tiff.extend_from_slice(&u32::to_be_bytes(denominator));
+    }
+    tiff
+}
+
+/// JPEG headers only: an EXIF APP1 segment and a 640x480 baseline frame header.
+fn jpeg_with_exif() -> Vec<u8> {
+    let mut app1 = b"Exif\0\0".to_vec();
+    app1.extend(exif_block());
+    let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE1];
+    jpeg.extend_from_slice(&u16::try_from(app1.len() + 2).unwrap().to_be_bytes());
+    jpeg.extend(app1);
+    jpeg.extend_from_slice(&[0xFF, 0xC0, 0, 11, 8, 0x01, 0xE0, 0x02, 0x80, 1, 1, 0x11, 0]);
+    jpeg.extend_from_slice(&[0xFF, 0xD9]);
+    jpeg
+}
+
+/// Test putting a JPEG records its dimensions, EXIF, GPS and media manifest and embeds it
+/// with the attached CLIP provider.
+#[test]
+fn put_image_extracts_exif_and_clip_embedding() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+    let mut mem = Memvid::create(&path).unwrap();
+    mem.set_clip_provider(Arc::new(FixedClipProvider));
+
+    let opts = PutOptions {
+        uri: Some("mv2://field/harbour.jpg".to_string()),
+        ..Default::default()
+    };
+    mem.put_bytes_with_options(&jpeg_with_exif(), opts).unwrap();
+    // A PNG without EXIF still gets its dimensions.
+    let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+    png.extend_from_slice(&[0, 0, 0, 32, 0, 0, 0, 16, 8, 6, 0, 0, 0, 0, 0, 0, 0]);
+    mem.put_bytes(&png).unwrap();
+    mem.commit().unwrap();
+    drop(mem);
+
+    let mut mem = Memvid::open(&path).unwrap();
+    let photo = mem.frame_by_uri("mv2://field/harbour.jpg").unwrap();
+    let metadata = photo.metadata.clone().expect("image metadata");
+    assert_eq!(metadata.mime.as_deref(), Some("image/jpeg"));
+    assert_eq!((metadata.width, metadata.height), (Some(640), Some(480)));
+    let exif = metadata.exif.expect("exif");
+    assert_eq!(exif.make.as_deref(), Some("Fjord"));
+    assert_eq!(exif.model.as_deref(), Some("X1"));
+    let gps = exif.gps.expect("gps");
+    assert!((gps.latitude - 51.5).abs() < 1e-9, "{gps:?}");
+    assert!((gps.longitude + 0.125).abs() < 1e-9, "{gps:?}");
+
+    let media = mem.media_manifest(photo.id).unwrap().expect("manifest");
+    assert_eq!(media.kind, "image");
+    assert_eq!(media.filename.as_deref(), Some("harbour.jpg"));
+    assert_eq!(media.codec.as_deref(), Some("jpeg"));
+    assert_eq!((media.width, media.height), (Some(640), Some(480)));
+
+    let hits = mem.search_clip(&[1.0, 0.0, 0.0, 0.0], 5).unwrap();
+    assert_eq!(hits.len(), 2);
+    assert!(hits.iter().any(|hit| hit.frame_id == photo.id));
+
+    let other = mem
+        .frame_by_id(
+            hits.iter()
+                .map(|hit| hit.frame_id)
+                .find(|id| *id != photo.id)
+                .unwrap(),
+        )
+        .unwrap();
+    let metadata = other.metadata.expect("png metadata");
+    assert_eq!((metadata.width, metadata.height), (Some(32), Some(16)));
+    assert!(metadata.exif.is_none());
+}
+
+/// Two-page PDF whose second page draws a 128x128 RGB gradient.
+fn pdf_with_page_image() -> Vec<u8> {
+    use lopdf::{Document, Object, Stream, dictionary};
+
+    let mut doc = Document::with_version("1.5");
+    let pages_id = doc.new_object_id();
+    let pixels: Vec<u8> = (0..128u32 * 128)
+        .flat_map(|i| {
+            let (x, y) = (i % 128, i / 128);
+            [(x * 2) as u8, (y * 2) as u8, (x + y) as u8]
+        })
+        .collect();
+    let image_id = doc.add_object(Stream::new(
+        dictionary! {
+            "Type" => "XObject",
+            "Subtype" => "Image",
+            "Width" => 128i64,
+            "Height" => 128i64,
+            "ColorSpace" => "DeviceRGB",
+            "BitsPerComponent" => 8i64,
+        },
+        pixels,
+    ));
+    let mut kids = Vec::new();
+    for with_image in [false, true] {
+        let resources = if with_image {
+            dictionary! { "XObject" => dictionary! { "Im1" => image_id } }
+        } else {
+            dictionary! {}
+        };
+        let contents = doc.add_object(Stream::new(dictionary! {}, Vec::new()));
+        let page = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "Contents" => contents,
+            "Resources" => resources,
+            "MediaBox" => vec![0i64.into(), 0i64.into(), 200i64.into(), 200i64.into()],
+        });
+        kids.push(Object::from(page));
+    }
+    doc.objects.insert(
+        pages_id,
+        Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Kids" => kids,
+            "Count" => 2i64,
+        }),
+    );
+    let catalog = doc.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+    doc.trailer.set("Root", catalog);
+    let mut bytes = Vec::new();
+    doc.save_to(&mut bytes).unwrap();
+    bytes
+}
+
+fn clip_pdf_options() -> PutOptions {
+    PutOptions::builder()
+        .uri("mv2://reports/site.pdf")
+        .title("Site survey")
+        .clip_pdf_pages(true)
+        .build()
+}
+
+/// Test visual PDF indexing fails up front, writing nothing, without a provider or the
+/// `clip` feature.
+#[test]
+fn put_pdf_page_images_require_clip_provider() {
+    let dir = TempDir::new().unwrap();
+    let mut mem = Memvid::create(dir.path().join("test.mv2")).unwrap();
+    let pdf = pdf_with_page_image();
+
+    let err = mem
+        .put_bytes_with_options(&pdf, clip_pdf_options())
+        .unwrap_err();
+    assert!(matches!(err, MemvidError::ClipNotEnabled), "{err}");
+
+    #[cfg(not(feature = "clip"))]
+    {
+        mem.set_clip_provider(Arc::new(FixedClipProvider));
+        let err = mem
+            .put_bytes_with_options(&pdf, clip_pdf_options())
+            .unwrap_err();
+        assert!(
+            matches!(err, MemvidError::FeatureUnavailable { feature: "clip" }),
+            "{err}"
+        );
+    }
+    assert_eq!(mem.next_frame_id(), 0);
+}
+
+/// Test PDF pages become CLIP-embedded `ExtractedImage` children of the document, with
+/// hits mapping back to the page.
+#[test]
+#[cfg(feature = "clip")]
+fn put_pdf_indexes_page_images() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+    let mut mem = Memvid::create(&path).unwrap();
+    mem.set_clip_provider(Arc::new(FixedClipProvider));
+    mem.put_bytes_with_options(&pdf_with_page_image(), clip_pdf_options())
+        .unwrap();
+    mem.commit().unwrap();
+    drop(mem);
+
+    let mut mem = Memvid::open(&path).unwrap();
+    let document = mem.frame_by_uri("mv2://reports/site.pdf").unwrap();
+    let page = mem
+        .frame_by_uri("mv2://reports/site.pdf#page-2-image")
+        .unwrap();
+    assert_eq!(page.role, memvid_core::FrameRole::ExtractedImage);
+    assert_eq!(page.parent_id, Some(document.id));
+    assert_eq!(page.title.as_deref(), Some("Site survey (page 2 image)"));
+    let metadata = page.metadata.expect("page image metadata");
+    assert_eq!(metadata.mime.as_deref(), Some("image/png"));
+    assert_eq!((metadata.width, metadata.height), (Some(128), Some(128)));
+    assert!(
+        mem.frame_by_uri("mv2://reports/site.pdf#page-1-image")
+            .is_err()
+    );
+
+    let hits = mem.search_clip(&[1.0, 0.0, 0.0, 0.0], 5).unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!((hits[0].frame_id, hits[0].page), (page.id, Some(2)));
+}
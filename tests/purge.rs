@@ -0,0 +1,228 @@
+//! Integration tests for hard-delete purges.
+//! Tests: byte removal, derived artifact scrubbing, signed receipts, selectors
+
+mod common;
+
+use ed25519_dalek::SigningKey;
+use memvid_core::types::{EntityKind, LinkType, MeshEdge, MeshNode, SketchVariant};
+use memvid_core::{
+    FrameStatus, MemoryCardBuilder, Memvid, PurgeSelector, PutOptions, SearchRequest,
+    VerificationStatus, verify_purge_receipt,
+};
+use tempfile::TempDir;
+
+const SECRET: &str = "zanzibar quokka ledger belongs to alice";
+
+fn put(mem: &mut Memvid, uri: &str, text: &str) -> u64 {
+    let opts = PutOptions {
+        search_text: Some(text.to_string()),
+        ..Default::default()
+    };
+    common::put_with(mem, uri, text, opts)
+}
+
+fn card(frame_id: u64, entity: &str, value: &str) -> memvid_core::MemoryCard {
+    MemoryCardBuilder::new()
+        .fact()
+        .entity(entity)
+        .slot("note")
+        .value(value)
+        .source(frame_id, None)
+        .engine("test", "1.0.0")
+        .build(0)
+        .unwrap()
+}
+
+/// Test a purge removes the payload bytes and every derived record, and signs the receipt.
+#[test]
+#[cfg(feature = "lex")]
+fn purge_removes_bytes_and_derived_artifacts() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+    let key = SigningKey::from_bytes(&[9u8; 32]);
+
+    let (alice, bob, receipt) = {
+        let mut mem = Memvid::create(&path).unwrap();
+        mem.enable_lex().unwrap();
+        let alice = put(&mut mem, "mv2://people/alice", SECRET);
+        let bob = put(
+            &mut mem,
+            "mv2://people/bob",
+            "bob likes ordinary sandwiches",
+        );
+
+        mem.put_memory_card(card(alice, "alice", "zanzibar"))
+            .unwrap();
+        mem.put_memory_card(card(bob, "bob", "sandwiches")).unwrap();
+        let alice_node = MeshNode::new(
+            "alice".into(),
+            "Alice".into(),
+            EntityKind::Person,
+            0.9,
+            alice,
+            0,
+            5,
+        );
+        let bob_node = MeshNode::new(
+            "bob".into(),
+            "Bob".into(),
+            EntityKind::Person,
+            0.9,
+            bob,
+            0,
+            3,
+        );
+        let edge = MeshEdge::new(
+            alice_node.id,
+            bob_node.id,
+            LinkType::from_str("knows"),
+            0.8,
+            alice,
+        );
+        mem.logic_mesh_mut().merge_node(alice_node);
+        mem.logic_mesh_mut().merge_node(bob_node);
+        mem.logic_mesh_mut().merge_edge(edge);
+        mem.build_all_sketches(SketchVariant::Small);
+        mem.commit().unwrap();
+
+        let receipt = mem
+            .purge_frames(&PurgeSelector::Uri("mv2://people/alice".into()), Some(&key))
+            .unwrap();
+        (alice, bob, receipt)
+    };
+
+    assert_eq!(receipt.frames.len(), 1);
+    assert_eq!(receipt.frames[0].frame_id, alice);
+    assert_eq!(receipt.artifacts.memory_cards, 1);
+    assert_eq!(receipt.artifacts.mesh_nodes, 1);
+    assert_eq!(receipt.artifacts.mesh_edges, 1);
+    assert_eq!(receipt.artifacts.sketch_entries, 1);
+    verify_purge_receipt(&key.verifying_key(), &receipt).unwrap();
+
+    let bytes = std::fs::read(&path).unwrap();
+    assert_eq!(
+        blake3::hash(&bytes).to_hex().to_string(),
+        receipt.file_checksum
+    );
+    assert!(
+        !bytes.windows(8).any(|window| window == b"zanzibar"),
+        "purged text must not survive anywhere in the file"
+    );
+
+    let mut mem = Memvid::open(&path).unwrap();
+    let purged = mem.frame_by_id(alice).unwrap();
+    assert_eq!(purged.status, FrameStatus::Deleted);
+    assert!(purged.uri.is_none() && purged.search_text.is_none());
+    assert_eq!(mem.memories().card_count(), 1);
+    assert_eq!(mem.logic_mesh().nodes.len(), 1);
+    assert_eq!(mem.sketches().get(alice).unwrap().simhash, 0);
+    assert_ne!(mem.sketches().get(bob).unwrap().simhash, 0);
+    let hits = mem
+        .search(SearchRequest {
+            query: "zanzibar".to_string(),
+            top_k: 10,
+            snippet_chars: 200,
+            uri: None,
+            scope: None,
+            cursor: None,
+            #[cfg(feature = "temporal_track")]
+            temporal: None,
+            as_of_frame: None,
+            as_of_ts: None,
+            no_sketch: false,
+            reranker: None,
+            filter: None,
+            fusion: None,
+        })
+        .unwrap()
+        .hits;
+    assert!(hits.is_empty(), "purged text must not be searchable");
+    assert!(
+        mem.frame_text_by_id(bob)
+            .unwrap()
+            .starts_with("bob likes ordinary sandwiches")
+    );
+    drop(mem);
+    assert_ne!(
+        Memvid::verify(&path, false).unwrap().overall_status,
+        VerificationStatus::Failed
+    );
+}
+
+fn frames(mem: &Memvid) -> Vec<memvid_core::Frame> {
+    (0..mem.frame_count() as u64)
+        .map(|frame_id| mem.frame_by_id(frame_id).unwrap())
+        .collect()
+}
+
+/// Test explicit frame selection takes chunks and older versions stay untouched.
+#[test]
+fn purge_by_frame_includes_chunks() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    let mut mem = Memvid::create(&path).unwrap();
+    let long_text = "private diary entry about the quokka. ".repeat(200);
+    put(&mut mem, "mv2://diary/2024", &long_text);
+    let keep = put(&mut mem, "mv2://notes/keep", "shopping list");
+    let diary = frames(&mem)
+        .into_iter()
+        .find(|frame| frame.parent_id.is_none() && frame.uri.as_deref() == Some("mv2://diary/2024"))
+        .unwrap()
+        .id;
+    let chunks = frames(&mem)
+        .iter()
+        .filter(|frame| frame.parent_id == Some(diary))
+        .count();
+    assert!(chunks > 0, "long document should be chunked");
+
+    let receipt = mem
+        .purge_frames(&PurgeSelector::Frames(vec![diary]), None)
+        .unwrap();
+    assert_eq!(receipt.frames.len(), chunks + 1);
+    assert!(!receipt.is_signed());
+    assert_eq!(mem.frame_by_id(keep).unwrap().status, FrameStatus::Active);
+    assert!(
+        frames(&mem)
+            .iter()
+            .filter(|frame| frame.id != keep)
+            .all(|frame| frame.status == FrameStatus::Deleted && frame.payload_length == 0)
+    );
+}
+
+/// Test replay actions that logged purged content are redacted on disk.
+#[test]
+#[cfg(all(feature = "lex", feature = "replay"))]
+fn purge_redacts_replay_actions() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    {
+        let mut mem = Memvid::create(&path).unwrap();
+        mem.enable_lex().unwrap();
+        mem.start_session(Some("intake".to_string()), None).unwrap();
+        put(&mut mem, "mv2://people/alice", SECRET);
+        mem.end_session().unwrap();
+        mem.commit().unwrap();
+        mem.save_replay_sessions().unwrap();
+        mem.commit().unwrap();
+
+        let receipt = mem
+            .purge_frames(&PurgeSelector::Uri("mv2://people/alice".into()), None)
+            .unwrap();
+        assert!(receipt.artifacts.replay_actions > 0);
+    }
+
+    let bytes = std::fs::read(&path).unwrap();
+    assert!(!bytes.windows(8).any(|window| window == b"zanzibar"));
+
+    let mut mem = Memvid::open_read_only(&path).unwrap();
+    mem.load_replay_sessions().unwrap();
+    let session = mem.list_sessions()[0].session_id;
+    let actions = &mem.get_session(session).unwrap().actions;
+    assert!(
+        actions
+            .iter()
+            .any(|action| action.input_preview == "[purged]")
+    );
+}
@@ -44,6 +44,9 @@ fn replay_save_does_not_corrupt_lex_index() {
                 as_of_frame: None,
                 as_of_ts: None,
                 no_sketch: false,
+                reranker: None,
+                filter: None,
+                fusion: None,
             })
             .unwrap();
         mem.end_session().unwrap();
@@ -69,6 +72,9 @@ fn replay_save_does_not_corrupt_lex_index() {
             as_of_frame: None,
             as_of_ts: None,
             no_sketch: false,
+            reranker: None,
+            filter: None,
+            fusion: None,
         })
         .unwrap();
 
@@ -82,3 +88,59 @@ fn replay_save_does_not_corrupt_lex_index() {
     let sessions = reopened.list_sessions();
     assert_eq!(sessions.len(), 1, "expected one recorded replay session");
 }
+
+#[test]
+#[cfg(all(feature = "lex", feature = "replay"))]
+fn vacuum_relocates_replay_segment() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("replay_vacuum.mv2");
+
+    {
+        let mut mem = Memvid::create(&path).unwrap();
+        mem.enable_lex().unwrap();
+        for (uri, text) in [
+            ("mv2://doc/0", "stale draft"),
+            ("mv2://doc/1", "climate notes"),
+        ] {
+            let opts = PutOptions {
+                uri: Some(uri.to_string()),
+                ..Default::default()
+            };
+            mem.put_bytes_with_options(text.as_bytes(), opts).unwrap();
+            mem.commit().unwrap();
+        }
+
+        mem.start_session(Some("Test".to_string()), None).unwrap();
+        mem.end_session().unwrap();
+        mem.commit().unwrap();
+        mem.save_replay_sessions().unwrap();
+        mem.commit().unwrap();
+
+        let stale = mem.frame_by_uri("mv2://doc/0").unwrap().id;
+        mem.delete_frame(stale).unwrap();
+        mem.vacuum().unwrap();
+    }
+
+    let mut reopened = Memvid::open_read_only(&path).unwrap();
+    reopened.load_replay_sessions().unwrap();
+    assert_eq!(reopened.list_sessions().len(), 1);
+    let results = reopened
+        .search(SearchRequest {
+            query: "climate".to_string(),
+            top_k: 5,
+            snippet_chars: 120,
+            uri: None,
+            scope: None,
+            cursor: None,
+            #[cfg(feature = "temporal_track")]
+            temporal: None,
+            as_of_frame: None,
+            as_of_ts: None,
+            no_sketch: false,
+            reranker: None,
+            filter: None,
+            fusion: None,
+        })
+        .unwrap();
+    assert_eq!(results.hits.len(), 1);
+}
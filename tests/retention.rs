@@ -0,0 +1,225 @@
+//! Integration tests for retention policies.
+//! Tests: max-age expiry, keep-last-N versions, persistence, validation
+
+mod common;
+
+use memvid_core::{
+    FilterExpr, FilterField, FrameRole, FrameStatus, Memvid, MemvidError, PutOptions,
+    RetentionPolicy, RetentionRule,
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tempfile::TempDir;
+
+const DAY: u64 = 86_400;
+
+fn days_ago(days: u64) -> i64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    i64::try_from(now - days * DAY).unwrap()
+}
+
+fn put_at(mem: &mut Memvid, uri: &str, track: &str, timestamp: i64, text: &str) {
+    let opts = PutOptions {
+        track: Some(track.to_string()),
+        timestamp: Some(timestamp),
+        ..Default::default()
+    };
+    common::put_with(mem, uri, text, opts);
+}
+
+/// Test max-age rules expire only in-scope frames, chunks included.
+#[test]
+fn max_age_expires_scoped_frames() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+    // Range bounds left unset must survive the TOC encoding.
+    let scope = FilterExpr::and(vec![
+        FilterExpr::eq(FilterField::Track, "chat"),
+        FilterExpr::range(FilterField::Timestamp, Some("0"), None),
+    ]);
+
+    {
+        let mut mem = Memvid::create(&path).unwrap();
+        put_at(
+            &mut mem,
+            "mv2://chat/old",
+            "chat",
+            days_ago(120),
+            "old chat",
+        );
+        put_at(
+            &mut mem,
+            "mv2://chat/new",
+            "chat",
+            days_ago(5),
+            "recent chat",
+        );
+        put_at(
+            &mut mem,
+            "mv2://docs/old",
+            "docs",
+            days_ago(400),
+            "old manual",
+        );
+        let long_chat = "stale transcript line. ".repeat(400);
+        put_at(
+            &mut mem,
+            "mv2://chat/long",
+            "chat",
+            days_ago(200),
+            &long_chat,
+        );
+        mem.set_retention_policy(RetentionPolicy::new(vec![
+            RetentionRule::max_age("chat-90d", 90 * DAY).with_scope(scope.clone()),
+        ]))
+        .unwrap();
+        mem.commit().unwrap();
+    }
+
+    let mut mem = Memvid::open(&path).unwrap();
+    assert_eq!(mem.retention_policy().rules.len(), 1);
+    assert_eq!(mem.retention_policy().rules[0].scope.as_ref(), Some(&scope));
+    let long_id = mem.frame_by_uri("mv2://chat/long").unwrap().id;
+    let report = mem.apply_retention().unwrap();
+    assert_eq!(report.rules[0].expired, 2);
+    assert!(report.vacuum.is_none());
+    // Chunks of the long transcript are tombstoned with their parent.
+    assert!(report.tombstoned.len() > 2, "{report:?}");
+
+    let status = |mem: &Memvid, uri: &str| mem.frame_by_uri(uri).unwrap().status;
+    assert_eq!(status(&mem, "mv2://chat/old"), FrameStatus::Deleted);
+    assert_eq!(status(&mem, "mv2://chat/new"), FrameStatus::Active);
+    assert_eq!(status(&mem, "mv2://docs/old"), FrameStatus::Active);
+    assert_eq!(
+        mem.frame_by_id(long_id).unwrap().status,
+        FrameStatus::Deleted
+    );
+    for &frame_id in &report.tombstoned {
+        assert_eq!(
+            mem.frame_by_id(frame_id).unwrap().status,
+            FrameStatus::Deleted
+        );
+    }
+
+    // A second pass finds nothing left to expire.
+    assert!(mem.apply_retention().unwrap().tombstoned.is_empty());
+}
+
+/// Test keep-versions rules trim superseded history and can compact afterwards.
+#[test]
+fn keep_versions_trims_history() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    let mut mem = Memvid::create(&path).unwrap();
+    let opts = PutOptions {
+        uri: Some("mv2://profile".to_string()),
+        extra_metadata: [("tenant".to_string(), "acme".to_string())].into(),
+        ..Default::default()
+    };
+    mem.put_bytes_with_options(b"profile v1", opts).unwrap();
+    mem.commit().unwrap();
+    for version in 2..=4 {
+        let current = mem.frame_by_uri("mv2://profile").unwrap().id;
+        mem.update_frame(
+            current,
+            Some(format!("profile v{version}").into_bytes()),
+            PutOptions::default(),
+            None,
+        )
+        .unwrap();
+        mem.commit().unwrap();
+    }
+    let head = mem.frame_by_uri("mv2://profile").unwrap();
+
+    mem.set_retention_policy(
+        RetentionPolicy::new(vec![
+            RetentionRule::keep_versions("last-2", 2)
+                .with_scope(FilterExpr::eq(FilterField::metadata("tenant"), "acme")),
+        ])
+        .with_vacuum(true),
+    )
+    .unwrap();
+    let report = mem.apply_retention().unwrap();
+    assert_eq!(report.rules[0].expired, 2);
+    assert!(report.vacuum.is_some());
+
+    let head_after = mem.frame_by_uri("mv2://profile").unwrap();
+    assert_eq!(head_after.id, head.id);
+    let previous = mem.frame_by_id(head.supersedes.unwrap()).unwrap();
+    assert_eq!(previous.status, FrameStatus::Superseded);
+    let oldest = mem.frame_by_id(previous.supersedes.unwrap()).unwrap();
+    assert_eq!(oldest.status, FrameStatus::Deleted);
+    assert_eq!(
+        mem.frame_canonical_payload(head.id).unwrap(),
+        b"profile v4".to_vec()
+    );
+}
+
+/// Test role scopes expire matching children without their parent document.
+#[test]
+fn role_scope_expires_chunks_only() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    let mut mem = Memvid::create(&path).unwrap();
+    let long_text = "archived transcript line. ".repeat(400);
+    put_at(
+        &mut mem,
+        "mv2://chat/long",
+        "chat",
+        days_ago(200),
+        &long_text,
+    );
+    put_at(
+        &mut mem,
+        "mv2://chat/recent",
+        "chat",
+        days_ago(2),
+        &long_text,
+    );
+    mem.commit().unwrap();
+    let parent = mem.frame_by_uri("mv2://chat/long").unwrap().id;
+    let chunk_ids = |mem: &Memvid, parent| {
+        (0..mem.frame_count() as u64)
+            .filter_map(|id| mem.frame_by_id(id).ok())
+            .filter(|frame| frame.parent_id == Some(parent))
+            .inspect(|frame| assert_eq!(frame.role, FrameRole::DocumentChunk))
+            .map(|frame| frame.id)
+            .collect::<Vec<_>>()
+    };
+    let chunks = chunk_ids(&mem, parent);
+    assert!(!chunks.is_empty());
+
+    mem.set_retention_policy(RetentionPolicy::new(vec![
+        RetentionRule::max_age("chunks-90d", 90 * DAY)
+            .with_scope(FilterExpr::eq(FilterField::Role, "document_chunk")),
+    ]))
+    .unwrap();
+    let report = mem.apply_retention().unwrap();
+    assert_eq!(report.rules[0].expired, chunks.len());
+    assert_eq!(report.tombstoned, chunks);
+    assert_eq!(mem.frame_by_id(parent).unwrap().status, FrameStatus::Active);
+    let recent = mem.frame_by_uri("mv2://chat/recent").unwrap().id;
+    for chunk in chunk_ids(&mem, recent) {
+        assert_eq!(mem.frame_by_id(chunk).unwrap().status, FrameStatus::Active);
+    }
+}
+
+/// Test rules that would delete every version are rejected.
+#[test]
+fn keep_zero_versions_is_rejected() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    let mut mem = Memvid::create(&path).unwrap();
+    let err = mem
+        .set_retention_policy(RetentionPolicy::new(vec![RetentionRule::keep_versions(
+            "none", 0,
+        )]))
+        .unwrap_err();
+    assert!(matches!(err, MemvidError::InvalidRetention { .. }));
+    assert!(mem.retention_policy().is_empty());
+}
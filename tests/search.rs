@@ -1,8 +1,13 @@
 //! Integration tests for Memvid search operations.
-//! Tests: search (lex), timeline queries
+//! Tests: search (lex), audio hit ranges, ask synthesis, timeline queries
 
-use memvid_core::{Memvid, PutOptions, SearchRequest, TimelineQuery};
+use memvid_core::{
+    AnswerSynthesizer, AskMode, AskRequest, AudioSegmentMetadata, DocAudioMetadata, DocMetadata,
+    FilterExpr, FilterField, Memvid, PutOptions, Reranker, RerankerDocument, RerankerKind,
+    RerankerResult, SearchRequest, SynthesizedAnswer, TimelineQuery, VecEmbedder,
+};
 use std::num::NonZeroU64;
+use std::sync::Arc;
 use tempfile::TempDir;
 
 /// Helper to create a memory with searchable content.
@@ -76,6 +81,9 @@ fn search_basic_query() {
             as_of_frame: None,
             as_of_ts: None,
             no_sketch: false,
+            reranker: None,
+            filter: None,
+            fusion: None,
         })
         .unwrap();
 
@@ -111,6 +119,9 @@ fn search_multiple_results() {
             as_of_frame: None,
             as_of_ts: None,
             no_sketch: false,
+            reranker: None,
+            filter: None,
+            fusion: None,
         })
         .unwrap();
 
@@ -163,6 +174,9 @@ fn search_respects_top_k() {
             as_of_frame: None,
             as_of_ts: None,
             no_sketch: false,
+            reranker: None,
+            filter: None,
+            fusion: None,
         })
         .unwrap();
 
@@ -194,6 +208,9 @@ fn search_with_scope() {
             as_of_frame: None,
             as_of_ts: None,
             no_sketch: false,
+            reranker: None,
+            filter: None,
+            fusion: None,
         })
         .unwrap();
 
@@ -229,6 +246,9 @@ fn search_returns_snippets() {
             as_of_frame: None,
             as_of_ts: None,
             no_sketch: false,
+            reranker: None,
+            filter: None,
+            fusion: None,
         })
         .unwrap();
 
@@ -239,6 +259,82 @@ fn search_returns_snippets() {
     assert!(!hit.text.is_empty(), "Hit should include text snippet");
 }
 
+/// Test hits on frames ingested from audio expose the recording time range.
+#[test]
+#[cfg(feature = "lex")]
+fn search_hits_expose_audio_range() {
+    let dir = TempDir::new().unwrap();
+    let mut mem = Memvid::create(dir.path().join("test.mv2")).unwrap();
+    mem.enable_lex().unwrap();
+
+    let segment = |start_seconds: f32, end_seconds: f32| AudioSegmentMetadata {
+        start_seconds,
+        end_seconds,
+        label: None,
+    };
+    let recordings = [
+        (
+            "mv2://meetings/standup#page-2",
+            "the rollout of the billing service slipped a week",
+            DocAudioMetadata {
+                duration_secs: Some(600.0),
+                segments: vec![segment(62.5, 70.0), segment(70.0, 81.25)],
+                ..Default::default()
+            },
+        ),
+        (
+            "mv2://memos/billing",
+            "billing memo recorded on the train",
+            DocAudioMetadata {
+                duration_secs: Some(42.0),
+                ..Default::default()
+            },
+        ),
+    ];
+    for (uri, text, audio) in recordings {
+        let opts = PutOptions {
+            uri: Some(uri.to_string()),
+            search_text: Some(text.to_string()),
+            metadata: Some(DocMetadata {
+                mime: Some("audio/mpeg".into()),
+                audio: Some(audio),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        mem.put_bytes_with_options(text.as_bytes(), opts).unwrap();
+    }
+    mem.put_bytes(b"billing notes typed by hand").unwrap();
+    mem.commit().unwrap();
+
+    let request = SearchRequest {
+        top_k: 10,
+        ..rerank_request("billing", None)
+    };
+    let results = mem.search(request).unwrap();
+    let range_of = |uri: &str| {
+        results
+            .hits
+            .iter()
+            .find(|hit| hit.uri == uri)
+            .and_then(|hit| hit.metadata.as_ref())
+            .map(|metadata| metadata.audio_range.clone())
+            .expect("hit")
+    };
+    assert_eq!(
+        range_of("mv2://meetings/standup#page-2"),
+        Some(segment(62.5, 81.25))
+    );
+    assert_eq!(range_of("mv2://memos/billing"), Some(segment(0.0, 42.0)));
+    assert!(
+        results
+            .hits
+            .iter()
+            .filter_map(|hit| hit.metadata.as_ref())
+            .any(|metadata| metadata.audio_range.is_none())
+    );
+}
+
 /// Test search with no results.
 #[test]
 #[cfg(feature = "lex")]
@@ -262,6 +358,9 @@ fn search_no_results() {
             as_of_frame: None,
             as_of_ts: None,
             no_sketch: false,
+            reranker: None,
+            filter: None,
+            fusion: None,
         })
         .unwrap();
 
@@ -295,6 +394,9 @@ fn search_empty_memory() {
             as_of_frame: None,
             as_of_ts: None,
             no_sketch: false,
+            reranker: None,
+            filter: None,
+            fusion: None,
         })
         .unwrap();
 
@@ -305,6 +407,600 @@ fn search_empty_memory() {
     );
 }
 
+/// Build a lexical-only ask request.
+fn ask_request(question: &str) -> AskRequest {
+    AskRequest {
+        question: question.to_string(),
+        top_k: 3,
+        snippet_chars: 200,
+        uri: None,
+        scope: None,
+        cursor: None,
+        start: None,
+        end: None,
+        #[cfg(feature = "temporal_track")]
+        temporal: None,
+        context_only: false,
+        mode: AskMode::Lex,
+        as_of_frame: None,
+        as_of_ts: None,
+        adaptive: None,
+        reranker: None,
+        filter: None,
+    }
+}
+
+/// Synthesizer that echoes the first fragment's URI, or fails on demand.
+struct EchoSynthesizer {
+    fail: bool,
+}
+
+impl AnswerSynthesizer for EchoSynthesizer {
+    fn kind(&self) -> &'static str {
+        "echo"
+    }
+
+    fn synthesize(
+        &self,
+        _question: &str,
+        fragments: &[memvid_core::types::AskContextFragment],
+    ) -> memvid_core::Result<Option<SynthesizedAnswer>> {
+        if self.fail {
+            return Err(memvid_core::MemvidError::SynthesisFailed {
+                reason: "stub failure".into(),
+            });
+        }
+        Ok(fragments.first().map(|fragment| SynthesizedAnswer {
+            text: format!("{} [1]", fragment.uri),
+            cited: vec![1],
+        }))
+    }
+}
+
+/// Test ask routes context through a custom synthesizer and falls back on failure.
+#[test]
+#[cfg(feature = "lex")]
+fn ask_uses_custom_synthesizer() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    create_searchable_memory(&path);
+
+    let mut mem = Memvid::open_read_only(&path).unwrap();
+    let response = mem
+        .ask_with_synthesizer(
+            ask_request("quantum mechanics"),
+            None::<&dyn VecEmbedder>,
+            &EchoSynthesizer { fail: false },
+        )
+        .unwrap();
+    let top_uri = response.context_fragments[0].uri.clone();
+    assert_eq!(response.answer, Some(format!("{top_uri} [1]")));
+    assert_eq!(response.citations[0].index, 1);
+
+    let fallback = mem
+        .ask_with_synthesizer(
+            ask_request("quantum mechanics"),
+            None::<&dyn VecEmbedder>,
+            &EchoSynthesizer { fail: true },
+        )
+        .unwrap();
+    let answer = fallback.answer.expect("extractive fallback answer");
+    assert!(answer.contains("Quantum mechanics"));
+    assert!(answer.contains("[1]"));
+}
+
+/// Build a search request with an optional reranker.
+fn rerank_request(query: &str, reranker: Option<RerankerKind>) -> SearchRequest {
+    SearchRequest {
+        query: query.to_string(),
+        top_k: 2,
+        snippet_chars: 200,
+        uri: None,
+        scope: None,
+        cursor: None,
+        #[cfg(feature = "temporal_track")]
+        temporal: None,
+        as_of_frame: None,
+        as_of_ts: None,
+        no_sketch: false,
+        reranker,
+        filter: None,
+        fusion: None,
+    }
+}
+
+/// Reranker that scores documents by their reversed candidate position.
+struct ReverseReranker;
+
+impl Reranker for ReverseReranker {
+    fn kind(&self) -> &'static str {
+        "reverse"
+    }
+
+    fn rerank(
+        &self,
+        _query: &str,
+        documents: &[RerankerDocument],
+        top_k: usize,
+    ) -> memvid_core::Result<Vec<RerankerResult>> {
+        Ok(documents
+            .iter()
+            .rev()
+            .take(top_k)
+            .enumerate()
+            .map(|(idx, doc)| RerankerResult {
+                id: doc.id,
+                score: 1.0 / (idx + 1) as f32,
+                original_rank: documents.len() - idx,
+                new_rank: idx + 1,
+            })
+            .collect())
+    }
+}
+
+/// Test search applies the built-in BM25 reranker and rewrites scores and ranks.
+#[test]
+#[cfg(feature = "lex")]
+fn search_with_bm25_reranker() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    create_searchable_memory(&path);
+
+    let mut mem = Memvid::open_read_only(&path).unwrap();
+    let response = mem
+        .search(rerank_request("mechanics", Some(RerankerKind::Bm25)))
+        .unwrap();
+
+    assert_eq!(response.hits.len(), 2);
+    assert_eq!(response.params.top_k, 2);
+    for (idx, hit) in response.hits.iter().enumerate() {
+        assert_eq!(hit.rank, idx + 1);
+        let score = hit.score.expect("reranked hits carry scores");
+        assert!((0.0..=1.0).contains(&score));
+    }
+    assert!(response.hits[0].score >= response.hits[1].score);
+    assert!(response.hits[0].text.to_lowercase().contains("mechanics"));
+}
+
+/// Test registered rerankers drive ordering and unregistered LLM rerankers fail.
+#[test]
+#[cfg(feature = "lex")]
+fn search_with_registered_reranker() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    create_searchable_memory(&path);
+
+    let mut mem = Memvid::open_read_only(&path).unwrap();
+    let err = mem
+        .search(rerank_request("mechanics", Some(RerankerKind::Llm)))
+        .unwrap_err();
+    assert!(matches!(err, memvid_core::MemvidError::RerankFailed { .. }));
+
+    let baseline = mem.search(rerank_request("mechanics", None)).unwrap();
+    mem.register_reranker(RerankerKind::Llm, Arc::new(ReverseReranker));
+    let reranked = mem
+        .search(rerank_request("mechanics", Some(RerankerKind::Llm)))
+        .unwrap();
+
+    assert_eq!(baseline.hits.len(), 2);
+    assert_eq!(reranked.hits.len(), 2);
+    assert_eq!(reranked.hits[0].frame_id, baseline.hits[1].frame_id);
+    assert_eq!(reranked.hits[1].frame_id, baseline.hits[0].frame_id);
+
+    let mut request = ask_request("mechanics");
+    request.reranker = Some(RerankerKind::Bm25);
+    let answer = mem.ask(request, None::<&dyn VecEmbedder>).unwrap();
+    assert!(!answer.context_fragments.is_empty());
+}
+
+/// Reranker that, like a capped cross-encoder, only scores part of its input.
+struct LastOnlyReranker;
+
+impl Reranker for LastOnlyReranker {
+    fn kind(&self) -> &'static str {
+        "last-only"
+    }
+
+    fn rerank(
+        &self,
+        _query: &str,
+        documents: &[RerankerDocument],
+        _top_k: usize,
+    ) -> memvid_core::Result<Vec<RerankerResult>> {
+        Ok(documents
+            .last()
+            .map(|doc| RerankerResult {
+                id: doc.id,
+                score: 1.0,
+                original_rank: documents.len(),
+                new_rank: 1,
+            })
+            .into_iter()
+            .collect())
+    }
+}
+
+/// Test candidates the reranker leaves unscored still fill `top_k` in first-stage order.
+#[test]
+#[cfg(feature = "lex")]
+fn reranker_keeps_unscored_candidates() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    create_searchable_memory(&path);
+
+    let mut mem = Memvid::open_read_only(&path).unwrap();
+    let baseline = mem.search(rerank_request("mechanics", None)).unwrap();
+    mem.register_reranker(RerankerKind::Llm, Arc::new(LastOnlyReranker));
+    let reranked = mem
+        .search(rerank_request("mechanics", Some(RerankerKind::Llm)))
+        .unwrap();
+
+    assert_eq!(baseline.hits.len(), 2);
+    assert_eq!(reranked.hits.len(), 2);
+    assert_eq!(reranked.hits[1].frame_id, baseline.hits[0].frame_id);
+    assert_eq!(reranked.hits[1].rank, 2);
+}
+
+/// Reranker that puts documents mentioning a keyword first.
+struct KeywordReranker(&'static str);
+
+impl Reranker for KeywordReranker {
+    fn kind(&self) -> &'static str {
+        "keyword"
+    }
+
+    fn rerank(
+        &self,
+        _query: &str,
+        documents: &[RerankerDocument],
+        top_k: usize,
+    ) -> memvid_core::Result<Vec<RerankerResult>> {
+        let mut ranked: Vec<(usize, &RerankerDocument)> = documents.iter().enumerate().collect();
+        ranked.sort_by_key(|(_, doc)| !doc.text.contains(self.0));
+        Ok(ranked
+            .into_iter()
+            .take(top_k)
+            .enumerate()
+            .map(|(idx, (original, doc))| RerankerResult {
+                id: doc.id,
+                score: 1.0 / (idx + 1) as f32,
+                original_rank: original + 1,
+                new_rank: idx + 1,
+            })
+            .collect())
+    }
+}
+
+/// Test the ask reranker decides the final order even after semantic re-ranking.
+#[test]
+#[cfg(feature = "lex")]
+fn ask_reranked_order_survives_semantic_ranking() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    let mut mem = Memvid::create(&path).unwrap();
+    mem.enable_lex().unwrap();
+    mem.enable_vec().unwrap();
+    for (uri, text, embedding) in [
+        (
+            "mv2://notes/borrow",
+            "Rust borrow checker guide",
+            [1.0, 0.0],
+        ),
+        (
+            "mv2://notes/gc",
+            "Rust garbage collection guide",
+            [0.0, 1.0],
+        ),
+    ] {
+        let opts = PutOptions {
+            uri: Some(uri.to_string()),
+            search_text: Some(text.to_string()),
+            ..Default::default()
+        };
+        mem.put_with_embedding_and_options(text.as_bytes(), embedding.to_vec(), opts)
+            .unwrap();
+    }
+    mem.commit().unwrap();
+
+    let mut request = ask_request("rust guide");
+    request.mode = AskMode::Hybrid;
+    request.context_only = true;
+    let semantic = mem.ask(request.clone(), Some(&AxisEmbedder)).unwrap();
+    assert_eq!(semantic.context_fragments[0].uri, "mv2://notes/borrow");
+
+    mem.register_reranker(RerankerKind::Llm, Arc::new(KeywordReranker("garbage")));
+    request.reranker = Some(RerankerKind::Llm);
+    let reranked = mem.ask(request, Some(&AxisEmbedder)).unwrap();
+    let uris: Vec<&str> = reranked
+        .context_fragments
+        .iter()
+        .map(|fragment| fragment.uri.as_str())
+        .collect();
+    assert_eq!(uris, vec!["mv2://notes/gc", "mv2://notes/borrow"]);
+}
+
+/// Create a memory whose documents carry tenant and doc type metadata; the vector is
+/// identical for every document so only the filter decides what is returned.
+fn create_tenant_memory(path: &std::path::Path) {
+    let mut mem = Memvid::create(path).unwrap();
+    mem.enable_lex().unwrap();
+    mem.enable_vec().unwrap();
+
+    for i in 0..24 {
+        let tenant = if i % 6 == 0 { "globex" } else { "acme" };
+        let doc_type = if i % 2 == 0 { "invoice" } else { "memo" };
+        let content = format!("Quarterly report number {i} for {tenant}");
+        let mut opts = PutOptions {
+            uri: Some(format!("mv2://{tenant}/{i}")),
+            search_text: Some(content.clone()),
+            tags: vec![doc_type.to_string()],
+            ..Default::default()
+        };
+        opts.extra_metadata
+            .insert("tenant".to_string(), tenant.to_string());
+        opts.extra_metadata
+            .insert("amount".to_string(), (i * 10).to_string());
+        mem.put_with_embedding_and_options(content.as_bytes(), vec![1.0, 0.0], opts)
+            .unwrap();
+    }
+
+    mem.commit().unwrap();
+}
+
+/// Test metadata filters are applied before ranking so `top_k` is filled with matches.
+#[test]
+#[cfg(feature = "lex")]
+fn search_with_metadata_filter() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    create_tenant_memory(&path);
+
+    let mut mem = Memvid::open_read_only(&path).unwrap();
+    let tenant = FilterExpr::eq(FilterField::metadata("tenant"), "globex");
+    let mut request = rerank_request("quarterly report", None);
+    request.top_k = 3;
+    request.filter = Some(tenant.clone());
+    let response = mem.search(request).unwrap();
+    assert_eq!(response.hits.len(), 3, "top_k should be filled by matches");
+    assert!(
+        response
+            .hits
+            .iter()
+            .all(|hit| hit.uri.starts_with("mv2://globex/"))
+    );
+
+    let mut request = rerank_request("quarterly report", None);
+    request.top_k = 20;
+    request.filter = Some(FilterExpr::and(vec![
+        FilterExpr::negate(tenant.clone()),
+        FilterExpr::eq(FilterField::Tag, "invoice"),
+        FilterExpr::range(FilterField::metadata("amount"), Some("50"), Some("150")),
+    ]));
+    let response = mem.search(request).unwrap();
+    let mut uris: Vec<String> = response.hits.iter().map(|hit| hit.uri.clone()).collect();
+    uris.sort();
+    assert_eq!(
+        uris,
+        vec!["mv2://acme/10", "mv2://acme/14", "mv2://acme/8"],
+        "only non-globex invoices with 50 <= amount <= 150"
+    );
+
+    let hits = mem
+        .search_vec_filtered(&[1.0, 0.0], 2, Some(&tenant))
+        .unwrap();
+    assert_eq!(hits.len(), 2);
+    assert!(hits.iter().all(|hit| hit.frame_id % 6 == 0));
+
+    let mut ask = ask_request("quarterly report");
+    ask.filter = Some(tenant);
+    let response = mem.ask(ask, None::<&dyn VecEmbedder>).unwrap();
+    assert!(!response.retrieval.hits.is_empty());
+    assert!(
+        response
+            .retrieval
+            .hits
+            .iter()
+            .all(|hit| hit.uri.starts_with("mv2://globex/"))
+    );
+}
+
+/// Test cursors page through every lexical and vector result exactly once.
+#[test]
+#[cfg(feature = "lex")]
+fn search_cursor_pages_through_results() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    create_tenant_memory(&path);
+
+    let mut mem = Memvid::open_read_only(&path).unwrap();
+    let mut request = rerank_request("quarterly report", None);
+    request.top_k = 5;
+    let mut seen = Vec::new();
+    let mut pages = 0;
+    loop {
+        let response = mem.search(request.clone()).unwrap();
+        assert!(response.hits.len() <= 5);
+        seen.extend(response.hits.iter().map(|hit| hit.frame_id));
+        pages += 1;
+        match response.next_cursor {
+            Some(cursor) => {
+                assert!(cursor.parse::<usize>().is_err(), "cursor should be opaque");
+                request.cursor = Some(cursor);
+            }
+            None => break,
+        }
+    }
+    assert!(pages > 1);
+    assert_eq!(
+        seen.len(),
+        24,
+        "every matching document is paged exactly once"
+    );
+    let mut unique = seen.clone();
+    unique.sort_unstable();
+    unique.dedup();
+    assert_eq!(unique.len(), seen.len(), "pages must not repeat hits");
+
+    let mut request = rerank_request("quarterly report", None);
+    request.top_k = 10;
+    let page_one = mem.vec_search_request(&request, &[1.0, 0.0]).unwrap();
+    request.cursor = page_one.next_cursor.clone();
+    let page_two = mem.vec_search_request(&request, &[1.0, 0.0]).unwrap();
+    request.cursor = page_two.next_cursor.clone();
+    let page_three = mem.vec_search_request(&request, &[1.0, 0.0]).unwrap();
+    assert_eq!(page_one.hits.len(), 10);
+    assert_eq!(page_two.hits.len(), 10);
+    assert_eq!(page_three.hits.len(), 4);
+    assert!(page_three.next_cursor.is_none());
+    let mut all: Vec<u64> = [&page_one, &page_two, &page_three]
+        .iter()
+        .flat_map(|page| page.hits.iter().map(|hit| hit.frame_id))
+        .collect();
+    all.sort_unstable();
+    all.dedup();
+    assert_eq!(all.len(), 24);
+}
+
+/// Test cursors are rejected once the memory changes or the request differs.
+#[test]
+#[cfg(feature = "lex")]
+fn search_cursor_rejected_after_changes() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    create_tenant_memory(&path);
+
+    let mut mem = Memvid::open(&path).unwrap();
+    let mut request = rerank_request("quarterly report", None);
+    request.top_k = 5;
+    let cursor = mem.search(request.clone()).unwrap().next_cursor.unwrap();
+
+    let mut other = rerank_request("quarterly", None);
+    other.top_k = 5;
+    other.cursor = Some(cursor.clone());
+    let err = mem.search(other).unwrap_err();
+    assert!(matches!(
+        err,
+        memvid_core::MemvidError::InvalidCursor { .. }
+    ));
+
+    let mut garbage = request.clone();
+    garbage.cursor = Some("5".to_string());
+    assert!(matches!(
+        mem.search(garbage).unwrap_err(),
+        memvid_core::MemvidError::InvalidCursor { .. }
+    ));
+
+    mem.put_bytes_with_options(b"Quarterly report addendum", PutOptions::default())
+        .unwrap();
+    mem.commit().unwrap();
+
+    request.cursor = Some(cursor);
+    let err = mem.search(request).unwrap_err();
+    assert!(
+        matches!(err, memvid_core::MemvidError::InvalidCursor { reason } if reason.contains("memory changed"))
+    );
+}
+
+/// Embedder mapping queries about collection to the second axis.
+struct AxisEmbedder;
+
+impl VecEmbedder for AxisEmbedder {
+    fn embed_query(&self, text: &str) -> memvid_core::Result<Vec<f32>> {
+        if text.contains("collection") {
+            Ok(vec![0.0, 1.0])
+        } else {
+            Ok(vec![1.0, 0.0])
+        }
+    }
+
+    fn embedding_dimension(&self) -> usize {
+        2
+    }
+}
+
+/// Test hybrid search fuses lexical and vector legs and reports per-leg scores.
+#[test]
+#[cfg(feature = "lex")]
+fn search_hybrid_fuses_lexical_and_vector() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.mv2");
+
+    let mut mem = Memvid::create(&path).unwrap();
+    mem.enable_lex().unwrap();
+    mem.enable_vec().unwrap();
+    let docs = [
+        (
+            "mv2://notes/borrow",
+            "Rust borrow checker guide",
+            [1.0, 0.0],
+        ),
+        (
+            "mv2://notes/gc",
+            "Garbage collection in managed runtimes",
+            [0.0, 1.0],
+        ),
+        (
+            "mv2://notes/async",
+            "Rust async runtime internals in Rust",
+            [0.6, 0.8],
+        ),
+    ];
+    for (uri, text, embedding) in docs {
+        let opts = PutOptions {
+            uri: Some(uri.to_string()),
+            search_text: Some(text.to_string()),
+            ..Default::default()
+        };
+        mem.put_with_embedding_and_options(text.as_bytes(), embedding.to_vec(), opts)
+            .unwrap();
+    }
+    mem.commit().unwrap();
+
+    let mut request = rerank_request("rust", None);
+    request.top_k = 10;
+    let response = mem
+        .search_with_embedding(request.clone(), Some(&[0.0, 1.0]))
+        .unwrap();
+    assert_eq!(response.engine, memvid_core::SearchEngineKind::Hybrid);
+    assert_eq!(response.hits.len(), 3);
+    assert_eq!(response.hits[0].uri, "mv2://notes/async");
+    let gc = response
+        .hits
+        .iter()
+        .find(|hit| hit.uri == "mv2://notes/gc")
+        .expect("vector-only hit is fused in");
+    assert!(gc.lexical_score.is_none());
+    assert!(gc.vector_score.is_some());
+    assert!(response.hits[0].lexical_score.is_some());
+    assert!(response.hits[0].vector_score.is_some());
+
+    request.fusion = Some(memvid_core::FusionMethod::Weighted {
+        lexical: 0.0,
+        vector: 1.0,
+    });
+    let vector_only = mem
+        .search_with_embedder(request.clone(), Some(&AxisEmbedder))
+        .unwrap();
+    assert_eq!(vector_only.hits[0].uri, "mv2://notes/borrow");
+
+    request.query = "collection".to_string();
+    let semantic = mem
+        .search_with_embedder(request.clone(), Some(&AxisEmbedder))
+        .unwrap();
+    assert_eq!(semantic.hits[0].uri, "mv2://notes/gc");
+
+    let lexical = mem.search_with_embedding(request, None).unwrap();
+    assert_ne!(lexical.engine, memvid_core::SearchEngineKind::Hybrid);
+}
+
 /// Test timeline query returns ordered results.
 #[test]
 fn timeline_returns_ordered() {
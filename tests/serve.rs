@@ -0,0 +1,141 @@
+//! Drives the `memvid-serve` binary over HTTP.
+#![cfg(feature = "serve")]
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+use memvid_core::{Memvid, PutOptions};
+use serde_json::Value;
+use tempfile::TempDir;
+
+/// A running `memvid-serve`, killed on drop.
+struct ServeProcess {
+    child: Child,
+    addr: String,
+}
+
+impl ServeProcess {
+    fn spawn(path: &Path, flags: &[&str]) -> Self {
+        let addr = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().to_string()
+        };
+        let child = Command::new(env!("CARGO_BIN_EXE_memvid-serve"))
+            .arg(path)
+            .args(["--addr", &addr])
+            .args(flags)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap();
+        let server = Self { child, addr };
+        for _ in 0..200 {
+            if TcpStream::connect(&server.addr).is_ok() {
+                return server;
+            }
+            thread::sleep(Duration::from_millis(25));
+        }
+        panic!("memvid-serve did not start listening on {}", server.addr);
+    }
+
+    /// Send one request and return the status code and JSON body.
+    fn request(&self, method: &str, target: &str, body: &str) -> (u16, Value) {
+        let mut stream = TcpStream::connect(&self.addr).unwrap();
+        write!(
+            stream,
+            "{method} {target} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            self.addr,
+            body.len()
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, payload) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+        (status, serde_json::from_str(payload).unwrap())
+    }
+}
+
+impl Drop for ServeProcess {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn writable_server_round_trip() {
+    let dir = TempDir::new().unwrap();
+    let server = ServeProcess::spawn(&dir.path().join("serve.mv2"), &["--create"]);
+
+    let (status, put) = server.request(
+        "POST",
+        "/put",
+        r#"[
+            {"title": "Rust", "label": "notes", "text": "Rust ownership and borrowing"},
+            {"title": "Go", "label": "notes", "text": "Go channels and goroutines"}
+        ]"#,
+    );
+    assert_eq!(status, 200, "{put}");
+    let frame_ids: Vec<u64> = put["frame_ids"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|id| id.as_u64().unwrap())
+        .collect();
+    assert_eq!(frame_ids.len(), 2);
+
+    let (status, frame) = server.request("GET", &format!("/frames/{}", frame_ids[1]), "");
+    assert_eq!(status, 200, "{frame}");
+    assert!(frame["text"].as_str().unwrap().contains("goroutines"));
+
+    let (status, search) = server.request(
+        "POST",
+        "/search",
+        r#"{"query": "ownership", "top_k": 5, "snippet_chars": 120}"#,
+    );
+    assert_eq!(status, 200, "{search}");
+    assert_eq!(search["hits"][0]["frame_id"].as_u64(), Some(frame_ids[0]));
+
+    let (status, missing) = server.request("GET", "/mesh/entities/C++", "");
+    assert_eq!(status, 404);
+    assert_eq!(missing["error"]["message"], "no entity named C++");
+}
+
+#[test]
+fn read_only_server_follows_writer_commits() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("serve.mv2");
+    let mut writer = Memvid::create(&path).unwrap();
+    writer.enable_lex().unwrap();
+    writer
+        .put_bytes_with_options(b"First note", PutOptions::default())
+        .unwrap();
+    writer.commit().unwrap();
+
+    let server = ServeProcess::spawn(&path, &["--read-only"]);
+    let (status, health) = server.request("GET", "/health", "");
+    assert_eq!(status, 200, "{health}");
+    assert_eq!(health["frames"], 1);
+
+    writer
+        .put_bytes_with_options(b"Second note", PutOptions::default())
+        .unwrap();
+    writer.commit().unwrap();
+
+    let (_, health) = server.request("GET", "/health", "");
+    assert_eq!(health["frames"], 2);
+    assert_eq!(health["generation"].as_u64(), Some(writer.generation()));
+
+    let (status, rejected) = server.request(
+        "POST",
+        "/put",
+        r#"{"title": "Nope", "label": "notes", "text": "read-only"}"#,
+    );
+    assert_eq!(status, 409, "{rejected}");
+}
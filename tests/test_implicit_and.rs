@@ -50,6 +50,9 @@ fn test_implicit_and_precision() -> memvid_core::Result<()> {
         as_of_frame: None,
         as_of_ts: None,
         no_sketch: false,
+        reranker: None,
+        filter: None,
+        fusion: None,
     })?;
 
     assert_eq!(
@@ -103,6 +106,9 @@ fn test_explicit_operators_still_work() -> memvid_core::Result<()> {
         as_of_frame: None,
         as_of_ts: None,
         no_sketch: false,
+        reranker: None,
+        filter: None,
+        fusion: None,
     })?;
 
     assert_eq!(results.hits.len(), 1, "Explicit AND should work");
@@ -120,6 +126,9 @@ fn test_explicit_operators_still_work() -> memvid_core::Result<()> {
         as_of_frame: None,
         as_of_ts: None,
         no_sketch: false,
+        reranker: None,
+        filter: None,
+        fusion: None,
     })?;
 
     assert!(results.hits.len() >= 2, "Explicit OR should work");